@@ -0,0 +1,135 @@
+// src/achievement_gallery.rs
+use bevy::prelude::*;
+use crate::{
+    game::AppState,
+    achievements::{ACHIEVEMENTS, AchievementCondition, AchievementSession, reward_label},
+    items::{AutomaticWeaponLibrary, ItemLibrary},
+    profile::PlayerProfile,
+};
+
+const GALLERY_ENTRY_BG_COLOR: Color = Color::rgba(0.12, 0.12, 0.18, 0.9);
+const GALLERY_ENTRY_UNLOCKED_BG_COLOR: Color = Color::rgba(0.25, 0.2, 0.08, 0.9);
+const GALLERY_PROGRESS_BAR_WIDTH: f32 = 400.0;
+
+pub struct AchievementGalleryPlugin;
+
+impl Plugin for AchievementGalleryPlugin {
+    fn build(&self, app: &mut App) {
+        app .add_systems(OnEnter(AppState::AchievementGallery), setup_achievement_gallery_ui)
+            .add_systems(Update, achievement_gallery_input_system.run_if(in_state(AppState::AchievementGallery)))
+            .add_systems(OnExit(AppState::AchievementGallery), despawn_achievement_gallery_ui);
+    }
+}
+
+#[derive(Component)] struct AchievementGalleryUIRoot;
+#[derive(Component)] struct GalleryBackButton;
+
+fn format_progress(condition: AchievementCondition, current: u32, target: u32) -> String {
+    match condition {
+        AchievementCondition::Survive { .. } => format!("{:02}:{:02} / {:02}:{:02}", current / 60, current % 60, target / 60, target % 60),
+        AchievementCondition::KillEliteWithoutBeingHit(_) => "Not yet achieved".to_string(),
+        _ => format!("{} / {}", current, target),
+    }
+}
+
+fn setup_achievement_gallery_ui(
+    mut commands: Commands, asset_server: Res<AssetServer>, profile: Res<PlayerProfile>, session: Res<AchievementSession>,
+    weapon_library: Res<AutomaticWeaponLibrary>, item_library: Res<ItemLibrary>,
+) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let title_style = TextStyle { font: font.clone(), font_size: 50.0, color: Color::WHITE };
+    let name_style = TextStyle { font: font.clone(), font_size: 22.0, color: Color::WHITE };
+    let detail_style = TextStyle { font: font.clone(), font_size: 15.0, color: Color::rgb(0.8, 0.8, 0.85) };
+    let reward_style = TextStyle { font, font_size: 15.0, color: Color::GOLD };
+    let unlocked_count = ACHIEVEMENTS.iter().filter(|def| profile.achievements.is_unlocked(def.id)).count();
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(10.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.05, 0.05, 0.1, 0.95).into(),
+            ..default()
+        },
+        AchievementGalleryUIRoot,
+        Name::new("AchievementGalleryUIRoot"),
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section("Chronicle of Deeds", title_style));
+        parent.spawn(TextBundle::from_section(format!("{} / {} unlocked", unlocked_count, ACHIEVEMENTS.len()), detail_style.clone()));
+
+        for def in ACHIEVEMENTS.iter() {
+            let unlocked = profile.achievements.is_unlocked(def.id);
+            let (current, target) = profile.achievements.progress(def.condition, &session);
+            parent.spawn(NodeBundle {
+                style: Style {
+                    width: Val::Px(620.0),
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(3.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: if unlocked { GALLERY_ENTRY_UNLOCKED_BG_COLOR.into() } else { GALLERY_ENTRY_BG_COLOR.into() },
+                border_color: BorderColor(if unlocked { Color::GOLD } else { Color::rgba(0.5, 0.5, 0.6, 0.6) }),
+                ..default()
+            }).with_children(|entry| {
+                entry.spawn(TextBundle::from_section(def.name, name_style.clone()));
+                entry.spawn(TextBundle::from_section(def.description, detail_style.clone()));
+                if let Some(reward) = def.reward {
+                    entry.spawn(TextBundle::from_section(reward_label(reward, &weapon_library, &item_library), reward_style.clone()));
+                }
+                let progress_text = if unlocked { "Unlocked".to_string() } else { format_progress(def.condition, current, target) };
+                entry.spawn(TextBundle::from_section(progress_text, detail_style.clone()));
+                // Progress bar: a filled child inside a dark track
+                let fraction = if unlocked { 1.0 } else { current as f32 / target.max(1) as f32 };
+                entry.spawn(NodeBundle {
+                    style: Style { width: Val::Px(GALLERY_PROGRESS_BAR_WIDTH), height: Val::Px(6.0), ..default() },
+                    background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                    ..default()
+                }).with_children(|track| {
+                    track.spawn(NodeBundle {
+                        style: Style { width: Val::Percent(fraction * 100.0), height: Val::Percent(100.0), ..default() },
+                        background_color: Color::GOLD.into(),
+                        ..default()
+                    });
+                });
+            });
+        }
+
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(200.0),
+                    height: Val::Px(45.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.2, 0.2, 0.3, 0.9).into(),
+                border_color: BorderColor(Color::rgba(0.7, 0.7, 0.8, 0.7)),
+                ..default()
+            },
+            GalleryBackButton,
+        )).with_children(|button_parent| {
+            button_parent.spawn(TextBundle::from_section("Back (Esc)", name_style.clone()));
+        });
+    });
+}
+
+fn achievement_gallery_input_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    back_button_query: Query<&Interaction, (Changed<Interaction>, With<GalleryBackButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let back_pressed = back_button_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if back_pressed || keyboard_input.just_pressed(KeyCode::Escape) { next_app_state.set(AppState::MainMenu); }
+}
+
+fn despawn_achievement_gallery_ui(mut commands: Commands, query: Query<Entity, With<AchievementGalleryUIRoot>>) { for entity in query.iter() { commands.entity(entity).despawn_recursive(); } }
@@ -0,0 +1,309 @@
+// src/achievements.rs
+// Achievements are fed by gameplay during a run. Per-run counters live in `AchievementSession` and are
+// folded into the profile's `AchievementRecord` when the run ends, so kills don't rewrite the profile
+// file every frame; unlocks themselves are written straight away.
+use bevy::prelude::*;
+use bevy::app::AppExit;
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use crate::{
+    persistence,
+    game::{AppState, GameState, session_ending},
+    survivor::{Survivor, SurvivorHitEvent},
+    horror::{Horror, HorrorKilledEvent, HorrorType},
+    items::{AutomaticWeaponId, AutomaticWeaponLibrary, ItemId, ItemLibrary},
+    profile::{PlayerProfile, PROFILE_FILE_PATH},
+    audio::{PlaySoundEvent, SoundEffect},
+};
+
+const TOAST_DURATION_SECS: f32 = 4.0;
+const TOAST_TOP_MARGIN: f32 = 70.0;
+const TOAST_SPACING: f32 = 60.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum AchievementId {
+    ShadowlingExterminator,
+    LeechPurge,
+    EnduringWitness,
+    VoidVigil,
+    Ascendant,
+    BehemothUntouched,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementCondition {
+    KillHorrors { horror_type: HorrorType, count: u32 }, // Lifetime total across runs
+    Survive { weapon_id: Option<AutomaticWeaponId>, seconds: u32 }, // In a single run; `None` means any weapon
+    ReachLevel(u32),
+    KillEliteWithoutBeingHit(HorrorType), // The survivor takes no damage between the elite's arrival and its death
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AchievementReward {
+    UnlockWeapon(AutomaticWeaponId), // Also makes the characters starting with it selectable
+    UnlockRelic(ItemId),
+    Lore(u32),
+}
+
+pub struct AchievementDefinition {
+    pub id: AchievementId,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub condition: AchievementCondition,
+    pub reward: Option<AchievementReward>,
+}
+
+pub const ACHIEVEMENTS: [AchievementDefinition; 6] = [
+    AchievementDefinition {
+        id: AchievementId::ShadowlingExterminator, name: "Shadow Exterminator", description: "Slay 1000 Skittering Shadowlings.",
+        condition: AchievementCondition::KillHorrors { horror_type: HorrorType::SkitteringShadowling, count: 1000 },
+        reward: Some(AchievementReward::UnlockWeapon(AutomaticWeaponId(6))),
+    },
+    AchievementDefinition {
+        id: AchievementId::LeechPurge, name: "Leech Purge", description: "Slay 250 Mind Leeches.",
+        condition: AchievementCondition::KillHorrors { horror_type: HorrorType::MindLeech, count: 250 },
+        reward: Some(AchievementReward::Lore(100)),
+    },
+    AchievementDefinition {
+        id: AchievementId::EnduringWitness, name: "Enduring Witness", description: "Survive 10 minutes in a single run.",
+        condition: AchievementCondition::Survive { weapon_id: None, seconds: 600 },
+        reward: Some(AchievementReward::UnlockRelic(ItemId(9))),
+    },
+    AchievementDefinition {
+        id: AchievementId::VoidVigil, name: "Void Vigil", description: "Survive 15 minutes wielding the Void Cannon.",
+        condition: AchievementCondition::Survive { weapon_id: Some(AutomaticWeaponId(2)), seconds: 900 },
+        reward: Some(AchievementReward::UnlockRelic(ItemId(11))),
+    },
+    AchievementDefinition {
+        id: AchievementId::Ascendant, name: "Ascendant", description: "Reach level 40.",
+        condition: AchievementCondition::ReachLevel(40),
+        reward: Some(AchievementReward::UnlockWeapon(AutomaticWeaponId(8))),
+    },
+    AchievementDefinition {
+        id: AchievementId::BehemothUntouched, name: "Untouched by the Behemoth", description: "Defeat an elite Frenzied Behemoth without being hit.",
+        condition: AchievementCondition::KillEliteWithoutBeingHit(HorrorType::FrenziedBehemoth),
+        reward: Some(AchievementReward::UnlockWeapon(AutomaticWeaponId(7))),
+    },
+];
+
+pub fn get_achievement_definition(id: AchievementId) -> &'static AchievementDefinition {
+    ACHIEVEMENTS.iter().find(|def| def.id == id).expect("every AchievementId has a definition")
+}
+
+// Lifetime progress, stored in the profile.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct AchievementRecord {
+    pub unlocked: Vec<AchievementId>,
+    pub horror_kills: HashMap<HorrorType, u32>,
+    pub highest_level: u32,
+    pub longest_survival_secs: HashMap<AutomaticWeaponId, u32>,
+}
+
+impl AchievementRecord {
+    pub fn is_unlocked(&self, id: AchievementId) -> bool { self.unlocked.contains(&id) }
+
+    pub fn merge_session(&mut self, session: &AchievementSession) {
+        for (horror_type, kills) in session.horror_kills.iter() { *self.horror_kills.entry(*horror_type).or_insert(0) += kills; }
+        self.highest_level = self.highest_level.max(session.highest_level);
+        if let Some(weapon_id) = session.weapon_id {
+            let best = self.longest_survival_secs.entry(weapon_id).or_insert(0);
+            *best = (*best).max(session.survived_secs);
+        }
+    }
+
+    // (current, target) for the gallery and unlock checks, counting the run in progress.
+    pub fn progress(&self, condition: AchievementCondition, session: &AchievementSession) -> (u32, u32) {
+        match condition {
+            AchievementCondition::KillHorrors { horror_type, count } => {
+                let kills = self.horror_kills.get(&horror_type).copied().unwrap_or(0) + session.horror_kills.get(&horror_type).copied().unwrap_or(0);
+                (kills.min(count), count)
+            }
+            AchievementCondition::Survive { weapon_id, seconds } => {
+                let recorded_best = match weapon_id {
+                    Some(weapon_id) => self.longest_survival_secs.get(&weapon_id).copied().unwrap_or(0),
+                    None => self.longest_survival_secs.values().copied().max().unwrap_or(0),
+                };
+                let current_run = if weapon_id.is_none() || weapon_id == session.weapon_id { session.survived_secs } else { 0 };
+                (recorded_best.max(current_run).min(seconds), seconds)
+            }
+            AchievementCondition::ReachLevel(level) => (self.highest_level.max(session.highest_level).min(level), level),
+            AchievementCondition::KillEliteWithoutBeingHit(_) => (0, 1), // Only ever completed by the kill itself
+        }
+    }
+}
+
+// Counters for the run in progress; folded into the profile and cleared when the run ends.
+#[derive(Resource, Debug, Default)]
+pub struct AchievementSession {
+    pub horror_kills: HashMap<HorrorType, u32>,
+    pub highest_level: u32,
+    pub weapon_id: Option<AutomaticWeaponId>,
+    pub survived_secs: u32,
+    pub survivor_hits: u32,
+    elite_hits_at_arrival: HashMap<Entity, u32>,
+}
+
+impl AchievementSession {
+    pub fn record_kill(&mut self, horror_type: HorrorType) { *self.horror_kills.entry(horror_type).or_insert(0) += 1; }
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AchievementUnlockedEvent(pub AchievementId);
+
+#[derive(Component)]
+struct AchievementToast { timer: Timer }
+
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app .register_type::<AchievementId>()
+            .register_type::<AchievementRecord>()
+            .init_resource::<AchievementSession>()
+            .add_event::<AchievementUnlockedEvent>()
+            .add_systems(Update, (
+                track_elite_arrivals_system,
+                track_survivor_hits_system,
+                track_horror_kills_system,
+                track_run_progress_system,
+                check_achievements_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(Update, (spawn_achievement_toasts_system, achievement_toast_lifetime_system))
+            .add_systems(OnEnter(AppState::GameOver), commit_achievement_session)
+            .add_systems(OnExit(AppState::Paused), commit_achievement_session.run_if(session_ending))
+            .add_systems(Last, commit_achievement_session_on_exit.run_if(on_event::<AppExit>()));
+    }
+}
+
+fn track_elite_arrivals_system(mut session: ResMut<AchievementSession>, new_horrors: Query<(Entity, &Horror), Added<Horror>>) {
+    for (entity, _) in new_horrors.iter().filter(|(_, horror)| horror.is_elite) {
+        let hits = session.survivor_hits;
+        session.elite_hits_at_arrival.insert(entity, hits);
+    }
+}
+
+// Only bodies and projectiles count; regeneration swings, pool ticks and the like aren't hits
+pub fn track_survivor_hits_system(mut session: ResMut<AchievementSession>, mut events: EventReader<SurvivorHitEvent>) {
+    session.survivor_hits += events.read().count() as u32;
+}
+
+fn track_horror_kills_system(
+    mut events: EventReader<HorrorKilledEvent>,
+    mut session: ResMut<AchievementSession>,
+    mut profile: ResMut<PlayerProfile>,
+    mut unlocked_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    for event in events.read() {
+        session.record_kill(event.horror_type);
+        let Some(hits_at_arrival) = session.elite_hits_at_arrival.remove(&event.entity) else { continue; };
+        if !event.is_elite || hits_at_arrival != session.survivor_hits { continue; }
+        for def in ACHIEVEMENTS.iter() {
+            if def.condition == AchievementCondition::KillEliteWithoutBeingHit(event.horror_type) && !profile.achievements.is_unlocked(def.id) {
+                unlock_achievement(def, &mut profile, &mut unlocked_writer);
+            }
+        }
+    }
+}
+
+fn track_run_progress_system(mut session: ResMut<AchievementSession>, game_state: Res<GameState>, survivor_query: Query<&Survivor>) {
+    let Ok(survivor) = survivor_query.get_single() else { return; };
+    session.highest_level = session.highest_level.max(survivor.level);
+    session.weapon_id = Some(survivor.inherent_weapon_id);
+    session.survived_secs = game_state.game_timer.elapsed_secs() as u32;
+}
+
+fn check_achievements_system(
+    session: Res<AchievementSession>,
+    mut profile: ResMut<PlayerProfile>,
+    mut unlocked_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    for def in ACHIEVEMENTS.iter() {
+        if profile.achievements.is_unlocked(def.id) { continue; }
+        let (current, target) = profile.achievements.progress(def.condition, &session);
+        if current >= target { unlock_achievement(def, &mut profile, &mut unlocked_writer); }
+    }
+}
+
+fn unlock_achievement(def: &AchievementDefinition, profile: &mut PlayerProfile, unlocked_writer: &mut EventWriter<AchievementUnlockedEvent>) {
+    profile.achievements.unlocked.push(def.id);
+    match def.reward {
+        Some(AchievementReward::UnlockWeapon(weapon_id)) => { profile.grant_weapon(weapon_id); }
+        Some(AchievementReward::UnlockRelic(item_id)) => { profile.grant_item(item_id); }
+        Some(AchievementReward::Lore(amount)) => { profile.lore += amount; profile.lifetime_lore_earned += amount; }
+        None => {}
+    }
+    info!("Achievement unlocked: {}", def.name);
+    unlocked_writer.send(AchievementUnlockedEvent(def.id));
+}
+
+pub fn commit_achievement_session(mut session: ResMut<AchievementSession>, mut profile: ResMut<PlayerProfile>) {
+    profile.achievements.merge_session(&session);
+    *session = AchievementSession::default();
+}
+
+// The regular profile save runs in Update and won't get another frame once the app is exiting
+fn commit_achievement_session_on_exit(session: Res<AchievementSession>, mut profile: ResMut<PlayerProfile>, current_app_state: Res<State<AppState>>) {
+    if !matches!(current_app_state.get(), AppState::InGame | AppState::Paused | AppState::LevelUp | AppState::ChestReward | AppState::Merchant) { return; }
+    profile.achievements.merge_session(&session);
+    persistence::save_ron(PROFILE_FILE_PATH, &*profile);
+}
+
+pub fn reward_label(reward: AchievementReward, weapon_library: &AutomaticWeaponLibrary, item_library: &ItemLibrary) -> String {
+    match reward {
+        AchievementReward::UnlockWeapon(weapon_id) => format!("Unlocks weapon: {}", weapon_library.get_weapon_definition(weapon_id).map_or("???", |def| def.name.as_str())),
+        AchievementReward::UnlockRelic(item_id) => format!("Unlocks relic: {}", item_library.get_item_definition(item_id).map_or("???", |def| def.name.as_str())),
+        AchievementReward::Lore(amount) => format!("Grants {} Lore", amount),
+    }
+}
+
+fn spawn_achievement_toasts_system(
+    mut commands: Commands,
+    mut events: EventReader<AchievementUnlockedEvent>,
+    asset_server: Res<AssetServer>,
+    weapon_library: Res<AutomaticWeaponLibrary>,
+    item_library: Res<ItemLibrary>,
+    toast_query: Query<(), With<AchievementToast>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for (toast_index, event) in (toast_query.iter().count()..).zip(events.read()) {
+        let def = get_achievement_definition(event.0);
+        let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+        let reward_text = def.reward.map(|reward| reward_label(reward, &weapon_library, &item_library)).unwrap_or_default();
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation));
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(TOAST_TOP_MARGIN + toast_index as f32 * TOAST_SPACING),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-180.0)), // Centers the fixed-width toast
+                    width: Val::Px(360.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.1, 0.08, 0.15, 0.92).into(),
+                border_color: BorderColor(Color::GOLD),
+                z_index: ZIndex::Global(20),
+                ..default()
+            },
+            AchievementToast { timer: Timer::from_seconds(TOAST_DURATION_SECS, TimerMode::Once) },
+            Name::new(format!("AchievementToast_{}", def.name)),
+        )).with_children(|parent| {
+            parent.spawn(TextBundle::from_section(format!("Achievement Unlocked: {}", def.name), TextStyle { font: font.clone(), font_size: 20.0, color: Color::GOLD }));
+            if !reward_text.is_empty() {
+                parent.spawn(TextBundle::from_section(reward_text, TextStyle { font, font_size: 15.0, color: Color::rgb(0.85, 0.85, 0.9) }));
+            }
+        });
+    }
+}
+
+fn achievement_toast_lifetime_system(mut commands: Commands, time: Res<Time>, mut toast_query: Query<(Entity, &mut AchievementToast)>) {
+    for (entity, mut toast) in toast_query.iter_mut() {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() { commands.entity(entity).despawn_recursive(); }
+    }
+}
@@ -0,0 +1,439 @@
+// src/arena.rs
+// Cyclopean ruins scattered over the endless floor. The world is cut into square chunks, and each chunk's pillars,
+// walls and pits are generated from the run's seed and the chunk's coordinates alone, so the same seed always lays
+// out the same ruins no matter which way the survivor wanders. Chunks stream in around the survivor and are dropped
+// again once they fall far enough behind. Everything that walks is pushed back out of the ruins (flyers drift over
+// pits), horrors slide around them toward the survivor, and projectiles either stop on stone or ricochet off it.
+// Chests, relics and nests that land in the ruins are moved out onto open ground where the survivor can reach them.
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy::utils::HashMap;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use crate::{
+    components::{Velocity, Damage, Lifetime},
+    survivor::{Survivor, SURVIVOR_SIZE, survivor_movement},
+    game::{AppState, RunSeed, session_ending},
+    horror::{Horror, HorrorType, HorrorProjectile, horror_movement_system, horror_projectile_movement_system},
+    crowd::horror_crowd_system,
+    automatic_projectiles::{AutomaticProjectile, handle_bounce_stat_updates, projectile_movement_system},
+    weapon_systems::{
+        ReturningProjectileComponent, ReturningProjectileState, LobbedProjectileComponent, returning_projectile_system,
+        lobbed_projectile_system, spawn_pool_and_despawn_projectile,
+    },
+    skills::SkillProjectile,
+    chests::RewardChest,
+    items::ItemDrop,
+    nests::Nest,
+    boss::Boss,
+    merchant::Merchant,
+};
+
+pub const ARENA_CHUNK_SIZE: f32 = 1024.0;
+const ARENA_STREAM_RADIUS: i32 = 2; // Chunks kept loaded on each side of the survivor's chunk
+const ARENA_UNLOAD_RADIUS: i32 = 3; // A chunk is only dropped past this, so walking along a border doesn't thrash
+const SPAWN_CLEARING_RADIUS: f32 = 320.0; // Nothing is built where the survivor starts
+const CHUNK_MARGIN: f32 = 240.0; // Obstacles stay this far inside their chunk, so none straddles a border
+const OBSTACLE_GAP: f32 = 90.0; // Room left between obstacles for horrors to squeeze through
+const MAX_PLACEMENT_ATTEMPTS: u32 = 12;
+const PIT_Z_POS: f32 = -5.0; // Above the floor tiles, below everything else
+const RUIN_Z_POS: f32 = 0.2;
+const SLIDE_SPEED_FACTOR: f32 = 0.8; // How much of its speed a blocked horror keeps while edging around an obstacle
+const SIGHT_STEP: f32 = 12.0; // Thinner than the thinnest wall, so a sight line can't step over one
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ObstacleKind { Pillar, Wall, Pit }
+
+impl ObstacleKind {
+    // Pits swallow walkers but projectiles fly straight over them
+    pub fn blocks_projectiles(self) -> bool { !matches!(self, ObstacleKind::Pit) }
+    // Flyers pass over ground obstacles; only standing stone is in their way
+    pub fn blocks_flyers(self) -> bool { !matches!(self, ObstacleKind::Pit) }
+}
+
+// Horror types that float over pits
+pub fn flies(horror_type: HorrorType) -> bool { matches!(horror_type, HorrorType::FloatingEyeball) }
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum ObstacleShape {
+    Circle { radius: f32 },
+    Rect { half_extents: Vec2 }, // Axis-aligned
+}
+
+impl ObstacleShape {
+    pub fn bounding_radius(&self) -> f32 {
+        match *self { ObstacleShape::Circle { radius } => radius, ObstacleShape::Rect { half_extents } => half_extents.length() }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ObstacleSpec {
+    pub kind: ObstacleKind,
+    pub centre: Vec2,
+    pub shape: ObstacleShape,
+}
+
+impl ObstacleSpec {
+    // How far a circle at `position` has to move to stop overlapping this obstacle, or None if it's clear
+    pub fn penetration(&self, position: Vec2, radius: f32) -> Option<Vec2> {
+        match self.shape {
+            ObstacleShape::Circle { radius: obstacle_radius } => {
+                let offset = position - self.centre;
+                let overlap = obstacle_radius + radius - offset.length();
+                if overlap <= 0.0 { return None; }
+                Some(offset.try_normalize().unwrap_or(Vec2::Y) * overlap)
+            }
+            ObstacleShape::Rect { half_extents } => {
+                let local = position - self.centre;
+                let closest = local.clamp(-half_extents, half_extents);
+                let offset = local - closest;
+                if offset != Vec2::ZERO {
+                    let overlap = radius - offset.length();
+                    return (overlap > 0.0).then(|| offset.normalize() * overlap);
+                }
+                // Centre is inside the rectangle: leave through the nearest side
+                let to_edge = half_extents - local.abs();
+                if to_edge.x < to_edge.y {
+                    Some(Vec2::new((to_edge.x + radius) * local.x.signum(), 0.0))
+                } else {
+                    Some(Vec2::new(0.0, (to_edge.y + radius) * local.y.signum()))
+                }
+            }
+        }
+    }
+
+    fn sprite(&self) -> (&'static str, Vec2, f32) {
+        let size = match self.shape { ObstacleShape::Circle { radius } => Vec2::splat(radius * 2.0), ObstacleShape::Rect { half_extents } => half_extents * 2.0 };
+        match self.kind {
+            ObstacleKind::Pillar => ("sprites/ruin_pillar_placeholder.png", size, RUIN_Z_POS),
+            ObstacleKind::Wall => ("sprites/ruin_wall_placeholder.png", size, RUIN_Z_POS),
+            ObstacleKind::Pit => ("sprites/ruin_pit_placeholder.png", size, PIT_Z_POS),
+        }
+    }
+}
+
+pub fn chunk_of(position: Vec2) -> IVec2 { (position / ARENA_CHUNK_SIZE).floor().as_ivec2() }
+
+// Mixes the run seed with the chunk's coordinates so neighbouring chunks get unrelated layouts
+pub fn chunk_seed(seed: u64, chunk: IVec2) -> u64 {
+    seed ^ (chunk.x as i64 as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15) ^ (chunk.y as i64 as u64).wrapping_mul(0xC2B2_AE3D_27D4_EB4F)
+}
+
+// The ruins of one chunk, a pure function of the seed and the chunk's coordinates
+pub fn generate_chunk(seed: u64, chunk: IVec2) -> Vec<ObstacleSpec> {
+    let mut rng = StdRng::seed_from_u64(chunk_seed(seed, chunk));
+    let origin = chunk.as_vec2() * ARENA_CHUNK_SIZE;
+    let mut obstacles: Vec<ObstacleSpec> = Vec::new();
+    let features = rng.gen_range(2..=5);
+    for _ in 0..features {
+        for _ in 0..MAX_PLACEMENT_ATTEMPTS {
+            let centre = origin + Vec2::new(rng.gen_range(CHUNK_MARGIN..ARENA_CHUNK_SIZE - CHUNK_MARGIN), rng.gen_range(CHUNK_MARGIN..ARENA_CHUNK_SIZE - CHUNK_MARGIN));
+            let candidate = roll_feature(&mut rng, centre);
+            if candidate.is_empty() { continue; }
+            let fits = candidate.iter().all(|new| {
+                new.centre.length() - new.shape.bounding_radius() > SPAWN_CLEARING_RADIUS
+                    && obstacles.iter().all(|old| old.centre.distance(new.centre) > old.shape.bounding_radius() + new.shape.bounding_radius() + OBSTACLE_GAP)
+            });
+            if fits { obstacles.extend(candidate); break; }
+        }
+    }
+    obstacles
+}
+
+// A lone pillar, a colonnade, a stretch of wall or a pit
+fn roll_feature(rng: &mut StdRng, centre: Vec2) -> Vec<ObstacleSpec> {
+    match rng.gen_range(0..4) {
+        0 => vec![ObstacleSpec { kind: ObstacleKind::Pillar, centre, shape: ObstacleShape::Circle { radius: rng.gen_range(24.0..40.0) } }],
+        1 => {
+            let count = rng.gen_range(3..=4);
+            let spacing = rng.gen_range(110.0..140.0);
+            let axis = if rng.gen_bool(0.5) { Vec2::X } else { Vec2::Y };
+            let radius = rng.gen_range(20.0..28.0);
+            (0..count).map(|i| {
+                let offset = axis * (i as f32 - (count - 1) as f32 / 2.0) * spacing;
+                ObstacleSpec { kind: ObstacleKind::Pillar, centre: centre + offset, shape: ObstacleShape::Circle { radius } }
+            }).collect()
+        }
+        2 => {
+            let length = rng.gen_range(160.0..340.0);
+            let thickness = rng.gen_range(28.0..40.0);
+            let half_extents = if rng.gen_bool(0.5) { Vec2::new(length, thickness) / 2.0 } else { Vec2::new(thickness, length) / 2.0 };
+            vec![ObstacleSpec { kind: ObstacleKind::Wall, centre, shape: ObstacleShape::Rect { half_extents } }]
+        }
+        _ => vec![ObstacleSpec { kind: ObstacleKind::Pit, centre, shape: ObstacleShape::Circle { radius: rng.gen_range(50.0..90.0) } }],
+    }
+}
+
+// The ruins currently streamed in. Collision looks obstacles up by chunk instead of walking every sprite.
+#[derive(Resource, Default)]
+pub struct ArenaLayout {
+    seed: u64,
+    chunks: HashMap<IVec2, Vec<ObstacleSpec>>,
+}
+
+impl ArenaLayout {
+    pub fn new(seed: u64) -> Self { Self { seed, chunks: HashMap::default() } }
+
+    pub fn seed(&self) -> u64 { self.seed }
+
+    pub fn is_loaded(&self, chunk: IVec2) -> bool { self.chunks.contains_key(&chunk) }
+
+    // Generates the chunk if it isn't loaded yet; returns the obstacles that are new
+    pub fn load_chunk(&mut self, chunk: IVec2) -> Option<&[ObstacleSpec]> {
+        if self.is_loaded(chunk) { return None; }
+        let obstacles = generate_chunk(self.seed, chunk);
+        Some(self.chunks.entry(chunk).or_insert(obstacles))
+    }
+
+    pub fn unload_chunk(&mut self, chunk: IVec2) -> bool { self.chunks.remove(&chunk).is_some() }
+
+    pub fn loaded_chunks(&self) -> impl Iterator<Item = IVec2> + '_ { self.chunks.keys().copied() }
+
+    pub fn chunk_obstacles(&self, chunk: IVec2) -> Option<&[ObstacleSpec]> { self.chunks.get(&chunk).map(Vec::as_slice) }
+
+    // Every loaded obstacle that a circle of `radius` at `position` could touch
+    pub fn obstacles_near(&self, position: Vec2, radius: f32) -> impl Iterator<Item = &ObstacleSpec> + '_ {
+        let min = chunk_of(position - Vec2::splat(radius));
+        let max = chunk_of(position + Vec2::splat(radius));
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|chunk| self.chunks.get(&chunk))
+            .flatten()
+    }
+
+    // Where a circle at `position` ends up once pushed out of every obstacle `blocks` accepts, and the normal of the
+    // last surface it was pushed off, if any
+    pub fn resolve(&self, position: Vec2, radius: f32, blocks: impl Fn(ObstacleKind) -> bool) -> (Vec2, Option<Vec2>) {
+        let mut resolved = position;
+        let mut normal = None;
+        for _ in 0..2 { // A second pass settles circles wedged between two obstacles
+            for obstacle in self.obstacles_near(resolved, radius) {
+                if !blocks(obstacle.kind) { continue; }
+                if let Some(push) = obstacle.penetration(resolved, radius) {
+                    resolved += push;
+                    normal = push.try_normalize().or(normal);
+                }
+            }
+        }
+        (resolved, normal)
+    }
+
+    pub fn is_blocked(&self, position: Vec2, radius: f32, blocks: impl Fn(ObstacleKind) -> bool) -> bool {
+        self.obstacles_near(position, radius).any(|obstacle| blocks(obstacle.kind) && obstacle.penetration(position, radius).is_some())
+    }
+
+    // Whether a straight line from `from` to `to` clears every obstacle `blocks` accepts
+    pub fn line_of_sight(&self, from: Vec2, to: Vec2, blocks: impl Fn(ObstacleKind) -> bool) -> bool {
+        let steps = (from.distance(to) / SIGHT_STEP).ceil().max(1.0) as u32;
+        (0..=steps).all(|step| !self.is_blocked(from.lerp(to, step as f32 / steps as f32), 0.0, &blocks))
+    }
+
+    // The nearest spot to `position` where a circle of `radius` sits clear of stone and pits alike
+    pub fn open_ground(&self, position: Vec2, radius: f32) -> Vec2 { self.resolve(position, radius, |_| true).0 }
+}
+
+// Along a blocking surface, the way that still leads toward where the horror wanted to go
+pub fn slide_direction(normal: Vec2, desired: Vec2) -> Vec2 {
+    let tangent = normal.perp();
+    let along = desired.dot(tangent);
+    if along.abs() < 1e-3 { return tangent; } // Dead on: any way round will do, as long as it's the same every frame
+    tangent * along.signum()
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Obstacle { pub chunk: IVec2, pub spec: ObstacleSpec }
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ArenaLayout>()
+            .add_systems(Update, (
+                arena_streaming_system,
+                survivor_obstacle_collision_system.after(survivor_movement),
+                horror_obstacle_collision_system.after(horror_movement_system).after(horror_crowd_system),
+                automatic_projectile_obstacle_system.after(projectile_movement_system),
+                horror_projectile_obstacle_system.after(horror_projectile_movement_system),
+                skill_projectile_obstacle_system,
+                returning_projectile_obstacle_system.after(returning_projectile_system),
+                lobbed_projectile_obstacle_system.after(lobbed_projectile_system),
+            ).run_if(in_state(AppState::InGame)))
+            .add_systems(PostUpdate, settle_on_open_ground_system.before(TransformSystem::TransformPropagate).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_arena.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_arena.run_if(session_ending));
+    }
+}
+
+pub fn arena_streaming_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    run_seed: Res<RunSeed>,
+    mut layout: ResMut<ArenaLayout>,
+    survivor_query: Query<&Transform, With<Survivor>>,
+    obstacle_query: Query<(Entity, &Obstacle)>,
+) {
+    let Ok(survivor_transform) = survivor_query.get_single() else { return; };
+    if layout.seed() != run_seed.0 { // A new run, or a resumed one: the old ruins belong to another seed
+        for (entity, _) in obstacle_query.iter() { commands.entity(entity).despawn_recursive(); }
+        *layout = ArenaLayout::new(run_seed.0);
+    }
+    let centre = chunk_of(survivor_transform.translation.truncate());
+    let stale: Vec<IVec2> = layout.loaded_chunks()
+        .filter(|chunk| (*chunk - centre).abs().max_element() > ARENA_UNLOAD_RADIUS)
+        .collect();
+    if !stale.is_empty() {
+        for chunk in &stale { layout.unload_chunk(*chunk); }
+        for (entity, obstacle) in obstacle_query.iter() {
+            if stale.contains(&obstacle.chunk) { commands.entity(entity).despawn_recursive(); }
+        }
+    }
+    for x in -ARENA_STREAM_RADIUS..=ARENA_STREAM_RADIUS {
+        for y in -ARENA_STREAM_RADIUS..=ARENA_STREAM_RADIUS {
+            let chunk = centre + IVec2::new(x, y);
+            if layout.is_loaded(chunk) { continue; } // Checked first so a settled layout isn't flagged as changed
+            let Some(obstacles) = layout.load_chunk(chunk) else { continue; };
+            for spec in obstacles {
+                let (sprite_path, size, z) = spec.sprite();
+                commands.spawn((
+                    SpriteBundle {
+                        texture: asset_server.load(sprite_path),
+                        sprite: Sprite { custom_size: Some(size), ..default() },
+                        transform: Transform::from_translation(spec.centre.extend(z)),
+                        ..default()
+                    },
+                    Obstacle { chunk, spec: *spec },
+                    Name::new(format!("{:?}", spec.kind)),
+                ));
+            }
+        }
+    }
+}
+
+fn survivor_obstacle_collision_system(layout: Res<ArenaLayout>, mut survivor_query: Query<&mut Transform, With<Survivor>>) {
+    let Ok(mut transform) = survivor_query.get_single_mut() else { return; };
+    let (resolved, _) = layout.resolve(transform.translation.truncate(), SURVIVOR_SIZE.x / 2.0, |_| true);
+    transform.translation = resolved.extend(transform.translation.z);
+}
+
+fn horror_obstacle_collision_system(
+    time: Res<Time>,
+    layout: Res<ArenaLayout>,
+    survivor_query: Query<&Transform, (With<Survivor>, Without<Horror>)>,
+    mut horror_query: Query<(&mut Transform, &Horror)>,
+) {
+    let survivor_pos = survivor_query.get_single().map(|transform| transform.translation.truncate()).ok();
+    for (mut transform, horror) in horror_query.iter_mut() {
+        if horror.speed == 0.0 { continue; } // Nests are built where they stand
+        let flyer = flies(horror.horror_type);
+        let radius = horror.size.min_element() / 2.0;
+        let position = transform.translation.truncate();
+        let (mut resolved, normal) = layout.resolve(position, radius, |kind| !flyer || kind.blocks_flyers());
+        // Pressed against ruins: edge around them toward the survivor rather than grinding into the stone
+        if let (Some(normal), Some(survivor_pos)) = (normal, survivor_pos) {
+            let desired = (survivor_pos - resolved).normalize_or_zero();
+            if desired.dot(normal) < 0.0 {
+                resolved += slide_direction(normal, desired) * horror.speed * SLIDE_SPEED_FACTOR * time.delta_seconds();
+            }
+        }
+        if resolved != position { transform.translation = resolved.extend(transform.translation.z); }
+    }
+}
+
+fn automatic_projectile_obstacle_system(
+    mut commands: Commands,
+    layout: Res<ArenaLayout>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Velocity, &mut AutomaticProjectile, &mut Damage, &Sprite)>,
+) {
+    for (entity, mut transform, mut velocity, mut projectile, mut damage, sprite) in projectile_query.iter_mut() {
+        let radius = sprite.custom_size.map_or(4.0, |size| size.min_element() / 2.0);
+        let position = transform.translation.truncate();
+        let (resolved, Some(normal)) = layout.resolve(position, radius, ObstacleKind::blocks_projectiles) else { continue; };
+        if projectile.bounces_left.unwrap_or(0) == 0 {
+            commands.entity(entity).despawn_recursive(); // Stone stops it
+            continue;
+        }
+        // Ricochet: mirror the heading about the surface it struck
+        let heading = velocity.0;
+        velocity.0 = heading - 2.0 * heading.dot(normal) * normal;
+        handle_bounce_stat_updates(&mut projectile, &mut damage, &mut velocity);
+        transform.translation = resolved.extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(velocity.0.y.atan2(velocity.0.x));
+    }
+}
+
+fn horror_projectile_obstacle_system(mut commands: Commands, layout: Res<ArenaLayout>, projectile_query: Query<(Entity, &Transform, &HorrorProjectile)>) {
+    for (entity, transform, projectile) in projectile_query.iter() {
+        if layout.is_blocked(transform.translation.truncate(), projectile.radius, ObstacleKind::blocks_projectiles) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn skill_projectile_obstacle_system(mut commands: Commands, layout: Res<ArenaLayout>, projectile_query: Query<(Entity, &Transform, &Sprite), With<SkillProjectile>>) {
+    for (entity, transform, sprite) in projectile_query.iter() {
+        let radius = sprite.custom_size.map_or(4.0, |size| size.min_element() / 2.0);
+        if layout.is_blocked(transform.translation.truncate(), radius, ObstacleKind::blocks_projectiles) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+// A returning blade turns back at the stone it strikes, and one caught on stone on the way back is lost
+fn returning_projectile_obstacle_system(
+    mut commands: Commands,
+    layout: Res<ArenaLayout>,
+    mut projectile_query: Query<(Entity, &mut Transform, &mut Velocity, &mut ReturningProjectileComponent)>,
+) {
+    for (entity, mut transform, mut velocity, mut projectile) in projectile_query.iter_mut() {
+        let radius = projectile.projectile_size.min_element() / 2.0;
+        let (resolved, Some(_)) = layout.resolve(transform.translation.truncate(), radius, ObstacleKind::blocks_projectiles) else { continue; };
+        match projectile.state {
+            ReturningProjectileState::Outgoing => {
+                projectile.state = ReturningProjectileState::Returning;
+                projectile.hit_enemies_this_pass.clear();
+                velocity.0 = (projectile.start_position.truncate() - resolved).normalize_or_zero() * projectile.speed;
+                transform.translation = resolved.extend(transform.translation.z);
+            }
+            ReturningProjectileState::Returning => commands.entity(entity).despawn_recursive(),
+        }
+    }
+}
+
+// A lobbed shell that strikes stone mid-flight bursts against it instead of sailing on
+fn lobbed_projectile_obstacle_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    layout: Res<ArenaLayout>,
+    projectile_query: Query<(Entity, &Transform, &Sprite, &LobbedProjectileComponent, &Lifetime)>,
+) {
+    for (entity, transform, sprite, lobbed, lifetime) in projectile_query.iter() {
+        if lifetime.timer.finished() { continue; } // Already landed this frame
+        let radius = sprite.custom_size.map_or(4.0, |size| size.min_element() / 2.0);
+        let (resolved, Some(_)) = layout.resolve(transform.translation.truncate(), radius, ObstacleKind::blocks_projectiles) else { continue; };
+        spawn_pool_and_despawn_projectile(&mut commands, &asset_server, entity, &Transform::from_translation(resolved.extend(transform.translation.z)), lobbed);
+    }
+}
+
+// Rewards, nests and the merchant appear wherever a horror fell or a roll put them, which can be inside a wall or a
+// pit. They're moved onto open ground as they appear, and again whenever new ruins stream in around them (a resumed
+// run). Horrors keep to their own rules, so a flying boss may still hang over a pit.
+#[allow(clippy::type_complexity)]
+fn settle_on_open_ground_system(
+    layout: Res<ArenaLayout>,
+    mut query: Query<(&mut Transform, &Sprite, Option<&Horror>), Or<(With<RewardChest>, With<ItemDrop>, With<Nest>, With<Boss>, With<Merchant>)>>,
+) {
+    for (mut transform, sprite, horror) in query.iter_mut() {
+        if !transform.is_added() && !layout.is_changed() { continue; }
+        let radius = sprite.custom_size.map_or(0.0, |size| size.max_element() / 2.0);
+        let position = transform.translation.truncate();
+        let settled = match horror {
+            Some(horror) if flies(horror.horror_type) => layout.resolve(position, radius, ObstacleKind::blocks_flyers).0,
+            _ => layout.open_ground(position, radius),
+        };
+        if settled != position { transform.translation = settled.extend(transform.translation.z); }
+    }
+}
+
+fn despawn_arena(mut commands: Commands, mut layout: ResMut<ArenaLayout>, query: Query<Entity, With<Obstacle>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+    *layout = ArenaLayout::default();
+}
@@ -1,7 +1,13 @@
 // mescgit/bulletheavengame/bulletheavengame-a4c13a6183f1601049189db29b13bcfdace86153/src/audio.rs
 use bevy::prelude::*;
 use bevy::audio::Volume; // Added import for Volume
-use crate::game::AppState;
+use crate::game::{AppState, session_ending};
+use crate::settings::GameSettings;
+
+// Base mix levels, scaled by the player's volume settings
+const SFX_BASE_VOLUME: f32 = 0.5;
+const LOOP_BASE_VOLUME: f32 = 0.4;
+const MUSIC_BASE_VOLUME: f32 = 0.3;
 
 #[derive(Event)]
 pub struct PlaySoundEvent(pub SoundEffect);
@@ -58,8 +64,11 @@ impl Plugin for GameAudioPlugin {
             .init_resource::<ActiveLoopingSounds>() // Initialize the new resource
             .add_systems(Startup, setup_audio_handles)
             .add_systems(Update, play_sound_system)
+            .add_systems(Update, apply_volume_settings_system.run_if(resource_changed::<GameSettings>()))
             .add_systems(OnEnter(AppState::InGame), start_background_music)
-            .add_systems(OnExit(AppState::InGame), stop_background_music);
+            .add_systems(OnExit(AppState::InGame), stop_background_music.run_if(not(in_state(AppState::Paused))))
+            .add_systems(OnEnter(AppState::Paused), pause_background_music)
+            .add_systems(OnExit(AppState::Paused), (resume_background_music, stop_background_music.run_if(session_ending)).chain());
     }
 }
 
@@ -84,7 +93,9 @@ fn play_sound_system(
     audio_handles: Res<GameAudioHandles>,
     asset_server: Res<AssetServer>, // Added asset_server
     mut active_loops: ResMut<ActiveLoopingSounds>, // Added active_loops
+    settings: Res<GameSettings>,
 ) {
+    let sfx_volume = settings.effective_sfx_volume();
     for event in sound_events.read() {
         info!("Playing sound effect: {:?}", event.0); // Added logging line
         
@@ -107,7 +118,7 @@ fn play_sound_system(
                 let source = asset_server.load(path_str.clone()); // Clone path_str for loading
                 commands.spawn(AudioBundle {
                     source,
-                    settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.5)),
+                    settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(SFX_BASE_VOLUME * sfx_volume)),
                 });
             }
             SoundEffect::LoopPathStart(owner_entity, ref path_str) => { // owner_entity is Entity, path_str is &String
@@ -119,7 +130,7 @@ fn play_sound_system(
                     source,
                     settings: PlaybackSettings {
                         mode: bevy::audio::PlaybackMode::Loop,
-                        volume: Volume::new_relative(0.4),
+                        volume: Volume::new_relative(LOOP_BASE_VOLUME * sfx_volume),
                         ..default()
                     },
                 }).id();
@@ -143,7 +154,7 @@ fn play_sound_system(
         if let Some(source) = source_to_play_once {
             commands.spawn(AudioBundle {
                 source,
-            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(0.5)),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(SFX_BASE_VOLUME * sfx_volume)),
 			});
 		}
     }
@@ -153,6 +164,7 @@ fn start_background_music(
     mut commands: Commands,
     audio_handles: Res<GameAudioHandles>,
     music_controller_query: Query<Entity, With<BackgroundMusicController>>,
+    settings: Res<GameSettings>,
 ) {
     if !music_controller_query.is_empty() {
         return;
@@ -162,7 +174,7 @@ fn start_background_music(
             source: audio_handles.background_music.clone(),
             settings: PlaybackSettings {
                 mode: bevy::audio::PlaybackMode::Loop,
-                volume: Volume::new_relative(0.3),
+                volume: Volume::new_relative(MUSIC_BASE_VOLUME * settings.effective_music_volume()),
                 ..default()
             },
         },
@@ -177,4 +189,25 @@ fn stop_background_music(
     for entity in music_controller_query.iter() {
         commands.entity(entity).despawn_recursive();
     }
-}
\ No newline at end of file
+}
+
+fn pause_background_music(music_query: Query<&AudioSink, With<BackgroundMusicController>>) {
+    for sink in music_query.iter() { sink.pause(); }
+}
+
+fn resume_background_music(music_query: Query<&AudioSink, With<BackgroundMusicController>>) {
+    for sink in music_query.iter() { sink.play(); }
+}
+
+// Sinks that are already playing keep the volume they were spawned with, so push changes to them directly.
+fn apply_volume_settings_system(
+    settings: Res<GameSettings>,
+    music_query: Query<&AudioSink, With<BackgroundMusicController>>,
+    sink_query: Query<&AudioSink>,
+    active_loops: Res<ActiveLoopingSounds>,
+) {
+    for sink in music_query.iter() { sink.set_volume(MUSIC_BASE_VOLUME * settings.effective_music_volume()); }
+    for audio_entity in active_loops.0.values() {
+        if let Ok(sink) = sink_query.get(*audio_entity) { sink.set_volume(LOOP_BASE_VOLUME * settings.effective_sfx_volume()); }
+    }
+}
@@ -10,6 +10,9 @@ use crate::{
     survivor::Survivor,
     items::{ItemLibrary, /*ItemEffect, ExplosionEffect,*/ AutomaticWeaponId}, // ItemEffect, ExplosionEffect unused
     game::AppState,
+    run_stats::{DamageSource, SurvivorHealedEvent},
+    elites::HorrorDamage,
+    knockback::{KnockbackEvent, KnockbackOnHit},
 };
 use crate::camera_systems::MainCamera;
 use bevy::render::camera::OrthographicProjection;
@@ -26,7 +29,8 @@ struct CollisionAction {
     projectile_explodes_params: Option<crate::weapon_systems::ExplodesOnFinalImpact>, 
     projectile_debuff_params: Option<crate::weapon_systems::DebuffOnHitComponent>,   
     projectile_tether_params: Option<crate::items::RepositioningTetherParams>,     
-    horror_health_at_collision: i32,
+    knockback: f32,
+    knockback_direction: Vec2,
 }
 
 // Make sure AutomaticProjectile can be cloned for storing in CollisionAction
@@ -86,7 +90,7 @@ impl Plugin for AutomaticProjectilesPlugin {
 // The redundant definition of AutomaticProjectile (without Clone) and its Default impl were here.
 // They are removed by this diff. The Default impl above now correctly targets the struct with Clone.
 
-fn handle_bounce_stat_updates(
+pub fn handle_bounce_stat_updates(
     stats: &mut AutomaticProjectile,
     damage_comp: &mut Damage,
     velocity_comp: &mut Velocity,
@@ -132,7 +136,7 @@ pub fn spawn_automatic_projectile(
     opt_tether_params_for_comp: Option<crate::items::RepositioningTetherParams>,
     opt_blink_params: Option<crate::items::BlinkStrikeProjectileParams>,
     opt_trail_params: Option<crate::items::TrailOfFireParams>,
-) {
+) -> Entity {
     let normalized_direction = direction.normalize_or_zero();
     
     let mut spawn_pos = position;
@@ -191,9 +195,10 @@ pub fn spawn_automatic_projectile(
         color.r(), color.g(), color.b(), color.a(),
         spawn_pos.x, spawn_pos.y, spawn_pos.z
     );
+    projectile_commands.id()
 }
 
-fn projectile_movement_system(
+pub fn projectile_movement_system(
     mut query: Query<(&mut Transform, &Velocity, &AutomaticProjectile)>,
     time: Res<Time>,
 ) {
@@ -217,6 +222,7 @@ fn automatic_projectile_lifetime_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn automatic_projectile_collision_system(
     mut commands: Commands,
     mut query_set: ParamSet<(
@@ -231,6 +237,7 @@ fn automatic_projectile_collision_system(
             Option<&crate::weapon_systems::ExplodesOnFinalImpact>,
             Option<&crate::weapon_systems::DebuffOnHitComponent>,
             Option<&crate::weapon_systems::TetherProjectileComponent>,
+            Option<&KnockbackOnHit>,
         )>,
         Query<( // p1: Horror Query
             Entity,
@@ -250,6 +257,9 @@ fn automatic_projectile_collision_system(
     time: Res<Time>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
     _player_blink_event_writer: EventWriter<crate::components::PlayerBlinkEvent>, // Not mutated
+    mut horror_damage: HorrorDamage,
+    mut healed_writer: EventWriter<SurvivorHealedEvent>,
+    mut knockback_writer: EventWriter<KnockbackEvent>,
 ) {
     // Stage 1: Collect relevant information from projectiles and horrors
     let mut projectile_info_list = Vec::new();
@@ -264,7 +274,8 @@ fn automatic_projectile_collision_system(
         _transform, 
         explodes_on_impact_comp,
         debuff_on_hit_comp,
-        tether_projectile_comp
+        tether_projectile_comp,
+        knockback_on_hit
     ) in p0_binding.iter() { 
         if stats.has_bounced_this_frame { continue; }
         projectile_info_list.push((
@@ -275,7 +286,8 @@ fn automatic_projectile_collision_system(
             // Clone component data if it exists, not the Option<&Component>
             explodes_on_impact_comp.cloned(), 
             debuff_on_hit_comp.cloned(),     
-            tether_projectile_comp.map(|t| t.params_snapshot.clone()) 
+            tether_projectile_comp.map(|t| t.params_snapshot.clone()),
+            knockback_on_hit.map_or(0.0, |knockback| knockback.0),
         ));
     }
 
@@ -301,6 +313,9 @@ fn automatic_projectile_collision_system(
 
     // Stage 2: Identify actual collisions and prepare actions
     let mut collision_actions = Vec::new(); 
+    let p2_binding = query_set.p2();
+    let survivor_stats = p2_binding.get_single().ok().map(|(_, _, survivor)| survivor);
+    let mut rng = rand::thread_rng();
 
     for (
         proj_entity,
@@ -309,7 +324,8 @@ fn automatic_projectile_collision_system(
         proj_sprite_custom_size,
         proj_opt_explodes_params, 
         proj_opt_debuff_params,   
-        proj_opt_tether_params    
+        proj_opt_tether_params,
+        proj_knockback
     ) in &projectile_info_list {
         
         let mut current_projectile_piercing_left = proj_stats.piercing_left;
@@ -319,7 +335,7 @@ fn automatic_projectile_collision_system(
             horror_entity,
             horror_gtransform,
             horror_local_transform, 
-            initial_horror_health_value, 
+            _horror_health_value, 
             horror_size
         ) in &horror_info_list {
             if projectile_would_be_consumed_by_hits { break; }
@@ -334,12 +350,13 @@ fn automatic_projectile_collision_system(
                     horror_entity: *horror_entity,
                     horror_gtransform: *horror_gtransform,
                     horror_local_transform: *horror_local_transform,
-                    damage_to_apply: proj_stats.damage_on_hit,
+                    damage_to_apply: survivor_stats.map_or(proj_stats.damage_on_hit, |survivor| survivor.roll_auto_attack_crit(proj_stats.damage_on_hit, &mut rng)),
                     original_projectile_stats: proj_stats.clone(),
                     projectile_explodes_params: proj_opt_explodes_params.clone(),
                     projectile_debuff_params: proj_opt_debuff_params.clone(),
                     projectile_tether_params: proj_opt_tether_params.clone(),
-                    horror_health_at_collision: *initial_horror_health_value,
+                    knockback: *proj_knockback,
+                    knockback_direction: horror_gtransform.translation().truncate() - proj_gtransform.translation().truncate(),
                 });
 
                 if proj_opt_tether_params.is_some() {
@@ -385,17 +402,18 @@ fn automatic_projectile_collision_system(
         let mut projectile_should_despawn = false;
         let mut bounce_occurred_this_hit = false;
 
+        let shield_penetration = query_set.p2().get_single().map_or(0.0, |(_, _, p_stats)| p_stats.auto_attack_shield_penetration_percent);
         if let Ok((_, _, _, mut horror_health, _, _opt_damage_amp_debuff, _)) = query_set.p1().get_mut(action.horror_entity) { // opt_damage_amp_debuff not mutated, prefixed with _
             sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit));
-            let actual_damage_dealt = action.damage_to_apply.min(action.horror_health_at_collision);
-            horror_health.0 = horror_health.0.saturating_sub(action.damage_to_apply);
+            let actual_damage_dealt = horror_damage.apply_penetrating(DamageSource::InherentWeapon, action.horror_entity, &mut horror_health, action.damage_to_apply, shield_penetration);
             visual_effects::spawn_damage_text(&mut commands, &asset_server, action.horror_gtransform.translation(), action.damage_to_apply, &time);
+            knockback_writer.send(KnockbackEvent { target: action.horror_entity, direction: action.knockback_direction, force: action.knockback });
 
             if let Some(lifesteal_pct) = action.original_projectile_stats.lifesteal_percentage {
                 if lifesteal_pct > 0.0 && actual_damage_dealt > 0 {
                     if let Ok((_p_transform, mut p_health, p_stats)) = query_set.p2().get_single_mut() {
                         let heal_amount = (actual_damage_dealt as f32 * lifesteal_pct).round() as i32;
-                        if heal_amount > 0 { p_health.0 = (p_health.0 + heal_amount).min(p_stats.max_health); }
+                        if heal_amount > 0 { healed_writer.send(SurvivorHealedEvent(p_stats.heal(&mut p_health, heal_amount))); }
                     }
                 }
             }
@@ -405,7 +423,7 @@ fn automatic_projectile_collision_system(
             if let Some(_debuff_data) = action.projectile_debuff_params { /* Debuff application */ } // Prefixed
         }
 
-        if let Ok((_proj_e, _proj_gt, _proj_dmg, mut proj_stats, _proj_vel, _proj_sprite, _proj_tf, _, _, _)) = query_set.p0().get_mut(action.projectile_entity) { // Prefixed several unused here
+        if let Ok((_proj_e, _proj_gt, _proj_dmg, mut proj_stats, _proj_vel, _proj_sprite, _proj_tf, _, _, _, _)) = query_set.p0().get_mut(action.projectile_entity) { // Prefixed several unused here
             // Simplified for brace checking
             if !processed_projectiles_this_frame.contains(&action.projectile_entity) {
                 if proj_stats.bounces_left.is_some() && proj_stats.bounces_left.unwrap_or(0) > 0 && !proj_stats.has_bounced_this_frame {
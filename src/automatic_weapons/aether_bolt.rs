@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, BlinkStrikeProjectileParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, BlinkStrikeProjectileParams, UnlockCondition};
 
 pub fn define_aether_bolt() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(23),
         name: "Aether Bolt".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::BlinkStrikeProjectile(BlinkStrikeProjectileParams {
             base_fire_rate_secs: 0.3,
             base_damage: 14,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChanneledBeamParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChanneledBeamParams, UnlockCondition};
 
 pub fn define_arcane_ray() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(6),
         name: "Arcane Ray".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::ChanneledBeam(ChanneledBeamParams {
             damage_per_tick: 5, // Renamed from base_damage_per_tick
             tick_interval_secs: 0.15, // Renamed from tick_rate_secs
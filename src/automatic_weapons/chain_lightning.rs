@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChainZapParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChainZapParams, UnlockCondition};
 
 pub fn define_chain_lightning() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(5),
         name: "Chain Lightning".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::ChainZap(ChainZapParams {
             base_fire_rate_secs: 1.2,
             initial_target_range: 300.0,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LifestealProjectileParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LifestealProjectileParams, UnlockCondition};
 
 pub fn define_chi_bolt() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(21),
         name: "Chi Bolt".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::LifestealProjectile(LifestealProjectileParams {
             base_fire_rate_secs: 0.45,
             base_damage: 18,
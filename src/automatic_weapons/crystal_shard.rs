@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, BouncingProjectileParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, BouncingProjectileParams, UnlockCondition};
 
 pub fn define_crystal_shard() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(16),
         name: "Crystal Shard".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::BouncingProjectile(BouncingProjectileParams {
             base_fire_rate_secs: 0.3,
             num_shards_per_shot: 5,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, GroundTargetedAoEParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, GroundTargetedAoEParams, UnlockCondition};
 
 pub fn define_earthshatter_shard() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(11),
         name: "EarthShatter Shard".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::GroundTargetedAoE(GroundTargetedAoEParams {
             base_fire_rate_secs: 1.8,
             targeting_range: 400.0,
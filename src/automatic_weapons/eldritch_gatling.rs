@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChanneledBeamParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChanneledBeamParams, UnlockCondition};
 use crate::upgrades::{UpgradeCard, UpgradeType, UpgradeRarity, UpgradeId, ChanneledBeamField};
 
 pub fn define_eldritch_gatling() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(1),
         name: "Eldritch Gatling".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::ChanneledBeam(ChanneledBeamParams {
             damage_per_tick: 2, // Renamed
             tick_interval_secs: 0.1, // Renamed
@@ -25,17 +26,17 @@ pub fn define_eldritch_gatling() -> AutomaticWeaponDefinition {
 
 pub fn get_specific_upgrades() -> Vec<UpgradeCard> {
     vec![
-        UpgradeCard {
-            id: UpgradeId(20002),
-            name: "Extended Gatling Beam".to_string(),
-            description: "Increases the range of the Eldritch Gatling beam by 15%.".to_string(),
-            upgrade_type: UpgradeType::ModifyChanneledBeam {
+        UpgradeCard::new(
+            UpgradeId(20002),
+            "Extended Gatling Beam",
+            "Increases the range of the Eldritch Gatling beam by 15%.",
+            UpgradeType::ModifyChanneledBeam {
                 weapon_id: AutomaticWeaponId(1), // ID for Eldritch Gatling
                 field: ChanneledBeamField::Range,
                 change_value: 0.15, // 15% increase
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, PointBlankNovaParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, PointBlankNovaParams, UnlockCondition};
 
 pub fn define_glacial_spike() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(10),
         name: "Glacial Spike".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::PointBlankNova(PointBlankNovaParams {
             base_fire_rate_secs: 0.9,
             damage: 22,
@@ -14,6 +15,7 @@ pub fn define_glacial_spike() -> AutomaticWeaponDefinition {
             slow_effect_multiplier: 0.5,
             slow_duration_secs: 2.0,
             fire_sound_effect: Some("audio/glacial_spike_nova.ogg".to_string()),
+            knockback: 400.0,
         }),
     }
 }
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LineDashAttackParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LineDashAttackParams, UnlockCondition};
 
 pub fn define_holy_lance() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(8),
         name: "Holy Lance".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::LineDashAttack(LineDashAttackParams {
             base_fire_rate_secs: 1.2,
             dash_speed: 900.0,
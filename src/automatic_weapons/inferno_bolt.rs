@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, TrailOfFireParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, TrailOfFireParams, UnlockCondition};
 
 pub fn define_inferno_bolt() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(4),
         name: "Inferno Bolt".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::TrailOfFire(TrailOfFireParams {
             base_damage_on_impact: 10,
             base_fire_rate_secs: 0.8,
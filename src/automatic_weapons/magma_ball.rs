@@ -1,10 +1,11 @@
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LobbedBouncingMagmaParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LobbedBouncingMagmaParams, UnlockCondition};
 use bevy::prelude::{Color, Vec2}; // Added for Color and Vec2
 
 pub fn define_magma_ball() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(17),
         name: "Magma Ball".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::LobbedBouncingMagma(LobbedBouncingMagmaParams {
             base_fire_rate_secs: 0.9,
             projectile_speed: 350.0,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, PersistentAuraParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, PersistentAuraParams, UnlockCondition};
 
 pub fn define_metal_shrapnel() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(19),
         name: "Metal Shrapnel".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::PersistentAura(PersistentAuraParams {
             is_active_by_default: true,
             damage_per_tick: 2,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, HomingDebuffProjectileParams, ProjectileDebuffType};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, HomingDebuffProjectileParams, ProjectileDebuffType, UnlockCondition};
 
 pub fn define_moonbeam_dart() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(13),
         name: "Moonbeam Dart".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::HomingDebuffProjectile(HomingDebuffProjectileParams {
             base_fire_rate_secs: 0.4,
             num_darts_per_shot: 2,
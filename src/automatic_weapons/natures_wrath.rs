@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, GroundTargetedAoEParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, GroundTargetedAoEParams, UnlockCondition};
 
 pub fn define_natures_wrath() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(20),
         name: "Nature's Wrath".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::GroundTargetedAoE(GroundTargetedAoEParams {
             base_fire_rate_secs: 1.1,
             targeting_range: 350.0,
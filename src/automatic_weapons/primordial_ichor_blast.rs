@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LobbedAoEPoolParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, LobbedAoEPoolParams, UnlockCondition};
 use crate::upgrades::{UpgradeCard, UpgradeType, UpgradeRarity, UpgradeId, LobbedAoEPoolField};
 
 pub fn define_primordial_ichor_blast() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(0),
         name: "Primordial Ichor Blast".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::LobbedAoEPool(LobbedAoEPoolParams {
             base_damage_on_impact: 5,
             pool_damage_per_tick: 3,
@@ -27,41 +28,41 @@ pub fn define_primordial_ichor_blast() -> AutomaticWeaponDefinition {
 
 pub fn get_specific_upgrades() -> Vec<UpgradeCard> {
     vec![
-        UpgradeCard {
-            id: UpgradeId(20000),
-            name: "Wider Ichor Pool".to_string(),
-            description: "Increases the radius of the Ichor Blast pool by 10%.".to_string(),
-            upgrade_type: UpgradeType::ModifyLobbedAoEPool {
+        UpgradeCard::new(
+            UpgradeId(20000),
+            "Wider Ichor Pool",
+            "Increases the radius of the Ichor Blast pool by 10%.",
+            UpgradeType::ModifyLobbedAoEPool {
                 weapon_id: AutomaticWeaponId(0), // ID for Primordial Ichor Blast
                 field: LobbedAoEPoolField::PoolRadius,
                 change_value: 0.10, // 10% increase
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
-        UpgradeCard {
-            id: UpgradeId(20001),
-            name: "Lingering Ichor".to_string(),
-            description: "Increases the duration of the Ichor Blast pool by 0.5 seconds.".to_string(),
-            upgrade_type: UpgradeType::ModifyLobbedAoEPool {
+            UpgradeRarity::Regular,
+        ),
+        UpgradeCard::new(
+            UpgradeId(20001),
+            "Lingering Ichor",
+            "Increases the duration of the Ichor Blast pool by 0.5 seconds.",
+            UpgradeType::ModifyLobbedAoEPool {
                 weapon_id: AutomaticWeaponId(0),
                 field: LobbedAoEPoolField::PoolDurationSecs,
                 change_value: 0.5, // 0.5 seconds flat increase
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
-        UpgradeCard {
-            id: UpgradeId(20004), // New unique ID
-            name: "Potent Ichor".to_string(),
-            description: "Increases the damage per tick of the Ichor Blast pool by 2.".to_string(),
-            upgrade_type: UpgradeType::ModifyLobbedAoEPool {
+            UpgradeRarity::Regular,
+        ),
+        UpgradeCard::new(
+            UpgradeId(20004), // New unique ID
+            "Potent Ichor",
+            "Increases the damage per tick of the Ichor Blast pool by 2.",
+            UpgradeType::ModifyLobbedAoEPool {
                 weapon_id: AutomaticWeaponId(0),
                 field: LobbedAoEPoolField::PoolDamagePerTick,
                 change_value: 2.0, // Flat increase of 2
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, RepositioningTetherParams, RepositioningTetherMode};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, RepositioningTetherParams, RepositioningTetherMode, UnlockCondition};
 
 pub fn define_psionic_lash() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(22),
         name: "Psionic Lash".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::RepositioningTether(RepositioningTetherParams {
             base_fire_rate_secs: 1.0,
             tether_projectile_speed: 800.0,
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, DebuffAuraParams, AuraDebuffType};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, DebuffAuraParams, AuraDebuffType, UnlockCondition};
 
 pub fn define_sand_blast() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(18),
         name: "Sand Blast".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::DebuffAura(DebuffAuraParams {
             base_fire_rate_secs: 1.5,
             cloud_radius: 120.0,
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, OrbitingPetParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, OrbitingPetParams, UnlockCondition};
 use crate::upgrades::{UpgradeCard, UpgradeType, OrbitingPetField, UpgradeRarity, UpgradeId};
 
 pub fn define_shadow_orb() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(7),
         name: "Shadow Orb".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::OrbitingPet(OrbitingPetParams {
             base_fire_rate_secs: 1.0,  // Reduced respawn cooldown to 1 second
             max_active_orbs: 1,          // Start with one orb
@@ -40,69 +41,69 @@ pub fn get_shadow_orb_upgrade_cards() -> Vec<UpgradeCard> {
     let shadow_orb_weapon_id = AutomaticWeaponId(7); // Matches existing definition
     vec![
         // Upgrade MaxActiveOrbs
-        UpgradeCard {
-            id: UpgradeId(7000), // Ensure unique ID
-            name: "Twin Orbs".to_string(),
-            description: "Summon an additional Shadow Orb. Max Orbs +1.".to_string(),
-            upgrade_type: UpgradeType::ModifyOrbitingPet {
+        UpgradeCard::new(
+            UpgradeId(7000), // Ensure unique ID
+            "Twin Orbs",
+            "Summon an additional Shadow Orb. Max Orbs +1.",
+            UpgradeType::ModifyOrbitingPet {
                 weapon_id: shadow_orb_weapon_id,
                 field: OrbitingPetField::MaxActiveOrbs,
                 change_value: 1.0, // Increase by 1
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Rare,
-        },
+            UpgradeRarity::Rare,
+        ),
         // Upgrade OrbDurationSecs
-        UpgradeCard {
-            id: UpgradeId(7001),
-            name: "Lingering Shadows".to_string(),
-            description: "Shadow Orbs persist for a longer duration. Duration +5s.".to_string(),
-            upgrade_type: UpgradeType::ModifyOrbitingPet {
+        UpgradeCard::new(
+            UpgradeId(7001),
+            "Lingering Shadows",
+            "Shadow Orbs persist for a longer duration. Duration +5s.",
+            UpgradeType::ModifyOrbitingPet {
                 weapon_id: shadow_orb_weapon_id,
                 field: OrbitingPetField::OrbDurationSecs,
                 change_value: 5.0, // Increase by 5 seconds
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
         // Upgrade PulseDamage
-        UpgradeCard {
-            id: UpgradeId(7002),
-            name: "Deeper Pulse".to_string(),
-            description: "Shadow Orb pulses deal increased damage. Pulse Damage +3.".to_string(),
-            upgrade_type: UpgradeType::ModifyOrbitingPet {
+        UpgradeCard::new(
+            UpgradeId(7002),
+            "Deeper Pulse",
+            "Shadow Orb pulses deal increased damage. Pulse Damage +3.",
+            UpgradeType::ModifyOrbitingPet {
                 weapon_id: shadow_orb_weapon_id,
                 field: OrbitingPetField::PulseDamage,
                 change_value: 3.0, // Increase by 3
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
         // Upgrade PulseIntervalSecs (decrease for faster pulses)
-        UpgradeCard {
-            id: UpgradeId(7003),
-            name: "Accelerated Pulse".to_string(),
-            description: "Shadow Orbs pulse more frequently. Pulse Interval -0.3s (faster).".to_string(),
-            upgrade_type: UpgradeType::ModifyOrbitingPet {
+        UpgradeCard::new(
+            UpgradeId(7003),
+            "Accelerated Pulse",
+            "Shadow Orbs pulse more frequently. Pulse Interval -0.3s (faster).",
+            UpgradeType::ModifyOrbitingPet {
                 weapon_id: shadow_orb_weapon_id,
                 field: OrbitingPetField::PulseIntervalSecs,
                 change_value: -0.3, // Decrease interval by 0.3s
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
         // Upgrade PulseRadius
-        UpgradeCard {
-            id: UpgradeId(7004),
-            name: "Widening Shadows".to_string(),
-            description: "Shadow Orb pulse radius increased by 15%. Radius +15%.".to_string(),
-            upgrade_type: UpgradeType::ModifyOrbitingPet {
+        UpgradeCard::new(
+            UpgradeId(7004),
+            "Widening Shadows",
+            "Shadow Orb pulse radius increased by 15%. Radius +15%.",
+            UpgradeType::ModifyOrbitingPet {
                 weapon_id: shadow_orb_weapon_id,
                 field: OrbitingPetField::PulseRadius,
                 change_value: 0.15, // Increase by 15%
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
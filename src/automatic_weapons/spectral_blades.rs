@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ReturningProjectileParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ReturningProjectileParams, UnlockCondition};
 use crate::upgrades::{UpgradeCard, UpgradeType, UpgradeRarity, UpgradeId, ReturningProjectileField};
 
 pub fn define_spectral_blades() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(3),
         name: "Spectral Blades".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::ReturningProjectile(ReturningProjectileParams {
             base_damage: 12,
             base_fire_rate_secs: 0.75,
@@ -22,17 +23,17 @@ pub fn define_spectral_blades() -> AutomaticWeaponDefinition {
 
 pub fn get_specific_upgrades() -> Vec<UpgradeCard> {
     vec![
-        UpgradeCard {
-            id: UpgradeId(20003),
-            name: "Far-Reaching Blades".to_string(),
-            description: "Increases the travel distance of Spectral Blades by 20%.".to_string(),
-            upgrade_type: UpgradeType::ModifyReturningProjectile {
+        UpgradeCard::new(
+            UpgradeId(20003),
+            "Far-Reaching Blades",
+            "Increases the travel distance of Spectral Blades by 20%.",
+            UpgradeType::ModifyReturningProjectile {
                 weapon_id: AutomaticWeaponId(3), // ID for Spectral Blades
                 field: ReturningProjectileField::TravelDistance,
                 change_value: 0.20, // 20% increase
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ExpandingEnergyBombParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ExpandingEnergyBombParams, UnlockCondition};
 
 pub fn define_spirit_bomb() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(14),
         name: "Spirit Bomb".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::ExpandingEnergyBomb(ExpandingEnergyBombParams {
             base_fire_rate_secs: 2.5,
             max_radius: 300.0,
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ConeAttackParams}; // Vec2 and Color are covered by bevy::prelude::*
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ConeAttackParams, UnlockCondition}; // Vec2 and Color are covered by bevy::prelude::*
 use crate::upgrades::{UpgradeCard, UpgradeType, UpgradeRarity, UpgradeId, ConeAttackField};
 
 pub fn define_sunfire_burst() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(12),
         name: "Sunfire Burst".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::ConeAttack(ConeAttackParams {
             base_damage: 20,
             base_fire_rate_secs: 0.9,
@@ -21,23 +22,24 @@ pub fn define_sunfire_burst() -> AutomaticWeaponDefinition {
             burn_duration_secs: Some(3.0),
             burn_tick_interval_secs: Some(0.5),
             fire_sound_effect: Some("audio/sunfire_burst_fire.ogg".to_string()),
+            knockback: 250.0,
         }),
     }
 }
 
 pub fn get_specific_upgrades() -> Vec<UpgradeCard> {
     vec![
-        UpgradeCard {
-            id: UpgradeId(20007), // New unique ID
-            name: "Wider Sunfire Cone".to_string(),
-            description: "Increases the angle of the Sunfire Burst cone by 10%.".to_string(),
-            upgrade_type: UpgradeType::ModifyConeAttack {
+        UpgradeCard::new(
+            UpgradeId(20007), // New unique ID
+            "Wider Sunfire Cone",
+            "Increases the angle of the Sunfire Burst cone by 10%.",
+            UpgradeType::ModifyConeAttack {
                 weapon_id: AutomaticWeaponId(12), // ID for Sunfire Burst
                 field: ConeAttackField::ConeAngleDegrees,
                 change_value: 0.10, // 10% increase
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
@@ -1,11 +1,12 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, StandardProjectileParams}; // Vec2 and Color are covered by bevy::prelude::*
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, StandardProjectileParams, UnlockCondition}; // Vec2 and Color are covered by bevy::prelude::*
 use crate::upgrades::{UpgradeCard, UpgradeType, UpgradeRarity, UpgradeId, StandardProjectileField};
 
 pub fn define_venom_spit() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(9),
         name: "Venom Spit".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::StandardProjectile(StandardProjectileParams {
             base_damage: 10,
             base_fire_rate_secs: 0.4,
@@ -23,29 +24,29 @@ pub fn define_venom_spit() -> AutomaticWeaponDefinition {
 
 pub fn get_specific_upgrades() -> Vec<UpgradeCard> {
     vec![
-        UpgradeCard {
-            id: UpgradeId(20005), // New unique ID
-            name: "Extra Venom Projectile".to_string(),
-            description: "Adds one additional projectile to Venom Spit.".to_string(),
-            upgrade_type: UpgradeType::ModifyStandardProjectile {
+        UpgradeCard::new(
+            UpgradeId(20005), // New unique ID
+            "Extra Venom Projectile",
+            "Adds one additional projectile to Venom Spit.",
+            UpgradeType::ModifyStandardProjectile {
                 weapon_id: AutomaticWeaponId(9), // ID for Venom Spit
                 field: StandardProjectileField::AdditionalProjectiles,
                 change_value: 1.0, // Add 1 projectile
                 is_percentage: false,
             },
-            rarity: UpgradeRarity::Regular,
-        },
-        UpgradeCard {
-            id: UpgradeId(20006), // New unique ID
-            name: "Faster Venom Bolts".to_string(),
-            description: "Increases Venom Spit projectile speed by 15%.".to_string(),
-            upgrade_type: UpgradeType::ModifyStandardProjectile {
+            UpgradeRarity::Regular,
+        ),
+        UpgradeCard::new(
+            UpgradeId(20006), // New unique ID
+            "Faster Venom Bolts",
+            "Increases Venom Spit projectile speed by 15%.",
+            UpgradeType::ModifyStandardProjectile {
                 weapon_id: AutomaticWeaponId(9),
                 field: StandardProjectileField::BaseProjectileSpeed,
                 change_value: 0.15, // 15% increase
                 is_percentage: true,
             },
-            rarity: UpgradeRarity::Regular,
-        },
+            UpgradeRarity::Regular,
+        ),
     ]
 }
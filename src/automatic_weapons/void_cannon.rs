@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChargeUpEnergyShotParams, ChargeLevelParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ChargeUpEnergyShotParams, ChargeLevelParams, UnlockCondition};
 
 pub fn define_void_cannon() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(2),
         name: "Void Cannon".to_string(),
+        unlock: UnlockCondition::Starter,
         attack_data: AttackTypeData::ChargeUpEnergyShot(ChargeUpEnergyShotParams {
             base_fire_rate_secs: 1.25,
             base_projectile_sprite_path: "sprites/void_cannon_projectile_placeholder.png".to_string(),
@@ -23,6 +24,7 @@ pub fn define_void_cannon() -> AutomaticWeaponDefinition {
                     projectile_sprite_path: "".to_string(), // Use base_projectile_sprite_path from parent
                     projectile_color: Color::WHITE, // Use base_projectile_color from parent
                     aoe_radius_on_impact: None, // Added
+                    knockback: 0.0,
                 },
                 ChargeLevelParams {
                     charge_time_secs: 0.75,
@@ -36,6 +38,7 @@ pub fn define_void_cannon() -> AutomaticWeaponDefinition {
                     projectile_sprite_path: "".to_string(), // Use base_projectile_sprite_path from parent
                     projectile_color: Color::WHITE, // Use base_projectile_color from parent
                     aoe_radius_on_impact: None, // Added
+                    knockback: 150.0,
                 },
                 ChargeLevelParams {
                     charge_time_secs: 1.5,
@@ -49,6 +52,7 @@ pub fn define_void_cannon() -> AutomaticWeaponDefinition {
                     projectile_sprite_path: "sprites/void_cannon_projectile_placeholder.png".to_string(), // Specific override
                     projectile_color: Color::rgb(0.6, 0.3, 0.9), // Example color for charged shot
                     aoe_radius_on_impact: Some(50.0), // Example AoE
+                    knockback: 500.0,
                 },
             ],
             charge_sound_effect: None,
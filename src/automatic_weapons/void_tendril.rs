@@ -1,10 +1,11 @@
 use bevy::prelude::*;
-use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ConeAttackParams};
+use crate::items::{AutomaticWeaponDefinition, AutomaticWeaponId, AttackTypeData, ConeAttackParams, UnlockCondition};
 
 pub fn define_void_tendril() -> AutomaticWeaponDefinition {
     AutomaticWeaponDefinition {
         id: AutomaticWeaponId(15),
         name: "Void Tendril".to_string(),
+        unlock: UnlockCondition::Purchase,
         attack_data: AttackTypeData::ConeAttack(ConeAttackParams {
             base_damage: 18,
             base_fire_rate_secs: 0.65,
@@ -20,6 +21,7 @@ pub fn define_void_tendril() -> AutomaticWeaponDefinition {
             burn_duration_secs: None,
             burn_tick_interval_secs: None,
             fire_sound_effect: Some("audio/void_tendril_fire.ogg".to_string()),
+            knockback: 120.0,
         }),
     }
 }
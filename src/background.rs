@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 use crate::camera_systems::MainCamera;
-use crate::game::AppState;
+use crate::game::{AppState, session_ending};
 
 pub const BACKGROUND_TILE_SIZE: f32 = 2048.0;
 const BACKGROUND_Z: f32 = -10.0;
@@ -25,11 +25,13 @@ impl Plugin for BackgroundPlugin {
         app
             .add_systems(OnEnter(AppState::InGame), setup_background)
             .add_systems(Update, infinite_scroll_background.run_if(in_state(AppState::InGame)))
-            .add_systems(OnExit(AppState::InGame), cleanup_background);
+            .add_systems(OnExit(AppState::InGame), cleanup_background.run_if(not(in_state(AppState::Paused))))
+            .add_systems(OnExit(AppState::Paused), cleanup_background.run_if(session_ending));
     }
 }
 
-fn setup_background(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn setup_background(mut commands: Commands, asset_server: Res<AssetServer>, existing_tiles: Query<(), With<BackgroundTile>>) {
+    if !existing_tiles.is_empty() { return; } // Resuming from pause keeps the current grid
     let mut tiles = [Entity::PLACEHOLDER; NUM_TILES];
     let grid_half_span_offset = (GRID_DIMENSION as f32 - 1.0) / 2.0; 
 
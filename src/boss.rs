@@ -0,0 +1,427 @@
+// src/boss.rs
+// Bosses arrive at fixed marks on the run timeline. A boss is an ordinary `Horror` with a `Boss` component on top,
+// so every weapon, skill and kill tally already applies to it. Each boss walks through phases as its health drops;
+// a phase pairs behaviour graphs shared with regular horrors (the Frenzied Behemoth's charge, the Void Blinker's
+// blink) with timed attacks: bullet patterns and minion summoning.
+// Killing a boss always drops relics.
+use bevy::prelude::*;
+use bevy::ecs::system::EntityCommands;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::{
+    components::{Health, Velocity},
+    survivor::Survivor,
+    game::{AppState, GameState},
+    camera_systems::ScreenShake,
+    audio::{PlaySoundEvent, SoundEffect},
+    profile::PlayerProfile,
+    items::{ItemLibrary, spawn_item_drop},
+    chests::{spawn_reward_chest, BOSS_CHEST_QUALITY},
+    horror::{
+        Horror, HorrorStats, HorrorType,
+        spawn_and_return_horror_entity, handle_horror_death_drops,
+        FRENZIED_BEHEMOTH_SIZE, VOID_BLINKER_SIZE, FLESH_WEAVER_SIZE,
+    },
+    bullet_patterns::{BulletPattern, PatternTarget, ProjectileStyle, fire_pattern},
+    horror_behavior::{BehaviorGraph, HorrorBehavior, FRENZIED_BEHEMOTH_BEHAVIOR, VOID_BLINKER_BEHAVIOR, BLINK_OVERLAY_BEHAVIOR},
+};
+
+const BOSS_SPAWN_DISTANCE_FACTOR: f32 = 0.6; // Of the screen width; closer than regular spawns so the arrival is seen
+const BOSS_Z_POS: f32 = 0.55;
+const BOSS_PHASE_TRAUMA: f32 = 0.5;
+const BOSS_RELIC_SCATTER_RADIUS: f32 = 40.0;
+const BOSS_BAR_TOP: f32 = 48.0;
+const BOSS_BAR_SPACING: f32 = 46.0;
+const BOSS_BAR_WIDTH: f32 = 480.0;
+const BOSS_BAR_HEIGHT: f32 = 12.0;
+const BOSS_BAR_FILL_COLOR: Color = Color::rgb(0.7, 0.1, 0.25);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BossId { GorgedColossus, HeraldOfHollowStars, MotherOfThousands }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BossAttack {
+    Volley { pattern: BulletPattern, style: ProjectileStyle, interval_secs: f32 },
+    Summon { minion: HorrorType, count: u32, max_active: u32, interval_secs: f32 },
+}
+
+impl BossAttack {
+    fn interval_secs(self) -> f32 {
+        match self {
+            BossAttack::Volley { interval_secs, .. } | BossAttack::Summon { interval_secs, .. } => interval_secs,
+        }
+    }
+}
+
+pub struct BossPhase {
+    pub name: &'static str,
+    pub health_threshold: f32, // Entered once health falls to this fraction of the maximum
+    pub speed_multiplier: f32,
+    pub behaviors: &'static [&'static BehaviorGraph], // Layered in order; none means a plain chase
+    pub attacks: &'static [BossAttack],
+}
+
+pub struct BossDefinition {
+    pub id: BossId,
+    pub name: &'static str,
+    pub base_type: HorrorType, // Sprite, and what the kill counts as for achievements and run stats
+    pub health: i32,           // Before the wave multiplier
+    pub damage_on_collision: i32,
+    pub speed: f32,
+    pub size: Vec2,
+    pub xp_value: u32,
+    pub relic_drops: u32,
+    pub phases: &'static [BossPhase], // From full health down
+}
+
+impl BossDefinition {
+    pub fn phase_for_health(&self, health: i32, max_health: i32) -> usize {
+        let fraction = health as f32 / max_health.max(1) as f32;
+        self.phases.iter().rposition(|phase| fraction <= phase.health_threshold).unwrap_or(0)
+    }
+}
+
+pub static BOSS_DEFINITIONS: [BossDefinition; 3] = [
+    BossDefinition {
+        id: BossId::GorgedColossus, name: "The Gorged Colossus", base_type: HorrorType::FrenziedBehemoth,
+        health: 2500, damage_on_collision: 30, speed: 60.0, size: Vec2::new(FRENZIED_BEHEMOTH_SIZE.x * 2.5, FRENZIED_BEHEMOTH_SIZE.y * 2.5), xp_value: 400, relic_drops: 1,
+        phases: &[
+            BossPhase { name: "Gorging", health_threshold: 1.0, speed_multiplier: 1.0, behaviors: &[&FRENZIED_BEHEMOTH_BEHAVIOR], attacks: &[] },
+            BossPhase { name: "Retching", health_threshold: 0.6, speed_multiplier: 1.1, behaviors: &[&FRENZIED_BEHEMOTH_BEHAVIOR], attacks: &[
+                BossAttack::Volley { pattern: BulletPattern::Ring { projectiles: 12 }, style: ProjectileStyle::ichor(220.0, 12), interval_secs: 3.5 },
+            ] },
+            BossPhase { name: "Bursting", health_threshold: 0.25, speed_multiplier: 1.3, behaviors: &[&FRENZIED_BEHEMOTH_BEHAVIOR], attacks: &[
+                BossAttack::Volley { pattern: BulletPattern::Ring { projectiles: 16 }, style: ProjectileStyle::ichor(250.0, 14), interval_secs: 2.5 },
+                BossAttack::Volley { pattern: BulletPattern::Spiral { arms: 4, volleys: 14, interval_secs: 0.12, degrees_per_volley: 14.0 }, style: ProjectileStyle::needle(230.0, 10), interval_secs: 7.0 },
+                BossAttack::Summon { minion: HorrorType::CrawlingTorment, count: 4, max_active: 8, interval_secs: 6.0 },
+            ] },
+        ],
+    },
+    BossDefinition {
+        id: BossId::HeraldOfHollowStars, name: "Herald of the Hollow Stars", base_type: HorrorType::VoidBlinker,
+        health: 3200, damage_on_collision: 25, speed: 90.0, size: Vec2::new(VOID_BLINKER_SIZE.x * 2.5, VOID_BLINKER_SIZE.y * 2.5), xp_value: 550, relic_drops: 2,
+        phases: &[
+            BossPhase { name: "Heralding", health_threshold: 1.0, speed_multiplier: 1.0, behaviors: &[&VOID_BLINKER_BEHAVIOR], attacks: &[
+                BossAttack::Volley { pattern: BulletPattern::AimedSpread { projectiles: 5, spread_degrees: 40.0 }, style: ProjectileStyle::ichor(300.0, 12), interval_secs: 2.5 },
+            ] },
+            BossPhase { name: "Unstarred", health_threshold: 0.5, speed_multiplier: 1.2, behaviors: &[&VOID_BLINKER_BEHAVIOR], attacks: &[
+                BossAttack::Volley { pattern: BulletPattern::LeadShot { projectiles: 7, spread_degrees: 60.0 }, style: ProjectileStyle::ichor(320.0, 14), interval_secs: 2.0 },
+                BossAttack::Volley { pattern: BulletPattern::HomingOrb { delay_secs: 0.8, homing_secs: 2.5, turn_degrees_per_sec: 120.0 }, style: ProjectileStyle::orb(240.0, 16), interval_secs: 5.0 },
+                BossAttack::Volley { pattern: BulletPattern::Ring { projectiles: 10 }, style: ProjectileStyle::ichor(200.0, 12), interval_secs: 4.0 },
+                BossAttack::Summon { minion: HorrorType::MindLeech, count: 3, max_active: 6, interval_secs: 8.0 },
+            ] },
+        ],
+    },
+    BossDefinition {
+        id: BossId::MotherOfThousands, name: "Mother of Thousands", base_type: HorrorType::FleshWeaver,
+        health: 4200, damage_on_collision: 28, speed: 55.0, size: Vec2::new(FLESH_WEAVER_SIZE.x * 3.0, FLESH_WEAVER_SIZE.y * 3.0), xp_value: 700, relic_drops: 2,
+        phases: &[
+            BossPhase { name: "Brooding", health_threshold: 1.0, speed_multiplier: 1.0, behaviors: &[], attacks: &[
+                BossAttack::Summon { minion: HorrorType::CrawlingTorment, count: 3, max_active: 9, interval_secs: 5.0 },
+                BossAttack::Volley { pattern: BulletPattern::AimedSpread { projectiles: 3, spread_degrees: 25.0 }, style: ProjectileStyle::ichor(260.0, 12), interval_secs: 3.0 },
+            ] },
+            BossPhase { name: "Birthing", health_threshold: 0.5, speed_multiplier: 1.15, behaviors: &[&FRENZIED_BEHEMOTH_BEHAVIOR], attacks: &[
+                BossAttack::Summon { minion: HorrorType::SkitteringShadowling, count: 4, max_active: 12, interval_secs: 5.0 },
+                BossAttack::Volley { pattern: BulletPattern::AimedSpread { projectiles: 5, spread_degrees: 45.0 }, style: ProjectileStyle::ichor(280.0, 14), interval_secs: 2.5 },
+                BossAttack::Volley { pattern: BulletPattern::Wall { projectiles: 14, spacing: 28.0, gap: 3 }, style: ProjectileStyle::needle(200.0, 12), interval_secs: 6.0 },
+            ] },
+            BossPhase { name: "Unravelling", health_threshold: 0.2, speed_multiplier: 1.3, behaviors: &[&FRENZIED_BEHEMOTH_BEHAVIOR, &BLINK_OVERLAY_BEHAVIOR], attacks: &[
+                BossAttack::Volley { pattern: BulletPattern::Ring { projectiles: 18 }, style: ProjectileStyle::ichor(240.0, 14), interval_secs: 3.0 },
+                BossAttack::Summon { minion: HorrorType::CrawlingTorment, count: 5, max_active: 15, interval_secs: 4.0 },
+            ] },
+        ],
+    },
+];
+
+pub fn get_boss_definition(id: BossId) -> &'static BossDefinition {
+    BOSS_DEFINITIONS.iter().find(|def| def.id == id).expect("every BossId has a definition")
+}
+
+pub struct BossMark { pub at_secs: f32, pub boss: BossId }
+
+// Seconds into the run; each mark spawns once
+pub static BOSS_TIMELINE: [BossMark; 6] = [
+    BossMark { at_secs: 300.0, boss: BossId::GorgedColossus },
+    BossMark { at_secs: 600.0, boss: BossId::HeraldOfHollowStars },
+    BossMark { at_secs: 900.0, boss: BossId::MotherOfThousands },
+    BossMark { at_secs: 1200.0, boss: BossId::GorgedColossus },
+    BossMark { at_secs: 1500.0, boss: BossId::HeraldOfHollowStars },
+    BossMark { at_secs: 1800.0, boss: BossId::MotherOfThousands },
+];
+
+// How far along BOSS_TIMELINE the current run is
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct BossTimeline { pub next_mark: usize }
+
+impl BossTimeline {
+    // A resumed run has already had every mark up to its elapsed time; a boss still alive is restored with the horrors
+    pub fn resumed_at(elapsed_secs: f32) -> Self {
+        Self { next_mark: BOSS_TIMELINE.iter().take_while(|mark| mark.at_secs <= elapsed_secs).count() }
+    }
+
+    pub fn next_due(&mut self, elapsed_secs: f32) -> Option<BossId> {
+        let mark = BOSS_TIMELINE.get(self.next_mark).filter(|mark| mark.at_secs <= elapsed_secs)?;
+        self.next_mark += 1;
+        Some(mark.boss)
+    }
+}
+
+#[derive(Component)]
+pub struct Boss {
+    pub id: BossId,
+    pub max_health: i32,
+    pub phase: usize,
+    timed_attacks: Vec<(BossAttack, Timer)>,
+    minions: Vec<Entity>,
+    defeated: bool,
+}
+
+impl Boss {
+    fn new(def: &BossDefinition, max_health: i32) -> Self {
+        let mut boss = Self { id: def.id, max_health, phase: 0, timed_attacks: Vec::new(), minions: Vec::new(), defeated: false };
+        boss.enter_phase(def, 0);
+        boss
+    }
+
+    fn enter_phase(&mut self, def: &BossDefinition, phase: usize) {
+        self.phase = phase;
+        self.timed_attacks = def.phases[phase].attacks.iter()
+            .map(|attack| (*attack, Timer::from_seconds(attack.interval_secs(), TimerMode::Repeating)))
+            .collect();
+    }
+}
+
+// Each part of a health bar holds the boss it tracks
+#[derive(Component)] struct BossHealthBar(Entity);
+#[derive(Component)] struct BossHealthBarFill(Entity);
+#[derive(Component)] struct BossHealthBarLabel(Entity);
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossTimeline>()
+            .add_systems(OnEnter(AppState::MainMenu), reset_boss_timeline)
+            .add_systems(Update, (
+                boss_timeline_system,
+                boss_phase_system,
+                boss_attack_system,
+                boss_defeat_system.before(handle_horror_death_drops), // Must see the boss before its entity is despawned
+                boss_health_bar_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_boss_health_bars);
+    }
+}
+
+fn reset_boss_timeline(mut boss_timeline: ResMut<BossTimeline>) { *boss_timeline = BossTimeline::default(); }
+
+// Each phase starts its behaviour graphs afresh
+fn insert_phase_behaviours(entity_commands: &mut EntityCommands, phase: &BossPhase) {
+    entity_commands.remove::<HorrorBehavior>();
+    if !phase.behaviors.is_empty() { entity_commands.insert(HorrorBehavior::new(phase.behaviors)); }
+}
+
+pub fn spawn_boss(commands: &mut Commands, asset_server: &Res<AssetServer>, id: BossId, position: Vec3, wave_multiplier: f32) -> Entity {
+    let def = get_boss_definition(id);
+    let sprite_path = HorrorStats::get_for_type(def.base_type, wave_multiplier).sprite_path;
+    let max_health = (def.health as f32 * wave_multiplier).ceil() as i32;
+    let mut entity_commands = commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load(sprite_path),
+            sprite: Sprite { custom_size: Some(def.size), ..default() },
+            transform: Transform::from_translation(position.truncate().extend(BOSS_Z_POS)), ..default()
+        },
+        Horror {
+            horror_type: def.base_type, size: def.size, damage_on_collision: def.damage_on_collision,
+            speed: def.speed * def.phases[0].speed_multiplier, xp_value: def.xp_value, item_drop_chance: 0.0, is_elite: false, // Relics come from boss_defeat_system instead
+        },
+        Health(max_health), Velocity(Vec2::ZERO), Boss::new(def, max_health), Name::new(def.name),
+    ));
+    insert_phase_behaviours(&mut entity_commands, &def.phases[0]);
+    entity_commands.id()
+}
+
+fn boss_timeline_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    mut boss_timeline: ResMut<BossTimeline>,
+    player_query: Query<&Transform, With<Survivor>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    let Ok(player_transform) = player_query.get_single() else { return; };
+    let wave_multiplier = 1.0 + (game_state.wave_number as f32 - 1.0) * 0.1;
+    let mut rng = rand::thread_rng();
+    while let Some(boss_id) = boss_timeline.next_due(game_state.game_timer.elapsed_secs()) {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let spawn_pos = player_transform.translation.truncate() + Vec2::from_angle(angle) * crate::game::SCREEN_WIDTH * BOSS_SPAWN_DISTANCE_FACTOR;
+        spawn_boss(&mut commands, &asset_server, boss_id, spawn_pos.extend(BOSS_Z_POS), wave_multiplier);
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::MadnessConsumes));
+        screen_shake.add_trauma(BOSS_PHASE_TRAUMA);
+        info!("Boss arrived: {}", get_boss_definition(boss_id).name);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn boss_phase_system(
+    mut commands: Commands,
+    mut boss_query: Query<(Entity, &mut Boss, &Health, &mut Horror, &mut Sprite, &mut Visibility)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    for (entity, mut boss, health, mut horror, mut sprite, mut visibility) in boss_query.iter_mut() {
+        let def = get_boss_definition(boss.id);
+        let phase = def.phase_for_health(health.0, boss.max_health);
+        if phase <= boss.phase || health.0 <= 0 { continue; }
+
+        boss.enter_phase(def, phase);
+        horror.speed = def.speed * def.phases[phase].speed_multiplier;
+        // A charge telegraph or blink fade may be cut short; put the sprite back before the new behaviours take over
+        sprite.color = Color::WHITE;
+        *visibility = Visibility::Visible;
+        insert_phase_behaviours(&mut commands.entity(entity), &def.phases[phase]);
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::MadnessConsumes));
+        screen_shake.add_trauma(BOSS_PHASE_TRAUMA);
+    }
+}
+
+fn boss_attack_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    mut boss_query: Query<(Entity, &GlobalTransform, &mut Boss, &Visibility)>,
+    player_query: Query<(&Transform, &Velocity), With<Survivor>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Ok((player_transform, player_velocity)) = player_query.get_single() else { return; };
+    let pattern_target = PatternTarget { position: player_transform.translation.truncate(), velocity: player_velocity.0 };
+    let wave_multiplier = 1.0 + (game_state.wave_number as f32 - 1.0) * 0.1;
+    let mut rng = rand::thread_rng();
+
+    for (boss_entity, boss_gtransform, mut boss, visibility) in boss_query.iter_mut() {
+        if *visibility == Visibility::Hidden { continue; } // Mid-blink
+        let boss_pos = boss_gtransform.translation();
+        let Boss { timed_attacks, minions, .. } = &mut *boss;
+        minions.retain(|&minion| commands.get_entity(minion).is_some());
+
+        for (attack, timer) in timed_attacks.iter_mut() {
+            timer.tick(time.delta());
+            if !timer.just_finished() { continue; }
+            match *attack {
+                BossAttack::Volley { pattern, style, .. } => {
+                    sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorProjectile));
+                    fire_pattern(&mut commands, &asset_server, pattern, style, boss_pos, pattern_target, Some(boss_entity), &mut rng);
+                }
+                BossAttack::Summon { minion, count, max_active, .. } => {
+                    for _ in 0..count {
+                        if minions.len() >= max_active as usize { break; }
+                        let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(40.0..90.0);
+                        minions.push(spawn_and_return_horror_entity(&mut commands, &asset_server, minion, (boss_pos.truncate() + offset).extend(0.5), wave_multiplier));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Guaranteed relics, preferring ones the survivor hasn't collected yet, and a boss-grade reward chest
+fn boss_defeat_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    item_library: Res<ItemLibrary>,
+    profile: Res<PlayerProfile>,
+    mut boss_query: Query<(&mut Boss, &Health, &Transform)>,
+    survivor_query: Query<&Survivor>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let mut rng = rand::thread_rng();
+    for (mut boss, health, transform) in boss_query.iter_mut() {
+        if health.0 > 0 || boss.defeated { continue; }
+        boss.defeated = true;
+        let def = get_boss_definition(boss.id);
+        let collected = survivor_query.get_single().map(|survivor| survivor.collected_item_ids.clone()).unwrap_or_default();
+        for item_def in item_library.pick_relic_rewards(|item_def| profile.is_item_unlocked(item_def), &collected, def.relic_drops as usize, &mut rng) {
+            let scatter = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * BOSS_RELIC_SCATTER_RADIUS;
+            spawn_item_drop(&mut commands, &asset_server, transform.translation.truncate() + scatter, item_def);
+        }
+        spawn_reward_chest(&mut commands, &asset_server, transform.translation.truncate(), BOSS_CHEST_QUALITY);
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation));
+        info!("Boss defeated: {}", def.name);
+    }
+}
+
+// One bar per living boss, stacked under the top HUD bar
+fn boss_health_bar_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    boss_query: Query<(Entity, &Boss, &Health)>,
+    mut bar_query: Query<(Entity, &BossHealthBar, &mut Style), Without<BossHealthBarFill>>,
+    mut fill_query: Query<(&BossHealthBarFill, &mut Style), Without<BossHealthBar>>,
+    mut label_query: Query<(&BossHealthBarLabel, &mut Text)>,
+) {
+    let mut bar_count = 0;
+    for (bar_entity, bar, mut style) in bar_query.iter_mut() {
+        if boss_query.contains(bar.0) {
+            style.top = Val::Px(BOSS_BAR_TOP + bar_count as f32 * BOSS_BAR_SPACING);
+            bar_count += 1;
+        } else {
+            commands.entity(bar_entity).despawn_recursive();
+        }
+    }
+    for (fill, mut style) in fill_query.iter_mut() {
+        let Ok((_, boss, health)) = boss_query.get(fill.0) else { continue; };
+        style.width = Val::Percent((health.0.max(0) as f32 / boss.max_health.max(1) as f32).min(1.0) * 100.0);
+    }
+    for (label, mut text) in label_query.iter_mut() {
+        let Ok((_, boss, _)) = boss_query.get(label.0) else { continue; };
+        let def = get_boss_definition(boss.id);
+        text.sections[0].value = format!("{} - {}", def.name, def.phases[boss.phase].name);
+    }
+
+    for (boss_entity, boss, _) in boss_query.iter() {
+        if bar_query.iter().any(|(_, bar, _)| bar.0 == boss_entity) { continue; }
+        let def = get_boss_definition(boss.id);
+        let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+        commands.spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(BOSS_BAR_TOP + bar_count as f32 * BOSS_BAR_SPACING),
+                    left: Val::Percent(50.0),
+                    margin: UiRect::left(Val::Px(-BOSS_BAR_WIDTH / 2.0)), // Centers the fixed-width bar
+                    width: Val::Px(BOSS_BAR_WIDTH),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                z_index: ZIndex::Global(5),
+                ..default()
+            },
+            BossHealthBar(boss_entity),
+            Name::new(format!("BossHealthBar_{}", def.name)),
+        )).with_children(|parent| {
+            parent.spawn((
+                TextBundle::from_section(format!("{} - {}", def.name, def.phases[boss.phase].name), TextStyle { font, font_size: 18.0, color: Color::rgb(0.95, 0.8, 0.85) }),
+                BossHealthBarLabel(boss_entity),
+            ));
+            parent.spawn(NodeBundle {
+                style: Style { width: Val::Percent(100.0), height: Val::Px(BOSS_BAR_HEIGHT), border: UiRect::all(Val::Px(1.0)), ..default() },
+                background_color: Color::rgba(0.05, 0.0, 0.05, 0.8).into(),
+                border_color: BorderColor(Color::rgb(0.4, 0.3, 0.4)),
+                ..default()
+            }).with_children(|track| {
+                track.spawn((
+                    NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), ..default() }, background_color: BOSS_BAR_FILL_COLOR.into(), ..default() },
+                    BossHealthBarFill(boss_entity),
+                ));
+            });
+        });
+        bar_count += 1;
+    }
+}
+
+fn despawn_boss_health_bars(mut commands: Commands, bar_query: Query<Entity, With<BossHealthBar>>) {
+    for entity in bar_query.iter() { commands.entity(entity).despawn_recursive(); }
+}
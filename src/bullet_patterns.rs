@@ -0,0 +1,257 @@
+// src/bullet_patterns.rs
+// Horror projectiles fired in patterns described by data. A `BulletPattern` says where the shots go (aimed spread,
+// ring burst, rotating spiral, predictive lead shot, delayed homing orb, wall with a gap) and a `ProjectileStyle`
+// what each shot is (speed, damage, lifetime and sprite). Behaviour graphs, elite affixes and boss phases all fire
+// through `fire_pattern`. Instant patterns spawn their whole volley at once; a spiral leaves a `PatternEmitter`
+// that follows the shooter and keeps turning, and homing orbs carry a `HomingProjectile` that steers them.
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{
+    components::Velocity,
+    survivor::Survivor,
+    game::{AppState, session_ending},
+    audio::{PlaySoundEvent, SoundEffect},
+    horror::{HorrorProjectile, spawn_horror_projectile, horror_projectile_movement_system},
+};
+
+const ICHOR_SPRITE: &str = "sprites/horror_ichor_blast_placeholder.png";
+const ORB_DRIFT_FRACTION: f32 = 0.15; // Of the orb's speed, while it hangs in the air before homing
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectileStyle {
+    pub speed: f32,
+    pub damage: i32,
+    pub lifetime_secs: f32,
+    pub sprite: &'static str,
+    pub size: Vec2, // Also the collision size
+    pub color: Color,
+}
+
+impl ProjectileStyle {
+    // The green ichor blast most ranged horrors spit
+    pub const fn ichor(speed: f32, damage: i32) -> Self {
+        Self { speed, damage, lifetime_secs: 3.5, sprite: ICHOR_SPRITE, size: Vec2::new(15.0, 15.0), color: Color::rgb(0.3, 0.8, 0.4) }
+    }
+
+    // Big, slow and long-lived, for homing orbs
+    pub const fn orb(speed: f32, damage: i32) -> Self {
+        Self { speed, damage, lifetime_secs: 7.0, sprite: ICHOR_SPRITE, size: Vec2::new(26.0, 26.0), color: Color::rgb(0.7, 0.3, 1.0) }
+    }
+
+    // Small and quick, for spirals and walls that fill the screen
+    pub const fn needle(speed: f32, damage: i32) -> Self {
+        Self { speed, damage, lifetime_secs: 4.5, sprite: ICHOR_SPRITE, size: Vec2::new(10.0, 10.0), color: Color::rgb(0.9, 0.4, 0.3) }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BulletPattern {
+    AimedSpread { projectiles: u32, spread_degrees: f32 },
+    Ring { projectiles: u32 }, // Evenly spaced all round, from a random starting angle
+    Spiral { arms: u32, volleys: u32, interval_secs: f32, degrees_per_volley: f32 }, // Fired over time, following the shooter
+    LeadShot { projectiles: u32, spread_degrees: f32 }, // Aimed where the survivor will be when the shot arrives
+    HomingOrb { delay_secs: f32, homing_secs: f32, turn_degrees_per_sec: f32 }, // Hangs in the air, then seeks the survivor
+    Wall { projectiles: u32, spacing: f32, gap: u32 }, // A line across the aim marching at the survivor, with `gap` missing shots
+}
+
+// Where the survivor is and how they're moving, for aimed and leading patterns
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PatternTarget { pub position: Vec2, pub velocity: Vec2 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shot { pub origin: Vec2, pub direction: Vec2 }
+
+// The direction to fire at `speed` from `origin` to meet a target moving in a straight line; straight at it if it can't be caught
+pub fn intercept_direction(origin: Vec2, target: PatternTarget, speed: f32) -> Vec2 {
+    let offset = target.position - origin;
+    let a = target.velocity.length_squared() - speed * speed;
+    let b = 2.0 * offset.dot(target.velocity);
+    let c = offset.length_squared();
+    let time = if a.abs() < f32::EPSILON {
+        if b < 0.0 { -c / b } else { -1.0 }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 { -1.0 } else {
+            let root = discriminant.sqrt();
+            let (t1, t2) = ((-b - root) / (2.0 * a), (-b + root) / (2.0 * a));
+            let (early, late) = (t1.min(t2), t1.max(t2));
+            if early > 0.0 { early } else { late }
+        }
+    };
+    if time > 0.0 { (offset + target.velocity * time).normalize_or_zero() } else { offset.normalize_or_zero() }
+}
+
+fn fan(origin: Vec2, aim: Vec2, projectiles: u32, spread_degrees: f32) -> Vec<Shot> {
+    let spread = spread_degrees.to_radians();
+    (0..projectiles).map(|i| {
+        let angle = if projectiles > 1 { -spread / 2.0 + spread * i as f32 / (projectiles - 1) as f32 } else { 0.0 };
+        Shot { origin, direction: Vec2::from_angle(angle).rotate(aim) }
+    }).collect()
+}
+
+// One volley of `pattern`. `spin` is the spiral's current angle; other patterns ignore it. Aimed patterns fire
+// nothing when the shooter sits right on the target.
+pub fn volley(pattern: BulletPattern, origin: Vec2, target: PatternTarget, speed: f32, spin: f32, rng: &mut impl Rng) -> Vec<Shot> {
+    let aim = (target.position - origin).normalize_or_zero();
+    match pattern {
+        BulletPattern::AimedSpread { projectiles, spread_degrees } => if aim == Vec2::ZERO { Vec::new() } else { fan(origin, aim, projectiles, spread_degrees) },
+        BulletPattern::Ring { projectiles } => {
+            let offset = rng.gen_range(0.0..std::f32::consts::TAU);
+            (0..projectiles).map(|i| Shot { origin, direction: Vec2::from_angle(offset + i as f32 / projectiles as f32 * std::f32::consts::TAU) }).collect()
+        }
+        BulletPattern::Spiral { arms, .. } => {
+            (0..arms).map(|i| Shot { origin, direction: Vec2::from_angle(spin + i as f32 / arms as f32 * std::f32::consts::TAU) }).collect()
+        }
+        BulletPattern::LeadShot { projectiles, spread_degrees } => {
+            let lead = intercept_direction(origin, target, speed);
+            if lead == Vec2::ZERO { Vec::new() } else { fan(origin, lead, projectiles, spread_degrees) }
+        }
+        BulletPattern::HomingOrb { .. } => if aim == Vec2::ZERO { Vec::new() } else { vec![Shot { origin, direction: aim }] },
+        BulletPattern::Wall { projectiles, spacing, gap } => {
+            if aim == Vec2::ZERO || gap >= projectiles { return Vec::new(); }
+            let gap_start = rng.gen_range(0..=projectiles - gap);
+            let centre = (projectiles - 1) as f32 / 2.0;
+            (0..projectiles)
+                .filter(|i| !(gap_start..gap_start + gap).contains(i))
+                .map(|i| Shot { origin: origin + aim.perp() * (i as f32 - centre) * spacing, direction: aim })
+                .collect()
+        }
+    }
+}
+
+// Keeps firing a spiral from wherever its shooter is
+#[derive(Component)]
+pub struct PatternEmitter {
+    pattern: BulletPattern,
+    style: ProjectileStyle,
+    source: Option<Entity>,
+    timer: Timer,
+    volleys_left: u32,
+    spin: f32,
+}
+
+#[derive(Component)]
+pub struct HomingProjectile {
+    pub delay: Timer,
+    pub homing: Timer,
+    pub turn_rate: f32, // Radians per second
+    pub speed: f32,
+}
+
+impl HomingProjectile {
+    // New velocity for an orb at `position` chasing `target`
+    pub fn steer(&mut self, delta: std::time::Duration, velocity: Vec2, position: Vec2, target: Vec2) -> Vec2 {
+        if !self.delay.finished() {
+            self.delay.tick(delta);
+            return velocity;
+        }
+        if self.homing.finished() { return velocity; }
+        self.homing.tick(delta);
+        let heading = velocity.try_normalize().unwrap_or(Vec2::X);
+        let wanted = (target - position).normalize_or_zero();
+        if wanted == Vec2::ZERO { return heading * self.speed; }
+        let max_turn = self.turn_rate * delta.as_secs_f32();
+        let turn = heading.angle_between(wanted).clamp(-max_turn, max_turn);
+        Vec2::from_angle(turn).rotate(heading) * self.speed
+    }
+}
+
+// Fires `pattern` from `origin`. `source` is the shooter, which a spiral follows until it dies or blinks away.
+#[allow(clippy::too_many_arguments)]
+pub fn fire_pattern(
+    commands: &mut Commands,
+    asset_server: &Res<AssetServer>,
+    pattern: BulletPattern,
+    style: ProjectileStyle,
+    origin: Vec3,
+    target: PatternTarget,
+    source: Option<Entity>,
+    rng: &mut impl Rng,
+) {
+    let spin = if matches!(pattern, BulletPattern::Spiral { .. }) { rng.gen_range(0.0..std::f32::consts::TAU) } else { 0.0 };
+    for shot in volley(pattern, origin.truncate(), target, style.speed, spin, rng) {
+        let projectile = spawn_horror_projectile(commands, asset_server, shot.origin.extend(origin.z), shot.direction, &style);
+        if let BulletPattern::HomingOrb { delay_secs, homing_secs, turn_degrees_per_sec } = pattern {
+            commands.entity(projectile).insert((
+                Velocity(shot.direction * style.speed * ORB_DRIFT_FRACTION),
+                HomingProjectile {
+                    delay: Timer::from_seconds(delay_secs, TimerMode::Once),
+                    homing: Timer::from_seconds(homing_secs, TimerMode::Once),
+                    turn_rate: turn_degrees_per_sec.to_radians(),
+                    speed: style.speed,
+                },
+            ));
+        }
+    }
+    if let BulletPattern::Spiral { volleys, interval_secs, degrees_per_volley, .. } = pattern {
+        if volleys > 1 {
+            commands.spawn((
+                TransformBundle::from_transform(Transform::from_translation(origin)),
+                PatternEmitter {
+                    pattern, style, source,
+                    timer: Timer::from_seconds(interval_secs, TimerMode::Repeating),
+                    volleys_left: volleys - 1,
+                    spin: spin + degrees_per_volley.to_radians(),
+                },
+                Name::new("PatternEmitter"),
+            ));
+        }
+    }
+}
+
+pub struct BulletPatternsPlugin;
+
+impl Plugin for BulletPatternsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                pattern_emitter_system,
+                homing_projectile_system.before(horror_projectile_movement_system),
+            ).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_patterns.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_patterns.run_if(session_ending));
+    }
+}
+
+fn pattern_emitter_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut emitter_query: Query<(Entity, &mut PatternEmitter, &mut Transform)>,
+    source_query: Query<(&Transform, &Visibility), Without<PatternEmitter>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let mut rng = rand::thread_rng();
+    for (emitter_entity, mut emitter, mut transform) in emitter_query.iter_mut() {
+        if let Some(source) = emitter.source {
+            match source_query.get(source) {
+                Ok((source_transform, visibility)) if *visibility != Visibility::Hidden => transform.translation = source_transform.translation,
+                _ => { commands.entity(emitter_entity).despawn_recursive(); continue; }
+            }
+        }
+        if !emitter.timer.tick(time.delta()).just_finished() { continue; }
+        let BulletPattern::Spiral { degrees_per_volley, .. } = emitter.pattern else { continue; };
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorProjectile));
+        for shot in volley(emitter.pattern, transform.translation.truncate(), PatternTarget::default(), emitter.style.speed, emitter.spin, &mut rng) {
+            spawn_horror_projectile(&mut commands, &asset_server, shot.origin.extend(transform.translation.z), shot.direction, &emitter.style);
+        }
+        emitter.spin += degrees_per_volley.to_radians();
+        emitter.volleys_left -= 1;
+        if emitter.volleys_left == 0 { commands.entity(emitter_entity).despawn_recursive(); }
+    }
+}
+
+fn homing_projectile_system(
+    time: Res<Time>,
+    mut orb_query: Query<(&mut HomingProjectile, &mut Velocity, &Transform)>,
+    player_query: Query<&Transform, With<Survivor>>,
+) {
+    let Ok(player_transform) = player_query.get_single() else { return; };
+    for (mut homing, mut velocity, transform) in orb_query.iter_mut() {
+        velocity.0 = homing.steer(time.delta(), velocity.0, transform.translation.truncate(), player_transform.translation.truncate());
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn despawn_patterns(mut commands: Commands, query: Query<Entity, Or<(With<PatternEmitter>, With<HorrorProjectile>)>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+}
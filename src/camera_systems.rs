@@ -1,19 +1,36 @@
 use bevy::prelude::*;
 use crate::survivor::Survivor; // Corrected: player::Player to survivor::Survivor
+use rand::Rng;
 use crate::game::AppState;
+use crate::settings::GameSettings;
 
 const CAMERA_LERP_FACTOR: f32 = 0.05; // Adjust for more or less "softness" (lower is softer)
+const MAX_SCREEN_SHAKE_OFFSET: f32 = 12.0; // Pixels at full trauma and 100% intensity
+const SCREEN_SHAKE_DECAY_PER_SEC: f32 = 2.5;
+pub const SURVIVOR_HIT_TRAUMA: f32 = 0.4;
 
 #[derive(Component)]
 pub struct MainCamera; // Marker component for the main game camera
 
+// Trauma-based shake; the offset is squared from trauma so small hits stay subtle.
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    pub trauma: f32,
+    applied_offset: Vec2,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) { self.trauma = (self.trauma + amount).min(1.0); }
+}
+
 pub struct CameraSystemsPlugin;
 
 impl Plugin for CameraSystemsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, 
-            soft_camera_follow_system.run_if(in_state(AppState::InGame))
-        );
+        app.init_resource::<ScreenShake>()
+            .add_systems(Update, 
+                (soft_camera_follow_system, screen_shake_system).chain().run_if(in_state(AppState::InGame))
+            );
     }
 }
 
@@ -32,4 +49,24 @@ fn soft_camera_follow_system(
             // camera_transform.translation.z = desired_camera_z_value; // e.g. 10.0 or what was set at spawn
         }
     }
-}
\ No newline at end of file
+}
+
+fn screen_shake_system(
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut camera_query: Query<&mut Transform, With<MainCamera>>,
+) {
+    let Ok(mut camera_transform) = camera_query.get_single_mut() else { return; };
+    screen_shake.trauma = (screen_shake.trauma - SCREEN_SHAKE_DECAY_PER_SEC * time.delta_seconds()).max(0.0);
+
+    let strength = screen_shake.trauma * screen_shake.trauma * settings.screen_shake_intensity * MAX_SCREEN_SHAKE_OFFSET;
+    let new_offset = if strength > 0.0 {
+        let mut rng = rand::thread_rng();
+        Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0)) * strength
+    } else {
+        Vec2::ZERO
+    };
+    camera_transform.translation += (new_offset - screen_shake.applied_offset).extend(0.0);
+    screen_shake.applied_offset = new_offset;
+}
@@ -0,0 +1,198 @@
+// src/characters.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::{
+    items::{AutomaticWeaponId, AutomaticWeaponLibrary, ItemId, populate_automatic_weapon_library},
+    skills::{ActiveSkillInstance, SkillId},
+    survivor::Survivor,
+};
+
+const DEFAULT_PORTRAIT_PATH: &str = "sprites/survivor_placeholder.png";
+// Drifters are generated for weapons without a hand-written character, starting after the authored ids
+const DRIFTER_CHARACTER_ID_OFFSET: u32 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, Serialize, Deserialize)]
+pub struct CharacterId(pub u32);
+
+// Per-character replacements for the shared Survivor base stats. `None` keeps the default.
+#[derive(Debug, Clone, Reflect, Default, PartialEq, Serialize, Deserialize)]
+pub struct CharacterStatOverrides {
+    pub max_health: Option<i32>,
+    pub speed: Option<f32>,
+    pub health_regen_rate: Option<f32>,
+    pub armor: Option<u32>,
+    pub pickup_radius_multiplier: Option<f32>,
+    pub xp_gain_multiplier: Option<f32>,
+    pub auto_weapon_damage_bonus: Option<i32>,
+    pub auto_attack_crit_chance: Option<f32>,
+}
+
+impl CharacterStatOverrides {
+    pub fn apply_to(&self, survivor: &mut Survivor) {
+        if let Some(max_health) = self.max_health { survivor.max_health = max_health; }
+        if let Some(speed) = self.speed { survivor.speed = speed; }
+        if let Some(regen) = self.health_regen_rate { survivor.health_regen_rate = regen; }
+        if let Some(armor) = self.armor { survivor.armor = armor; }
+        if let Some(radius) = self.pickup_radius_multiplier { survivor.pickup_radius_multiplier = radius; }
+        if let Some(xp_gain) = self.xp_gain_multiplier { survivor.xp_gain_multiplier = xp_gain; }
+        if let Some(damage) = self.auto_weapon_damage_bonus { survivor.auto_weapon_damage_bonus = damage; }
+        if let Some(crit) = self.auto_attack_crit_chance { survivor.auto_attack_crit_chance = crit; }
+    }
+}
+
+// A character's unique passive, applied each time the survivor reaches a new level.
+#[derive(Debug, Clone, Copy, Reflect, Default, PartialEq, Serialize, Deserialize)]
+pub enum CharacterPassive {
+    #[default]
+    None,
+    ExtraProjectileEveryLevels(u32),
+    BonusDamageEveryLevels(u32),
+    MaxHealthPerLevel(i32),
+    CritChancePerLevel(f32),
+    PickupRadiusPerLevel(f32),
+    SpeedPerLevel(f32),
+}
+
+impl CharacterPassive {
+    pub fn description(self) -> String {
+        match self {
+            CharacterPassive::None => "No innate gift".to_string(),
+            CharacterPassive::ExtraProjectileEveryLevels(levels) => format!("+1 projectile every {} levels", levels),
+            CharacterPassive::BonusDamageEveryLevels(levels) => format!("+1 weapon damage every {} levels", levels),
+            CharacterPassive::MaxHealthPerLevel(amount) => format!("+{} Max Endurance per level", amount),
+            CharacterPassive::CritChancePerLevel(chance) => format!("+{:.0}% crit chance per level", chance * 100.0),
+            CharacterPassive::PickupRadiusPerLevel(bonus) => format!("+{:.0}% pickup radius per level", bonus * 100.0),
+            CharacterPassive::SpeedPerLevel(bonus) => format!("+{:.0}% speed per level", bonus * 100.0),
+        }
+    }
+
+    // Called after `survivor.level` has been incremented
+    pub fn apply_on_level_reached(self, survivor: &mut Survivor) {
+        let level = survivor.level;
+        match self {
+            CharacterPassive::None => {}
+            CharacterPassive::ExtraProjectileEveryLevels(levels) => { if levels > 0 && level % levels == 0 { survivor.auto_weapon_additional_projectiles_bonus += 1; } }
+            CharacterPassive::BonusDamageEveryLevels(levels) => { if levels > 0 && level % levels == 0 { survivor.auto_weapon_damage_bonus += 1; } }
+            CharacterPassive::MaxHealthPerLevel(amount) => { survivor.max_health += amount; }
+            CharacterPassive::CritChancePerLevel(chance) => { survivor.auto_attack_crit_chance = (survivor.auto_attack_crit_chance + chance).min(1.0); }
+            CharacterPassive::PickupRadiusPerLevel(bonus) => { survivor.pickup_radius_multiplier += bonus; }
+            CharacterPassive::SpeedPerLevel(bonus) => { survivor.speed *= 1.0 + bonus; }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Reflect, PartialEq, Serialize, Deserialize)]
+pub struct CharacterDefinition {
+    pub id: CharacterId,
+    pub name: String,
+    pub description: String,
+    pub portrait_path: String,
+    pub starting_weapon_id: AutomaticWeaponId,
+    pub starting_skills: Vec<SkillId>,
+    pub starting_relics: Vec<ItemId>, // Collected through ItemCollectedEvent on spawn so their effects apply
+    pub stat_overrides: CharacterStatOverrides,
+    pub passive: CharacterPassive,
+}
+
+impl CharacterDefinition {
+    // The survivor this character starts a run as, before permanent boons and starting relics.
+    pub fn create_survivor(&self, weapon_library: &AutomaticWeaponLibrary) -> Survivor {
+        let starting_skills = self.starting_skills.iter().map(|skill_id| ActiveSkillInstance::new(*skill_id)).collect();
+        let mut survivor = Survivor::new_with_skills_items_and_weapon(starting_skills, Vec::new(), self.starting_weapon_id, weapon_library);
+        self.stat_overrides.apply_to(&mut survivor);
+        survivor.character_passive = self.passive;
+        survivor
+    }
+}
+
+#[derive(Resource, Default, Reflect)] #[reflect(Resource)]
+pub struct CharacterLibrary { pub characters: Vec<CharacterDefinition>, }
+impl CharacterLibrary {
+    pub fn get_character_definition(&self, id: CharacterId) -> Option<&CharacterDefinition> { self.characters.iter().find(|def| def.id == id) }
+    pub fn get_for_weapon(&self, weapon_id: AutomaticWeaponId) -> Option<&CharacterDefinition> { self.characters.iter().find(|def| def.starting_weapon_id == weapon_id) }
+}
+
+pub struct CharactersPlugin;
+impl Plugin for CharactersPlugin {
+    fn build(&self, app: &mut App) {
+        app .register_type::<CharacterId>()
+            .register_type::<CharacterStatOverrides>()
+            .register_type::<CharacterPassive>()
+            .register_type::<CharacterDefinition>()
+            .register_type::<CharacterLibrary>()
+            .init_resource::<CharacterLibrary>()
+            .add_systems(Startup, populate_character_library.after(populate_automatic_weapon_library));
+    }
+}
+
+pub fn populate_character_library(mut library: ResMut<CharacterLibrary>, weapon_library: Res<AutomaticWeaponLibrary>) {
+    library.characters.extend(authored_characters());
+    // Every weapon stays playable once unlocked: those without an authored character get a plain Drifter
+    for weapon_def in weapon_library.weapons.iter() {
+        if library.get_for_weapon(weapon_def.id).is_some() { continue; }
+        library.characters.push(CharacterDefinition {
+            id: CharacterId(DRIFTER_CHARACTER_ID_OFFSET + weapon_def.id.0),
+            name: format!("Drifter ({})", weapon_def.name),
+            description: "A nameless wanderer clutching a strange armament.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: weapon_def.id,
+            starting_skills: vec![SkillId(1)],
+            starting_relics: Vec::new(),
+            stat_overrides: CharacterStatOverrides::default(),
+            passive: CharacterPassive::None,
+        });
+    }
+}
+
+pub fn authored_characters() -> Vec<CharacterDefinition> {
+    vec![
+        CharacterDefinition {
+            id: CharacterId(0), name: "The Alchemist".to_string(),
+            description: "Distills ichor from the things that crawl beneath the city.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(0), starting_skills: vec![SkillId(1)], starting_relics: Vec::new(),
+            stat_overrides: CharacterStatOverrides { max_health: Some(110), health_regen_rate: Some(0.5), ..default() },
+            passive: CharacterPassive::MaxHealthPerLevel(3),
+        },
+        CharacterDefinition {
+            id: CharacterId(1), name: "The Zealot".to_string(),
+            description: "Feeds the Gatling with prayers and spent shells alike.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(1), starting_skills: vec![SkillId(1)], starting_relics: Vec::new(),
+            stat_overrides: CharacterStatOverrides { speed: Some(225.0), armor: Some(25), ..default() },
+            passive: CharacterPassive::ExtraProjectileEveryLevels(10),
+        },
+        CharacterDefinition {
+            id: CharacterId(2), name: "The Void Seer".to_string(),
+            description: "Stared too long into the dark and learned where to strike.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(2), starting_skills: vec![SkillId(3)], starting_relics: Vec::new(),
+            stat_overrides: CharacterStatOverrides { max_health: Some(85), auto_attack_crit_chance: Some(0.05), ..default() },
+            passive: CharacterPassive::CritChancePerLevel(0.01),
+        },
+        CharacterDefinition {
+            id: CharacterId(3), name: "The Duelist".to_string(),
+            description: "Dances between the horrors, spectral blades trailing behind.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(3), starting_skills: vec![SkillId(4)], starting_relics: Vec::new(),
+            stat_overrides: CharacterStatOverrides { max_health: Some(90), speed: Some(280.0), ..default() },
+            passive: CharacterPassive::SpeedPerLevel(0.01),
+        },
+        CharacterDefinition {
+            id: CharacterId(4), name: "The Pyromancer".to_string(),
+            description: "Carries a shard of agony that still burns to the touch.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(4), starting_skills: vec![SkillId(1)], starting_relics: vec![ItemId(3)],
+            stat_overrides: CharacterStatOverrides { max_health: Some(95), ..default() },
+            passive: CharacterPassive::BonusDamageEveryLevels(3),
+        },
+        CharacterDefinition {
+            id: CharacterId(5), name: "The Stormcaller".to_string(),
+            description: "Etches crackling glyphs into every round, and they burst on impact.".to_string(),
+            portrait_path: DEFAULT_PORTRAIT_PATH.to_string(),
+            starting_weapon_id: AutomaticWeaponId(5), starting_skills: vec![SkillId(1)], starting_relics: vec![ItemId(6)],
+            stat_overrides: CharacterStatOverrides { pickup_radius_multiplier: Some(1.25), xp_gain_multiplier: Some(1.1), ..default() },
+            passive: CharacterPassive::PickupRadiusPerLevel(0.03),
+        },
+    ]
+}
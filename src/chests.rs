@@ -0,0 +1,309 @@
+// src/chests.rs
+// Reward chests. Elites, bosses and nests leave one behind; it sits where it was dropped until the survivor walks into
+// it, then play stops for the opening. A chest holds 1, 3 or 5 rewards: level-ups for the equipped weapon, relics the
+// survivor hasn't collected yet, or a burst of echoes. They are revealed one at a time; any key or click skips to the
+// full haul and the next one claims it. A chest's quality comes from whatever dropped it and the survivor's luck, and
+// a better chest holds more, rarer rewards.
+use bevy::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+use std::time::Duration;
+use crate::{
+    survivor::{Survivor, SURVIVOR_SIZE},
+    game::{AppState, UpgradeChosenEvent, ItemCollectedEvent, session_ending},
+    audio::{PlaySoundEvent, SoundEffect},
+    profile::PlayerProfile,
+    items::{ItemId, ItemLibrary},
+    upgrades::{UpgradeCard, UpgradeContext, UpgradePool, UpgradeRarity},
+    echoing_soul::{spawn_echoing_soul, ECHOING_SOUL_VALUE},
+};
+
+pub const REWARD_CHEST_SIZE: Vec2 = Vec2::new(40.0, 32.0);
+const CHEST_Z_POS: f32 = 0.35;
+const CHEST_SCATTER_RADIUS: f32 = 45.0;
+const ECHOES_REWARD_SOULS: u32 = 4; // Plus two per point of quality
+pub const ELITE_CHEST_QUALITY: f32 = 1.0;
+pub const ELITE_AFFIX_CHEST_QUALITY: f32 = 0.5; // Per affix
+pub const BOSS_CHEST_QUALITY: f32 = 4.0;
+pub const MAX_CHEST_REWARDS: usize = 5;
+const WEAPON_LEVEL_UP_WEIGHT: f32 = 4.0;
+const RELIC_WEIGHT: f32 = 2.0; // Plus the chest's quality
+const ECHOES_WEIGHT: f32 = 4.0;
+pub const REVEAL_INTERVAL_SECS: f32 = 0.6;
+const SLOT_HIDDEN_COLOR: Color = Color::rgb(0.3, 0.3, 0.35);
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct RewardChest { pub quality: f32 }
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChestReward {
+    WeaponLevelUp(UpgradeCard), // A card for the equipped weapon
+    Relic(ItemId),
+    Echoes { souls: u32, value: u32 },
+}
+
+pub fn elite_chest_quality(affix_count: usize) -> f32 { ELITE_CHEST_QUALITY + affix_count as f32 * ELITE_AFFIX_CHEST_QUALITY }
+
+// Luck is a percentage on top of the chest's own quality
+pub fn effective_chest_quality(quality: f32, luck: f32) -> f32 { quality * (1.0 + luck.max(0.0) / 100.0) }
+
+pub fn roll_reward_count(quality: f32, rng: &mut impl Rng) -> usize {
+    let five = ((quality - 2.0) * 0.2).clamp(0.0, 0.75) as f64;
+    let three = (quality * 0.3).clamp(0.0, 0.9) as f64;
+    if rng.gen_bool(five) { MAX_CHEST_REWARDS } else if rng.gen_bool(three) { 3 } else { 1 }
+}
+
+// How likely a weapon card of each rarity is to turn up; rarer cards need better chests
+pub fn chest_rarity_weight(rarity: UpgradeRarity, quality: f32) -> f32 {
+    match rarity {
+        UpgradeRarity::Regular => 3.0,
+        UpgradeRarity::Rare => 1.0 + quality * 0.5,
+        UpgradeRarity::Legendary => quality * 0.25,
+    }
+}
+
+// Rolls a chest's contents. `weapon_upgrades` are the cards for the equipped weapon and `relics` the relics on offer in
+// order of preference. Cards the survivor has already taken as often as they stack are left out, no card or relic comes
+// out twice, and a slot with nothing left to give holds echoes instead.
+pub fn roll_chest_rewards(quality: f32, weapon_upgrades: &[UpgradeCard], context: &UpgradeContext, relics: &[ItemId], rng: &mut impl Rng) -> Vec<ChestReward> {
+    let reward_count = roll_reward_count(quality, rng);
+    let stackable: Vec<&UpgradeCard> = weapon_upgrades.iter().filter(|card| context.times_picked(card.id) < card.upgrade_type.max_stacks()).collect();
+    let mut weapon_cards = stackable.choose_multiple_weighted(rng, reward_count.min(stackable.len()), |card| chest_rarity_weight(card.rarity, quality))
+        .map_or_else(|_| Vec::new(), |chosen| chosen.map(|card| (*card).clone()).collect()).into_iter();
+    let mut relics = relics.iter().copied();
+    let relic_weight = RELIC_WEIGHT + quality;
+    (0..reward_count).map(|_| {
+        let roll = rng.gen_range(0.0..WEAPON_LEVEL_UP_WEIGHT + relic_weight + ECHOES_WEIGHT);
+        let reward = if roll < WEAPON_LEVEL_UP_WEIGHT {
+            weapon_cards.next().map(ChestReward::WeaponLevelUp)
+        } else if roll < WEAPON_LEVEL_UP_WEIGHT + relic_weight {
+            relics.next().map(ChestReward::Relic)
+        } else {
+            None
+        };
+        reward.unwrap_or(ChestReward::Echoes { souls: ECHOES_REWARD_SOULS + 2 * quality.round() as u32, value: ECHOING_SOUL_VALUE })
+    }).collect()
+}
+
+// The chest being opened, and how far its reveal has got
+#[derive(Resource, Debug)]
+pub struct OpenedChest {
+    pub rewards: Vec<ChestReward>,
+    revealed: usize,
+    reveal_timer: Timer,
+}
+
+impl OpenedChest {
+    pub fn new(rewards: Vec<ChestReward>) -> Self {
+        Self { rewards, revealed: 0, reveal_timer: Timer::from_seconds(REVEAL_INTERVAL_SECS, TimerMode::Repeating) }
+    }
+
+    pub fn revealed(&self) -> usize { self.revealed }
+
+    pub fn all_revealed(&self) -> bool { self.revealed >= self.rewards.len() }
+
+    pub fn reveal_all(&mut self) { self.revealed = self.rewards.len(); }
+
+    // Returns how many rewards were newly revealed
+    pub fn tick(&mut self, delta: Duration) -> usize {
+        if self.all_revealed() { return 0; }
+        let before = self.revealed;
+        self.revealed = (self.revealed + self.reveal_timer.tick(delta).times_finished_this_tick() as usize).min(self.rewards.len());
+        self.revealed - before
+    }
+}
+
+#[derive(Component)] struct ChestRewardUI;
+#[derive(Component)] struct ChestRewardSlot(usize);
+#[derive(Component)] struct ChestRewardSlotName(usize);
+#[derive(Component)] struct ChestRewardSlotDescription(usize);
+#[derive(Component)] struct ChestRewardHint;
+
+pub struct ChestsPlugin;
+
+impl Plugin for ChestsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, chest_opening_system.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::ChestReward), setup_chest_reward_ui)
+            .add_systems(Update, (chest_reveal_system, update_chest_reward_ui).chain().run_if(in_state(AppState::ChestReward)))
+            .add_systems(OnExit(AppState::ChestReward), despawn_chest_reward_ui)
+            .add_systems(OnExit(AppState::InGame), despawn_chests.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_chests.run_if(session_ending));
+    }
+}
+
+pub fn spawn_reward_chest(commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2, quality: f32) -> Entity {
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/reward_chest_placeholder.png"),
+            sprite: Sprite { custom_size: Some(REWARD_CHEST_SIZE), ..default() },
+            transform: Transform::from_translation(position.extend(CHEST_Z_POS)),
+            ..default()
+        },
+        RewardChest { quality },
+        Name::new("RewardChest"),
+    )).id()
+}
+
+#[allow(clippy::too_many_arguments)]
+fn chest_opening_system(
+    mut commands: Commands,
+    item_library: Res<ItemLibrary>,
+    profile: Res<PlayerProfile>,
+    upgrade_pool: Res<UpgradePool>,
+    chest_query: Query<(Entity, &RewardChest, &Transform)>,
+    survivor_query: Query<(&Transform, &Survivor)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Ok((survivor_transform, survivor)) = survivor_query.get_single() else { return; };
+    let mut rng = rand::thread_rng();
+    for (chest_entity, chest, chest_transform) in chest_query.iter() {
+        if survivor_transform.translation.truncate().distance(chest_transform.translation.truncate()) > (SURVIVOR_SIZE.x + REWARD_CHEST_SIZE.x) / 2.0 { continue; }
+        let context = UpgradeContext::for_survivor(survivor);
+        let weapon_upgrades: Vec<UpgradeCard> = upgrade_pool.eligible_upgrades(&context).into_iter()
+            .filter(|card| card.upgrade_type.weapon_id().is_some())
+            .collect();
+        let relics: Vec<ItemId> = item_library.pick_relic_rewards(|item_def| profile.is_item_unlocked(item_def), &survivor.collected_item_ids, MAX_CHEST_REWARDS, &mut rng)
+            .into_iter().map(|item_def| item_def.id).collect();
+        let quality = effective_chest_quality(chest.quality, survivor.luck);
+        commands.insert_resource(OpenedChest::new(roll_chest_rewards(quality, &weapon_upgrades, &context, &relics, &mut rng)));
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation));
+        commands.entity(chest_entity).despawn_recursive();
+        next_app_state.set(AppState::ChestReward);
+        return; // One chest at a time; a second one in reach opens on the way back
+    }
+}
+
+fn chest_reward_label(reward: &ChestReward, item_library: &ItemLibrary) -> (String, String, Color) {
+    match reward {
+        ChestReward::WeaponLevelUp(card) => {
+            let color = match card.rarity { UpgradeRarity::Regular => Color::rgb(0.75, 0.75, 0.75), UpgradeRarity::Rare => Color::PURPLE, UpgradeRarity::Legendary => Color::GOLD };
+            (card.name.clone(), card.description.clone(), color)
+        }
+        ChestReward::Relic(item_id) => item_library.get_item_definition(*item_id)
+            .map_or_else(|| ("Relic".to_string(), String::new(), Color::ORANGE), |item_def| (item_def.name.clone(), item_def.description.clone(), Color::ORANGE)),
+        ChestReward::Echoes { souls, value } => ("Echoes".to_string(), format!("{} echoing souls, {} echoes in all", souls, souls * value), Color::CYAN),
+    }
+}
+
+fn setup_chest_reward_ui(mut commands: Commands, asset_server: Res<AssetServer>, opened_chest: Option<Res<OpenedChest>>) {
+    let Some(opened_chest) = opened_chest else { return; };
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0), height: Val::Percent(100.0), position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center, align_items: AlignItems::Center, flex_direction: FlexDirection::Column, row_gap: Val::Px(20.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.1, 0.08, 0.05, 0.9).into(),
+            z_index: ZIndex::Global(10),
+            ..default()
+        },
+        ChestRewardUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section(
+            format!("The chest opens! {} reward{}", opened_chest.rewards.len(), if opened_chest.rewards.len() == 1 { "" } else { "s" }),
+            TextStyle { font: font.clone(), font_size: 44.0, color: Color::GOLD },
+        ));
+        parent.spawn(NodeBundle {
+            style: Style { flex_direction: FlexDirection::Row, column_gap: Val::Px(12.0), ..default() },
+            ..default()
+        }).with_children(|row| {
+            for index in 0..opened_chest.rewards.len() {
+                row.spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(200.0), height: Val::Px(150.0), padding: UiRect::all(Val::Px(8.0)),
+                            flex_direction: FlexDirection::Column, align_items: AlignItems::Center, justify_content: JustifyContent::Center,
+                            border: UiRect::all(Val::Px(3.0)),
+                            ..default()
+                        },
+                        border_color: BorderColor(SLOT_HIDDEN_COLOR),
+                        background_color: Color::DARK_GRAY.into(),
+                        ..default()
+                    },
+                    ChestRewardSlot(index),
+                )).with_children(|slot| {
+                    slot.spawn((TextBundle::from_section("?", TextStyle { font: font.clone(), font_size: 22.0, color: Color::WHITE }), ChestRewardSlotName(index)));
+                    slot.spawn((TextBundle::from_section("", TextStyle { font: font.clone(), font_size: 15.0, color: Color::rgb(0.9, 0.9, 0.9) }), ChestRewardSlotDescription(index)));
+                });
+            }
+        });
+        parent.spawn((TextBundle::from_section("Press any key to skip", TextStyle { font: font.clone(), font_size: 20.0, color: Color::rgb(0.7, 0.7, 0.7) }), ChestRewardHint));
+    });
+}
+
+// Advances the reveal; a key or click skips it, and once everything is showing the next one claims the lot
+#[allow(clippy::too_many_arguments)]
+fn chest_reveal_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mouse_input: Res<Input<MouseButton>>,
+    opened_chest: Option<ResMut<OpenedChest>>,
+    survivor_query: Query<&Transform, With<Survivor>>,
+    mut upgrade_chosen_writer: EventWriter<UpgradeChosenEvent>,
+    mut item_collected_writer: EventWriter<ItemCollectedEvent>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Some(mut opened_chest) = opened_chest else { next_app_state.set(AppState::InGame); return; };
+    let pressed = keyboard_input.get_just_pressed().next().is_some() || mouse_input.just_pressed(MouseButton::Left);
+    if !opened_chest.all_revealed() {
+        if pressed { opened_chest.reveal_all(); }
+        else if opened_chest.tick(time.delta()) > 0 { sound_event_writer.send(PlaySoundEvent(SoundEffect::SoulCollect)); }
+        return;
+    }
+    if !pressed { return; }
+    let survivor_pos = survivor_query.get_single().map_or(Vec2::ZERO, |transform| transform.translation.truncate());
+    let mut rng = rand::thread_rng();
+    for reward in opened_chest.rewards.iter() {
+        match reward {
+            ChestReward::WeaponLevelUp(card) => { upgrade_chosen_writer.send(UpgradeChosenEvent(card.clone())); }
+            ChestReward::Relic(item_id) => { item_collected_writer.send(ItemCollectedEvent(*item_id)); }
+            ChestReward::Echoes { souls, value } => for _ in 0..*souls {
+                let scatter = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(10.0..CHEST_SCATTER_RADIUS);
+                spawn_echoing_soul(&mut commands, &asset_server, (survivor_pos + scatter).extend(0.5), *value);
+            },
+        }
+    }
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+    next_app_state.set(AppState::InGame);
+}
+
+#[allow(clippy::type_complexity)]
+fn update_chest_reward_ui(
+    opened_chest: Option<Res<OpenedChest>>,
+    item_library: Res<ItemLibrary>,
+    mut slot_query: Query<(&ChestRewardSlot, &mut BorderColor)>,
+    mut name_query: Query<(&ChestRewardSlotName, &mut Text), (Without<ChestRewardSlotDescription>, Without<ChestRewardHint>)>,
+    mut description_query: Query<(&ChestRewardSlotDescription, &mut Text), (Without<ChestRewardSlotName>, Without<ChestRewardHint>)>,
+    mut hint_query: Query<&mut Text, (With<ChestRewardHint>, Without<ChestRewardSlotName>, Without<ChestRewardSlotDescription>)>,
+) {
+    let Some(opened_chest) = opened_chest else { return; };
+    if !opened_chest.is_changed() { return; }
+    let label = |index: usize| chest_reward_label(&opened_chest.rewards[index], &item_library);
+    for (slot, mut border_color) in slot_query.iter_mut() {
+        if slot.0 < opened_chest.revealed() { border_color.0 = label(slot.0).2; }
+    }
+    for (slot, mut text) in name_query.iter_mut() {
+        if slot.0 < opened_chest.revealed() { text.sections[0].value = label(slot.0).0; }
+    }
+    for (slot, mut text) in description_query.iter_mut() {
+        if slot.0 < opened_chest.revealed() { text.sections[0].value = label(slot.0).1; }
+    }
+    if opened_chest.all_revealed() {
+        for mut text in hint_query.iter_mut() { text.sections[0].value = "Press any key to claim".to_string(); }
+    }
+}
+
+fn despawn_chest_reward_ui(mut commands: Commands, query: Query<Entity, With<ChestRewardUI>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+    commands.remove_resource::<OpenedChest>();
+}
+
+fn despawn_chests(mut commands: Commands, query: Query<Entity, With<RewardChest>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+}
@@ -0,0 +1,168 @@
+// src/consumables.rs
+// Consumable pickups dropped by horrors and props: a Healing Ichor flask, a Soul Magnet that pulls every echoing soul
+// on the map in, a Purge sigil that kills every ordinary horror on screen and a Frenzy totem that speeds up the
+// survivor's weapon for a while. Each kind's drop chance lives in its definition and is scaled by the survivor's echo
+// or relic drop rate. A pickup that isn't collected fades away after a while.
+use bevy::prelude::*;
+use rand::Rng;
+use crate::{
+    components::Health,
+    survivor::{Survivor, SURVIVOR_SIZE},
+    game::{AppState, session_ending, SCREEN_WIDTH, SCREEN_HEIGHT},
+    camera_systems::MainCamera,
+    audio::{PlaySoundEvent, SoundEffect},
+    horror::Horror,
+    boss::Boss,
+    nests::Nest,
+    props::Prop,
+    echoing_soul::{EchoingSoul, Magnetized},
+    run_stats::SurvivorHealedEvent,
+};
+
+pub const CONSUMABLE_SIZE: Vec2 = Vec2::new(22.0, 22.0);
+const CONSUMABLE_Z_POS: f32 = 0.4;
+const CONSUMABLE_COLLECTION_RADIUS: f32 = SURVIVOR_SIZE.x / 2.0 + CONSUMABLE_SIZE.x / 2.0;
+const CONSUMABLE_FADE_SECS: f32 = 5.0; // Blinks for this long before it's gone
+const HEALING_ICHOR_FRACTION: f32 = 0.3; // Of max health
+const FRENZY_FIRE_RATE_BONUS: f32 = 1.0; // Fires twice as fast
+const FRENZY_DURATION_SECS: f32 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ConsumableKind { HealingIchor, SoulMagnet, PurgeSigil, FrenzyTotem }
+
+// Which of the survivor's drop rate stats scales a consumable's drop chance
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropRateStat { Echoes, Relics }
+
+pub struct ConsumableDefinition {
+    pub kind: ConsumableKind,
+    pub name: &'static str,
+    pub sprite_path: &'static str,
+    pub drop_chance: f64, // Per ordinary horror killed
+    pub elite_drop_chance: f64,
+    pub drop_rate_stat: DropRateStat,
+    pub lifetime_secs: f32, // How long it waits on the ground
+}
+
+pub static CONSUMABLE_DEFINITIONS: [ConsumableDefinition; 4] = [
+    ConsumableDefinition { kind: ConsumableKind::HealingIchor, name: "Healing Ichor", sprite_path: "sprites/healing_ichor_placeholder.png", drop_chance: 0.012, elite_drop_chance: 0.15, drop_rate_stat: DropRateStat::Relics, lifetime_secs: 40.0 },
+    ConsumableDefinition { kind: ConsumableKind::SoulMagnet, name: "Soul Magnet", sprite_path: "sprites/soul_magnet_placeholder.png", drop_chance: 0.004, elite_drop_chance: 0.08, drop_rate_stat: DropRateStat::Echoes, lifetime_secs: 40.0 },
+    ConsumableDefinition { kind: ConsumableKind::PurgeSigil, name: "Purge Sigil", sprite_path: "sprites/purge_sigil_placeholder.png", drop_chance: 0.002, elite_drop_chance: 0.05, drop_rate_stat: DropRateStat::Relics, lifetime_secs: 30.0 },
+    ConsumableDefinition { kind: ConsumableKind::FrenzyTotem, name: "Frenzy Totem", sprite_path: "sprites/frenzy_totem_placeholder.png", drop_chance: 0.004, elite_drop_chance: 0.08, drop_rate_stat: DropRateStat::Relics, lifetime_secs: 30.0 },
+];
+
+pub fn get_consumable_definition(kind: ConsumableKind) -> &'static ConsumableDefinition {
+    CONSUMABLE_DEFINITIONS.iter().find(|def| def.kind == kind).expect("every consumable kind has a definition")
+}
+
+impl ConsumableDefinition {
+    pub fn scaled_chance(&self, base_chance: f64, echoes_multiplier: f32, relic_multiplier: f32) -> f64 {
+        let multiplier = match self.drop_rate_stat { DropRateStat::Echoes => echoes_multiplier, DropRateStat::Relics => relic_multiplier };
+        (base_chance * multiplier as f64).clamp(0.0, 1.0)
+    }
+}
+
+// At most one consumable per kill; kinds are rolled in definition order
+pub fn roll_consumable_drop(is_elite: bool, echoes_multiplier: f32, relic_multiplier: f32, rng: &mut impl Rng) -> Option<ConsumableKind> {
+    CONSUMABLE_DEFINITIONS.iter()
+        .find(|def| rng.gen_bool(def.scaled_chance(if is_elite { def.elite_drop_chance } else { def.drop_chance }, echoes_multiplier, relic_multiplier)))
+        .map(|def| def.kind)
+}
+
+#[derive(Component, Debug)]
+pub struct ConsumablePickup { pub kind: ConsumableKind, lifetime: Timer }
+
+// The Frenzy totem's effect on the survivor's weapon
+#[derive(Component, Debug)]
+pub struct FrenzyBuff { pub fire_rate_multiplier_bonus: f32, pub timer: Timer }
+
+pub struct ConsumablesPlugin;
+
+impl Plugin for ConsumablesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                consumable_lifetime_system,
+                consumable_collection_system,
+                frenzy_buff_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_consumables.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_consumables.run_if(session_ending));
+    }
+}
+
+pub fn spawn_consumable(commands: &mut Commands, asset_server: &Res<AssetServer>, kind: ConsumableKind, position: Vec2) -> Entity {
+    let def = get_consumable_definition(kind);
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load(def.sprite_path),
+            sprite: Sprite { custom_size: Some(CONSUMABLE_SIZE), ..default() },
+            transform: Transform::from_translation(position.extend(CONSUMABLE_Z_POS)),
+            ..default()
+        },
+        ConsumablePickup { kind, lifetime: Timer::from_seconds(def.lifetime_secs, TimerMode::Once) },
+        Name::new(def.name),
+    )).id()
+}
+
+fn consumable_lifetime_system(mut commands: Commands, time: Res<Time>, mut pickup_query: Query<(Entity, &mut ConsumablePickup, &mut Sprite)>) {
+    for (entity, mut pickup, mut sprite) in pickup_query.iter_mut() {
+        pickup.lifetime.tick(time.delta());
+        if pickup.lifetime.finished() { commands.entity(entity).despawn_recursive(); continue; }
+        let fading = pickup.lifetime.remaining_secs() < CONSUMABLE_FADE_SECS;
+        sprite.color.set_a(if fading && (time.elapsed_seconds() * 8.0).sin() < 0.0 { 0.3 } else { 1.0 });
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn consumable_collection_system(
+    mut commands: Commands,
+    pickup_query: Query<(Entity, &ConsumablePickup, &Transform)>,
+    mut survivor_query: Query<(Entity, &Transform, &Survivor, &mut Health, Option<&mut FrenzyBuff>), Without<Horror>>,
+    camera_query: Query<&Transform, With<MainCamera>>,
+    mut horror_query: Query<(&Horror, &Transform, &mut Health), (Without<Survivor>, Without<Boss>, Without<Nest>, Without<Prop>)>,
+    soul_query: Query<Entity, (With<EchoingSoul>, Without<Magnetized>)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut healed_writer: EventWriter<SurvivorHealedEvent>,
+) {
+    let Ok((survivor_entity, survivor_transform, survivor, mut health, mut frenzy)) = survivor_query.get_single_mut() else { return; };
+    let survivor_pos = survivor_transform.translation.truncate();
+    for (pickup_entity, pickup, pickup_transform) in pickup_query.iter() {
+        if pickup_transform.translation.truncate().distance(survivor_pos) > CONSUMABLE_COLLECTION_RADIUS { continue; }
+        match pickup.kind {
+            ConsumableKind::HealingIchor => {
+                let heal = (survivor.max_health as f32 * HEALING_ICHOR_FRACTION).round() as i32;
+                healed_writer.send(SurvivorHealedEvent(survivor.heal(&mut health, heal)));
+            }
+            ConsumableKind::SoulMagnet => {
+                for soul_entity in soul_query.iter() { commands.entity(soul_entity).insert(Magnetized); }
+            }
+            ConsumableKind::PurgeSigil => {
+                // Everything ordinary on screen dies; elites, bosses and structures shrug it off
+                let view_centre = camera_query.get_single().map_or(survivor_pos, |camera| camera.translation.truncate());
+                let half_extents = Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT) / 2.0;
+                for (horror, horror_transform, mut horror_health) in horror_query.iter_mut() {
+                    let offset = horror_transform.translation.truncate() - view_centre;
+                    if horror.is_elite || offset.x.abs() > half_extents.x || offset.y.abs() > half_extents.y { continue; }
+                    horror_health.0 = 0;
+                }
+                sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation));
+            }
+            ConsumableKind::FrenzyTotem => match frenzy.as_mut() {
+                Some(buff) => buff.timer.reset(), // A second totem extends the frenzy rather than stacking it
+                None => { commands.entity(survivor_entity).insert(FrenzyBuff { fire_rate_multiplier_bonus: FRENZY_FIRE_RATE_BONUS, timer: Timer::from_seconds(FRENZY_DURATION_SECS, TimerMode::Once) }); }
+            },
+        }
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::SoulCollect));
+        commands.entity(pickup_entity).despawn_recursive();
+    }
+}
+
+fn frenzy_buff_system(mut commands: Commands, time: Res<Time>, mut buff_query: Query<(Entity, &mut FrenzyBuff)>) {
+    for (entity, mut buff) in buff_query.iter_mut() {
+        if buff.timer.tick(time.delta()).finished() { commands.entity(entity).remove::<FrenzyBuff>(); }
+    }
+}
+
+fn despawn_consumables(mut commands: Commands, query: Query<Entity, With<ConsumablePickup>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+}
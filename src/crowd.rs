@@ -0,0 +1,166 @@
+// src/crowd.rs
+// Keeps horrors from collapsing into a single blob. After horror movement each frame, every horror is bucketed into
+// a uniform grid and pushes apart the neighbours it overlaps, lighter horrors giving way to heavier ones. Swarm types
+// also flock: they drift toward their packmates' heading and centre, so Crawling Torments move as a pack.
+// The grid keeps this to a handful of neighbour checks per horror even with hundreds on screen.
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+use crate::{
+    components::Velocity,
+    game::AppState,
+    horror::{Horror, HorrorType, horror_movement_system},
+};
+
+pub const CROWD_CELL_SIZE: f32 = 64.0;
+const PERSONAL_SPACE: f32 = 1.1; // Radii are padded so horrors keep a sliver of space between them
+const SEPARATION_STIFFNESS: f32 = 0.5; // Fraction of an overlap resolved per frame; the rest is left for later frames to avoid jitter
+const MAX_PUSH_PER_SECOND: f32 = 300.0;
+const MASS_REFERENCE_AREA: f32 = 900.0;
+const FLOCK_RADIUS: f32 = 90.0;
+const FLOCK_ALIGNMENT: f32 = 0.6;
+const FLOCK_COHESION: f32 = 0.4;
+
+// A 30x30 horror weighs 1; elites and bosses are bigger, so heavier
+pub fn horror_mass(size: Vec2) -> f32 { (size.x * size.y / MASS_REFERENCE_AREA).max(0.1) }
+
+// Swarm types that flock with others of their kind
+pub fn flocks(horror_type: HorrorType) -> bool {
+    matches!(horror_type, HorrorType::CrawlingTorment | HorrorType::SkitteringShadowling)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CrowdMember {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub radius: f32,
+    pub mass: f32,
+    pub flock: Option<HorrorType>, // Packmates share the same type
+}
+
+impl CrowdMember {
+    pub fn from_horror(entity: Entity, position: Vec2, velocity: Vec2, horror: &Horror) -> Self {
+        Self {
+            entity, position, velocity,
+            radius: horror.size.min_element() * 0.5,
+            mass: horror_mass(horror.size),
+            flock: flocks(horror.horror_type).then_some(horror.horror_type),
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<CrowdMember>>,
+    max_radius: f32, // Lets neighbour searches reach big horrors whose centres sit a few cells away
+}
+
+impl Default for SpatialGrid { fn default() -> Self { Self::new(CROWD_CELL_SIZE) } }
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self { Self { cell_size, cells: HashMap::default(), max_radius: 0.0 } }
+
+    fn cell_of(&self, position: Vec2) -> IVec2 { (position / self.cell_size).floor().as_ivec2() }
+
+    // Keeps the allocations of buckets that were filled last frame, since the crowd mostly stays put, and drops the
+    // ones it has moved out of so the map doesn't grow with every cell the horde ever crossed
+    pub fn clear(&mut self) {
+        self.cells.retain(|_, members| {
+            let occupied = !members.is_empty();
+            members.clear();
+            occupied
+        });
+        self.max_radius = 0.0;
+    }
+
+    pub fn bucket_count(&self) -> usize { self.cells.len() }
+
+    pub fn insert(&mut self, member: CrowdMember) {
+        self.max_radius = self.max_radius.max(member.radius);
+        let cell = self.cell_of(member.position);
+        self.cells.entry(cell).or_default().push(member);
+    }
+
+    pub fn members(&self) -> impl Iterator<Item = &CrowdMember> { self.cells.values().flatten() }
+
+    // Every member whose centre lies within `radius` of `position`
+    pub fn neighbors(&self, position: Vec2, radius: f32) -> impl Iterator<Item = &CrowdMember> + '_ {
+        let min = self.cell_of(position - Vec2::splat(radius));
+        let max = self.cell_of(position + Vec2::splat(radius));
+        (min.x..=max.x)
+            .flat_map(move |x| (min.y..=max.y).map(move |y| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(move |member| member.position.distance_squared(position) <= radius * radius)
+    }
+
+    // How far `member` should be moved this frame: pushed out of its neighbours, plus flocking for swarm types
+    pub fn displacement(&self, member: &CrowdMember, delta_secs: f32) -> Vec2 {
+        let reach = (member.radius + self.max_radius) * PERSONAL_SPACE;
+        let mut push = Vec2::ZERO;
+        let (mut pack_velocity, mut pack_centre, mut pack_size) = (Vec2::ZERO, Vec2::ZERO, 0.0);
+        for other in self.neighbors(member.position, reach.max(FLOCK_RADIUS)) {
+            if other.entity == member.entity { continue; }
+            push += separation_push(member, other);
+            if member.flock.is_some() && other.flock == member.flock && other.position.distance(member.position) <= FLOCK_RADIUS {
+                pack_velocity += other.velocity;
+                pack_centre += other.position;
+                pack_size += 1.0;
+            }
+        }
+        let mut displacement = (push * SEPARATION_STIFFNESS).clamp_length_max(MAX_PUSH_PER_SECOND * delta_secs);
+        if pack_size > 0.0 {
+            let alignment = (pack_velocity / pack_size - member.velocity) * FLOCK_ALIGNMENT;
+            let cohesion = (pack_centre / pack_size - member.position) * FLOCK_COHESION;
+            displacement += (alignment + cohesion) * delta_secs;
+        }
+        displacement
+    }
+}
+
+// How far `member` has to move to stop overlapping `other`; the lighter of the two does most of the moving
+pub fn separation_push(member: &CrowdMember, other: &CrowdMember) -> Vec2 {
+    let offset = member.position - other.position;
+    let overlap = (member.radius + other.radius) * PERSONAL_SPACE - offset.length();
+    if overlap <= 0.0 { return Vec2::ZERO; }
+    // Exactly stacked horrors split along an angle picked from their ids so the pair moves opposite ways
+    let direction = offset.try_normalize().unwrap_or_else(|| {
+        let angle = member.entity.index().min(other.entity.index()) as f32;
+        let away = Vec2::from_angle(angle);
+        if member.entity.index() < other.entity.index() { away } else { -away }
+    });
+    direction * overlap * other.mass / (member.mass + other.mass)
+}
+
+pub struct CrowdPlugin;
+
+impl Plugin for CrowdPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialGrid>()
+            .add_systems(Update, horror_crowd_system.after(horror_movement_system).run_if(in_state(AppState::InGame)));
+    }
+}
+
+pub fn horror_crowd_system(
+    time: Res<Time>,
+    mut grid: ResMut<SpatialGrid>,
+    mut horror_query: Query<(Entity, &mut Transform, &Velocity, &Horror, &Visibility)>,
+) {
+    grid.clear();
+    for (entity, transform, velocity, horror, visibility) in horror_query.iter() {
+        if *visibility == Visibility::Hidden { continue; } // Mid-blink horrors aren't really there
+        grid.insert(CrowdMember::from_horror(entity, transform.translation.truncate(), velocity.0, horror));
+    }
+    let delta_secs = time.delta_seconds();
+    let displacements: Vec<(Entity, Vec2)> = grid.members()
+        .map(|member| (member.entity, grid.displacement(member, delta_secs)))
+        .filter(|(_, displacement)| *displacement != Vec2::ZERO)
+        .collect();
+    for (entity, displacement) in displacements {
+        if let Ok((_, mut transform, _, horror, _)) = horror_query.get_mut(entity) {
+            if horror.speed == 0.0 { continue; } // Nests push but can't be pushed
+            transform.translation += displacement.extend(0.0);
+        }
+    }
+}
@@ -0,0 +1,227 @@
+// src/death_effects.rs
+// What a horror leaves behind when it dies, declared per horror type: the Amorphous Fleshbeast bursts into Crawling
+// Torments, the Frenzied Behemoth and Void Blinker detonate after a short fuse, the Floating Eyeball leaves a pool
+// of lingering gaze and the Mind Leech a puff of maddening spores.
+// Effects only spawn things from handle_horror_death_drops, so they fire exactly once per death. Fuse blasts hurt
+// horrors as well as the survivor, and a horror killed that way dies like any other: its own death effects and the
+// survivor's kill-trigger relics fire in turn, so blasts can chain through a crowd.
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::{
+    components::Health,
+    survivor::{Survivor, MindStrainDebuff, SURVIVOR_SIZE, MIND_STRAIN_DEBUFF_DURATION, MAX_MIND_STRAIN_STACKS},
+    game::{AppState, session_ending},
+    camera_systems::{ScreenShake, SURVIVOR_HIT_TRAUMA},
+    audio::{PlaySoundEvent, SoundEffect},
+    skills::ActiveShield,
+    elites::HorrorDamage,
+    run_stats::DamageSource,
+    horror::{Horror, HorrorType, spawn_and_return_horror_entity},
+    weapon_systems::spawn_explosion_effect,
+    visual_effects,
+};
+
+const FUSE_PULSE_HZ: f32 = 6.0;
+const FUSE_Z_POS: f32 = 0.45;
+const POOL_Z_POS: f32 = 0.05; // Under the horrors
+const SPLIT_SCATTER: f32 = 25.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Reflect, Serialize, Deserialize)]
+pub enum DeathEffect {
+    Split { minion: HorrorType, count: u32 },
+    Explode { fuse_secs: f32, radius: f32, damage: i32 },               // Hurts horrors and the survivor alike
+    GazePool { radius: f32, damage_per_tick: i32, tick_secs: f32, duration_secs: f32 }, // Survivor only
+    SanityPuff { radius: f32, stacks: u32, duration_secs: f32 },        // Mind Strain stacks, once per puff
+}
+
+pub fn death_effects(horror_type: HorrorType) -> &'static [DeathEffect] {
+    match horror_type {
+        HorrorType::AmorphousFleshbeast => &[DeathEffect::Split { minion: HorrorType::CrawlingTorment, count: 3 }],
+        HorrorType::FrenziedBehemoth => &[DeathEffect::Explode { fuse_secs: 0.9, radius: 110.0, damage: 25 }],
+        HorrorType::VoidBlinker => &[DeathEffect::Explode { fuse_secs: 0.6, radius: 70.0, damage: 12 }],
+        HorrorType::FloatingEyeball => &[DeathEffect::GazePool { radius: 55.0, damage_per_tick: 3, tick_secs: 0.5, duration_secs: 4.0 }],
+        HorrorType::MindLeech => &[DeathEffect::SanityPuff { radius: 60.0, stacks: 1, duration_secs: 2.5 }],
+        _ => &[],
+    }
+}
+
+#[derive(Component)] struct DeathFuse { timer: Timer, radius: f32, horror_damage: i32, survivor_damage: i32 }
+#[derive(Component)] struct GazePool { tick_timer: Timer, lifetime: Timer, radius: f32, damage_per_tick: i32 }
+#[derive(Component)] struct SanityPuff { lifetime: Timer, radius: f32, stacks: u32, spent: bool }
+
+pub struct DeathEffectsPlugin;
+
+impl Plugin for DeathEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                death_fuse_system,
+                gaze_pool_system,
+                sanity_puff_system,
+            ).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_death_effects.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_death_effects.run_if(session_ending));
+    }
+}
+
+// Called from handle_horror_death_drops for each of the horror's own effects
+pub fn spawn_death_effect(commands: &mut Commands, asset_server: &Res<AssetServer>, effect: DeathEffect, position: Vec3, wave_multiplier: f32) {
+    let mut rng = rand::thread_rng();
+    let scaled = |damage: i32| (damage as f32 * wave_multiplier).ceil() as i32;
+    match effect {
+        DeathEffect::Split { minion, count } => {
+            for _ in 0..count {
+                let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * SPLIT_SCATTER;
+                spawn_and_return_horror_entity(commands, asset_server, minion, position + offset.extend(0.0), wave_multiplier);
+            }
+        }
+        DeathEffect::Explode { fuse_secs, radius, damage } => {
+            commands.spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/eldritch_nova_effect_placeholder.png"),
+                    sprite: Sprite { color: Color::rgba(1.0, 0.3, 0.1, 0.5), custom_size: Some(Vec2::splat(radius * 2.0)), ..default() },
+                    transform: Transform::from_translation(position.truncate().extend(FUSE_Z_POS)),
+                    ..default()
+                },
+                // Only the survivor's share scales with the wave; horrors take the listed damage
+                DeathFuse { timer: Timer::from_seconds(fuse_secs, TimerMode::Once), radius, horror_damage: damage, survivor_damage: scaled(damage) },
+                Name::new("DeathFuse"),
+            ));
+        }
+        DeathEffect::GazePool { radius, damage_per_tick, tick_secs, duration_secs } => {
+            commands.spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/eldritch_nova_effect_placeholder.png"),
+                    sprite: Sprite { color: Color::rgba(0.3, 0.8, 0.4, 0.35), custom_size: Some(Vec2::splat(radius * 2.0)), ..default() },
+                    transform: Transform::from_translation(position.truncate().extend(POOL_Z_POS)),
+                    ..default()
+                },
+                GazePool {
+                    tick_timer: Timer::from_seconds(tick_secs, TimerMode::Repeating),
+                    lifetime: Timer::from_seconds(duration_secs, TimerMode::Once),
+                    radius, damage_per_tick: scaled(damage_per_tick),
+                },
+                Name::new("GazePool"),
+            ));
+        }
+        DeathEffect::SanityPuff { radius, stacks, duration_secs } => {
+            commands.spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/eldritch_nova_effect_placeholder.png"),
+                    sprite: Sprite { color: Color::rgba(0.7, 0.4, 0.9, 0.4), custom_size: Some(Vec2::splat(radius * 2.0)), ..default() },
+                    transform: Transform::from_translation(position.truncate().extend(FUSE_Z_POS)),
+                    ..default()
+                },
+                SanityPuff { lifetime: Timer::from_seconds(duration_secs, TimerMode::Once), radius, stacks, spent: false },
+                Name::new("SanityPuff"),
+            ));
+        }
+    }
+}
+
+// Shield first, then health; armor and invincibility frames are the caller's business
+fn hurt_survivor(commands: &mut Commands, survivor_entity: Entity, health: &mut Health, shield: Option<Mut<ActiveShield>>, damage: i32) {
+    let mut damage_to_take = damage;
+    if let Some(mut shield) = shield {
+        let damage_absorbed = damage_to_take.min(shield.amount.max(0));
+        shield.amount -= damage_absorbed;
+        damage_to_take -= damage_absorbed;
+        if shield.amount <= 0 { commands.entity(survivor_entity).remove::<ActiveShield>(); }
+    }
+    if damage_to_take > 0 { health.0 -= damage_to_take; }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn death_fuse_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    mut fuse_query: Query<(Entity, &mut DeathFuse, &Transform, &mut Sprite)>,
+    mut horror_query: Query<(Entity, &Transform, &mut Health), (With<Horror>, Without<Survivor>)>,
+    mut survivor_query: Query<(Entity, &Transform, &mut Health, &mut Survivor, Option<&mut ActiveShield>), Without<Horror>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut horror_damage: HorrorDamage,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    for (fuse_entity, mut fuse, fuse_transform, mut sprite) in fuse_query.iter_mut() {
+        fuse.timer.tick(time.delta());
+        let pulse = (fuse.timer.elapsed_secs() * FUSE_PULSE_HZ * std::f32::consts::TAU).sin() * 0.5 + 0.5;
+        sprite.color.set_a(0.2 + 0.4 * pulse * fuse.timer.percent());
+        if !fuse.timer.finished() { continue; }
+
+        let position = fuse_transform.translation.truncate();
+        spawn_explosion_effect(&mut commands, &asset_server, fuse_transform.translation, fuse.horror_damage, fuse.radius, Color::ORANGE_RED, String::from("sprites/explosion_placeholder.png"), 0.3);
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorDeath));
+        for (horror_entity, horror_transform, mut horror_health) in horror_query.iter_mut() {
+            if horror_health.0 <= 0 || horror_transform.translation.truncate().distance(position) > fuse.radius { continue; }
+            horror_damage.apply(DamageSource::DeathBurst, horror_entity, &mut horror_health, fuse.horror_damage);
+            visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_transform.translation, fuse.horror_damage, &time);
+        }
+        if let Ok((survivor_entity, survivor_transform, mut survivor_health, mut survivor, shield)) = survivor_query.get_single_mut() {
+            let in_blast = survivor_transform.translation.truncate().distance(position) <= fuse.radius + SURVIVOR_SIZE.x / 2.0;
+            if in_blast && survivor.invincibility_timer.finished() {
+                hurt_survivor(&mut commands, survivor_entity, &mut survivor_health, shield, survivor.armored_damage(fuse.survivor_damage));
+                survivor.invincibility_timer.reset();
+                sound_event_writer.send(PlaySoundEvent(SoundEffect::SurvivorHit));
+                screen_shake.add_trauma(SURVIVOR_HIT_TRAUMA);
+            }
+        }
+        commands.entity(fuse_entity).despawn_recursive();
+    }
+}
+
+// Pool ticks ignore invincibility frames, and don't grant any: it's a slow burn, not a hit
+#[allow(clippy::type_complexity)]
+fn gaze_pool_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pool_query: Query<(Entity, &mut GazePool, &Transform, &mut Sprite)>,
+    mut survivor_query: Query<(Entity, &Transform, &mut Health, &Survivor, Option<&mut ActiveShield>), Without<Horror>>,
+) {
+    for (pool_entity, mut pool, pool_transform, mut sprite) in pool_query.iter_mut() {
+        pool.lifetime.tick(time.delta());
+        sprite.color.set_a(0.35 * (1.0 - pool.lifetime.percent()));
+        if pool.lifetime.finished() { commands.entity(pool_entity).despawn_recursive(); continue; }
+        if !pool.tick_timer.tick(time.delta()).just_finished() { continue; }
+        let Ok((survivor_entity, survivor_transform, mut survivor_health, survivor, shield)) = survivor_query.get_single_mut() else { continue; };
+        if survivor_transform.translation.truncate().distance(pool_transform.translation.truncate()) <= pool.radius {
+            hurt_survivor(&mut commands, survivor_entity, &mut survivor_health, shield, survivor.armored_damage(pool.damage_per_tick));
+        }
+    }
+}
+
+fn sanity_puff_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut puff_query: Query<(Entity, &mut SanityPuff, &Transform, &mut Sprite)>,
+    mut survivor_query: Query<(Entity, &Transform, Option<&mut MindStrainDebuff>), With<Survivor>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for (puff_entity, mut puff, puff_transform, mut sprite) in puff_query.iter_mut() {
+        puff.lifetime.tick(time.delta());
+        sprite.color.set_a(0.4 * (1.0 - puff.lifetime.percent()));
+        if puff.lifetime.finished() { commands.entity(puff_entity).despawn_recursive(); continue; }
+        if puff.spent { continue; }
+        let Ok((survivor_entity, survivor_transform, mind_strain)) = survivor_query.get_single_mut() else { continue; };
+        if survivor_transform.translation.truncate().distance(puff_transform.translation.truncate()) > puff.radius + SURVIVOR_SIZE.x / 2.0 { continue; }
+        puff.spent = true;
+        match mind_strain {
+            Some(mut debuff) => {
+                debuff.stacks = (debuff.stacks + puff.stacks).min(MAX_MIND_STRAIN_STACKS);
+                debuff.timer.reset();
+            }
+            None => {
+                commands.entity(survivor_entity).insert(MindStrainDebuff {
+                    stacks: puff.stacks.min(MAX_MIND_STRAIN_STACKS),
+                    timer: Timer::from_seconds(MIND_STRAIN_DEBUFF_DURATION, TimerMode::Once),
+                });
+            }
+        }
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::SurvivorHit));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn despawn_death_effects(mut commands: Commands, query: Query<Entity, Or<(With<DeathFuse>, With<GazePool>, With<SanityPuff>)>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+}
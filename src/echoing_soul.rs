@@ -1,17 +1,31 @@
 use bevy::prelude::*;
+use bevy::utils::HashMap;
+use rand::Rng;
 use crate::{
     survivor::{Survivor, SURVIVOR_SIZE}, // Updated
     components::Velocity,
-    game::AppState,
+    game::{AppState, GameState, SCREEN_WIDTH},
     audio::{PlaySoundEvent, SoundEffect},
 };
 
 pub const ECHOING_SOUL_SIZE: Vec2 = Vec2::new(10.0, 10.0);
 pub const ECHOING_SOUL_VALUE: u32 = 25; 
 const SOUL_GRAVITATE_SPEED: f32 = 300.0;
+const MAGNETIZED_SOUL_SPEED: f32 = 900.0;
 // Updated to use SURVIVOR_SIZE
 const SOUL_PICKUP_RADIUS_COLLISION: f32 = SURVIVOR_SIZE.x / 2.0 + ECHOING_SOUL_SIZE.x / 2.0 - 5.0; 
 
+// Soul consolidation: past the budget, loose souls beyond the survivor's reach merge into clusters worth their sum,
+// and souls left far offscreen are folded into a single bank soul waiting near the survivor.
+pub const SOUL_BUDGET: usize = 300;
+pub const SOUL_MERGE_CELL_SIZE: f32 = 200.0; // Distant souls in the same cell become one cluster
+pub const SOUL_KEEP_MARGIN: f32 = 150.0; // Beyond the pickup radius; closer souls are left alone
+pub const SOUL_BANK_DISTANCE: f32 = SCREEN_WIDTH * 1.5;
+const SOUL_MERGE_INTERVAL_SECS: f32 = 1.0;
+const SOUL_BANK_OFFSET: f32 = 80.0; // Past the pickup radius, so the bank is picked up on purpose
+pub const MERGED_SOUL_SIZE: Vec2 = Vec2::new(18.0, 18.0);
+pub const SOUL_BANK_SIZE: Vec2 = Vec2::new(26.0, 26.0);
+
 
 pub struct EchoingSoulPlugin; // Renamed
 
@@ -19,6 +33,7 @@ impl Plugin for EchoingSoulPlugin { // Renamed
     fn build(&self, app: &mut App) {
         app
             .add_systems(Update, (
+                echoing_soul_merge_system,
                 echoing_soul_gravitation_and_movement_system,
                 echoing_soul_collection_system,
             ).chain().run_if(in_state(AppState::InGame)));
@@ -30,6 +45,48 @@ pub struct EchoingSoul {
     pub value: u32,
 }
 
+// Pulled to the survivor from any distance, e.g. by a Soul Magnet
+#[derive(Component)]
+pub struct Magnetized;
+
+// The one soul that far-off souls are folded into
+#[derive(Component)]
+pub struct SoulBank;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoulCluster { pub members: Vec<Entity>, pub position: Vec2, pub value: u32 }
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SoulMergePlan {
+    pub clusters: Vec<SoulCluster>,
+    pub banked: Vec<Entity>,
+    pub banked_value: u32,
+}
+
+// Decides which souls merge. Nothing happens under the budget; over it, souls past `SOUL_BANK_DISTANCE` go to the
+// bank and souls past `keep_radius` merge with the others in their cell, at the value-weighted centre of the group.
+pub fn plan_soul_merges(souls: &[(Entity, Vec2, u32)], survivor_pos: Vec2, keep_radius: f32, budget: usize) -> SoulMergePlan {
+    let mut plan = SoulMergePlan::default();
+    if souls.len() <= budget { return plan; }
+    let mut cells: HashMap<IVec2, Vec<(Entity, Vec2, u32)>> = HashMap::default();
+    for &(entity, position, value) in souls {
+        let distance = position.distance(survivor_pos);
+        if distance > SOUL_BANK_DISTANCE {
+            plan.banked.push(entity);
+            plan.banked_value += value;
+        } else if distance > keep_radius {
+            cells.entry((position / SOUL_MERGE_CELL_SIZE).floor().as_ivec2()).or_default().push((entity, position, value));
+        }
+    }
+    for members in cells.into_values().filter(|members| members.len() > 1) {
+        let value: u32 = members.iter().map(|(_, _, value)| value).sum();
+        let weight = |value: u32| value.max(1) as f32;
+        let position = members.iter().map(|(_, position, value)| *position * weight(*value)).sum::<Vec2>() / members.iter().map(|(_, _, value)| weight(*value)).sum::<f32>();
+        plan.clusters.push(SoulCluster { members: members.into_iter().map(|(entity, _, _)| entity).collect(), position, value });
+    }
+    plan
+}
+
 pub fn spawn_echoing_soul(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
@@ -52,8 +109,61 @@ pub fn spawn_echoing_soul(
     ));
 }
 
+fn spawn_merged_soul(commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec3, value: u32, is_bank: bool) -> Entity {
+    let (sprite_path, size, name) = if is_bank { ("sprites/echoing_soul_bank_placeholder.png", SOUL_BANK_SIZE, "SoulBank") } else { ("sprites/echoing_soul_cluster_placeholder.png", MERGED_SOUL_SIZE, "MergedEchoingSoul") };
+    let mut soul = commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load(sprite_path),
+            sprite: Sprite { custom_size: Some(size), ..default() },
+            transform: Transform::from_translation(position),
+            ..default()
+        },
+        EchoingSoul { value },
+        Velocity(Vec2::ZERO),
+        Name::new(name),
+    ));
+    if is_bank { soul.insert(SoulBank); }
+    soul.id()
+}
+
+#[allow(clippy::type_complexity)]
+fn echoing_soul_merge_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut merge_timer: Local<Timer>,
+    soul_query: Query<(Entity, &Transform, &EchoingSoul), (Without<Magnetized>, Without<SoulBank>)>,
+    mut bank_query: Query<(&mut EchoingSoul, &mut Transform), With<SoulBank>>,
+    survivor_query: Query<(&Transform, &Survivor), Without<EchoingSoul>>,
+) {
+    if merge_timer.duration().as_secs_f32() == 0.0 {
+        merge_timer.set_duration(std::time::Duration::from_secs_f32(SOUL_MERGE_INTERVAL_SECS));
+        merge_timer.set_mode(TimerMode::Repeating);
+    }
+    if !merge_timer.tick(time.delta()).just_finished() { return; }
+    let Ok((survivor_transform, survivor)) = survivor_query.get_single() else { return; };
+    let survivor_pos = survivor_transform.translation.truncate();
+    let souls: Vec<(Entity, Vec2, u32)> = soul_query.iter().map(|(entity, transform, soul)| (entity, transform.translation.truncate(), soul.value)).collect();
+    let pickup_radius = survivor.get_effective_pickup_radius();
+    let plan = plan_soul_merges(&souls, survivor_pos, pickup_radius + SOUL_KEEP_MARGIN, SOUL_BUDGET);
+
+    for cluster in plan.clusters {
+        for entity in cluster.members { commands.entity(entity).despawn(); }
+        spawn_merged_soul(&mut commands, &asset_server, cluster.position.extend(0.5), cluster.value, false);
+    }
+    if plan.banked.is_empty() { return; }
+    for entity in plan.banked { commands.entity(entity).despawn(); }
+    let near_survivor = survivor_pos + Vec2::from_angle(rand::thread_rng().gen_range(0.0..std::f32::consts::TAU)) * (pickup_radius + SOUL_BANK_OFFSET);
+    if let Ok((mut bank, mut bank_transform)) = bank_query.get_single_mut() {
+        bank.value += plan.banked_value;
+        if bank_transform.translation.truncate().distance(survivor_pos) > SOUL_BANK_DISTANCE { bank_transform.translation = near_survivor.extend(0.5); } // Follows the survivor once it's left behind too
+    } else {
+        spawn_merged_soul(&mut commands, &asset_server, near_survivor.extend(0.5), plan.banked_value, true);
+    }
+}
+
 fn echoing_soul_gravitation_and_movement_system(
-    mut soul_query: Query<(&mut Transform, &mut Velocity), With<EchoingSoul>>,
+    mut soul_query: Query<(&mut Transform, &mut Velocity, Has<Magnetized>), With<EchoingSoul>>,
     player_query: Query<(&Transform, &Survivor), (With<Survivor>, Without<EchoingSoul>)>,
     time: Res<Time>,
 ) {
@@ -61,11 +171,13 @@ fn echoing_soul_gravitation_and_movement_system(
         let player_pos = player_transform.translation.truncate();
         let effective_gravitate_radius = player_stats.get_effective_pickup_radius();
 
-        for (mut soul_transform, mut soul_velocity) in soul_query.iter_mut() {
+        for (mut soul_transform, mut soul_velocity, magnetized) in soul_query.iter_mut() {
             let soul_pos = soul_transform.translation.truncate();
             let distance_to_player = player_pos.distance(soul_pos);
 
-            if distance_to_player < effective_gravitate_radius {
+            if magnetized {
+                soul_velocity.0 = (player_pos - soul_pos).normalize_or_zero() * MAGNETIZED_SOUL_SPEED;
+            } else if distance_to_player < effective_gravitate_radius {
                 let direction_to_player = (player_pos - soul_pos).normalize_or_zero();
                 soul_velocity.0 = direction_to_player * SOUL_GRAVITATE_SPEED;
             } else {
@@ -78,7 +190,7 @@ fn echoing_soul_gravitation_and_movement_system(
             soul_transform.translation.y += soul_velocity.0.y * time.delta_seconds();
         }
     } else {
-        for (mut soul_transform, mut soul_velocity) in soul_query.iter_mut() {
+        for (mut soul_transform, mut soul_velocity, _) in soul_query.iter_mut() {
             if soul_velocity.0 != Vec2::ZERO {
                  soul_velocity.0 *= 0.9; 
                  if soul_velocity.0.length_squared() < 0.1 {
@@ -97,6 +209,7 @@ fn echoing_soul_collection_system(
     mut player_query: Query<(&Transform, &mut Survivor), With<Survivor>>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut game_state: ResMut<GameState>,
 ) {
     if let Ok((player_transform, mut player_stats)) = player_query.get_single_mut() {
         let player_pos = player_transform.translation.truncate();
@@ -105,6 +218,7 @@ fn echoing_soul_collection_system(
             if player_pos.distance(soul_pos) < SOUL_PICKUP_RADIUS_COLLISION { 
                 commands.entity(soul_entity).despawn();
                 sound_event_writer.send(PlaySoundEvent(SoundEffect::SoulCollect));
+                game_state.echoes_collected += soul_data.value;
                 player_stats.add_experience(soul_data.value, &mut next_app_state, &mut sound_event_writer);
             }
         }
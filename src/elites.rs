@@ -0,0 +1,272 @@
+// src/elites.rs
+// Elite horrors roll one or more affixes from a registry on top of their tougher stats. Stat affixes are applied
+// at spawn; the rest are components and systems here. How many affixes an elite rolls, and how strong they are,
+// grows with the wave. Each affix also adds to the elite's relic drop chance and the score its kill is worth.
+use bevy::prelude::*;
+use bevy::ecs::system::{EntityCommands, SystemParam};
+use rand::{Rng, seq::SliceRandom};
+use serde::{Deserialize, Serialize};
+use crate::{
+    components::Health,
+    survivor::{Survivor, SURVIVOR_SIZE},
+    game::AppState,
+    camera_systems::{ScreenShake, SURVIVOR_HIT_TRAUMA},
+    audio::{PlaySoundEvent, SoundEffect},
+    skills::ActiveShield,
+    run_stats::{DamageDealtEvent, DamageSource},
+    horror::{Horror, HorrorStats, HorrorType, Frozen, spawn_horror_type, handle_horror_death_drops},
+};
+
+pub const HASTED_SPEED_MULTIPLIER: f32 = 1.35;
+const SHIELDED_WARD_FRACTION: f32 = 0.5; // Of the elite's health
+const VAMPIRIC_HEAL_FRACTION: f32 = 0.1;  // Of the elite's maximum health, per hit landed, before wave scaling
+const SPLITTING_SPAWN_COUNT: u32 = 2;
+const SPLITTING_HEALTH_FRACTION: f32 = 0.5;
+const FROST_NOVA_RADIUS: f32 = 120.0;
+const FROST_NOVA_BASE_DAMAGE: i32 = 8;
+const FROST_NOVA_SLOW_MULTIPLIER: f32 = 0.5;
+const FROST_NOVA_SLOW_SECS: f32 = 2.0;
+const FROST_NOVA_VISUAL_SECS: f32 = 0.4;
+const COMMANDER_AURA_RADIUS: f32 = 220.0;
+const COMMANDER_BASE_SPEED_BONUS: f32 = 0.2;
+const COMMANDED_REFRESH_SECS: f32 = 0.5; // The buff lingers briefly after leaving the aura
+const AFFIX_HALO_BASE_SCALE: f32 = 1.3;
+const AFFIX_HALO_STEP: f32 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EliteAffix { Hasted, Vampiric, Shielded, Splitting, Teleporting, FrostNova, Commander, Barrage }
+
+pub struct EliteAffixDefinition {
+    pub affix: EliteAffix,
+    pub name: &'static str,
+    pub color: Color,
+    pub drop_chance_bonus: f64,
+    pub score_bonus: f32, // Added to the kill's score multiplier
+}
+
+pub static ELITE_AFFIX_REGISTRY: [EliteAffixDefinition; 8] = [
+    EliteAffixDefinition { affix: EliteAffix::Hasted, name: "Hasted", color: Color::rgb(1.0, 0.85, 0.2), drop_chance_bonus: 0.02, score_bonus: 0.25 },
+    EliteAffixDefinition { affix: EliteAffix::Vampiric, name: "Vampiric", color: Color::rgb(0.8, 0.05, 0.15), drop_chance_bonus: 0.03, score_bonus: 0.3 },
+    EliteAffixDefinition { affix: EliteAffix::Shielded, name: "Shielded", color: Color::rgb(0.4, 0.7, 1.0), drop_chance_bonus: 0.04, score_bonus: 0.5 },
+    EliteAffixDefinition { affix: EliteAffix::Splitting, name: "Splitting", color: Color::rgb(0.5, 0.9, 0.3), drop_chance_bonus: 0.03, score_bonus: 0.4 },
+    EliteAffixDefinition { affix: EliteAffix::Teleporting, name: "Teleporting", color: Color::rgb(0.7, 0.3, 1.0), drop_chance_bonus: 0.03, score_bonus: 0.4 },
+    EliteAffixDefinition { affix: EliteAffix::FrostNova, name: "Frost Nova", color: Color::rgb(0.7, 0.95, 1.0), drop_chance_bonus: 0.03, score_bonus: 0.3 },
+    EliteAffixDefinition { affix: EliteAffix::Commander, name: "Commander", color: Color::rgb(1.0, 0.5, 0.1), drop_chance_bonus: 0.05, score_bonus: 0.5 },
+    EliteAffixDefinition { affix: EliteAffix::Barrage, name: "Barrage", color: Color::rgb(0.9, 0.4, 0.3), drop_chance_bonus: 0.04, score_bonus: 0.45 },
+];
+
+pub fn get_affix_definition(affix: EliteAffix) -> &'static EliteAffixDefinition {
+    ELITE_AFFIX_REGISTRY.iter().find(|def| def.affix == affix).expect("every EliteAffix is registered")
+}
+
+pub fn affix_count_for_wave(wave_number: u32) -> usize {
+    match wave_number { 0..=7 => 1, 8..=13 => 2, _ => 3 }
+}
+
+pub fn roll_elite_affixes(rng: &mut impl Rng, horror_type: HorrorType, wave_number: u32) -> Vec<EliteAffix> {
+    let eligible: Vec<EliteAffix> = ELITE_AFFIX_REGISTRY.iter().map(|def| def.affix)
+        .filter(|affix| !(*affix == EliteAffix::Teleporting && horror_type == HorrorType::VoidBlinker)) // Already blinks
+        .collect();
+    eligible.choose_multiple(rng, affix_count_for_wave(wave_number)).copied().collect()
+}
+
+#[derive(Component, Debug, Clone)]
+pub struct EliteAffixes {
+    pub affixes: Vec<EliteAffix>,
+    pub max_health: i32,
+    pub wave_multiplier: f32, // Scales the affixes' effects
+    death_handled: bool,
+}
+
+impl EliteAffixes {
+    pub fn new(affixes: Vec<EliteAffix>, max_health: i32, wave_multiplier: f32) -> Self {
+        Self { affixes, max_health, wave_multiplier, death_handled: false }
+    }
+    pub fn has(&self, affix: EliteAffix) -> bool { self.affixes.contains(&affix) }
+    pub fn drop_chance_bonus(&self) -> f64 { self.affixes.iter().map(|affix| get_affix_definition(*affix).drop_chance_bonus).sum() }
+    pub fn score_multiplier(&self) -> f32 { 1.0 + self.affixes.iter().map(|affix| get_affix_definition(*affix).score_bonus).sum::<f32>() }
+    pub fn vampiric_heal(&self) -> i32 { (self.max_health as f32 * VAMPIRIC_HEAL_FRACTION * self.wave_multiplier).ceil() as i32 }
+    pub fn frost_nova_damage(&self) -> i32 { (FROST_NOVA_BASE_DAMAGE as f32 * self.wave_multiplier).ceil() as i32 }
+    pub fn commander_speed_bonus(&self) -> f32 { COMMANDER_BASE_SPEED_BONUS * self.wave_multiplier }
+}
+
+// Absorbs damage before health. Shield penetration on auto-attacks lets part of a hit through.
+#[derive(Component, Debug, Clone)]
+pub struct EliteWard { pub amount: i32 }
+
+impl EliteWard {
+    pub fn new(amount: i32) -> Self { Self { amount } }
+
+    // Soaks what it can of a hit and returns the rest, which goes on to health
+    pub fn soak(&mut self, damage: i32, penetration_percent: f32) -> i32 {
+        let penetrating = (damage as f32 * (penetration_percent / 100.0).clamp(0.0, 1.0)).round() as i32;
+        let absorbed = (damage - penetrating).clamp(0, self.amount.max(0));
+        self.amount -= absorbed;
+        damage - absorbed
+    }
+}
+
+// Every hit on a horror goes through here, so a Shielded elite's ward has soaked its share before health drops and
+// only what got through is reported. Nothing ever sees a warded hit land, not even for a frame.
+#[derive(SystemParam)]
+pub struct HorrorDamage<'w, 's> {
+    wards: Query<'w, 's, &'static mut EliteWard>,
+    damage_dealt_writer: EventWriter<'w, DamageDealtEvent>,
+}
+
+impl HorrorDamage<'_, '_> {
+    // Returns the health the hit actually took
+    pub fn apply(&mut self, source: DamageSource, target: Entity, health: &mut Health, damage: i32) -> i32 {
+        self.apply_penetrating(source, target, health, damage, 0.0)
+    }
+
+    pub fn apply_penetrating(&mut self, source: DamageSource, target: Entity, health: &mut Health, damage: i32, penetration_percent: f32) -> i32 {
+        let damage = self.wards.get_mut(target).map_or(damage, |mut ward| ward.soak(damage, penetration_percent));
+        let hit = DamageDealtEvent::new(source, target, damage, health.0);
+        health.0 = health.0.saturating_sub(damage);
+        self.damage_dealt_writer.send(hit);
+        hit.amount
+    }
+}
+
+// Speed buff from a nearby Commander elite
+#[derive(Component, Debug)]
+pub struct Commanded { pub timer: Timer, pub speed_multiplier: f32 }
+
+#[derive(Component)] struct FrostNovaVisual { timer: Timer }
+
+pub struct ElitesPlugin;
+
+impl Plugin for ElitesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+                commander_aura_system,
+                elite_death_affix_system,
+            ).chain().before(handle_horror_death_drops).run_if(in_state(AppState::InGame)))
+            .add_systems(Update, frost_nova_visual_system.run_if(in_state(AppState::InGame)));
+    }
+}
+
+// Called from spawn_horror_type once the elite's base stats are final
+pub fn apply_elite_affixes(entity_commands: &mut EntityCommands, asset_server: &AssetServer, affixes: &[EliteAffix], size: Vec2, health: i32, wave_multiplier: f32) {
+    if affixes.contains(&EliteAffix::Shielded) {
+        entity_commands.insert(EliteWard::new((health as f32 * SHIELDED_WARD_FRACTION * wave_multiplier).ceil() as i32));
+    }
+    entity_commands.insert(EliteAffixes::new(affixes.to_vec(), health, wave_multiplier));
+    // A ring per affix behind the sprite, innermost first
+    entity_commands.with_children(|parent| {
+        for (ring_index, affix) in (0..).zip(affixes.iter()) {
+            let def = get_affix_definition(*affix);
+            parent.spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/eldritch_nova_effect_placeholder.png"),
+                    sprite: Sprite { color: def.color.with_a(0.45), custom_size: Some(size * (AFFIX_HALO_BASE_SCALE + ring_index as f32 * AFFIX_HALO_STEP)), ..default() },
+                    transform: Transform::from_xyz(0.0, 0.0, -0.05 - ring_index as f32 * 0.01),
+                    ..default()
+                },
+                Name::new(format!("AffixHalo_{}", def.name)),
+            ));
+        }
+    });
+}
+
+fn commander_aura_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    commander_query: Query<(Entity, &GlobalTransform, &EliteAffixes)>,
+    mut horror_query: Query<(Entity, &GlobalTransform, Option<&mut Commanded>), With<Horror>>,
+) {
+    let commanders: Vec<(Entity, Vec2, f32)> = commander_query.iter()
+        .filter(|(_, _, affixes)| affixes.has(EliteAffix::Commander))
+        .map(|(entity, transform, affixes)| (entity, transform.translation().truncate(), affixes.commander_speed_bonus()))
+        .collect();
+
+    for (entity, transform, commanded) in horror_query.iter_mut() {
+        let position = transform.translation().truncate();
+        let best_bonus = commanders.iter()
+            .filter(|(commander, commander_pos, _)| *commander != entity && commander_pos.distance_squared(position) < COMMANDER_AURA_RADIUS.powi(2))
+            .map(|(_, _, bonus)| *bonus)
+            .fold(0.0_f32, f32::max);
+        match commanded {
+            Some(mut commanded) => {
+                if best_bonus > 0.0 {
+                    commanded.timer.reset();
+                    commanded.speed_multiplier = 1.0 + best_bonus;
+                } else {
+                    commanded.timer.tick(time.delta());
+                    if commanded.timer.finished() { commands.entity(entity).remove::<Commanded>(); }
+                }
+            }
+            None if best_bonus > 0.0 => {
+                commands.entity(entity).insert(Commanded { timer: Timer::from_seconds(COMMANDED_REFRESH_SECS, TimerMode::Once), speed_multiplier: 1.0 + best_bonus });
+            }
+            None => {}
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn elite_death_affix_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut elite_query: Query<(&mut EliteAffixes, &Health, &Transform, &Horror)>,
+    mut survivor_query: Query<(Entity, &Transform, &mut Health, &mut Survivor, Option<&mut ActiveShield>), Without<Horror>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
+) {
+    let mut rng = rand::thread_rng();
+    for (mut affixes, health, transform, horror) in elite_query.iter_mut() {
+        if health.0 > 0 || affixes.death_handled { continue; }
+        affixes.death_handled = true;
+        let position = transform.translation;
+
+        if affixes.has(EliteAffix::Splitting) {
+            let split_health = ((HorrorStats::get_for_type(horror.horror_type, affixes.wave_multiplier).health as f32 * SPLITTING_HEALTH_FRACTION).ceil() as i32).max(1);
+            for _ in 0..SPLITTING_SPAWN_COUNT {
+                let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * horror.size.x * 0.5;
+                if let Some(split_entity) = spawn_horror_type(&mut commands, &asset_server, horror.horror_type, position + offset.extend(0.0), affixes.wave_multiplier, &[]) {
+                    commands.entity(split_entity).insert(Health(split_health));
+                }
+            }
+        }
+
+        if affixes.has(EliteAffix::FrostNova) {
+            commands.spawn((
+                SpriteBundle {
+                    texture: asset_server.load("sprites/eldritch_nova_effect_placeholder.png"),
+                    sprite: Sprite { color: get_affix_definition(EliteAffix::FrostNova).color.with_a(0.7), custom_size: Some(Vec2::splat(1.0)), ..default() },
+                    transform: Transform::from_translation(position.truncate().extend(0.6)),
+                    ..default()
+                },
+                FrostNovaVisual { timer: Timer::from_seconds(FROST_NOVA_VISUAL_SECS, TimerMode::Once) },
+                Name::new("EliteFrostNova"),
+            ));
+            let Ok((survivor_entity, survivor_transform, mut survivor_health, mut survivor, opt_shield)) = survivor_query.get_single_mut() else { continue; };
+            if survivor_transform.translation.truncate().distance(position.truncate()) > FROST_NOVA_RADIUS + SURVIVOR_SIZE.x / 2.0 { continue; }
+            commands.entity(survivor_entity).insert(Frozen { timer: Timer::from_seconds(FROST_NOVA_SLOW_SECS, TimerMode::Once), speed_multiplier: FROST_NOVA_SLOW_MULTIPLIER });
+            if !survivor.invincibility_timer.finished() { continue; }
+
+            let mut damage_to_take = survivor.armored_damage(affixes.frost_nova_damage());
+            if let Some(mut shield) = opt_shield {
+                let damage_absorbed = damage_to_take.min(shield.amount.max(0));
+                shield.amount -= damage_absorbed;
+                damage_to_take -= damage_absorbed;
+                if shield.amount <= 0 { commands.entity(survivor_entity).remove::<ActiveShield>(); }
+            }
+            if damage_to_take > 0 { survivor_health.0 -= damage_to_take; }
+            survivor.invincibility_timer.reset();
+            sound_event_writer.send(PlaySoundEvent(SoundEffect::SurvivorHit));
+            screen_shake.add_trauma(SURVIVOR_HIT_TRAUMA);
+        }
+    }
+}
+
+fn frost_nova_visual_system(mut commands: Commands, time: Res<Time>, mut nova_query: Query<(Entity, &mut FrostNovaVisual, &mut Transform, &mut Sprite)>) {
+    for (entity, mut nova, mut transform, mut sprite) in nova_query.iter_mut() {
+        nova.timer.tick(time.delta());
+        let progress = nova.timer.percent();
+        transform.scale = Vec3::splat(FROST_NOVA_RADIUS * 2.0 * progress);
+        sprite.color.set_a(0.7 * (1.0 - progress));
+        if nova.timer.finished() { commands.entity(entity).despawn_recursive(); }
+    }
+}
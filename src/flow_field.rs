@@ -0,0 +1,282 @@
+// src/flow_field.rs
+// A flow field toward the survivor. The ground around the survivor is cut into a grid, and a Dijkstra sweep outward
+// from the survivor's cell leaves every reachable cell pointing at the neighbour it was reached from. A horror then
+// finds its way around ruins by looking up its own cell, O(1) no matter how many horrors there are. Walkers and
+// flyers get separate layers, since flyers drift over pits. The sweep is spread over several frames with a cell
+// budget, and horrors keep following the previous field until the new one is complete. Small steps by the survivor
+// only re-sweep the cells near them, and which cells are blocked is remembered until the ruins change.
+use bevy::prelude::*;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use crate::{
+    survivor::Survivor,
+    game::{AppState, session_ending},
+    horror::{HorrorType, horror_movement_system},
+    arena::{ArenaLayout, ObstacleKind, flies},
+};
+
+pub const FLOW_CELL_SIZE: f32 = 48.0;
+pub const FLOW_FIELD_DIMENSION: i32 = 96; // Cells per side; covers the ruins streamed in around the survivor
+const FLOW_CELLS_PER_FRAME: usize = 2500; // Settled cells per layer per frame
+const ORTHOGONAL_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+const REPAIR_MARGIN: u32 = 16 * ORTHOGONAL_COST; // How far past the old target a repair re-sweeps
+const FULL_SWEEP_DRIFT: i32 = 8; // Cells the survivor may wander from the grid's centre before it's re-centred
+const NEIGHBOURS: [(IVec2, u32); 8] = [
+    (IVec2::new(1, 0), ORTHOGONAL_COST), (IVec2::new(-1, 0), ORTHOGONAL_COST), (IVec2::new(0, 1), ORTHOGONAL_COST), (IVec2::new(0, -1), ORTHOGONAL_COST),
+    (IVec2::new(1, 1), DIAGONAL_COST), (IVec2::new(1, -1), DIAGONAL_COST), (IVec2::new(-1, 1), DIAGONAL_COST), (IVec2::new(-1, -1), DIAGONAL_COST),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TraversalLayer { Ground, Flying }
+
+impl TraversalLayer {
+    pub const ALL: [TraversalLayer; 2] = [TraversalLayer::Ground, TraversalLayer::Flying];
+
+    pub fn blocked_by(self, kind: ObstacleKind) -> bool {
+        match self { TraversalLayer::Ground => true, TraversalLayer::Flying => kind.blocks_flyers() }
+    }
+}
+
+pub fn traversal_layer(horror_type: HorrorType) -> TraversalLayer {
+    if flies(horror_type) { TraversalLayer::Flying } else { TraversalLayer::Ground }
+}
+
+pub fn flow_cell_of(position: Vec2) -> IVec2 { (position / FLOW_CELL_SIZE).floor().as_ivec2() }
+
+fn cell_centre(cell: IVec2) -> Vec2 { (cell.as_vec2() + Vec2::splat(0.5)) * FLOW_CELL_SIZE }
+
+// One finished layer: for every cell the survivor can be reached from, which way to go
+pub struct FlowLayer {
+    origin: IVec2, // Bottom-left cell of the grid
+    target: IVec2,
+    costs: Vec<u32>,
+    directions: Vec<Vec2>,
+}
+
+impl FlowLayer {
+    fn index(&self, cell: IVec2) -> Option<usize> { grid_index(self.origin, cell) }
+
+    pub fn target(&self) -> IVec2 { self.target }
+
+    // Which way a horror at `position` should head, or None if it's off the grid, walled in, or already in the
+    // survivor's cell
+    pub fn direction(&self, position: Vec2) -> Option<Vec2> {
+        let index = self.index(flow_cell_of(position))?;
+        Some(self.directions[index]).filter(|direction| *direction != Vec2::ZERO)
+    }
+
+    // Path length to the survivor in tenths of a cell, or None if unreachable
+    pub fn cost(&self, position: Vec2) -> Option<u32> {
+        let index = self.index(flow_cell_of(position))?;
+        Some(self.costs[index]).filter(|cost| *cost != u32::MAX)
+    }
+}
+
+fn grid_index(origin: IVec2, cell: IVec2) -> Option<usize> {
+    let local = cell - origin;
+    if local.x < 0 || local.y < 0 || local.x >= FLOW_FIELD_DIMENSION || local.y >= FLOW_FIELD_DIMENSION { return None; }
+    Some((local.y * FLOW_FIELD_DIMENSION + local.x) as usize)
+}
+
+fn grid_cell(origin: IVec2, index: usize) -> IVec2 {
+    origin + IVec2::new(index as i32 % FLOW_FIELD_DIMENSION, index as i32 / FLOW_FIELD_DIMENSION)
+}
+
+// Which cells the ruins block, by world cell so it survives the grid re-centring. Only thrown away when the ruins
+// change, and trimmed to the current grid whenever a sweep picks it up.
+#[derive(Default)]
+pub struct BlockedCells(HashMap<IVec2, bool>);
+
+impl BlockedCells {
+    fn get(&mut self, cell: IVec2, blocked: &impl Fn(Vec2) -> bool) -> bool {
+        *self.0.entry(cell).or_insert_with(|| blocked(cell_centre(cell)))
+    }
+
+    fn retain_grid(&mut self, origin: IVec2) { self.0.retain(|cell, _| grid_index(origin, *cell).is_some()); }
+}
+
+// A sweep in progress. Cells are only tested against the ruins when the sweep first reaches them.
+pub struct FlowBuild {
+    layer: FlowLayer,
+    blocked: BlockedCells,
+    open: BinaryHeap<Reverse<(u32, usize)>>,
+    reseed: Option<u32>, // A repair: the cost of the old target from the new one, and nothing past REPAIR_MARGIN beyond it is re-swept
+}
+
+impl FlowBuild {
+    pub fn new(target: Vec2) -> Self {
+        let target = flow_cell_of(target);
+        Self::around(target, target - IVec2::splat(FLOW_FIELD_DIMENSION / 2), None)
+    }
+
+    // A cheap sweep after the survivor steps a short way: the grid stays put, only the cells near the survivor are
+    // re-swept, and everything further out keeps following the previous layer toward the old target, which the
+    // re-swept cells lead on from. None when the survivor has left the previous layer's reach and it needs a full sweep.
+    pub fn repair(target: Vec2, previous: &FlowLayer) -> Option<Self> {
+        let target = flow_cell_of(target);
+        let drift = target - (previous.origin + IVec2::splat(FLOW_FIELD_DIMENSION / 2));
+        if drift.abs().max_element() > FULL_SWEEP_DRIFT { return None; }
+        let offset = previous.costs[previous.index(target)?];
+        if offset == u32::MAX { return None; }
+        Some(Self::around(target, previous.origin, Some(offset)))
+    }
+
+    fn around(target: IVec2, origin: IVec2, reseed: Option<u32>) -> Self {
+        let cell_count = (FLOW_FIELD_DIMENSION * FLOW_FIELD_DIMENSION) as usize;
+        let mut build = Self {
+            layer: FlowLayer { origin, target, costs: vec![u32::MAX; cell_count], directions: vec![Vec2::ZERO; cell_count] },
+            blocked: BlockedCells::default(),
+            open: BinaryHeap::new(),
+            reseed,
+        };
+        let start = grid_index(origin, target).expect("the target lies inside its own grid");
+        build.layer.costs[start] = 0;
+        build.open.push(Reverse((0, start)));
+        build
+    }
+
+    fn with_blocked(mut self, mut blocked: BlockedCells) -> Self {
+        blocked.retain_grid(self.layer.origin);
+        self.blocked = blocked;
+        self
+    }
+
+    fn is_blocked(&mut self, index: usize, blocked: &impl Fn(Vec2) -> bool) -> bool {
+        let cell = grid_cell(self.layer.origin, index);
+        cell != self.layer.target && self.blocked.get(cell, blocked) // The survivor's own cell is open even when they hug a wall
+    }
+
+    // Settles up to `budget` cells; true once the whole reachable grid (or a repair's reach) is done
+    pub fn advance(&mut self, budget: usize, blocked: impl Fn(Vec2) -> bool) -> bool {
+        let limit = self.reseed.map_or(u32::MAX, |offset| offset + REPAIR_MARGIN);
+        let mut settled = 0;
+        while let Some(Reverse((cost, index))) = self.open.pop() {
+            if cost > self.layer.costs[index] { continue; } // A cheaper route already settled it
+            if cost > limit { self.open.clear(); break; } // The old layer takes over from here
+            let cell = grid_cell(self.layer.origin, index);
+            for (step, step_cost) in NEIGHBOURS {
+                let Some(next) = grid_index(self.layer.origin, cell + step) else { continue; };
+                if self.is_blocked(next, &blocked) { continue; }
+                // No cutting corners: a diagonal step needs both of the cells beside it open
+                if step.x != 0 && step.y != 0 {
+                    let side_a = grid_index(self.layer.origin, cell + IVec2::new(step.x, 0));
+                    let side_b = grid_index(self.layer.origin, cell + IVec2::new(0, step.y));
+                    let (Some(side_a), Some(side_b)) = (side_a, side_b) else { continue; };
+                    if self.is_blocked(side_a, &blocked) || self.is_blocked(side_b, &blocked) { continue; }
+                }
+                let next_cost = cost + step_cost;
+                if next_cost < self.layer.costs[next] {
+                    self.layer.costs[next] = next_cost;
+                    self.layer.directions[next] = -step.as_vec2().normalize();
+                    self.open.push(Reverse((next_cost, next)));
+                }
+            }
+            settled += 1;
+            if settled >= budget { return self.open.is_empty(); }
+        }
+        true
+    }
+
+    pub fn finish(self) -> FlowLayer { self.finish_over(None).0 }
+
+    // Completes a repair by falling back on `previous` wherever going by way of the old target is no longer than
+    // the re-swept route. Every cell still points at a cheaper one, so the merged field can't loop.
+    pub fn finish_over(self, previous: Option<&FlowLayer>) -> (FlowLayer, BlockedCells) {
+        let Self { mut layer, blocked, reseed, .. } = self;
+        if let (Some(offset), Some(previous)) = (reseed, previous.filter(|previous| previous.origin == layer.origin)) {
+            for (index, old_cost) in previous.costs.iter().enumerate() {
+                let via_old_target = old_cost.saturating_add(offset);
+                if via_old_target < layer.costs[index] {
+                    layer.costs[index] = via_old_target;
+                    layer.directions[index] = previous.directions[index];
+                }
+            }
+        }
+        (layer, blocked)
+    }
+}
+
+// One traversal layer's current field, the sweep that will replace it, and the blocked cells kept between sweeps
+#[derive(Default)]
+struct FlowTrack {
+    current: Option<FlowLayer>,
+    build: Option<FlowBuild>,
+    blocked: BlockedCells,
+}
+
+impl FlowTrack {
+    fn start(&mut self, target: Vec2, full: bool) {
+        let repair = self.current.as_ref().filter(|_| !full).and_then(|previous| FlowBuild::repair(target, previous));
+        let build = repair.unwrap_or_else(|| FlowBuild::new(target));
+        self.build = Some(build.with_blocked(std::mem::take(&mut self.blocked)));
+    }
+
+    fn advance(&mut self, budget: usize, blocked: impl Fn(Vec2) -> bool) {
+        let Some(build) = self.build.as_mut() else { return; };
+        if !build.advance(budget, blocked) { return; }
+        let (layer, blocked) = self.build.take().unwrap().finish_over(self.current.as_ref());
+        self.current = Some(layer);
+        self.blocked = blocked;
+    }
+}
+
+// The current layers horrors follow, and the sweeps that will replace them
+#[derive(Resource, Default)]
+pub struct FlowField {
+    ground: FlowTrack,
+    flying: FlowTrack,
+    stale: bool, // The ruins changed since the current sweeps started
+}
+
+impl FlowField {
+    pub fn layer(&self, layer: TraversalLayer) -> Option<&FlowLayer> {
+        match layer { TraversalLayer::Ground => self.ground.current.as_ref(), TraversalLayer::Flying => self.flying.current.as_ref() }
+    }
+
+    pub fn sample(&self, layer: TraversalLayer, position: Vec2) -> Option<Vec2> { self.layer(layer)?.direction(position) }
+
+    fn is_building(&self) -> bool { self.ground.build.is_some() || self.flying.build.is_some() }
+
+    // Starts fresh sweeps once the last ones are done and the survivor has changed cell or the ruins have changed.
+    // A sweep is never restarted halfway, so a survivor on the move still gets a complete field every few frames.
+    // A short step only repairs the layers around the survivor; new ruins mean every cached cell and distance is
+    // suspect, so those get a full sweep.
+    pub fn replan(&mut self, target: Vec2, layout_changed: bool) {
+        self.stale |= layout_changed;
+        if self.is_building() { return; }
+        let moved = self.ground.current.as_ref().map_or(true, |layer| layer.target() != flow_cell_of(target));
+        if !moved && !self.stale { return; }
+        let full = std::mem::take(&mut self.stale);
+        for track in [&mut self.ground, &mut self.flying] {
+            if full { track.blocked = BlockedCells::default(); }
+            track.start(target, full);
+        }
+    }
+
+    // Advances the sweeps in progress, swapping each layer in as it completes
+    pub fn advance(&mut self, budget: usize, layout: &ArenaLayout) {
+        let blocked = |layer: TraversalLayer| move |centre: Vec2| layout.is_blocked(centre, FLOW_CELL_SIZE / 2.0, |kind| layer.blocked_by(kind));
+        self.ground.advance(budget, blocked(TraversalLayer::Ground));
+        self.flying.advance(budget, blocked(TraversalLayer::Flying));
+    }
+}
+
+pub struct FlowFieldPlugin;
+
+impl Plugin for FlowFieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlowField>()
+            .add_systems(Update, flow_field_system.before(horror_movement_system).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), reset_flow_field.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), reset_flow_field.run_if(session_ending));
+    }
+}
+
+fn flow_field_system(layout: Res<ArenaLayout>, mut flow_field: ResMut<FlowField>, survivor_query: Query<&Transform, With<Survivor>>) {
+    let Ok(survivor_transform) = survivor_query.get_single() else { return; };
+    flow_field.replan(survivor_transform.translation.truncate(), layout.is_changed());
+    flow_field.advance(FLOW_CELLS_PER_FRAME, &layout);
+}
+
+fn reset_flow_field(mut flow_field: ResMut<FlowField>) { *flow_field = FlowField::default(); }
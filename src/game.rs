@@ -1,17 +1,27 @@
 // src/game.rs
 use bevy::prelude::*;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 use crate::{
     horror::{HorrorSpawnTimer, MaxHorrors},
     echoing_soul::{EchoingSoul, EchoingSoulPlugin},
     survivor::{Survivor, SanityStrain},
     components::Health,
-    upgrades::{UpgradePlugin, UpgradePool, OfferedUpgrades, UpgradeCard, UpgradeType, UpgradeRarity, LobbedAoEPoolField, ChanneledBeamField, ReturningProjectileField, StandardProjectileField, ConeAttackField}, // Added UpgradeRarity and new Field enums
+    upgrades::{UpgradePlugin, UpgradePool, OfferedUpgrades, UpgradeCard, UpgradeContext, UpgradeType, UpgradeRarity, MAX_EQUIPPED_SKILLS, LobbedAoEPoolField, ChanneledBeamField, ReturningProjectileField, StandardProjectileField, ConeAttackField}, // Added UpgradeRarity and new Field enums
     weapons::{CircleOfWarding, SwarmOfNightmares},
     audio::{PlaySoundEvent, SoundEffect},
     debug_menu::DebugMenuPlugin,
+    pause_menu::PauseMenuPlugin,
+    meta_shop::MetaShopPlugin,
+    achievement_gallery::AchievementGalleryPlugin,
+    run_save::{RunSavePlugin, SuspendedRun, ResumeRunButton},
+    run_stats::{RunStats, SurvivorHealedEvent, spawn_run_stats_panel},
+    leaderboard::{LatestLeaderboardResult, LeaderboardPlugin, submit_leaderboard_entry},
+    characters::{CharacterId, CharacterLibrary},
+    profile::PlayerProfile,
+    merchant::{try_spend, wave_price, REROLL_PRICE, BANISH_PRICE},
     items::{ItemId, ItemLibrary, AutomaticWeaponId, AutomaticWeaponLibrary, AttackTypeData}, 
-    skills::{ActiveSkillInstance}, 
+    skills::{ActiveSkillInstance, SkillLibrary}, 
     automatic_projectiles::AutomaticProjectile,
     in_game_debug_ui::{
         DebugDisplayState, InGameDebugUI, PlayerStatsDebugText, InherentWeaponDebugText,
@@ -40,33 +50,60 @@ pub enum AppState {
     LevelUp,
     GameOver,
     DebugUpgradeMenu,
+    Paused,
+    MetaShop,
+    AchievementGallery,
+    Leaderboard,
+    ChestReward, // Opening a reward chest, see chests.rs
+    Merchant, // Browsing the merchant's shop, see merchant.rs
 }
 
 #[derive(Resource, Default)]
 struct PreviousGameState(Option<AppState>);
 
 #[derive(Resource)]
-pub struct SelectedCharacter(pub AutomaticWeaponId); 
+pub struct SelectedCharacter(pub CharacterId); 
 
 impl Default for SelectedCharacter {
     fn default() -> Self {
-        SelectedCharacter(AutomaticWeaponId(0)) 
+        SelectedCharacter(CharacterId(0)) 
     }
 }
 
+// Rule sets a run can be played under. Leaderboards are kept separately per mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum GameMode { #[default] Standard }
+impl GameMode {
+    pub fn display_name(self) -> &'static str { match self { GameMode::Standard => "Standard" } }
+}
+
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct SelectedGameMode(pub GameMode);
+
+// Rolled when a run starts and carried through suspend/resume. Only the arena and prop layout are drawn from it; gameplay rolls still use `thread_rng`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSeed(pub u64);
+
 #[derive(Resource)]
 pub struct GameConfig { pub width: f32, pub height: f32, pub spawn_area_padding: f32, }
 impl Default for GameConfig { fn default() -> Self { Self { width: SCREEN_WIDTH, height: SCREEN_HEIGHT, spawn_area_padding: 50.0 } } }
 pub struct GamePlugin;
 #[derive(Resource, Default)]
-pub struct GameState { pub score: u32, pub wave_number: u32, pub horror_count: u32, pub game_over_timer: Timer, pub game_timer: Timer, pub difficulty_timer: Timer, }
+pub struct GameState { pub score: u32, pub wave_number: u32, pub horror_count: u32, pub game_over_timer: Timer, pub game_timer: Timer, pub difficulty_timer: Timer, pub echoes_collected: u32, }
 #[derive(Event)] pub struct UpgradeChosenEvent(pub UpgradeCard);
 #[derive(Event)] pub struct ItemCollectedEvent(pub ItemId);
 
 #[derive(Component)] struct MainMenuUI;
-#[derive(Component)] struct CharacterSelectButton(AutomaticWeaponId); 
+#[derive(Component)] struct CharacterSelectButton(CharacterId); 
+#[derive(Component)] struct OpenMetaShopButton;
+#[derive(Component)] struct OpenAchievementsButton;
+#[derive(Component)] struct OpenLeaderboardButton;
 #[derive(Component)] struct LevelUpUI;
 #[derive(Component)] struct UpgradeButton(UpgradeCard);
+#[derive(Component)] struct RerollButton;
+#[derive(Component)] struct BanishButton(usize);
+#[derive(Component)] struct SkipButton;
+#[derive(Component)] struct StaleLevelUpUI; // A card screen that's been answered, waiting to be replaced by the next queued one
 #[derive(Component)] struct GameOverUI;
 #[derive(Component)] struct InGameUI;
 #[derive(Component)] struct CollectedItemsUI; 
@@ -78,6 +115,7 @@ pub struct GameState { pub score: u32, pub wave_number: u32, pub horror_count: u
 #[derive(Component)] struct ScoreText;
 #[derive(Component)] struct TimerText;
 #[derive(Component)] struct WaveText;
+#[derive(Component)] struct ObolsText;
 
 
 fn reset_for_new_game_session(
@@ -88,6 +126,7 @@ fn reset_for_new_game_session(
     game_state.score = 0;
     game_state.wave_number = 1;
     game_state.horror_count = 0;
+    game_state.echoes_collected = 0;
     game_state.game_timer = Timer::from_seconds(3600.0, TimerMode::Once);
     game_state.game_timer.reset();
     game_state.game_timer.unpause();
@@ -107,6 +146,8 @@ fn on_enter_pause_like_state_actions(mut game_state: ResMut<GameState>, _current
     if !game_state.game_timer.paused() { game_state.game_timer.pause(); }
     if !game_state.difficulty_timer.paused() { game_state.difficulty_timer.pause(); }
 }
+// OnExit schedules run after `State` already holds the entered state, so this tells a finished run apart from pausing or levelling up.
+pub fn session_ending(current_app_state: Res<State<AppState>>) -> bool { matches!(current_app_state.get(), AppState::MainMenu | AppState::GameOver) }
 fn log_entering_debug_menu_state() {}
 fn log_exiting_debug_menu_state() {}
 
@@ -114,10 +155,12 @@ fn log_exiting_debug_menu_state() {}
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app .add_event::<UpgradeChosenEvent>() .add_event::<ItemCollectedEvent>()
-            .add_plugins((UpgradePlugin, DebugMenuPlugin)) .add_state::<AppState>() // Changed init_state to add_state
+            .add_plugins((UpgradePlugin, DebugMenuPlugin, PauseMenuPlugin, MetaShopPlugin, RunSavePlugin, AchievementGalleryPlugin, LeaderboardPlugin, SessionCleanupPlugin)) .add_state::<AppState>() // Changed init_state to add_state
             .init_resource::<GameConfig>() .init_resource::<GameState>()
             .init_resource::<PreviousGameState>()
             .init_resource::<SelectedCharacter>()
+            .init_resource::<SelectedGameMode>()
+            .init_resource::<RunSeed>()
             .init_resource::<DebugDisplayState>() // Added DebugDisplayState resource
             .register_type::<InGameDebugUI>() // Register components
             .register_type::<PlayerStatsDebugText>()
@@ -131,7 +174,7 @@ impl Plugin for GamePlugin {
             .add_plugins(EchoingSoulPlugin)
 
             .add_systems(OnEnter(AppState::MainMenu), setup_main_menu_ui)
-            .add_systems(Update, character_select_button_interaction_system.run_if(in_state(AppState::MainMenu))) 
+            .add_systems(Update, (character_select_button_interaction_system, open_meta_shop_button_system, open_achievements_button_system, open_leaderboard_button_system).run_if(in_state(AppState::MainMenu))) 
             .add_systems(OnExit(AppState::MainMenu), despawn_ui_by_marker::<MainMenuUI>)
 
             .add_systems(OnEnter(AppState::InGame), (
@@ -150,21 +193,30 @@ impl Plugin for GamePlugin {
                 debug_character_switch_system,
             ).chain().run_if(in_state(AppState::InGame).or_else(in_state(AppState::DebugUpgradeMenu))))
             .add_systems(OnExit(AppState::InGame), (
-                cleanup_session_entities,
                 despawn_ui_by_marker::<InGameUI>,
                 despawn_ui_by_marker::<CollectedItemsUI>,
                 despawn_ui_by_marker::<InGameDebugUI>, // Added despawn for in-game debug UI
             ))
 
             .add_systems(OnEnter(AppState::LevelUp), (setup_level_up_ui, on_enter_pause_like_state_actions))
-            .add_systems(Update, handle_upgrade_choice_interaction.run_if(in_state(AppState::LevelUp)))
+            .add_systems(Update, (handle_upgrade_choice_interaction, handle_reroll_interaction, handle_banish_interaction, handle_skip_interaction).run_if(in_state(AppState::LevelUp)))
             .add_systems(Update, apply_chosen_upgrade.run_if(on_event::<UpgradeChosenEvent>()))
+            .add_systems(Update, redraw_stale_level_up_ui.after(apply_chosen_upgrade).run_if(in_state(AppState::LevelUp)))
+            .add_systems(Update, resume_pending_level_ups.run_if(in_state(AppState::InGame)))
             .add_systems(OnExit(AppState::LevelUp), (despawn_ui_by_marker::<LevelUpUI>, on_enter_ingame_state_actions))
 
             .add_systems(OnEnter(AppState::DebugUpgradeMenu), (on_enter_pause_like_state_actions, log_entering_debug_menu_state))
-            .add_systems(OnExit(AppState::DebugUpgradeMenu), (on_enter_ingame_state_actions, log_exiting_debug_menu_state));
+            .add_systems(OnExit(AppState::DebugUpgradeMenu), (on_enter_ingame_state_actions, log_exiting_debug_menu_state))
+
+            .add_systems(OnEnter(AppState::ChestReward), on_enter_pause_like_state_actions)
+            .add_systems(OnExit(AppState::ChestReward), on_enter_ingame_state_actions)
+            .add_systems(OnEnter(AppState::Merchant), on_enter_pause_like_state_actions)
+            .add_systems(OnExit(AppState::Merchant), on_enter_ingame_state_actions)
 
-            app.add_systems(OnEnter(AppState::GameOver), setup_game_over_ui)
+            .add_systems(OnEnter(AppState::Paused), on_enter_pause_like_state_actions)
+            .add_systems(OnExit(AppState::Paused), (on_enter_ingame_state_actions, bank_run_rewards.run_if(session_ending)));
+
+            app.add_systems(OnEnter(AppState::GameOver), (bank_run_rewards, submit_leaderboard_entry, setup_game_over_ui).chain())
             .add_systems(Update, game_over_input_system.run_if(in_state(AppState::GameOver)))
             .add_systems(OnExit(AppState::GameOver), despawn_ui_by_marker::<GameOverUI>);
     }
@@ -181,6 +233,10 @@ fn global_key_listener(
         debug_display_state.visible = !debug_display_state.visible;
     }
 
+    if keyboard_input.just_pressed(KeyCode::Escape) && *current_app_state.get() == AppState::InGame {
+        next_app_state.set(AppState::Paused);
+    }
+
     if keyboard_input.just_pressed(KeyCode::Grave) { // Backquote -> Grave
         match current_app_state.get() {
             AppState::InGame => {
@@ -278,10 +334,16 @@ fn debug_character_switch_system(
 
 fn despawn_ui_by_marker<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) { for entity in query.iter() { commands.entity(entity).despawn_recursive(); } }
 
+#[allow(clippy::too_many_arguments)]
 fn setup_main_menu_ui(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
-    weapon_library: Res<AutomaticWeaponLibrary>
+    weapon_library: Res<AutomaticWeaponLibrary>,
+    character_library: Res<CharacterLibrary>,
+    skill_library: Res<SkillLibrary>,
+    item_library: Res<ItemLibrary>,
+    profile: Res<PlayerProfile>,
+    suspended_run: Res<SuspendedRun>,
 ) {
     commands.spawn((
         NodeBundle { // Main container for the entire menu
@@ -324,7 +386,39 @@ fn setup_main_menu_ui(
             }
         );
 
-        // Container for weapon/ability buttons
+        parent.spawn(TextBundle::from_section(
+            format!("Lore: {}", profile.lore),
+            TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 26.0, color: Color::GOLD },
+        ));
+
+        if let Some(snapshot) = suspended_run.0.as_ref() {
+            parent.spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(320.0),
+                        height: Val::Px(55.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    background_color: Color::rgba(0.15, 0.3, 0.2, 0.9).into(),
+                    border_color: BorderColor(Color::rgb(0.6, 0.9, 0.6)),
+                    ..default()
+                },
+                ResumeRunButton,
+                Name::new("ResumeRunButton"),
+            )).with_children(|button_parent| {
+                let minutes = snapshot.game_timer.elapsed_secs() as u32 / 60;
+                let seconds = snapshot.game_timer.elapsed_secs() as u32 % 60;
+                button_parent.spawn(TextBundle::from_section(
+                    format!("Resume Run (Wave {}, {:02}:{:02})", snapshot.wave_number, minutes, seconds),
+                    TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::WHITE },
+                ));
+            });
+        }
+
+        // Container for character buttons
         parent.spawn((
             NodeBundle {
                 style: Style {
@@ -341,15 +435,17 @@ fn setup_main_menu_ui(
                 },
                 ..default()
             },
-            Name::new("WeaponSelectionContainer"),
+            Name::new("CharacterSelectionContainer"),
         )).with_children(|button_container| {
-            // Style for each weapon/ability button
+            // Style for each character button
             let button_style = Style {
-                width: Val::Px(220.0),       // Width of each button box
-                height: Val::Px(70.0),      // Height of each button box
+                width: Val::Px(300.0),       // Width of each button box
+                min_height: Val::Px(70.0),   // Grows with the stat lines
                 margin: UiRect::all(Val::Px(5.0)), // Reduced margin as gap is handled by container
+                flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
+                row_gap: Val::Px(2.0),
                 border: UiRect::all(Val::Px(2.0)), // Border thickness
                 padding: UiRect::all(Val::Px(10.0)),
                 ..default()
@@ -366,8 +462,21 @@ fn setup_main_menu_ui(
             let _pressed_button_bg_color = Color::rgba(0.1, 0.1, 0.2, 0.9);
             let border_color = Color::rgba(0.7, 0.7, 0.8, 0.7); // Light grey border
 
-            // Iterate through available weapons/abilities and create a button for each
-            for weapon_def in weapon_library.weapons.iter() {
+            let detail_text_style = TextStyle { font: button_text_style.font.clone(), font_size: 14.0, color: Color::rgb(0.8, 0.8, 0.85) };
+            let weapon_text_style = TextStyle { color: Color::GOLD, ..detail_text_style.clone() };
+            let passive_text_style = TextStyle { color: Color::rgb(0.75, 0.55, 1.0), ..detail_text_style.clone() };
+
+            // A character is playable once its starting weapon is unlocked
+            for character in character_library.characters.iter().filter(|character| weapon_library.get_weapon_definition(character.starting_weapon_id).is_some_and(|weapon_def| profile.is_weapon_unlocked(weapon_def))) {
+                let base_survivor = character.create_survivor(&weapon_library);
+                let weapon_name = weapon_library.get_weapon_definition(character.starting_weapon_id).map_or("Unknown Weapon", |weapon_def| weapon_def.name.as_str());
+                let skill_names: Vec<&str> = character.starting_skills.iter().filter_map(|skill_id| skill_library.get_skill_definition(*skill_id)).map(|skill_def| skill_def.name.as_str()).collect();
+                let relic_names: Vec<&str> = character.starting_relics.iter().filter_map(|item_id| item_library.get_item_definition(*item_id)).map(|item_def| item_def.name.as_str()).collect();
+
+                let mut detail_lines = vec![format!("Endurance {}  Speed {:.0}  Armor {}", base_survivor.max_health, base_survivor.speed, base_survivor.armor)];
+                if !skill_names.is_empty() { detail_lines.push(format!("Skills: {}", skill_names.join(", "))); }
+                if !relic_names.is_empty() { detail_lines.push(format!("Relics: {}", relic_names.join(", "))); }
+
                 button_container.spawn((
                     ButtonBundle {
                         style: button_style.clone(),
@@ -375,26 +484,136 @@ fn setup_main_menu_ui(
                         border_color: BorderColor(border_color), // Apply border color
                         ..default()
                     },
-                    CharacterSelectButton(weapon_def.id), // Existing component for button logic
-                    Name::new(format!("WeaponButton_{}", weapon_def.name)),
+                    CharacterSelectButton(character.id),
+                    Name::new(format!("CharacterButton_{}", character.name)),
                 )).with_children(|button_parent| {
-                button_parent.spawn(TextBundle {
-                    text: Text {
-                        sections: vec![TextSection::new(
-                            weapon_def.name.clone(),
-                            button_text_style.clone(),
-                        )],
-                        alignment: TextAlignment::Center, // Added alignment here
+                    button_parent.spawn(NodeBundle {
+                        style: Style { flex_direction: FlexDirection::Row, align_items: AlignItems::Center, column_gap: Val::Px(8.0), ..default() },
                         ..default()
-                    },
-                    ..default()
-                }); // Center text inside button
+                    }).with_children(|header| {
+                        header.spawn(ImageBundle {
+                            style: Style { width: Val::Px(40.0), height: Val::Px(40.0), ..default() },
+                            image: UiImage::new(asset_server.load(character.portrait_path.clone())),
+                            ..default()
+                        });
+                        header.spawn(TextBundle::from_section(character.name.clone(), button_text_style.clone()));
+                    });
+                    button_parent.spawn(TextBundle::from_section(character.description.clone(), detail_text_style.clone()).with_text_alignment(TextAlignment::Center));
+                    button_parent.spawn(TextBundle::from_section(weapon_name, weapon_text_style.clone()));
+                    button_parent.spawn(TextBundle::from_section(detail_lines.join("\n"), detail_text_style.clone()).with_text_alignment(TextAlignment::Center));
+                    button_parent.spawn(TextBundle::from_section(character.passive.description(), passive_text_style.clone()));
                 });
             }
         });
+
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(260.0),
+                    height: Val::Px(50.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.3, 0.25, 0.1, 0.9).into(),
+                border_color: BorderColor(Color::GOLD),
+                ..default()
+            },
+            OpenMetaShopButton,
+            Name::new("OpenMetaShopButton"),
+        )).with_children(|button_parent| {
+            button_parent.spawn(TextBundle::from_section(
+                "Forbidden Archive",
+                TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::GOLD },
+            ));
+        });
+
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(260.0),
+                    height: Val::Px(50.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.2, 0.2, 0.3, 0.9).into(),
+                border_color: BorderColor(Color::rgba(0.7, 0.7, 0.8, 0.7)),
+                ..default()
+            },
+            OpenAchievementsButton,
+            Name::new("OpenAchievementsButton"),
+        )).with_children(|button_parent| {
+            button_parent.spawn(TextBundle::from_section(
+                "Chronicle of Deeds",
+                TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::WHITE },
+            ));
+        });
+
+        parent.spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(260.0),
+                    height: Val::Px(50.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                background_color: Color::rgba(0.2, 0.2, 0.3, 0.9).into(),
+                border_color: BorderColor(Color::rgba(0.7, 0.7, 0.8, 0.7)),
+                ..default()
+            },
+            OpenLeaderboardButton,
+            Name::new("OpenLeaderboardButton"),
+        )).with_children(|button_parent| {
+            button_parent.spawn(TextBundle::from_section(
+                "Hall of the Damned",
+                TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::WHITE },
+            ));
+        });
     });
 }
 
+fn open_meta_shop_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<OpenMetaShopButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+            next_app_state.set(AppState::MetaShop);
+        }
+    }
+}
+
+fn open_achievements_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<OpenAchievementsButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed { next_app_state.set(AppState::AchievementGallery); }
+    }
+}
+
+fn open_leaderboard_button_system(
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<OpenLeaderboardButton>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    for interaction in interaction_query.iter() {
+        if *interaction == Interaction::Pressed { next_app_state.set(AppState::Leaderboard); }
+    }
+}
+
+// Converts the finished run into Lore. Runs on game over and when a run is abandoned from the pause menu.
+pub fn bank_run_rewards(game_state: Res<GameState>, mut profile: ResMut<PlayerProfile>) {
+    let earned = profile.bank_run(game_state.score, game_state.echoes_collected);
+    info!("Run ended: banked {} Lore (score {}, echoes {}).", earned, game_state.score, game_state.echoes_collected);
+}
+
 fn character_select_button_interaction_system(
     mut commands: Commands,
     mut interaction_query: Query<
@@ -408,8 +627,9 @@ fn character_select_button_interaction_system(
     max_horrors: ResMut<MaxHorrors>, 
     player_entity_query: Query<Entity, With<Survivor>>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut run_seed: ResMut<RunSeed>,
 ) {
-    let mut character_chosen_id: Option<AutomaticWeaponId> = None;
+    let mut character_chosen_id: Option<CharacterId> = None;
 
     for (interaction, button_data, mut color) in &mut interaction_query {
         match *interaction {
@@ -429,6 +649,7 @@ fn character_select_button_interaction_system(
     if let Some(chosen_id) = character_chosen_id {
         sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
         selected_character.0 = chosen_id;
+        run_seed.0 = rand::random();
 
         for entity in player_entity_query.iter() {
             commands.entity(entity).despawn_recursive();
@@ -526,7 +747,7 @@ fn update_collected_items_ui(
 }
 
 
-fn setup_ingame_ui(mut commands: Commands, asset_server: Res<AssetServer>) { commands.spawn(( NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), flex_direction: FlexDirection::Column, justify_content: JustifyContent::SpaceBetween, padding: UiRect::all(Val::Px(10.0)), position_type: PositionType::Absolute, ..default() }, z_index: ZIndex::Global(1), ..default() }, InGameUI, )).with_children(|parent| { parent.spawn(NodeBundle { style: Style { width: Val::Percent(100.0), justify_content: JustifyContent::SpaceAround, align_items: AlignItems::Center, padding: UiRect::all(Val::Px(5.0)), ..default() }, background_color: Color::rgba(0.0, 0.0, 0.0, 0.3).into(), ..default() }).with_children(|top_bar| { top_bar.spawn((TextBundle::from_section( "Endurance: 100", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::GREEN, }, ), EnduranceText)); top_bar.spawn((TextBundle::from_section( "Insight: 1", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::CYAN, }, ), InsightText)); top_bar.spawn((TextBundle::from_section( "Echoes: 0/100", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::YELLOW, }, ), EchoesText)); top_bar.spawn((TextBundle::from_section( "Wave: 1", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::ORANGE_RED, }, ), WaveText)); }); parent.spawn(NodeBundle { style: Style { width: Val::Percent(100.0), justify_content: JustifyContent::SpaceBetween, align_items: AlignItems::FlexEnd, padding: UiRect::all(Val::Px(5.0)), ..default() }, ..default() }).with_children(|bottom_bar| { bottom_bar.spawn((TextBundle::from_section( "Score: 0", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE, }, ), ScoreText)); bottom_bar.spawn((TextBundle::from_section( "Time: 00:00", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE, }, ), TimerText)); }); }); }
+fn setup_ingame_ui(mut commands: Commands, asset_server: Res<AssetServer>) { commands.spawn(( NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), flex_direction: FlexDirection::Column, justify_content: JustifyContent::SpaceBetween, padding: UiRect::all(Val::Px(10.0)), position_type: PositionType::Absolute, ..default() }, z_index: ZIndex::Global(1), ..default() }, InGameUI, )).with_children(|parent| { parent.spawn(NodeBundle { style: Style { width: Val::Percent(100.0), justify_content: JustifyContent::SpaceAround, align_items: AlignItems::Center, padding: UiRect::all(Val::Px(5.0)), ..default() }, background_color: Color::rgba(0.0, 0.0, 0.0, 0.3).into(), ..default() }).with_children(|top_bar| { top_bar.spawn((TextBundle::from_section( "Endurance: 100", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::GREEN, }, ), EnduranceText)); top_bar.spawn((TextBundle::from_section( "Insight: 1", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::CYAN, }, ), InsightText)); top_bar.spawn((TextBundle::from_section( "Echoes: 0/100", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::YELLOW, }, ), EchoesText)); top_bar.spawn((TextBundle::from_section( "Wave: 1", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::ORANGE_RED, }, ), WaveText)); top_bar.spawn((TextBundle::from_section( "Obols: 0", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::rgb(0.85, 0.75, 0.4), }, ), ObolsText)); }); parent.spawn(NodeBundle { style: Style { width: Val::Percent(100.0), justify_content: JustifyContent::SpaceBetween, align_items: AlignItems::FlexEnd, padding: UiRect::all(Val::Px(5.0)), ..default() }, ..default() }).with_children(|bottom_bar| { bottom_bar.spawn((TextBundle::from_section( "Score: 0", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE, }, ), ScoreText)); bottom_bar.spawn((TextBundle::from_section( "Time: 00:00", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE, }, ), TimerText)); }); }); }
 fn update_game_timer(mut game_state: ResMut<GameState>, time: Res<Time>) { if !game_state.game_timer.paused() { game_state.game_timer.tick(time.delta()); } }
 fn difficulty_scaling_system(
     time: Res<Time>,
@@ -571,56 +792,20 @@ fn difficulty_scaling_system(
         game_state.difficulty_timer.reset();
     }
 }
-fn update_ingame_ui(player_query: Query<(&Survivor, &Health)>, game_state: Res<GameState>, mut ui_texts: ParamSet< ( Query<&mut Text, With<EnduranceText>>, Query<&mut Text, With<InsightText>>, Query<&mut Text, With<EchoesText>>, Query<&mut Text, With<ScoreText>>, Query<&mut Text, With<TimerText>>, Query<&mut Text, With<WaveText>>, )>,) { if let Ok((player_stats, player_health)) = player_query.get_single() { if let Ok(mut text) = ui_texts.p0().get_single_mut() { text.sections[0].value = format!("Endurance: {}/{}", player_health.0, player_stats.max_health); if player_health.0 < player_stats.max_health / 3 { text.sections[0].style.color = Color::RED; } else if player_health.0 < player_stats.max_health * 2 / 3 { text.sections[0].style.color = Color::YELLOW; } else { text.sections[0].style.color = Color::GREEN; } } if let Ok(mut text) = ui_texts.p1().get_single_mut() { text.sections[0].value = format!("Insight: {}", player_stats.level); } if let Ok(mut text) = ui_texts.p2().get_single_mut() { text.sections[0].value = format!("Echoes: {}/{}", player_stats.current_level_xp, player_stats.experience_to_next_level()); } } else { if let Ok(mut text) = ui_texts.p0().get_single_mut() { text.sections[0].value = "Endurance: --/--".to_string(); } if let Ok(mut text) = ui_texts.p1().get_single_mut() { text.sections[0].value = "Insight: --".to_string(); } if let Ok(mut text) = ui_texts.p2().get_single_mut() { text.sections[0].value = "Echoes: --/--".to_string(); } } if let Ok(mut text) = ui_texts.p3().get_single_mut() { text.sections[0].value = format!("Score: {}", game_state.score); } if let Ok(mut text) = ui_texts.p4().get_single_mut() { let elapsed_seconds = game_state.game_timer.elapsed().as_secs(); let minutes = elapsed_seconds / 60; let seconds = elapsed_seconds % 60; text.sections[0].value = format!("Time: {:02}:{:02}", minutes, seconds); } if let Ok(mut text) = ui_texts.p5().get_single_mut() { text.sections[0].value = format!("Wave: {}", game_state.wave_number); } }
-
-fn setup_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, player_query: Query<&Survivor>, upgrade_pool: Res<UpgradePool>,) {
-    let player_level = if let Ok(player) = player_query.get_single() { player.level } else { 0 };
-
-    let mut eligible_cards: Vec<UpgradeCard> = Vec::new();
-    if let Ok(player_stats) = player_query.get_single() {
-        if let Some(equipped_weapon_id) = player_stats.equipped_weapon_definition.as_ref().map(|def| def.id) {
-            for card_ref in upgrade_pool.available_upgrades.iter() { // Iterate over references
-                let card = card_ref.clone(); // Clone here if needed for ownership, or work with refs
-                match &card.upgrade_type {
-                    UpgradeType::ModifyStandardProjectile { weapon_id, .. } |
-                    UpgradeType::ModifyReturningProjectile { weapon_id, .. } |
-                    UpgradeType::ModifyChanneledBeam { weapon_id, .. } |
-                    UpgradeType::ModifyConeAttack { weapon_id, .. } |
-                    UpgradeType::ModifyLobbedAoEPool { weapon_id, .. } => {
-                        if *weapon_id == equipped_weapon_id {
-                            eligible_cards.push(card);
-                        }
-                    }
-                    _ => {
-                        eligible_cards.push(card);
-                    }
-                }
-            }
-        } else {
-            // Fallback: No weapon equipped, only offer general upgrades
-            for card_ref in upgrade_pool.available_upgrades.iter() {
-                let card = card_ref.clone();
-                match &card.upgrade_type {
-                    UpgradeType::ModifyStandardProjectile { .. } |
-                    UpgradeType::ModifyReturningProjectile { .. } |
-                    UpgradeType::ModifyChanneledBeam { .. } |
-                    UpgradeType::ModifyConeAttack { .. } |
-                    UpgradeType::ModifyLobbedAoEPool { .. } => {
-                        // Skip weapon-specific
-                    }
-                    _ => {
-                        eligible_cards.push(card);
-                    }
-                }
-            }
-        }
-    } else {
-        // Fallback: Player query failed, offer from all (or handle error)
-        eligible_cards.extend(upgrade_pool.available_upgrades.iter().cloned());
-    }
+fn update_ingame_ui(player_query: Query<(&Survivor, &Health)>, game_state: Res<GameState>, mut ui_texts: ParamSet< ( Query<&mut Text, With<EnduranceText>>, Query<&mut Text, With<InsightText>>, Query<&mut Text, With<EchoesText>>, Query<&mut Text, With<ScoreText>>, Query<&mut Text, With<TimerText>>, Query<&mut Text, With<WaveText>>, Query<&mut Text, With<ObolsText>>, )>,) { if let Ok((player_stats, player_health)) = player_query.get_single() { if let Ok(mut text) = ui_texts.p0().get_single_mut() { text.sections[0].value = format!("Endurance: {}/{}", player_health.0, player_stats.max_health); if player_health.0 < player_stats.max_health / 3 { text.sections[0].style.color = Color::RED; } else if player_health.0 < player_stats.max_health * 2 / 3 { text.sections[0].style.color = Color::YELLOW; } else { text.sections[0].style.color = Color::GREEN; } } if let Ok(mut text) = ui_texts.p1().get_single_mut() { text.sections[0].value = format!("Insight: {}", player_stats.level); } if let Ok(mut text) = ui_texts.p2().get_single_mut() { text.sections[0].value = format!("Echoes: {}/{}", player_stats.current_level_xp, player_stats.experience_to_next_level()); } for mut text in ui_texts.p6().iter_mut() { text.sections[0].value = format!("Obols: {}", player_stats.obols); } } else { if let Ok(mut text) = ui_texts.p0().get_single_mut() { text.sections[0].value = "Endurance: --/--".to_string(); } if let Ok(mut text) = ui_texts.p1().get_single_mut() { text.sections[0].value = "Insight: --".to_string(); } if let Ok(mut text) = ui_texts.p2().get_single_mut() { text.sections[0].value = "Echoes: --/--".to_string(); } } if let Ok(mut text) = ui_texts.p3().get_single_mut() { text.sections[0].value = format!("Score: {}", game_state.score); } if let Ok(mut text) = ui_texts.p4().get_single_mut() { let elapsed_seconds = game_state.game_timer.elapsed().as_secs(); let minutes = elapsed_seconds / 60; let seconds = elapsed_seconds % 60; text.sections[0].value = format!("Time: {:02}:{:02}", minutes, seconds); } if let Ok(mut text) = ui_texts.p5().get_single_mut() { text.sections[0].value = format!("Wave: {}", game_state.wave_number); } }
+
+fn setup_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, player_query: Query<&Survivor>, upgrade_pool: Res<UpgradePool>, game_state: Res<GameState>,) {
+    spawn_level_up_ui(&mut commands, &asset_server, &player_query, &upgrade_pool, game_state.wave_number);
+}
 
-    let mut rng = rand::thread_rng();
-    let choices: Vec<UpgradeCard> = eligible_cards.choose_multiple(&mut rng, 3.min(eligible_cards.len())).cloned().collect();
+fn spawn_level_up_ui(commands: &mut Commands, asset_server: &Res<AssetServer>, player_query: &Query<&Survivor>, upgrade_pool: &UpgradePool, wave_number: u32) {
+    let player_level = if let Ok(player) = player_query.get_single() { player.level.saturating_sub(player.pending_level_ups.saturating_sub(1)) } else { 0 };
+    let levels_after_this = player_query.get_single().map_or(0, |player| player.pending_level_ups.saturating_sub(1));
+    let (reroll_charges, banish_charges, skip_charges) = player_query.get_single().map_or((0, 0, 0), |player| (player.reroll_charges, player.banish_charges, player.skip_charges));
+    let obols = player_query.get_single().map_or(0, |player| player.obols);
+    let (reroll_price, banish_price) = (wave_price(REROLL_PRICE, wave_number), wave_price(BANISH_PRICE, wave_number));
+
+    let choices = player_query.get_single().map_or_else(|_| upgrade_pool.get_random_upgrades(3), |player| upgrade_pool.offer_upgrades(3, &UpgradeContext::for_survivor(player), &mut rand::thread_rng()));
     let current_offered_upgrades = OfferedUpgrades { choices };
 
     commands.spawn((
@@ -661,6 +846,16 @@ fn setup_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, pla
                 ..default()
             }
         );
+        if levels_after_this > 0 {
+            parent.spawn(TextBundle::from_section(
+                format!("{} more revelation{} after this", levels_after_this, if levels_after_this == 1 { "" } else { "s" }),
+                TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 22.0, color: Color::GOLD },
+            ));
+        }
+        parent.spawn(TextBundle::from_section(
+            format!("Rerolls: {}   Banishes: {}   Skips: {}   Obols: {}", reroll_charges, banish_charges, skip_charges, obols),
+            TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::rgb(0.8, 0.8, 0.9) },
+        ));
         for (index, card) in current_offered_upgrades.choices.iter().enumerate() { 
             let border_color_val = match card.rarity {
                 UpgradeRarity::Regular => Color::rgb(0.75, 0.75, 0.75), // Light Gray
@@ -672,7 +867,7 @@ fn setup_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, pla
                 ButtonBundle {
                     style: Style {
                         width: Val::Px(400.0),
-                        height: Val::Px(120.0),
+                        min_height: Val::Px(120.0),
                         padding: UiRect::all(Val::Px(10.0)),
                         justify_content: JustifyContent::Center,
                         align_items: AlignItems::FlexStart,
@@ -706,29 +901,194 @@ fn setup_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, pla
                         color: Color::rgb(0.9, 0.9, 0.9),
                     },
                 )); 
+                if let Some(chain) = upgrade_pool.chain_summary(card) {
+                    button_parent.spawn(TextBundle::from_section(
+                        chain,
+                        TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 15.0, color: Color::rgb(0.6, 0.85, 1.0) },
+                    ).with_style(Style { margin: UiRect::top(Val::Px(5.0)), ..default() }));
+                }
             });
         } 
+        if banish_charges > 0 || obols >= banish_price {
+            parent.spawn(NodeBundle { style: Style { column_gap: Val::Px(10.0), ..default() }, ..default() }).with_children(|banish_row| {
+                for index in 0..current_offered_upgrades.choices.len() {
+                    banish_row.spawn((
+                        ButtonBundle {
+                            style: Style { height: Val::Px(36.0), padding: UiRect::horizontal(Val::Px(10.0)), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() },
+                            border_color: BorderColor(Color::CRIMSON),
+                            background_color: Color::DARK_GRAY.into(),
+                            ..default()
+                        },
+                        BanishButton(index),
+                    )).with_children(|button_parent| {
+                        button_parent.spawn(TextBundle::from_section(
+                            if banish_charges > 0 { format!("Banish {} (free)", index + 1) } else { format!("Banish {} ({} obols)", index + 1, banish_price) },
+                            TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 16.0, color: Color::CRIMSON },
+                        ));
+                    });
+                }
+            });
+        }
+        if reroll_charges > 0 || obols >= reroll_price {
+            parent.spawn((
+                ButtonBundle {
+                    style: Style {
+                        width: Val::Px(220.0),
+                        height: Val::Px(45.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    border_color: BorderColor(Color::CYAN),
+                    background_color: Color::DARK_GRAY.into(),
+                    ..default()
+                },
+                RerollButton,
+                Name::new("Reroll Button"),
+            )).with_children(|button_parent| {
+                button_parent.spawn(TextBundle::from_section(
+                    if reroll_charges > 0 { format!("Reroll [R] ({} left)", reroll_charges) } else { format!("Reroll [R] ({} obols)", reroll_price) },
+                    TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::CYAN },
+                ));
+            });
+        }
+        if skip_charges > 0 {
+            parent.spawn((
+                ButtonBundle {
+                    style: Style { width: Val::Px(220.0), height: Val::Px(45.0), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() },
+                    border_color: BorderColor(Color::GRAY),
+                    background_color: Color::DARK_GRAY.into(),
+                    ..default()
+                },
+                SkipButton,
+                Name::new("Skip Button"),
+            )).with_children(|button_parent| {
+                button_parent.spawn(TextBundle::from_section(
+                    format!("Skip [X] ({} left)", skip_charges),
+                    TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 20.0, color: Color::WHITE },
+                ));
+            });
+        }
     });
 }
 
-fn handle_upgrade_choice_interaction(mut interaction_query: Query< (&Interaction, &UpgradeButton, &mut BackgroundColor), (Changed<Interaction>, With<Button>), >, mut upgrade_chosen_event: EventWriter<UpgradeChosenEvent>, mut next_app_state: ResMut<NextState<AppState>>, keyboard_input: Res<Input<KeyCode>>, level_up_ui_query: Query<&OfferedUpgrades, With<LevelUpUI>>, mut sound_event_writer: EventWriter<PlaySoundEvent>,) { for (interaction, upgrade_button_data, mut bg_color) in interaction_query.iter_mut() { match *interaction { Interaction::Pressed => { sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted)); upgrade_chosen_event.send(UpgradeChosenEvent(upgrade_button_data.0.clone())); next_app_state.set(AppState::InGame); return; } Interaction::Hovered => { *bg_color = Color::DARK_GREEN.into(); } Interaction::None => { *bg_color = Color::GRAY.into(); } } } if let Ok(offered) = level_up_ui_query.get_single() { let choice_made = if keyboard_input.just_pressed(KeyCode::Key1) && offered.choices.len() > 0 { Some(offered.choices[0].clone()) } else if keyboard_input.just_pressed(KeyCode::Key2) && offered.choices.len() > 1 { Some(offered.choices[1].clone()) } else if keyboard_input.just_pressed(KeyCode::Key3) && offered.choices.len() > 2 { Some(offered.choices[2].clone()) } else { None }; if let Some(chosen_card) = choice_made { sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted)); upgrade_chosen_event.send(UpgradeChosenEvent(chosen_card)); next_app_state.set(AppState::InGame); } } }
+#[allow(clippy::too_many_arguments)]
+fn handle_reroll_interaction(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<RerollButton>)>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut player_query: Query<&mut Survivor>,
+    upgrade_pool: Res<UpgradePool>,
+    game_state: Res<GameState>,
+    level_up_ui_query: Query<Entity, (With<LevelUpUI>, Without<StaleLevelUpUI>)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let reroll_requested = keyboard_input.just_pressed(KeyCode::R) || interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !reroll_requested { return; }
+    {
+        let Ok(mut player) = player_query.get_single_mut() else { return; };
+        if player.reroll_charges > 0 { player.reroll_charges -= 1; } // Free rerolls go first, then obols
+        else if !try_spend(&mut player.obols, wave_price(REROLL_PRICE, game_state.wave_number)) { return; }
+    }
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+    for entity in level_up_ui_query.iter() { commands.entity(entity).despawn_recursive(); }
+    spawn_level_up_ui(&mut commands, &asset_server, &player_query.to_readonly(), &upgrade_pool, game_state.wave_number);
+}
+
+// Passes on this level-up entirely, leaving the offered cards in the pool
+fn handle_skip_interaction(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<SkipButton>)>,
+    keyboard_input: Res<Input<KeyCode>>,
+    mut player_query: Query<&mut Survivor>,
+    level_up_ui_query: Query<Entity, (With<LevelUpUI>, Without<StaleLevelUpUI>)>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let skip_requested = keyboard_input.just_pressed(KeyCode::X) || interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if !skip_requested { return; }
+    let Ok(ui_entity) = level_up_ui_query.get_single() else { return; };
+    {
+        let Ok(mut player) = player_query.get_single_mut() else { return; };
+        if player.skip_charges == 0 { return; }
+        player.skip_charges -= 1;
+    }
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+    advance_level_up_queue(&mut commands, &mut player_query, ui_entity, &mut next_app_state);
+}
+
+// Pays to strike an offered card from the rest of the run, then redraws the hand
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
+fn handle_banish_interaction(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    interaction_query: Query<(&Interaction, &BanishButton), Changed<Interaction>>,
+    mut player_query: Query<&mut Survivor>,
+    upgrade_pool: Res<UpgradePool>,
+    game_state: Res<GameState>,
+    level_up_ui_query: Query<(Entity, &OfferedUpgrades), (With<LevelUpUI>, Without<StaleLevelUpUI>)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Some((_, button)) = interaction_query.iter().find(|(interaction, _)| **interaction == Interaction::Pressed) else { return; };
+    let Ok((ui_entity, offered)) = level_up_ui_query.get_single() else { return; };
+    let Some(card) = offered.choices.get(button.0) else { return; };
+    {
+        let Ok(mut player) = player_query.get_single_mut() else { return; };
+        if player.banish_charges > 0 { player.banish_charges -= 1; }
+        else if !try_spend(&mut player.obols, wave_price(BANISH_PRICE, game_state.wave_number)) { return; }
+        player.banished_upgrades.push(card.id);
+    }
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+    commands.entity(ui_entity).despawn_recursive();
+    spawn_level_up_ui(&mut commands, &asset_server, &player_query.to_readonly(), &upgrade_pool, game_state.wave_number);
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn handle_upgrade_choice_interaction(mut commands: Commands, mut interaction_query: Query< (&Interaction, &UpgradeButton, &mut BackgroundColor), (Changed<Interaction>, With<Button>), >, mut upgrade_chosen_event: EventWriter<UpgradeChosenEvent>, mut next_app_state: ResMut<NextState<AppState>>, keyboard_input: Res<Input<KeyCode>>, level_up_ui_query: Query<(Entity, &OfferedUpgrades), (With<LevelUpUI>, Without<StaleLevelUpUI>)>, mut player_query: Query<&mut Survivor>, mut sound_event_writer: EventWriter<PlaySoundEvent>,) { let Ok((ui_entity, offered)) = level_up_ui_query.get_single() else { return; }; for (interaction, upgrade_button_data, mut bg_color) in interaction_query.iter_mut() { match *interaction { Interaction::Pressed => { sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted)); upgrade_chosen_event.send(UpgradeChosenEvent(upgrade_button_data.0.clone())); advance_level_up_queue(&mut commands, &mut player_query, ui_entity, &mut next_app_state); return; } Interaction::Hovered => { *bg_color = Color::DARK_GREEN.into(); } Interaction::None => { *bg_color = Color::GRAY.into(); } } } let choice_made = if keyboard_input.just_pressed(KeyCode::Key1) && offered.choices.len() > 0 { Some(offered.choices[0].clone()) } else if keyboard_input.just_pressed(KeyCode::Key2) && offered.choices.len() > 1 { Some(offered.choices[1].clone()) } else if keyboard_input.just_pressed(KeyCode::Key3) && offered.choices.len() > 2 { Some(offered.choices[2].clone()) } else { None }; if let Some(chosen_card) = choice_made { sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted)); upgrade_chosen_event.send(UpgradeChosenEvent(chosen_card)); advance_level_up_queue(&mut commands, &mut player_query, ui_entity, &mut next_app_state); } }
 
+// Done with one card screen: deal the next queued level's hand, or go back to the hunt
+fn advance_level_up_queue(commands: &mut Commands, player_query: &mut Query<&mut Survivor>, ui_entity: Entity, next_app_state: &mut NextState<AppState>) {
+    let more_waiting = player_query.get_single_mut().is_ok_and(|mut player| player.finish_level_up());
+    if more_waiting { commands.entity(ui_entity).insert(StaleLevelUpUI); } else { next_app_state.set(AppState::InGame); }
+}
+
+// Redrawn a frame later, after apply_chosen_upgrade, so the new hand sees the card just taken
+fn redraw_stale_level_up_ui(mut commands: Commands, asset_server: Res<AssetServer>, stale_query: Query<Entity, (With<LevelUpUI>, With<StaleLevelUpUI>)>, player_query: Query<&Survivor>, upgrade_pool: Res<UpgradePool>, game_state: Res<GameState>,) {
+    for entity in stale_query.iter() {
+        commands.entity(entity).despawn_recursive();
+        spawn_level_up_ui(&mut commands, &asset_server, &player_query, &upgrade_pool, game_state.wave_number);
+    }
+}
+
+// Levels queued while another screen took precedence are picked up as soon as play resumes
+fn resume_pending_level_ups(player_query: Query<&Survivor>, mut next_app_state: ResMut<NextState<AppState>>) {
+    if player_query.get_single().is_ok_and(|player| player.pending_level_ups > 0) { next_app_state.set(AppState::LevelUp); }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn apply_chosen_upgrade(
     mut events: EventReader<UpgradeChosenEvent>,
     mut player_query: Query<(&mut Survivor, &mut SanityStrain, &mut Health, &mut CircleOfWarding, &mut SwarmOfNightmares)>,
     item_library: Res<ItemLibrary>,
     _weapon_library: Res<AutomaticWeaponLibrary>, // Renamed from weapon_library to avoid unused warning, as per simpler interpretation.
     mut item_collected_writer: EventWriter<ItemCollectedEvent>,
+    mut healed_writer: EventWriter<SurvivorHealedEvent>,
     skill_library: Res<crate::skills::SkillLibrary>,
+    profile: Res<PlayerProfile>,
 ) {
     for event in events.read() {
         let Ok((mut player_stats, mut sanity_strain, mut health_stats, mut circle_aura, mut nightmare_swarm)) = player_query.get_single_mut() else { continue; };
         
         let rarity = event.0.rarity; // Get the rarity
+        player_stats.picked_upgrades.push(event.0.id); // The run's owned cards: stack limits, prerequisites and follow-ups all read these
 
         match &event.0.upgrade_type {
             UpgradeType::SurvivorSpeed(percentage) => { player_stats.speed *= 1.0 + (*percentage as f32 / 100.0); }
             UpgradeType::MaxEndurance(amount) => { player_stats.max_health += *amount; health_stats.0 += *amount; health_stats.0 = health_stats.0.min(player_stats.max_health); }
+            UpgradeType::RestoreEndurance(amount) => { healed_writer.send(SurvivorHealedEvent(player_stats.heal(&mut health_stats, *amount))); }
 
             UpgradeType::IncreaseAutoWeaponDamage(bonus_amount) => { player_stats.auto_weapon_damage_bonus += *bonus_amount; }
             UpgradeType::IncreaseAutoWeaponFireRate(percentage) => {
@@ -796,11 +1156,11 @@ fn apply_chosen_upgrade(
             UpgradeType::IncreaseNightmareDamage(damage) => { if nightmare_swarm.is_active { nightmare_swarm.damage_per_hit += *damage; }}
             UpgradeType::IncreaseNightmareRadius(radius_increase) => { if nightmare_swarm.is_active { nightmare_swarm.orbit_radius += *radius_increase; }}
             UpgradeType::IncreaseNightmareRotationSpeed(speed_increase) => { if nightmare_swarm.is_active { nightmare_swarm.rotation_speed += *speed_increase; }}
-            UpgradeType::IncreaseSkillDamage { slot_index, amount } => { if let Some(skill_instance) = player_stats.equipped_skills.get_mut(*slot_index) { skill_instance.flat_damage_bonus += *amount; skill_instance.current_level += 1; } }
-            UpgradeType::GrantRandomRelic => { if !item_library.items.is_empty() { let mut rng = rand::thread_rng(); if let Some(random_item_def) = item_library.items.choose(&mut rng) { item_collected_writer.send(ItemCollectedEvent(random_item_def.id)); } } }
-            UpgradeType::GrantSkill(skill_id_to_grant) => { let already_has_skill = player_stats.equipped_skills.iter().any(|s| s.definition_id == *skill_id_to_grant); if !already_has_skill { if player_stats.equipped_skills.len() < 5 { if let Some(_skill_def) = skill_library.get_skill_definition(*skill_id_to_grant) { player_stats.equipped_skills.push(ActiveSkillInstance::new(*skill_id_to_grant )); } } } }
-            UpgradeType::ReduceSkillCooldown { slot_index, percent_reduction } => { if let Some(skill_instance) = player_stats.equipped_skills.get_mut(*slot_index) { skill_instance.cooldown_multiplier *= 1.0 - percent_reduction; skill_instance.cooldown_multiplier = skill_instance.cooldown_multiplier.max(0.1); skill_instance.current_level +=1; } }
-            UpgradeType::IncreaseSkillAoERadius { slot_index, percent_increase } => { if let Some(skill_instance) = player_stats.equipped_skills.get_mut(*slot_index) { skill_instance.aoe_radius_multiplier *= 1.0 + percent_increase; skill_instance.current_level +=1; } }
+            UpgradeType::IncreaseSkillDamage { skill_id, amount } => { if let Some(skill_instance) = player_stats.equipped_skills.iter_mut().find(|skill| skill.definition_id == *skill_id) { skill_instance.flat_damage_bonus += *amount; skill_instance.current_level += 1; } }
+            UpgradeType::GrantRandomRelic => { let unlocked_items: Vec<_> = item_library.items.iter().filter(|item_def| profile.is_item_unlocked(item_def)).collect(); let mut rng = rand::thread_rng(); if let Some(random_item_def) = unlocked_items.choose(&mut rng) { item_collected_writer.send(ItemCollectedEvent(random_item_def.id)); } }
+            UpgradeType::GrantSkill(skill_id_to_grant) => { let already_has_skill = player_stats.equipped_skills.iter().any(|s| s.definition_id == *skill_id_to_grant); if !already_has_skill { if player_stats.equipped_skills.len() < MAX_EQUIPPED_SKILLS { if let Some(_skill_def) = skill_library.get_skill_definition(*skill_id_to_grant) { player_stats.equipped_skills.push(ActiveSkillInstance::new(*skill_id_to_grant )); } } } }
+            UpgradeType::ReduceSkillCooldown { skill_id, percent_reduction } => { if let Some(skill_instance) = player_stats.equipped_skills.iter_mut().find(|skill| skill.definition_id == *skill_id) { skill_instance.cooldown_multiplier *= 1.0 - percent_reduction; skill_instance.cooldown_multiplier = skill_instance.cooldown_multiplier.max(0.1); skill_instance.current_level +=1; } }
+            UpgradeType::IncreaseSkillAoERadius { skill_id, percent_increase } => { if let Some(skill_instance) = player_stats.equipped_skills.iter_mut().find(|skill| skill.definition_id == *skill_id) { skill_instance.aoe_radius_multiplier *= 1.0 + percent_increase; skill_instance.current_level +=1; } }
 
             // --- Auto-Attack Focused (New Batch) ---
             UpgradeType::AutoAttackAddFireDamage(base_val) => {
@@ -842,7 +1202,7 @@ fn apply_chosen_upgrade(
                     UpgradeRarity::Rare => *base_val * 2.0,
                     UpgradeRarity::Legendary => *base_val * 3.0,
                 };
-                player_stats.auto_attack_crit_chance += actual_value;
+                player_stats.auto_attack_crit_chance = (player_stats.auto_attack_crit_chance + actual_value / 100.0).min(1.0); // Stored as a 0-1 chance, like the characters' crit
             }
             UpgradeType::AutoAttackCritDamage(base_val) => { // Percent bonus
                 let actual_value = match rarity {
@@ -966,7 +1326,6 @@ fn apply_chosen_upgrade(
                     UpgradeRarity::Legendary => *base_val * 3.0,
                 };
                 player_stats.auto_attack_shield_penetration_percent += actual_value;
-                // TO-DO: Implement shield penetration in damage calculation against shielded enemies
             }
             UpgradeType::AutoAttackCullStrikeChance(base_val) => { // Percent chance
                 let actual_value = match rarity {
@@ -1144,6 +1503,14 @@ fn apply_chosen_upgrade(
                 };
                 player_stats.relic_drop_rate_multiplier *= 1.0 + (actual_value / 100.0);
             }
+            UpgradeType::IncreaseLuck(base_val) => { // Percent better reward chests
+                let actual_value = match rarity {
+                    UpgradeRarity::Regular => *base_val,
+                    UpgradeRarity::Rare => *base_val * 2.0,
+                    UpgradeRarity::Legendary => *base_val * 3.0,
+                };
+                player_stats.luck += actual_value;
+            }
             UpgradeType::ChanceForFreeSkillUse(base_val) => { // Percent chance
                 let actual_value = match rarity {
                     UpgradeRarity::Regular => *base_val,
@@ -1369,14 +1736,33 @@ fn apply_chosen_upgrade(
         }
     }
 }
-fn setup_game_over_ui(mut commands: Commands, game_state: Res<GameState>, asset_server: Res<AssetServer>) { commands.spawn(( NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), justify_content: JustifyContent::Center, align_items: AlignItems::Center, flex_direction: FlexDirection::Column, row_gap: Val::Px(20.0), ..default() }, ..default() }, GameOverUI, )).with_children(|parent| { 
-        parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new("Consumed by Madness!", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 80.0, color: Color::RED, })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
-        parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new(format!("Score: {}", game_state.score), TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 50.0, color: Color::WHITE, })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
+#[allow(clippy::too_many_arguments)]
+fn setup_game_over_ui(
+    mut commands: Commands, game_state: Res<GameState>, asset_server: Res<AssetServer>, profile: Res<PlayerProfile>,
+    run_stats: Res<RunStats>, weapon_library: Res<AutomaticWeaponLibrary>, skill_library: Res<SkillLibrary>, leaderboard_result: Res<LatestLeaderboardResult>,
+) { commands.spawn(( NodeBundle { style: Style { width: Val::Percent(100.0), height: Val::Percent(100.0), justify_content: JustifyContent::Center, align_items: AlignItems::Center, flex_direction: FlexDirection::Column, row_gap: Val::Px(12.0), ..default() }, ..default() }, GameOverUI, )).with_children(|parent| { 
+        parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new("Consumed by Madness!", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 64.0, color: Color::RED, })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
+        parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new(format!("Score: {}", game_state.score), TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 40.0, color: Color::WHITE, })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
+        parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new(format!("Lore Gleaned: {} (Total: {})", profile.last_run_lore_earned, profile.lore), TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 32.0, color: Color::GOLD, })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
+        let placement = if leaderboard_result.is_personal_best() { Some(("New Personal Best!".to_string(), Color::GOLD)) } else { leaderboard_result.rank.map(|rank| (format!("Ranked #{} for this character", rank + 1), Color::rgb(0.8, 0.8, 0.9))) };
+        if let Some((placement_text, placement_color)) = placement {
+            parent.spawn(TextBundle::from_section(placement_text, TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 30.0, color: placement_color }));
+        }
+        spawn_run_stats_panel(parent, asset_server.load("fonts/FiraSans-Bold.ttf"), &run_stats, &game_state, &weapon_library, &skill_library);
         parent.spawn( TextBundle { text: Text { sections: vec![TextSection::new("Succumb Again? (R)", TextStyle { font: asset_server.load("fonts/FiraSans-Bold.ttf"), font_size: 40.0, color: Color::rgba(0.8,0.8,0.8,1.0), })], alignment: TextAlignment::Center, ..default() }, ..default() }); 
     }); 
 }
 fn game_over_input_system(mut commands: Commands, keyboard_input: Res<Input<KeyCode>>, mut next_app_state: ResMut<NextState<AppState>>, game_state: ResMut<GameState>, horror_spawn_timer: ResMut<HorrorSpawnTimer>, max_horrors: ResMut<MaxHorrors>, player_entity_query: Query<Entity, With<Survivor>>,) { if keyboard_input.just_pressed(KeyCode::R) { for entity in player_entity_query.iter() { commands.entity(entity).despawn_recursive(); } reset_for_new_game_session(game_state, horror_spawn_timer, max_horrors); next_app_state.set(AppState::MainMenu); } }
 
+// Clears the field only when the run is really over; level-ups, chests and the merchant leave it as it was
+pub struct SessionCleanupPlugin;
+impl Plugin for SessionCleanupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(AppState::InGame), cleanup_session_entities.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), cleanup_session_entities.run_if(session_ending));
+    }
+}
+
 fn cleanup_session_entities(
     mut commands: Commands,
     projectiles_query: Query<Entity, With<AutomaticProjectile>>,
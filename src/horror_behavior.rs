@@ -0,0 +1,387 @@
+// src/horror_behavior.rs
+// Special horrors are driven by small state machines described in data. A `BehaviorGraph` is a list of states;
+// each state says how the horror moves, what happens as it is entered (shoot, summon, pick a target, teleport...)
+// and which conditions move it on to another state. A horror can run several graphs as layers, e.g. an elite
+// Floating Eyeball with the Teleporting affix runs the eyeball graph with the blink overlay on top. A layer whose
+// state has `Movement::Inherit` leaves steering to the layers below it, and with no opinion at all the horror
+// simply chases the survivor. New archetypes only need a new graph.
+use bevy::prelude::*;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use crate::{
+    components::Velocity,
+    survivor::Survivor,
+    game::GameState,
+    audio::{PlaySoundEvent, SoundEffect},
+    horror::{Horror, HorrorType, spawn_and_return_horror_entity},
+    bullet_patterns::{BulletPattern, PatternTarget, ProjectileStyle, fire_pattern},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Movement {
+    Inherit, // No opinion; a lower layer or the default chase decides
+    Chase(f32),
+    Flee(f32),
+    Hold, // Stand still facing the survivor
+    KeepDistance { min: f32, max: f32, speed_multiplier: f32 },
+    MoveToTarget(f32),
+    MoveAlongAim(f32),
+    Telegraph { reaim_secs: f32 }, // Stand still, turning to re-aim at the survivor every so often
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StateEffect {
+    Fire(BulletPattern, ProjectileStyle),
+    Summon { minion: HorrorType, count: u32, max_active: u32 },
+    AimAtPlayer,
+    AimSideways, // Perpendicular to the survivor, either way
+    PickStrafeTarget { min: f32, max: f32 },
+    PickFlankTarget { flank_distance: f32, min_range: f32, max_range: f32 }, // Past the survivor, as seen from the horror
+    TeleportToTarget,
+    Hide,
+    Show,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Always,
+    After(f32),
+    AfterRandom(f32, f32), // Rolled once each time the state is entered
+    PlayerWithin(f32),
+    PlayerBeyond(f32),
+    TargetReached(f32),
+    MinionsBelow(u32),
+    All(&'static [Condition]),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tint { Keep, Set(Color), Restore }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Fade { None, Out(f32), In(f32) }
+
+#[derive(Debug)]
+pub struct Transition { pub to: usize, pub when: Condition }
+
+#[derive(Debug)]
+pub struct BehaviorState {
+    pub name: &'static str,
+    pub movement: Movement,
+    pub on_enter: &'static [StateEffect],
+    pub tint: Tint,
+    pub fade: Fade,
+    pub transitions: &'static [Transition], // Checked in order; the first that holds is taken
+    pub on_stagger: Option<usize>,          // Where a stagger sends the horror; None means this state can't be interrupted
+}
+
+// Starts in its first state
+#[derive(Debug)]
+pub struct BehaviorGraph { pub name: &'static str, pub states: &'static [BehaviorState] }
+
+const fn state(name: &'static str, movement: Movement, on_enter: &'static [StateEffect], transitions: &'static [Transition]) -> BehaviorState {
+    BehaviorState { name, movement, on_enter, tint: Tint::Keep, fade: Fade::None, transitions, on_stagger: None }
+}
+
+const EYEBALL_SHOT: StateEffect = StateEffect::Fire(BulletPattern::AimedSpread { projectiles: 1, spread_degrees: 0.0 }, ProjectileStyle::ichor(280.0, 10));
+const EYEBALL_LEAD_SHOT: StateEffect = StateEffect::Fire(BulletPattern::LeadShot { projectiles: 1, spread_degrees: 0.0 }, ProjectileStyle::ichor(280.0, 10)); // Punishes kiting in a straight line
+const BLINK_FLANK: StateEffect = StateEffect::PickFlankTarget { flank_distance: 75.0, min_range: 100.0, max_range: 250.0 };
+const BLINK_FADE_SECS: f32 = 0.3;
+
+pub static FLOATING_EYEBALL_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Floating Eyeball", states: &[
+    state("Approach", Movement::Chase(1.0), &[], &[Transition { to: 3, when: Condition::PlayerWithin(150.0) }, Transition { to: 1, when: Condition::PlayerWithin(350.0) }]),
+    state("Aim", Movement::Hold, &[], &[
+        Transition { to: 3, when: Condition::PlayerWithin(150.0) },
+        Transition { to: 0, when: Condition::PlayerBeyond(385.0) },
+        Transition { to: 2, when: Condition::After(2.8) },
+    ]),
+    state("Reposition", Movement::MoveToTarget(0.7), &[EYEBALL_SHOT, StateEffect::PickStrafeTarget { min: 50.0, max: 150.0 }], &[
+        Transition { to: 0, when: Condition::TargetReached(10.0) },
+        Transition { to: 0, when: Condition::After(1.5) },
+    ]),
+    state("Kite", Movement::Flee(0.8), &[], &[
+        Transition { to: 1, when: Condition::All(&[Condition::PlayerBeyond(180.0), Condition::PlayerWithin(350.0)]) },
+        Transition { to: 0, when: Condition::PlayerBeyond(350.0) },
+        Transition { to: 4, when: Condition::After(2.8) },
+    ]),
+    state("Kite Shot", Movement::Flee(0.8), &[EYEBALL_LEAD_SHOT], &[Transition { to: 3, when: Condition::Always }]),
+] };
+
+pub static VOID_BLINKER_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Void Blinker", states: &[
+    state("Stalk", Movement::Chase(1.0), &[], &[Transition { to: 1, when: Condition::After(5.0) }]),
+    BehaviorState { fade: Fade::Out(BLINK_FADE_SECS), ..state("Phase Out", Movement::Hold, &[BLINK_FLANK], &[Transition { to: 2, when: Condition::After(BLINK_FADE_SECS) }]) },
+    state("Phased", Movement::Hold, &[StateEffect::Hide], &[Transition { to: 3, when: Condition::After(0.05) }]),
+    BehaviorState { fade: Fade::In(BLINK_FADE_SECS), ..state("Phase In", Movement::Hold, &[StateEffect::TeleportToTarget, StateEffect::Show], &[Transition { to: 4, when: Condition::After(BLINK_FADE_SECS) }]) },
+    state("Recover", Movement::Chase(0.6), &[], &[Transition { to: 5, when: Condition::After(5.0) }]),
+    state("Hunt", Movement::Chase(1.0), &[], &[Transition { to: 1, when: Condition::AfterRandom(2.0, 5.0) }]),
+] };
+
+// Blinking on top of whatever else the horror does; used by the Teleporting elite affix and bosses
+pub static BLINK_OVERLAY_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Blink Overlay", states: &[
+    state("Waiting", Movement::Inherit, &[], &[Transition { to: 1, when: Condition::AfterRandom(4.0, 7.0) }]),
+    BehaviorState { fade: Fade::Out(BLINK_FADE_SECS), ..state("Phase Out", Movement::Hold, &[BLINK_FLANK], &[Transition { to: 2, when: Condition::After(BLINK_FADE_SECS) }]) },
+    state("Phased", Movement::Hold, &[StateEffect::Hide], &[Transition { to: 3, when: Condition::After(0.05) }]),
+    BehaviorState { fade: Fade::In(BLINK_FADE_SECS), ..state("Phase In", Movement::Hold, &[StateEffect::TeleportToTarget, StateEffect::Show], &[Transition { to: 0, when: Condition::After(BLINK_FADE_SECS) }]) },
+] };
+
+// Volleys on top of whatever else the horror does; used by the Barrage elite affix
+pub static BARRAGE_OVERLAY_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Barrage Overlay", states: &[
+    state("Waiting", Movement::Inherit, &[], &[Transition { to: 1, when: Condition::AfterRandom(3.0, 5.0) }]),
+    state("Ring Burst", Movement::Inherit, &[StateEffect::Fire(BulletPattern::Ring { projectiles: 10 }, ProjectileStyle::ichor(200.0, 8))], &[Transition { to: 2, when: Condition::Always }]),
+    state("Reloading", Movement::Inherit, &[], &[Transition { to: 3, when: Condition::AfterRandom(3.0, 5.0) }]),
+    state("Spiral", Movement::Hold, &[StateEffect::Fire(BulletPattern::Spiral { arms: 3, volleys: 10, interval_secs: 0.15, degrees_per_volley: 18.0 }, ProjectileStyle::needle(220.0, 6))], &[
+        Transition { to: 0, when: Condition::After(1.5) },
+    ]),
+] };
+
+pub static FLESH_WEAVER_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Flesh Weaver", states: &[
+    state("Keep Distance", Movement::KeepDistance { min: 250.0, max: 400.0, speed_multiplier: 0.5 }, &[], &[
+        Transition { to: 1, when: Condition::All(&[Condition::After(7.0), Condition::MinionsBelow(3)]) },
+    ]),
+    state("Summon", Movement::MoveAlongAim(1.5), &[StateEffect::Summon { minion: HorrorType::CrawlingTorment, count: 2, max_active: 3 }, StateEffect::AimSideways], &[
+        Transition { to: 0, when: Condition::After(0.5) },
+    ]),
+] };
+
+const CHARGE_WINDOW: Condition = Condition::All(&[Condition::PlayerWithin(400.0), Condition::PlayerBeyond(100.0)]);
+
+pub static FRENZIED_BEHEMOTH_BEHAVIOR: BehaviorGraph = BehaviorGraph { name: "Frenzied Behemoth", states: &[
+    state("Roam", Movement::Chase(1.0), &[], &[Transition { to: 1, when: Condition::All(&[Condition::After(6.0), CHARGE_WINDOW]) }]),
+    BehaviorState { tint: Tint::Set(Color::rgb(1.0, 0.5, 0.5)), on_stagger: Some(3), ..state("Telegraph", Movement::Telegraph { reaim_secs: 0.4 }, &[StateEffect::AimAtPlayer], &[Transition { to: 2, when: Condition::After(1.2) }]) },
+    BehaviorState { tint: Tint::Set(Color::rgb(1.0, 0.2, 0.2)), on_stagger: Some(3), ..state("Charge", Movement::MoveAlongAim(3.5), &[], &[Transition { to: 3, when: Condition::After(1.0) }]) },
+    BehaviorState { tint: Tint::Restore, ..state("Recover", Movement::Hold, &[], &[Transition { to: 4, when: Condition::After(6.0) }]) },
+    state("Stalk", Movement::Chase(1.0), &[], &[Transition { to: 1, when: CHARGE_WINDOW }]),
+] };
+
+pub fn default_behavior(horror_type: HorrorType) -> Option<&'static BehaviorGraph> {
+    match horror_type {
+        HorrorType::FloatingEyeball => Some(&FLOATING_EYEBALL_BEHAVIOR),
+        HorrorType::VoidBlinker => Some(&VOID_BLINKER_BEHAVIOR),
+        HorrorType::FleshWeaver => Some(&FLESH_WEAVER_BEHAVIOR),
+        HorrorType::FrenziedBehemoth => Some(&FRENZIED_BEHEMOTH_BEHAVIOR),
+        _ => None,
+    }
+}
+
+// Where the horror is relative to the survivor, for checking conditions
+#[derive(Debug, Clone, Copy)]
+pub struct Surroundings { pub position: Vec2, pub player_position: Vec2 }
+
+#[derive(Debug, Clone)]
+pub struct BehaviorLayer {
+    pub graph: &'static BehaviorGraph,
+    pub state: usize,
+    pub time_in_state: f32,
+    pub aim: Vec2,
+    pub target: Option<Vec2>,
+    pub minions: Vec<Entity>,
+    random_duration: Option<f32>,
+    reaim_elapsed: f32,
+    started: bool,
+    interrupted_to: Option<usize>, // Taken ahead of any transition on the next advance
+}
+
+impl BehaviorLayer {
+    pub fn new(graph: &'static BehaviorGraph) -> Self {
+        Self { graph, state: 0, time_in_state: 0.0, aim: Vec2::ZERO, target: None, minions: Vec::new(), random_duration: None, reaim_elapsed: 0.0, started: false, interrupted_to: None }
+    }
+
+    pub fn current(&self) -> &'static BehaviorState { &self.graph.states[self.state] }
+
+    fn condition_met(&mut self, condition: Condition, surroundings: Surroundings, rng: &mut impl Rng) -> bool {
+        let distance = surroundings.position.distance(surroundings.player_position);
+        match condition {
+            Condition::Always => true,
+            Condition::After(secs) => self.time_in_state >= secs,
+            Condition::AfterRandom(min, max) => self.time_in_state >= *self.random_duration.get_or_insert_with(|| rng.gen_range(min..max)),
+            Condition::PlayerWithin(range) => distance <= range,
+            Condition::PlayerBeyond(range) => distance > range,
+            Condition::TargetReached(tolerance) => match self.target { Some(target) => surroundings.position.distance(target) <= tolerance, None => true },
+            Condition::MinionsBelow(count) => self.minions.len() < count as usize,
+            Condition::All(conditions) => conditions.iter().all(|condition| self.condition_met(*condition, surroundings, rng)),
+        }
+    }
+
+    // Moves time on and takes at most one transition. Returns the state entered, whose effects the caller runs.
+    pub fn advance(&mut self, delta_secs: f32, surroundings: Surroundings, rng: &mut impl Rng) -> Option<usize> {
+        if !self.started {
+            self.started = true;
+            return Some(self.state);
+        }
+        self.time_in_state += delta_secs;
+        let next = match self.interrupted_to.take() {
+            Some(next) => next,
+            None => self.current().transitions.iter().find(|transition| self.condition_met(transition.when, surroundings, rng))?.to,
+        };
+        self.state = next;
+        self.time_in_state = 0.0;
+        self.random_duration = None;
+        self.reaim_elapsed = 0.0;
+        Some(next)
+    }
+
+    pub fn snapshot(&self) -> BehaviorLayerSnapshot {
+        BehaviorLayerSnapshot { state: self.state, time_in_state: self.time_in_state, aim: self.aim, target: self.target }
+    }
+
+    // Picks up mid-state without rerunning its on-enter effects; a state the graph no longer has is ignored
+    pub fn restore(&mut self, snapshot: &BehaviorLayerSnapshot) {
+        if snapshot.state >= self.graph.states.len() { return; }
+        *self = Self { state: snapshot.state, time_in_state: snapshot.time_in_state, aim: snapshot.aim, target: snapshot.target, started: true, ..Self::new(self.graph) };
+    }
+
+    // Returns false if the current state can't be interrupted
+    pub fn interrupt(&mut self) -> bool {
+        self.interrupted_to = self.current().on_stagger;
+        self.interrupted_to.is_some()
+    }
+
+    // The effects that only touch the layer's own aim and target
+    pub fn apply_targeting(&mut self, effect: StateEffect, surroundings: Surroundings, rng: &mut impl Rng) {
+        let to_player = (surroundings.player_position - surroundings.position).normalize_or_zero();
+        match effect {
+            StateEffect::AimAtPlayer => self.aim = to_player,
+            StateEffect::AimSideways => {
+                self.aim = if rng.gen_bool(0.5) { to_player.perp() } else { -to_player.perp() };
+                if self.aim == Vec2::ZERO { self.aim = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)); }
+            }
+            StateEffect::PickStrafeTarget { min, max } => {
+                let side = if rng.gen_bool(0.5) { 1.0 } else { -1.0 };
+                self.target = Some(surroundings.position + to_player.perp() * side * rng.gen_range(min..max));
+            }
+            StateEffect::PickFlankTarget { flank_distance, min_range, max_range } => {
+                let offset = to_player.perp().rotate(Vec2::from_angle(rng.gen_range(-0.5..0.5) * std::f32::consts::PI)) * rng.gen_range(0.0..50.0);
+                let flank = surroundings.player_position + to_player * flank_distance + offset;
+                let distance = flank.distance(surroundings.player_position).clamp(min_range, max_range);
+                self.target = Some(surroundings.player_position + (flank - surroundings.player_position).normalize_or_zero() * distance);
+            }
+            _ => {}
+        }
+    }
+
+    fn steering(&mut self, delta_secs: f32, surroundings: Surroundings) -> Option<Steering> {
+        let to_player = (surroundings.player_position - surroundings.position).normalize_or_zero();
+        let steer = |direction: Vec2, speed_multiplier: f32| Some(Steering { direction, speed_multiplier, facing: Some(direction) });
+        match self.current().movement {
+            Movement::Inherit => None,
+            Movement::Chase(speed_multiplier) => steer(to_player, speed_multiplier),
+            Movement::Flee(speed_multiplier) => steer(-to_player, speed_multiplier),
+            Movement::Hold => Some(Steering { direction: Vec2::ZERO, speed_multiplier: 0.0, facing: Some(to_player) }),
+            Movement::KeepDistance { min, max, speed_multiplier } => {
+                let distance = surroundings.position.distance(surroundings.player_position);
+                if distance < min { steer(-to_player, speed_multiplier) }
+                else if distance > max { steer(to_player, speed_multiplier) }
+                else { Some(Steering { direction: Vec2::ZERO, speed_multiplier: 0.0, facing: None }) }
+            }
+            Movement::MoveToTarget(speed_multiplier) => steer(self.target.map_or(Vec2::ZERO, |target| (target - surroundings.position).normalize_or_zero()), speed_multiplier),
+            Movement::MoveAlongAim(speed_multiplier) => steer(self.aim, speed_multiplier),
+            Movement::Telegraph { reaim_secs } => {
+                self.reaim_elapsed += delta_secs;
+                if self.reaim_elapsed >= reaim_secs {
+                    self.reaim_elapsed = 0.0;
+                    self.aim = to_player;
+                }
+                Some(Steering { direction: Vec2::ZERO, speed_multiplier: 0.0, facing: Some(self.aim) })
+            }
+        }
+    }
+}
+
+// Where a layer was in its graph, for suspended runs; minions and rolled durations start afresh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BehaviorLayerSnapshot { pub state: usize, pub time_in_state: f32, pub aim: Vec2, pub target: Option<Vec2> }
+
+// What the behaviour wants from horror_movement_system this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Steering { pub direction: Vec2, pub speed_multiplier: f32, pub facing: Option<Vec2> }
+
+#[derive(Component, Debug, Clone)]
+pub struct HorrorBehavior {
+    pub layers: Vec<BehaviorLayer>, // Later layers take priority over earlier ones
+    pub steering: Option<Steering>,
+    base_color: Option<Color>, // The sprite's colour before any state tinted it
+}
+
+impl HorrorBehavior {
+    pub fn new(graphs: &[&'static BehaviorGraph]) -> Self {
+        Self { layers: graphs.iter().map(|graph| BehaviorLayer::new(graph)).collect(), steering: None, base_color: None }
+    }
+
+    pub fn snapshot(&self) -> Vec<BehaviorLayerSnapshot> { self.layers.iter().map(BehaviorLayer::snapshot).collect() }
+    // Layers line up by index, since a horror of the same type and affixes gets the same graphs
+    pub fn restore(&mut self, snapshots: &[BehaviorLayerSnapshot]) {
+        for (layer, snapshot) in self.layers.iter_mut().zip(snapshots) { layer.restore(snapshot); }
+    }
+
+    // Staggered: every layer in an interruptible state bails out of it
+    pub fn interrupt(&mut self) {
+        for layer in self.layers.iter_mut() { layer.interrupt(); }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn horror_behavior_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    mut horror_query: Query<(Entity, &mut HorrorBehavior, &mut Transform, &mut Sprite, &mut Visibility), With<Horror>>,
+    player_query: Query<(&Transform, &Velocity), (With<Survivor>, Without<Horror>)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Ok((player_transform, player_velocity)) = player_query.get_single() else { return; };
+    let player_position = player_transform.translation.truncate();
+    let pattern_target = PatternTarget { position: player_position, velocity: player_velocity.0 };
+    let wave_multiplier = 1.0 + (game_state.wave_number as f32 - 1.0) * 0.1;
+    let delta_secs = time.delta_seconds();
+    let mut rng = rand::thread_rng();
+
+    for (horror_entity, mut behavior, mut transform, mut sprite, mut visibility) in horror_query.iter_mut() {
+        let base_color = *behavior.base_color.get_or_insert(sprite.color);
+        let mut steering = None;
+        for layer in behavior.layers.iter_mut() {
+            layer.minions.retain(|&minion| commands.get_entity(minion).is_some());
+            let previous_fade = layer.current().fade;
+            let surroundings = Surroundings { position: transform.translation.truncate(), player_position };
+
+            if let Some(entered) = layer.advance(delta_secs, surroundings, &mut rng) {
+                let entered_state = &layer.graph.states[entered];
+                match entered_state.tint {
+                    Tint::Keep => {}
+                    Tint::Set(color) => sprite.color = color.with_a(sprite.color.a()),
+                    Tint::Restore => sprite.color = base_color.with_a(sprite.color.a()),
+                }
+                if entered_state.fade == Fade::None && previous_fade != Fade::None { sprite.color.set_a(1.0); }
+                for effect in entered_state.on_enter {
+                    match *effect {
+                        StateEffect::Fire(pattern, style) => {
+                            sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorProjectile));
+                            fire_pattern(&mut commands, &asset_server, pattern, style, transform.translation, pattern_target, Some(horror_entity), &mut rng);
+                        }
+                        StateEffect::Summon { minion, count, max_active } => {
+                            for _ in 0..count {
+                                if layer.minions.len() >= max_active as usize { break; }
+                                let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(20.0..50.0);
+                                layer.minions.push(spawn_and_return_horror_entity(&mut commands, &asset_server, minion, (surroundings.position + offset).extend(0.5), wave_multiplier));
+                            }
+                        }
+                        StateEffect::TeleportToTarget => {
+                            if let Some(target) = layer.target.take() { transform.translation = target.extend(transform.translation.z); }
+                        }
+                        StateEffect::Hide => *visibility = Visibility::Hidden,
+                        StateEffect::Show => *visibility = Visibility::Visible,
+                        targeting => layer.apply_targeting(targeting, surroundings, &mut rng),
+                    }
+                }
+            }
+
+            match layer.current().fade {
+                Fade::None => {}
+                Fade::Out(secs) => { sprite.color.set_a(1.0 - (layer.time_in_state / secs).min(1.0)); }
+                Fade::In(secs) => { sprite.color.set_a((layer.time_in_state / secs).min(1.0)); }
+            }
+            let surroundings = Surroundings { position: transform.translation.truncate(), player_position };
+            if let Some(layer_steering) = layer.steering(delta_secs, surroundings) { steering = Some(layer_steering); }
+        }
+        behavior.steering = steering;
+    }
+}
@@ -1,6 +1,7 @@
 // src/items.rs
 use bevy::prelude::*;
 use serde::{Serialize, Deserialize};
+use rand::{Rng, seq::SliceRandom};
 use crate::{
     survivor::Survivor,
     components::Health,
@@ -10,6 +11,8 @@ use crate::{
     audio::{PlaySoundEvent, SoundEffect},
     skills::{SkillId, SkillLibrary, ActiveSkillInstance},
     weapons::{CircleOfWarding, SwarmOfNightmares},
+    elites::HorrorDamage,
+    run_stats::{DamageSource, SurvivorHealedEvent},
 };
 use crate::automatic_weapons;
 
@@ -141,6 +144,7 @@ pub struct ConeAttackParams {
     pub burn_duration_secs: Option<f32>, // Added
     pub burn_tick_interval_secs: Option<f32>, // Added
     pub fire_sound_effect: Option<String>,
+    pub knockback: f32, // Force per hit, resisted by the horror's mass; see knockback.rs
 }
 
 #[derive(Debug, Clone, Reflect, Default, PartialEq, Serialize, Deserialize)]
@@ -179,6 +183,7 @@ pub struct ChargeLevelParams {
     pub projectile_sprite_path: String, 
     pub projectile_color: Color, 
     pub aoe_radius_on_impact: Option<f32>, 
+    pub knockback: f32,
 }
 
 #[derive(Debug, Clone, Reflect, Default, PartialEq, Serialize, Deserialize)]
@@ -501,6 +506,7 @@ pub struct PointBlankNovaParams {
     pub slow_effect_multiplier: f32, // Added
     pub slow_duration_secs: f32, // Added
     pub fire_sound_effect: Option<String>,
+    pub knockback: f32,
 }
 
 #[derive(Debug, Clone, Reflect, Default, PartialEq, Serialize, Deserialize)]
@@ -654,11 +660,22 @@ impl Default for AttackTypeData {
 }
 
 
+// How a weapon or relic first becomes available to a profile. Kept on the definition so adding one never shifts
+// what else counts as a starter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect, Default, Serialize, Deserialize)]
+pub enum UnlockCondition {
+    Starter, // Available from the very first run
+    #[default]
+    Purchase, // Bought with Lore in the meta shop, or granted by an achievement
+}
+
 #[derive(Debug, Clone, Reflect, Default, Serialize, Deserialize)] // Added Default, Serialize, Deserialize
 #[reflect(Default)] // Added reflect Default
 pub struct ItemDefinition {
     pub id: ItemId,
     pub name: String,
+    #[serde(default)]
+    pub unlock: UnlockCondition,
     pub description: String,
     pub effects: Vec<ItemEffect>,
     pub icon_path: String,
@@ -666,13 +683,38 @@ pub struct ItemDefinition {
 
 #[derive(Resource, Default, Reflect, Serialize, Deserialize)] #[reflect(Resource)] // Added Serialize, Deserialize
 pub struct ItemLibrary { pub items: Vec<ItemDefinition>, }
-impl ItemLibrary { pub fn get_item_definition(&self, id: ItemId) -> Option<&ItemDefinition> { self.items.iter().find(|def| def.id == id) } }
+impl ItemLibrary {
+    pub fn get_item_definition(&self, id: ItemId) -> Option<&ItemDefinition> { self.items.iter().find(|def| def.id == id) }
+
+    // Up to `count` unlocked relics in random order, preferring ones not yet collected; for guaranteed drops
+    pub fn pick_relic_rewards(&self, is_unlocked: impl Fn(&ItemDefinition) -> bool, collected: &[ItemId], count: usize, rng: &mut impl Rng) -> Vec<&ItemDefinition> {
+        let unlocked: Vec<&ItemDefinition> = self.items.iter().filter(|item_def| is_unlocked(item_def)).collect();
+        let mut candidates: Vec<&ItemDefinition> = unlocked.iter().copied().filter(|item_def| !collected.contains(&item_def.id)).collect();
+        if candidates.is_empty() { candidates = unlocked; }
+        candidates.shuffle(rng);
+        candidates.truncate(count);
+        candidates
+    }
+}
 
 #[derive(Component, Debug, Reflect, Default, Serialize, Deserialize)] // Added Reflect, Default, Serialize, Deserialize
 #[reflect(Component, Default)] // Added reflect Component, Default
 pub struct ItemDrop { pub item_id: ItemId, }
 pub const ITEM_DROP_SIZE: Vec2 = Vec2::new(24.0, 24.0);
 
+pub fn spawn_item_drop(commands: &mut Commands, asset_server: &AssetServer, position: Vec2, item_def: &ItemDefinition) -> Entity {
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/eldritch_relic_placeholder.png"),
+            sprite: Sprite { custom_size: Some(ITEM_DROP_SIZE), ..default() },
+            transform: Transform::from_translation(position.extend(0.4)),
+            ..default()
+        },
+        ItemDrop { item_id: item_def.id },
+        Name::new(format!("ItemDrop_{}", item_def.name)),
+    )).id()
+}
+
 #[derive(Component, Reflect, Default, Debug, Serialize, Deserialize)] #[reflect(Component, Default)] // Added Serialize, Deserialize and reflect Default
 pub struct ExplosionEffect { pub damage: i32, pub radius_sq: f32, #[serde(skip)] pub timer: Timer, pub already_hit_entities: Vec<Entity>, }
 #[derive(Component, Reflect, Default, Debug, Serialize, Deserialize)] #[reflect(Component, Default)] // Added Serialize, Deserialize and reflect Default
@@ -686,6 +728,8 @@ pub struct TemporaryHealthRegenBuff { pub regen_per_second: f32, #[serde(skip)]
 pub struct AutomaticWeaponDefinition {
     pub id: AutomaticWeaponId,
     pub name: String,
+    #[serde(default)]
+    pub unlock: UnlockCondition,
     pub attack_data: AttackTypeData,
 }
 
@@ -757,22 +801,23 @@ pub fn populate_automatic_weapon_library(mut library: ResMut<AutomaticWeaponLibr
 }
 
 
-fn populate_item_library(mut library: ResMut<ItemLibrary>) {
-    library.items.push(ItemDefinition { id: ItemId(1), name: "Corrupted Heart".to_string(), description: "Increases Max Health by 25.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: Some(25), speed_multiplier: None, damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_corrupted_heart_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(2), name: "Whispering Idol".to_string(), description: "Increases Movement Speed by 15%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: Some(1.15), damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_whispering_idol_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(3), name: "Shard of Agony".to_string(), description: "Increases automatic weapon damage by 5.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: Some(5), xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_shard_of_agony_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(4), name: "Occult Tome Fragment".to_string(), description: "Increases XP gain by 20%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: None, xp_gain_multiplier: Some(1.20), pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_occult_tome_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(5), name: "Grasping Tentacle (Dried)".to_string(), description: "Increases pickup radius by 25%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: Some(0.25), auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_grasping_tentacle_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(6), name: "Fragmented Sanity".to_string(), description: "Your automatic projectiles have a chance to violently detonate on impact.".to_string(), effects: vec![ItemEffect::OnAutomaticProjectileHitExplode { chance: 0.15, explosion_damage: 20, explosion_radius: 75.0, explosion_color: Color::rgba(1.0, 0.5, 0.2, 0.6), }], icon_path: "sprites/icons/item_fragmented_sanity_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(7), name: "Cloak of VengefulSpirits".to_string(), description: "When struck, has a chance to unleash a damaging psychic nova.".to_string(), effects: vec![ItemEffect::OnSurvivorHitRetaliate { chance: 0.25, retaliation_damage: 30, retaliation_radius: 120.0, retaliation_color: Color::rgba(0.9, 0.1, 0.1, 0.5), }], icon_path: "sprites/icons/item_cloak_vengeful_spirits_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(8), name: "Soul Siphon Shard".to_string(), description: "Defeated foes have a 20% chance to grant brief, rapid health regeneration.".to_string(), effects: vec![ItemEffect::OnHorrorKillTrigger { chance: 0.20, effect: SurvivorTemporaryBuff::HealthRegen { rate: 5.0, duration_secs: 3.0 }, }], icon_path: "sprites/icons/item_soul_siphon_shard_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(9), name: "Tome of Forbidden Rites".to_string(), description: "Grants knowledge of the 'Void Lance' skill.".to_string(), effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(3) }], icon_path: "sprites/icons/item_tome_forbidden_rites_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(10), name: "Glyph-Etched Wardstone".to_string(), description: "Activates a Circle of Warding, damaging nearby foes.".to_string(), effects: vec![ItemEffect::ActivateCircleOfWarding { base_damage: 3, base_radius: 75.0, base_tick_interval: 0.5, }], icon_path: "sprites/icons/item_glyph_wardstone_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(11), name: "Broodmother's Oculus".to_string(), description: "Summons a Swarm of Nightmares to orbit and attack enemies.".to_string(), effects: vec![ItemEffect::ActivateSwarmOfNightmares { num_larvae: 2, base_damage: 5, base_orbit_radius: 80.0, base_rotation_speed: std::f32::consts::PI / 2.0, }], icon_path: "sprites/icons/item_broodmother_oculus_placeholder.png".to_string() });
-    library.items.push(ItemDefinition { id: ItemId(12), name: "Crystalline Conduit".to_string(), description: "Increases automatic weapon damage by +3 and projectile speed by +10%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: Some(3), xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: Some(0.10) }], icon_path: "sprites/icons/item_crystalline_conduit_placeholder.png".to_string() });
+pub fn populate_item_library(mut library: ResMut<ItemLibrary>) {
+    library.items.push(ItemDefinition { id: ItemId(1), name: "Corrupted Heart".to_string(), unlock: UnlockCondition::Starter, description: "Increases Max Health by 25.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: Some(25), speed_multiplier: None, damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_corrupted_heart_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(2), name: "Whispering Idol".to_string(), unlock: UnlockCondition::Starter, description: "Increases Movement Speed by 15%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: Some(1.15), damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_whispering_idol_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(3), name: "Shard of Agony".to_string(), unlock: UnlockCondition::Starter, description: "Increases automatic weapon damage by 5.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: Some(5), xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_shard_of_agony_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(4), name: "Occult Tome Fragment".to_string(), unlock: UnlockCondition::Starter, description: "Increases XP gain by 20%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: None, xp_gain_multiplier: Some(1.20), pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_occult_tome_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(5), name: "Grasping Tentacle (Dried)".to_string(), unlock: UnlockCondition::Starter, description: "Increases pickup radius by 25%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: None, xp_gain_multiplier: None, pickup_radius_increase: Some(0.25), auto_weapon_projectile_speed_multiplier_increase: None }], icon_path: "sprites/icons/item_grasping_tentacle_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(6), name: "Fragmented Sanity".to_string(), unlock: UnlockCondition::Starter, description: "Your automatic projectiles have a chance to violently detonate on impact.".to_string(), effects: vec![ItemEffect::OnAutomaticProjectileHitExplode { chance: 0.15, explosion_damage: 20, explosion_radius: 75.0, explosion_color: Color::rgba(1.0, 0.5, 0.2, 0.6), }], icon_path: "sprites/icons/item_fragmented_sanity_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(7), name: "Cloak of VengefulSpirits".to_string(), unlock: UnlockCondition::Starter, description: "When struck, has a chance to unleash a damaging psychic nova.".to_string(), effects: vec![ItemEffect::OnSurvivorHitRetaliate { chance: 0.25, retaliation_damage: 30, retaliation_radius: 120.0, retaliation_color: Color::rgba(0.9, 0.1, 0.1, 0.5), }], icon_path: "sprites/icons/item_cloak_vengeful_spirits_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(8), name: "Soul Siphon Shard".to_string(), unlock: UnlockCondition::Starter, description: "Defeated foes have a 20% chance to grant brief, rapid health regeneration.".to_string(), effects: vec![ItemEffect::OnHorrorKillTrigger { chance: 0.20, effect: SurvivorTemporaryBuff::HealthRegen { rate: 5.0, duration_secs: 3.0 }, }], icon_path: "sprites/icons/item_soul_siphon_shard_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(9), name: "Tome of Forbidden Rites".to_string(), unlock: UnlockCondition::Purchase, description: "Grants knowledge of the 'Void Lance' skill.".to_string(), effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(3) }], icon_path: "sprites/icons/item_tome_forbidden_rites_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(10), name: "Glyph-Etched Wardstone".to_string(), unlock: UnlockCondition::Purchase, description: "Activates a Circle of Warding, damaging nearby foes.".to_string(), effects: vec![ItemEffect::ActivateCircleOfWarding { base_damage: 3, base_radius: 75.0, base_tick_interval: 0.5, }], icon_path: "sprites/icons/item_glyph_wardstone_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(11), name: "Broodmother's Oculus".to_string(), unlock: UnlockCondition::Purchase, description: "Summons a Swarm of Nightmares to orbit and attack enemies.".to_string(), effects: vec![ItemEffect::ActivateSwarmOfNightmares { num_larvae: 2, base_damage: 5, base_orbit_radius: 80.0, base_rotation_speed: std::f32::consts::PI / 2.0, }], icon_path: "sprites/icons/item_broodmother_oculus_placeholder.png".to_string() });
+    library.items.push(ItemDefinition { id: ItemId(12), name: "Crystalline Conduit".to_string(), unlock: UnlockCondition::Purchase, description: "Increases automatic weapon damage by +3 and projectile speed by +10%.".to_string(), effects: vec![ItemEffect::PassiveStatBoost { max_health_increase: None, speed_multiplier: None, damage_increase: Some(3), xp_gain_multiplier: None, pickup_radius_increase: None, auto_weapon_projectile_speed_multiplier_increase: Some(0.10) }], icon_path: "sprites/icons/item_crystalline_conduit_placeholder.png".to_string() });
     library.items.push(ItemDefinition {
         id: ItemId(13),
         name: "Tome of Shattered Thoughts".to_string(),
+        unlock: UnlockCondition::Purchase,
         description: "Unlocks the 'Mind Shatter' psychic burst skill.".to_string(),
         effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(2) }],
         icon_path: "sprites/icons/item_tome_mind_shatter_placeholder.png".to_string()
@@ -780,6 +825,7 @@ fn populate_item_library(mut library: ResMut<ItemLibrary>) {
     library.items.push(ItemDefinition {
         id: ItemId(14),
         name: "Tome of the Glacial Heart".to_string(),
+        unlock: UnlockCondition::Purchase,
         description: "Unlocks the 'Glacial Nova' chilling skill.".to_string(),
         effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(5) }],
         icon_path: "sprites/icons/item_tome_glacial_nova_placeholder.png".to_string()
@@ -787,6 +833,7 @@ fn populate_item_library(mut library: ResMut<ItemLibrary>) {
     library.items.push(ItemDefinition {
         id: ItemId(15),
         name: "Tome of the Watcher".to_string(),
+        unlock: UnlockCondition::Purchase,
         description: "Unlocks the 'Psychic Sentry' summoning skill.".to_string(),
         effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(6) }],
         icon_path: "sprites/icons/item_tome_psychic_sentry_placeholder.png".to_string()
@@ -794,6 +841,7 @@ fn populate_item_library(mut library: ResMut<ItemLibrary>) {
      library.items.push(ItemDefinition {
         id: ItemId(16),
         name: "Tome of Ethereal Defense".to_string(),
+        unlock: UnlockCondition::Purchase,
         description: "Unlocks the 'Ethereal Ward' defensive skill.".to_string(),
         effects: vec![ItemEffect::GrantSpecificSkill { skill_id: SkillId(7) }],
         icon_path: "sprites/icons/item_tome_ethereal_ward_placeholder.png".to_string()
@@ -883,9 +931,9 @@ fn apply_collected_item_effects_system(
     }
 }
 
-fn explosion_effect_system( mut commands: Commands, time: Res<Time>, mut explosion_query: Query<(Entity, &mut ExplosionEffect, &GlobalTransform, &mut Sprite, &mut Transform)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>,) { for (explosion_entity, mut explosion, explosion_g_transform, mut sprite, mut vis_transform) in explosion_query.iter_mut() { explosion.timer.tick(time.delta()); let progress = explosion.timer.percent(); let current_radius = explosion.radius_sq.sqrt(); vis_transform.scale = Vec3::splat(current_radius * 2.0 * progress); sprite.color.set_a(1.0 - progress); if explosion.timer.percent() < 0.5 { let explosion_pos = explosion_g_transform.translation().truncate(); for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() { if explosion.already_hit_entities.contains(&horror_entity) { continue; } let horror_pos = horror_gtransform.translation().truncate(); if horror_pos.distance_squared(explosion_pos) < explosion.radius_sq { horror_health.0 -= explosion.damage; visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), explosion.damage, &time); sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); explosion.already_hit_entities.push(horror_entity); } } } if explosion.timer.finished() { commands.entity(explosion_entity).despawn_recursive(); } } }
-fn retaliation_nova_effect_system( mut commands: Commands, time: Res<Time>, mut nova_query: Query<(Entity, &mut RetaliationNovaEffect, &GlobalTransform, &mut Sprite, &mut Transform)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>,) { for (nova_entity, mut nova, nova_g_transform, mut sprite, mut vis_transform) in nova_query.iter_mut() { nova.timer.tick(time.delta()); let progress = nova.timer.percent(); let current_radius = nova.radius_sq.sqrt(); vis_transform.scale = Vec3::splat(current_radius * 2.0 * progress); sprite.color.set_a(1.0 - progress * progress); if nova.timer.percent() < 0.3 { let nova_pos = nova_g_transform.translation().truncate(); for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() { if nova.already_hit_entities.contains(&horror_entity) { continue; } let horror_pos = horror_gtransform.translation().truncate(); if horror_pos.distance_squared(nova_pos) < nova.radius_sq { horror_health.0 -= nova.damage; visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), nova.damage, &time); sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); nova.already_hit_entities.push(horror_entity); } } } if nova.timer.finished() { commands.entity(nova_entity).despawn_recursive(); } } }
-fn temporary_health_regen_buff_system( mut commands: Commands, time: Res<Time>, mut buff_query: Query<(Entity, &mut TemporaryHealthRegenBuff)>, mut player_query: Query<(&Survivor, &mut Health)>) {
+fn explosion_effect_system( mut commands: Commands, time: Res<Time>, mut explosion_query: Query<(Entity, &mut ExplosionEffect, &GlobalTransform, &mut Sprite, &mut Transform)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>, mut horror_damage: HorrorDamage,) { for (explosion_entity, mut explosion, explosion_g_transform, mut sprite, mut vis_transform) in explosion_query.iter_mut() { explosion.timer.tick(time.delta()); let progress = explosion.timer.percent(); let current_radius = explosion.radius_sq.sqrt(); vis_transform.scale = Vec3::splat(current_radius * 2.0 * progress); sprite.color.set_a(1.0 - progress); if explosion.timer.percent() < 0.5 { let explosion_pos = explosion_g_transform.translation().truncate(); for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() { if explosion.already_hit_entities.contains(&horror_entity) { continue; } let horror_pos = horror_gtransform.translation().truncate(); if horror_pos.distance_squared(explosion_pos) < explosion.radius_sq { horror_damage.apply(DamageSource::Explosion, horror_entity, &mut horror_health, explosion.damage); visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), explosion.damage, &time); sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); explosion.already_hit_entities.push(horror_entity); } } } if explosion.timer.finished() { commands.entity(explosion_entity).despawn_recursive(); } } }
+fn retaliation_nova_effect_system( mut commands: Commands, time: Res<Time>, mut nova_query: Query<(Entity, &mut RetaliationNovaEffect, &GlobalTransform, &mut Sprite, &mut Transform)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>, mut horror_damage: HorrorDamage,) { for (nova_entity, mut nova, nova_g_transform, mut sprite, mut vis_transform) in nova_query.iter_mut() { nova.timer.tick(time.delta()); let progress = nova.timer.percent(); let current_radius = nova.radius_sq.sqrt(); vis_transform.scale = Vec3::splat(current_radius * 2.0 * progress); sprite.color.set_a(1.0 - progress * progress); if nova.timer.percent() < 0.3 { let nova_pos = nova_g_transform.translation().truncate(); for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() { if nova.already_hit_entities.contains(&horror_entity) { continue; } let horror_pos = horror_gtransform.translation().truncate(); if horror_pos.distance_squared(nova_pos) < nova.radius_sq { horror_damage.apply(DamageSource::RetaliationNova, horror_entity, &mut horror_health, nova.damage); visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), nova.damage, &time); sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); nova.already_hit_entities.push(horror_entity); } } } if nova.timer.finished() { commands.entity(nova_entity).despawn_recursive(); } } }
+fn temporary_health_regen_buff_system( mut commands: Commands, time: Res<Time>, mut buff_query: Query<(Entity, &mut TemporaryHealthRegenBuff)>, mut player_query: Query<(&Survivor, &mut Health)>, mut healed_writer: EventWriter<SurvivorHealedEvent>) {
     if let Ok((survivor_stats, mut health_component)) = player_query.get_single_mut() {
         for (entity, mut buff) in buff_query.iter_mut() {
             buff.duration_timer.tick(time.delta());
@@ -893,8 +941,7 @@ fn temporary_health_regen_buff_system( mut commands: Commands, time: Res<Time>,
                 commands.entity(entity).remove::<TemporaryHealthRegenBuff>();
             } else {
                 let regen_amount = buff.regen_per_second * time.delta().as_secs_f32();
-                health_component.0 = (health_component.0 as f32 + regen_amount).round() as i32;
-                health_component.0 = health_component.0.min(survivor_stats.max_health);
+                healed_writer.send(SurvivorHealedEvent(survivor_stats.heal(&mut health_component, regen_amount.round() as i32)));
             }
         }
     }
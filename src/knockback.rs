@@ -0,0 +1,125 @@
+// src/knockback.rs
+// Weapons shove horrors. A hit sends a `KnockbackEvent`; the horror's mass turns the force into an impulse that
+// decays over a fraction of a second and rides on top of whatever its own movement wants. The same force wears
+// down the horror's poise. Once poise breaks the horror is staggered: it stops for a moment and abandons any
+// behaviour state that can be interrupted, like the Frenzied Behemoth's charge telegraph. Poise refills when the
+// horror is left alone for a while.
+use bevy::prelude::*;
+use std::time::Duration;
+use crate::{
+    game::AppState,
+    horror::{Horror, horror_movement_system},
+    horror_behavior::HorrorBehavior,
+    crowd::horror_mass,
+};
+
+const KNOCKBACK_DECAY_PER_SEC: f32 = 8.0; // Exponential; an impulse is mostly spent after ~0.3s
+const MAX_KNOCKBACK_SPEED: f32 = 900.0;
+const POISE_PER_MASS: f32 = 60.0;
+const POISE_DAMAGE_PER_FORCE: f32 = 0.1;
+const POISE_RECOVERY_DELAY_SECS: f32 = 1.5;
+const POISE_RECOVERY_FRACTION_PER_SEC: f32 = 0.5; // Of max poise, once recovering
+const STAGGER_SECS: f32 = 0.6;
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct KnockbackEvent { pub target: Entity, pub direction: Vec2, pub force: f32 }
+
+// On projectiles that shove whatever they hit
+#[derive(Component, Debug, Clone, Copy)]
+pub struct KnockbackOnHit(pub f32);
+
+// Added to a horror the first time it's shoved
+#[derive(Component, Debug, Clone)]
+pub struct Poise {
+    pub mass: f32,
+    pub max: f32,
+    pub current: f32,
+    pub velocity: Vec2, // Knockback impulse, added to the horror's own movement
+    since_last_hit: f32,
+    stagger_timer: Option<Timer>,
+}
+
+impl Poise {
+    pub fn new(mass: f32, max: f32) -> Self {
+        Self { mass, max, current: max, velocity: Vec2::ZERO, since_last_hit: 0.0, stagger_timer: None }
+    }
+
+    // Elites and bosses are bigger, so heavier and harder to stagger
+    pub fn for_horror(horror: &Horror) -> Self {
+        let mass = horror_mass(horror.size);
+        Self::new(mass, mass * POISE_PER_MASS)
+    }
+
+    pub fn is_staggered(&self) -> bool { self.stagger_timer.is_some() }
+
+    // Returns true if this hit broke poise. A staggered horror still gets shoved but takes no more poise damage.
+    pub fn take_hit(&mut self, direction: Vec2, force: f32) -> bool {
+        self.velocity = (self.velocity + direction.normalize_or_zero() * force / self.mass).clamp_length_max(MAX_KNOCKBACK_SPEED);
+        self.since_last_hit = 0.0;
+        if self.is_staggered() { return false; }
+        self.current -= force * POISE_DAMAGE_PER_FORCE;
+        if self.current > 0.0 { return false; }
+        self.current = self.max;
+        self.stagger_timer = Some(Timer::from_seconds(STAGGER_SECS, TimerMode::Once));
+        true
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.velocity *= (-KNOCKBACK_DECAY_PER_SEC * delta_secs).exp();
+        if self.velocity.length_squared() < 1.0 { self.velocity = Vec2::ZERO; }
+        if let Some(timer) = self.stagger_timer.as_mut() {
+            if timer.tick(Duration::from_secs_f32(delta_secs)).finished() { self.stagger_timer = None; }
+        }
+        self.since_last_hit += delta_secs;
+        if self.since_last_hit >= POISE_RECOVERY_DELAY_SECS {
+            self.current = (self.current + self.max * POISE_RECOVERY_FRACTION_PER_SEC * delta_secs).min(self.max);
+        }
+    }
+}
+
+pub struct KnockbackPlugin;
+
+impl Plugin for KnockbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<KnockbackEvent>()
+            .add_systems(Update, (
+                apply_knockback_system,
+                poise_tick_system,
+            ).chain().before(horror_movement_system).run_if(in_state(AppState::InGame)));
+    }
+}
+
+fn apply_knockback_system(
+    mut commands: Commands,
+    mut knockback_events: EventReader<KnockbackEvent>,
+    mut horror_query: Query<(&Horror, Option<&mut Poise>, Option<&mut HorrorBehavior>)>,
+) {
+    // Horrors shoved for the first time get their Poise once every hit this frame is in
+    let mut new_poise: Vec<(Entity, Poise)> = Vec::new();
+    for event in knockback_events.read() {
+        if event.force <= 0.0 { continue; }
+        let Ok((horror, poise, behavior)) = horror_query.get_mut(event.target) else { continue; };
+        let broke = match poise {
+            Some(mut poise) => poise.take_hit(event.direction, event.force),
+            None => match new_poise.iter_mut().find(|(entity, _)| *entity == event.target) {
+                Some((_, poise)) => poise.take_hit(event.direction, event.force),
+                None => {
+                    let mut poise = Poise::for_horror(horror);
+                    let broke = poise.take_hit(event.direction, event.force);
+                    new_poise.push((event.target, poise));
+                    broke
+                }
+            },
+        };
+        if broke {
+            if let Some(mut behavior) = behavior { behavior.interrupt(); }
+        }
+    }
+    for (entity, poise) in new_poise {
+        if let Some(mut entity_commands) = commands.get_entity(entity) { entity_commands.insert(poise); }
+    }
+}
+
+fn poise_tick_system(time: Res<Time>, mut poise_query: Query<&mut Poise>) {
+    for mut poise in poise_query.iter_mut() { poise.tick(time.delta_seconds()); }
+}
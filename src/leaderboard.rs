@@ -0,0 +1,242 @@
+// src/leaderboard.rs
+// Local high-score tables, one per character and game mode, kept in their own file beside the profile.
+// Only runs that end in death are ranked; abandoned runs still go to the run history.
+use bevy::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::{
+    persistence,
+    game::{AppState, GameMode, GameState, RunSeed, SelectedCharacter, SelectedGameMode},
+    characters::{CharacterId, CharacterLibrary},
+    items::{AutomaticWeaponId, AutomaticWeaponLibrary, ItemId, ItemLibrary},
+    skills::{SkillId, SkillLibrary},
+    survivor::Survivor,
+    run_stats::format_duration,
+};
+
+pub const LEADERBOARD_FILE_PATH: &str = "leaderboards.ron";
+pub const MAX_LEADERBOARD_ENTRIES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LeaderboardKey { pub character: CharacterId, pub mode: GameMode }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub score: u32,
+    pub survived_secs: u32,
+    pub wave_reached: u32,
+    pub seed: u64,
+    pub finished_at_unix_secs: u64,
+    // Final build
+    pub weapon_id: AutomaticWeaponId,
+    pub relic_ids: Vec<ItemId>,
+    pub skill_ids: Vec<SkillId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaderboardTable { pub key: LeaderboardKey, pub entries: Vec<LeaderboardEntry> }
+
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Leaderboards { pub tables: Vec<LeaderboardTable> }
+
+impl Leaderboards {
+    pub fn table(&self, key: LeaderboardKey) -> Option<&LeaderboardTable> { self.tables.iter().find(|table| table.key == key) }
+
+    // Returns the entry's rank (0 = personal best) if it made the table. Ties keep the earlier run ahead.
+    pub fn submit(&mut self, key: LeaderboardKey, entry: LeaderboardEntry) -> Option<usize> {
+        let table_index = match self.tables.iter().position(|table| table.key == key) {
+            Some(index) => index,
+            None => { self.tables.push(LeaderboardTable { key, entries: Vec::new() }); self.tables.len() - 1 }
+        };
+        let entries = &mut self.tables[table_index].entries;
+        let rank = entries.iter().position(|existing| entry.score > existing.score).unwrap_or(entries.len());
+        if rank >= MAX_LEADERBOARD_ENTRIES { return None; }
+        entries.insert(rank, entry);
+        entries.truncate(MAX_LEADERBOARD_ENTRIES);
+        Some(rank)
+    }
+}
+
+// How the run that just ended placed, for the game-over screen.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct LatestLeaderboardResult { pub rank: Option<usize> }
+impl LatestLeaderboardResult {
+    pub fn is_personal_best(&self) -> bool { self.rank == Some(0) }
+}
+
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app .insert_resource(persistence::load_ron_or_default::<Leaderboards>(LEADERBOARD_FILE_PATH))
+            .init_resource::<LatestLeaderboardResult>()
+            .init_resource::<LeaderboardPage>()
+            .add_systems(OnEnter(AppState::Leaderboard), setup_leaderboard_ui)
+            .add_systems(Update, (leaderboard_input_system, refresh_leaderboard_ui).chain().run_if(in_state(AppState::Leaderboard)))
+            .add_systems(OnExit(AppState::Leaderboard), despawn_leaderboard_ui);
+    }
+}
+
+// Chained into the game-over entry systems ahead of the results screen.
+pub fn submit_leaderboard_entry(
+    mut leaderboards: ResMut<Leaderboards>,
+    mut latest_result: ResMut<LatestLeaderboardResult>,
+    game_state: Res<GameState>,
+    selected_character: Res<SelectedCharacter>,
+    selected_game_mode: Res<SelectedGameMode>,
+    run_seed: Res<RunSeed>,
+    survivor_query: Query<&Survivor>,
+) {
+    let Ok(survivor) = survivor_query.get_single() else { latest_result.rank = None; return; };
+    let key = LeaderboardKey { character: selected_character.0, mode: selected_game_mode.0 };
+    let entry = LeaderboardEntry {
+        score: game_state.score,
+        survived_secs: game_state.game_timer.elapsed_secs() as u32,
+        wave_reached: game_state.wave_number,
+        seed: run_seed.0,
+        finished_at_unix_secs: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs()),
+        weapon_id: survivor.inherent_weapon_id,
+        relic_ids: survivor.collected_item_ids.clone(),
+        skill_ids: survivor.equipped_skills.iter().map(|skill| skill.definition_id).collect(),
+    };
+    latest_result.rank = leaderboards.submit(key, entry);
+    if latest_result.rank.is_some() { persistence::save_ron(LEADERBOARD_FILE_PATH, &*leaderboards); }
+}
+
+// YYYY-MM-DD (UTC) from a Unix timestamp, using the days-to-civil conversion so no date crate is needed.
+pub fn format_date(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+// --- Main-menu leaderboard screen: one table per page, cycled with the arrow keys ---
+
+#[derive(Resource, Default)]
+struct LeaderboardPage(usize);
+
+#[derive(Component)] struct LeaderboardUIRoot;
+#[derive(Component)] struct LeaderboardBackButton;
+#[derive(Component)] struct LeaderboardPageButton(i32);
+
+#[allow(clippy::too_many_arguments)]
+fn setup_leaderboard_ui(
+    mut commands: Commands, asset_server: Res<AssetServer>, leaderboards: Res<Leaderboards>, page: Res<LeaderboardPage>,
+    character_library: Res<CharacterLibrary>, weapon_library: Res<AutomaticWeaponLibrary>, item_library: Res<ItemLibrary>, skill_library: Res<SkillLibrary>,
+) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let title_style = TextStyle { font: font.clone(), font_size: 50.0, color: Color::WHITE };
+    let header_style = TextStyle { font: font.clone(), font_size: 24.0, color: Color::GOLD };
+    let row_style = TextStyle { font: font.clone(), font_size: 17.0, color: Color::WHITE };
+    let build_style = TextStyle { font: font.clone(), font_size: 13.0, color: Color::rgb(0.7, 0.7, 0.8) };
+    let button_style = TextStyle { font, font_size: 22.0, color: Color::WHITE };
+
+    let tables: Vec<&LeaderboardTable> = leaderboards.tables.iter().filter(|table| !table.entries.is_empty()).collect();
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.05, 0.05, 0.1, 0.95).into(),
+            ..default()
+        },
+        LeaderboardUIRoot,
+        Name::new("LeaderboardUIRoot"),
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section("Hall of the Damned", title_style));
+
+        if let Some(table) = tables.get(page.0 % tables.len().max(1)) {
+            let character_name = character_library.get_character_definition(table.key.character).map_or("Unknown", |def| def.name.as_str());
+            parent.spawn(TextBundle::from_section(
+                format!("{} - {} ({}/{})", character_name, table.key.mode.display_name(), page.0 % tables.len() + 1, tables.len()),
+                header_style.clone(),
+            ));
+            for (rank, entry) in table.entries.iter().enumerate() {
+                let weapon_name = weapon_library.get_weapon_definition(entry.weapon_id).map_or("???", |def| def.name.as_str());
+                let relic_names: Vec<&str> = entry.relic_ids.iter().filter_map(|item_id| item_library.get_item_definition(*item_id)).map(|item_def| item_def.name.as_str()).collect();
+                let skill_names: Vec<&str> = entry.skill_ids.iter().filter_map(|skill_id| skill_library.get_skill_definition(*skill_id)).map(|skill_def| skill_def.name.as_str()).collect();
+                parent.spawn(NodeBundle {
+                    style: Style { width: Val::Px(760.0), flex_direction: FlexDirection::Column, padding: UiRect::all(Val::Px(4.0)), ..default() },
+                    background_color: Color::rgba(0.12, 0.12, 0.18, if rank == 0 { 0.95 } else { 0.7 }).into(),
+                    ..default()
+                }).with_children(|row| {
+                    row.spawn(TextBundle::from_section(
+                        format!("#{}  {}   {}   Wave {}   {}   seed {:016x}", rank + 1, entry.score, format_duration(entry.survived_secs), entry.wave_reached, format_date(entry.finished_at_unix_secs), entry.seed),
+                        row_style.clone(),
+                    ));
+                    let mut build = weapon_name.to_string();
+                    if !skill_names.is_empty() { build.push_str(&format!(" | Skills: {}", skill_names.join(", "))); }
+                    if !relic_names.is_empty() { build.push_str(&format!(" | Relics: {}", relic_names.join(", "))); }
+                    row.spawn(TextBundle::from_section(build, build_style.clone()));
+                });
+            }
+        } else {
+            parent.spawn(TextBundle::from_section("No runs recorded yet. Go and die gloriously.", header_style.clone()));
+        }
+
+        parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Row, column_gap: Val::Px(12.0), margin: UiRect::top(Val::Px(10.0)), ..default() }, ..default() })
+            .with_children(|buttons| {
+                let mut spawn_button = |label: &str, marker: Option<i32>| {
+                    let mut button = buttons.spawn(ButtonBundle {
+                        style: Style { width: Val::Px(180.0), height: Val::Px(45.0), justify_content: JustifyContent::Center, align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() },
+                        background_color: Color::rgba(0.2, 0.2, 0.3, 0.9).into(),
+                        border_color: BorderColor(Color::rgba(0.7, 0.7, 0.8, 0.7)),
+                        ..default()
+                    });
+                    match marker { Some(step) => { button.insert(LeaderboardPageButton(step)); } None => { button.insert(LeaderboardBackButton); } }
+                    button.with_children(|button_parent| { button_parent.spawn(TextBundle::from_section(label, button_style.clone())); });
+                };
+                if tables.len() > 1 { spawn_button("< Prev", Some(-1)); }
+                spawn_button("Back (Esc)", None);
+                if tables.len() > 1 { spawn_button("Next >", Some(1)); }
+            });
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn leaderboard_input_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    back_button_query: Query<&Interaction, (Changed<Interaction>, With<LeaderboardBackButton>)>,
+    page_button_query: Query<(&Interaction, &LeaderboardPageButton), Changed<Interaction>>,
+    leaderboards: Res<Leaderboards>,
+    mut page: ResMut<LeaderboardPage>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let back_pressed = back_button_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    if back_pressed || keyboard_input.just_pressed(KeyCode::Escape) { next_app_state.set(AppState::MainMenu); return; }
+
+    let mut step = page_button_query.iter().filter(|(interaction, _)| **interaction == Interaction::Pressed).map(|(_, button)| button.0).sum::<i32>();
+    if keyboard_input.just_pressed(KeyCode::Left) { step -= 1; }
+    if keyboard_input.just_pressed(KeyCode::Right) { step += 1; }
+    let table_count = leaderboards.tables.iter().filter(|table| !table.entries.is_empty()).count() as i32;
+    if step != 0 && table_count > 1 { page.0 = (page.0 as i32 + step).rem_euclid(table_count) as usize; }
+}
+
+// Rebuilds the screen when the page changes
+#[allow(clippy::too_many_arguments)]
+fn refresh_leaderboard_ui(
+    mut commands: Commands, asset_server: Res<AssetServer>, leaderboards: Res<Leaderboards>, page: Res<LeaderboardPage>,
+    character_library: Res<CharacterLibrary>, weapon_library: Res<AutomaticWeaponLibrary>, item_library: Res<ItemLibrary>, skill_library: Res<SkillLibrary>,
+    root_query: Query<Entity, With<LeaderboardUIRoot>>,
+) {
+    if !page.is_changed() { return; }
+    for entity in root_query.iter() { commands.entity(entity).despawn_recursive(); }
+    setup_leaderboard_ui(commands, asset_server, leaderboards, page, character_library, weapon_library, item_library, skill_library);
+}
+
+fn despawn_leaderboard_ui(mut commands: Commands, query: Query<Entity, With<LeaderboardUIRoot>>) { for entity in query.iter() { commands.entity(entity).despawn_recursive(); } }
@@ -22,6 +22,31 @@ pub mod in_game_debug_ui; // If tests verify UI elements
 pub mod glyphs;
 pub mod weapon_systems; // If tests need to interact with these systems/components directly
 pub mod player_input; // Added for player input systems
+pub mod persistence;
+pub mod settings; // Persisted player options (volume, damage numbers, screen shake, window mode)
+pub mod pause_menu;
+pub mod profile; // Persisted meta-progression: Lore, permanent boons, unlocks
+pub mod meta_shop;
+pub mod run_save; // Suspend-and-resume snapshot of a run in progress
+pub mod characters; // Playable characters: starting kit, stat overrides and unique passive
+pub mod achievements; // Cross-run achievements, their unlock rewards and in-game toasts
+pub mod achievement_gallery;
+pub mod run_stats; // Per-run kill/damage bookkeeping for the results screen and the local run history
+pub mod leaderboard; // Local high-score tables per character and game mode
+pub mod boss; // Timeline bosses with health-driven phases and guaranteed relic drops
+pub mod elites; // Elite horror affixes: registry, rolling, and their on-hit, aura and on-death effects
+pub mod horror_behavior; // Data-driven state machines for special horrors
+pub mod crowd; // Separation, mass-based pushing and flocking between horrors over a spatial grid
+pub mod knockback; // Knockback impulses from weapon hits, resisted by mass, and poise-break staggers
+pub mod bullet_patterns; // Data-driven horror projectile patterns: spreads, rings, spirals, lead shots, homing orbs and walls
+pub mod chests; // Reward chests dropped by elites, bosses and nests, opened on a reward screen
+pub mod nests; // Destructible spawner structures that keep emitting horrors and leave a chest
+pub mod death_effects; // Per-type on-death splits, fuse blasts, gaze pools and sanity puffs, plus relic-granted ones
+pub mod arena; // Seeded cyclopean ruins streamed in chunks around the survivor, with collision for walkers and projectiles
+pub mod flow_field; // Budgeted Dijkstra flow field toward the survivor, one layer for walkers and one for flyers
+pub mod props; // Seeded breakable urns, idols and candles that drop loot from data-driven tables
+pub mod consumables; // Healing Ichor, Soul Magnet, Purge sigil and Frenzy totem pickups with configurable drop rates
+pub mod merchant; // Obols, the run currency, and the wandering merchant who sells relics, skills, healing and upgrades
 
 // You might also need to re-export specific items if you want shorter paths,
 // but for now, just declaring the modules as public should be enough
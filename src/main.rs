@@ -27,6 +27,24 @@ use eldritch_hero::weapon_systems;
 use eldritch_hero::automatic_projectiles; // Ensure this use statement is present
 use eldritch_hero::player_input::PlayerInputPlugin; // Added for the new player input plugin
 use eldritch_hero::glyphs::GlyphsPlugin; // Added for Glyphs
+use eldritch_hero::settings::SettingsPlugin;
+use eldritch_hero::profile::ProfilePlugin;
+use eldritch_hero::characters::CharactersPlugin;
+use eldritch_hero::achievements::AchievementsPlugin;
+use eldritch_hero::run_stats::RunStatsPlugin;
+use eldritch_hero::boss::BossPlugin;
+use eldritch_hero::elites::ElitesPlugin;
+use eldritch_hero::crowd::CrowdPlugin;
+use eldritch_hero::knockback::KnockbackPlugin;
+use eldritch_hero::death_effects::DeathEffectsPlugin;
+use eldritch_hero::bullet_patterns::BulletPatternsPlugin;
+use eldritch_hero::nests::NestsPlugin;
+use eldritch_hero::chests::ChestsPlugin;
+use eldritch_hero::arena::ArenaPlugin;
+use eldritch_hero::flow_field::FlowFieldPlugin;
+use eldritch_hero::props::PropsPlugin;
+use eldritch_hero::consumables::ConsumablesPlugin;
+use eldritch_hero::merchant::MerchantPlugin;
 
 // Modules specific to main.rs (if any)
 // mod automatic_projectiles; // This line should be removed
@@ -73,6 +91,8 @@ fn main() {
         .register_type::<AutomaticWeaponLibrary>()
         .register_type::<components::PlayerRequestsOrbDeployment>() // Added registration
         .add_event::<crate::components::PlayerBlinkEvent>()
+        .add_plugins((SettingsPlugin, ProfilePlugin, CharactersPlugin, AchievementsPlugin, RunStatsPlugin, BossPlugin, ElitesPlugin, CrowdPlugin, KnockbackPlugin, DeathEffectsPlugin, BulletPatternsPlugin, NestsPlugin, ChestsPlugin, ArenaPlugin, FlowFieldPlugin)) // Outside the tuple below, which is at Bevy's 15-plugin limit
+        .add_plugins((PropsPlugin, ConsumablesPlugin, MerchantPlugin)) // This tuple is for what no longer fits in the one above
         .add_plugins((
             GamePlugin,
             SurvivorPlugin,
@@ -0,0 +1,340 @@
+// src/merchant.rs
+// Obols and the wandering merchant. Obols are a run's spending money: horrors now and then drop one, elites a handful,
+// and the survivor scoops them up on contact. Every few waves a merchant turns up near the survivor and waits a while;
+// walking into them opens their shop, which sells relics, skills, healing and weapon upgrades at prices that rise with
+// the wave. The merchant moves on once the survivor leaves the shop. Obols also pay for rerolls and banishes on the
+// level-up screen once the free rerolls are spent.
+use bevy::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+use crate::{
+    components::Health,
+    survivor::{Survivor, SURVIVOR_SIZE},
+    game::{AppState, GameState, UpgradeChosenEvent, ItemCollectedEvent, session_ending},
+    audio::{PlaySoundEvent, SoundEffect},
+    profile::PlayerProfile,
+    items::{ItemId, ItemLibrary},
+    skills::{ActiveSkillInstance, SkillId, SkillLibrary},
+    upgrades::{UpgradeCard, UpgradeContext, UpgradePool, MAX_EQUIPPED_SKILLS},
+    run_stats::SurvivorHealedEvent,
+};
+
+pub const OBOL_SIZE: Vec2 = Vec2::new(14.0, 14.0);
+const OBOL_Z_POS: f32 = 0.45;
+const OBOL_COLLECTION_RADIUS: f32 = SURVIVOR_SIZE.x / 2.0 + OBOL_SIZE.x / 2.0 + 10.0;
+const OBOL_DROP_CHANCE: f64 = 0.05;
+const ELITE_OBOLS: u32 = 5;
+const ELITE_AFFIX_OBOLS: u32 = 2; // Per affix
+pub const MERCHANT_WAVE_INTERVAL: u32 = 3;
+pub const MERCHANT_SIZE: Vec2 = Vec2::new(44.0, 56.0);
+const MERCHANT_Z_POS: f32 = 0.35;
+const MERCHANT_SPAWN_DISTANCE: f32 = 300.0;
+const MERCHANT_STAY_SECS: f32 = 60.0;
+pub const PRICE_WAVE_SCALING: f32 = 0.15; // Prices rise this much per wave
+pub const RELIC_PRICE: u32 = 40;
+pub const SKILL_PRICE: u32 = 50;
+pub const HEALING_PRICE: u32 = 15;
+pub const WEAPON_UPGRADE_PRICE: u32 = 25;
+pub const REROLL_PRICE: u32 = 5;
+pub const BANISH_PRICE: u32 = 10;
+const HEALING_FRACTION: f32 = 0.5; // Of max health
+pub const STOCK_RELICS: usize = 2;
+pub const STOCK_WEAPON_UPGRADES: usize = 2;
+
+pub fn wave_price(base_price: u32, wave_number: u32) -> u32 {
+    (base_price as f32 * (1.0 + wave_number.saturating_sub(1) as f32 * PRICE_WAVE_SCALING)).round() as u32
+}
+
+// How many obols a kill drops; elites always drop some, more for each affix
+pub fn roll_obol_drop(is_elite: bool, affix_count: usize, rng: &mut impl Rng) -> u32 {
+    if is_elite { ELITE_OBOLS + affix_count as u32 * ELITE_AFFIX_OBOLS }
+    else if rng.gen_bool(OBOL_DROP_CHANCE) { 1 }
+    else { 0 }
+}
+
+pub fn merchant_due(wave_number: u32, last_visit_wave: u32) -> bool {
+    wave_number >= MERCHANT_WAVE_INTERVAL && wave_number % MERCHANT_WAVE_INTERVAL == 0 && wave_number != last_visit_wave
+}
+
+// Takes `price` from `obols` if there are enough
+pub fn try_spend(obols: &mut u32, price: u32) -> bool {
+    if *obols < price { return false; }
+    *obols -= price;
+    true
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MerchantWare {
+    Relic(ItemId),
+    Skill(SkillId),
+    Healing,
+    WeaponUpgrade(UpgradeCard),
+}
+
+impl MerchantWare {
+    pub fn base_price(&self) -> u32 {
+        match self {
+            MerchantWare::Relic(_) => RELIC_PRICE,
+            MerchantWare::Skill(_) => SKILL_PRICE,
+            MerchantWare::Healing => HEALING_PRICE,
+            MerchantWare::WeaponUpgrade(_) => WEAPON_UPGRADE_PRICE,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MerchantOffer { pub ware: MerchantWare, pub price: u32, pub sold: bool }
+
+// A merchant's stock: the first relics on offer, one skill the survivor doesn't have, healing and a couple of upgrades
+// for the equipped weapon, priced for the current wave
+pub fn stock_merchant(relics: &[ItemId], skills: &[SkillId], weapon_upgrades: &[UpgradeCard], wave_number: u32, rng: &mut impl Rng) -> Vec<MerchantOffer> {
+    relics.iter().take(STOCK_RELICS).map(|item_id| MerchantWare::Relic(*item_id))
+        .chain(skills.choose(rng).map(|skill_id| MerchantWare::Skill(*skill_id)))
+        .chain([MerchantWare::Healing])
+        .chain(weapon_upgrades.choose_multiple(rng, STOCK_WEAPON_UPGRADES).cloned().map(MerchantWare::WeaponUpgrade))
+        .map(|ware| MerchantOffer { price: wave_price(ware.base_price(), wave_number), ware, sold: false })
+        .collect()
+}
+
+#[derive(Component, Debug)]
+pub struct Obol { pub value: u32 }
+
+#[derive(Component, Debug)]
+pub struct Merchant { pub stock: Vec<MerchantOffer>, departure: Timer }
+
+// The wave the merchant last came on
+#[derive(Resource, Debug, Default)]
+pub struct MerchantSchedule { pub last_visit_wave: u32 }
+
+// The shop the survivor is browsing
+#[derive(Resource, Debug)]
+pub struct MerchantShop { pub offers: Vec<MerchantOffer> }
+
+#[derive(Component)] struct MerchantUI;
+#[derive(Component)] struct MerchantOfferButton(usize);
+#[derive(Component)] struct LeaveShopButton;
+
+pub struct MerchantPlugin;
+
+impl Plugin for MerchantPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MerchantSchedule>()
+            .add_systems(Update, (
+                obol_collection_system,
+                merchant_arrival_system,
+                merchant_departure_system,
+                merchant_contact_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(Update, (merchant_shop_input_system, rebuild_merchant_ui).chain().run_if(in_state(AppState::Merchant)))
+            .add_systems(OnExit(AppState::Merchant), despawn_merchant_ui)
+            .add_systems(OnExit(AppState::InGame), despawn_merchant_entities.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_merchant_entities.run_if(session_ending));
+    }
+}
+
+pub fn spawn_obols(commands: &mut Commands, asset_server: &Res<AssetServer>, position: Vec2, value: u32) {
+    if value == 0 { return; }
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/obol_placeholder.png"),
+            sprite: Sprite { custom_size: Some(OBOL_SIZE), ..default() },
+            transform: Transform::from_translation(position.extend(OBOL_Z_POS)),
+            ..default()
+        },
+        Obol { value },
+        Name::new("Obol"),
+    ));
+}
+
+fn obol_collection_system(
+    mut commands: Commands,
+    obol_query: Query<(Entity, &Obol, &Transform)>,
+    mut survivor_query: Query<(&Transform, &mut Survivor)>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Ok((survivor_transform, mut survivor)) = survivor_query.get_single_mut() else { return; };
+    let survivor_pos = survivor_transform.translation.truncate();
+    for (entity, obol, transform) in obol_query.iter() {
+        if transform.translation.truncate().distance(survivor_pos) > OBOL_COLLECTION_RADIUS { continue; }
+        survivor.obols += obol.value;
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::SoulCollect));
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn merchant_arrival_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    mut schedule: ResMut<MerchantSchedule>,
+    item_library: Res<ItemLibrary>,
+    skill_library: Res<SkillLibrary>,
+    upgrade_pool: Res<UpgradePool>,
+    profile: Res<PlayerProfile>,
+    survivor_query: Query<(&Transform, &Survivor)>,
+    merchant_query: Query<(), With<Merchant>>,
+) {
+    if !merchant_due(game_state.wave_number, schedule.last_visit_wave) { return; }
+    let Ok((survivor_transform, survivor)) = survivor_query.get_single() else { return; };
+    schedule.last_visit_wave = game_state.wave_number;
+    if !merchant_query.is_empty() { return; } // The last one is still waiting
+    let mut rng = rand::thread_rng();
+    let relics: Vec<ItemId> = item_library.pick_relic_rewards(|item_def| profile.is_item_unlocked(item_def), &survivor.collected_item_ids, STOCK_RELICS, &mut rng)
+        .into_iter().map(|item_def| item_def.id).collect();
+    let skills: Vec<SkillId> = if survivor.equipped_skills.len() < MAX_EQUIPPED_SKILLS {
+        skill_library.skills.iter().map(|def| def.id).filter(|id| !survivor.equipped_skills.iter().any(|skill| skill.definition_id == *id)).collect()
+    } else { Vec::new() };
+    let weapon_upgrades: Vec<UpgradeCard> = upgrade_pool.eligible_upgrades(&UpgradeContext::for_survivor(survivor)).into_iter()
+        .filter(|card| card.upgrade_type.weapon_id().is_some())
+        .collect();
+    let position = survivor_transform.translation.truncate() + Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * MERCHANT_SPAWN_DISTANCE;
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/merchant_placeholder.png"),
+            sprite: Sprite { custom_size: Some(MERCHANT_SIZE), ..default() },
+            transform: Transform::from_translation(position.extend(MERCHANT_Z_POS)),
+            ..default()
+        },
+        Merchant { stock: stock_merchant(&relics, &skills, &weapon_upgrades, game_state.wave_number, &mut rng), departure: Timer::from_seconds(MERCHANT_STAY_SECS, TimerMode::Once) },
+        Name::new("Merchant"),
+    ));
+    info!("A merchant has arrived (wave {})", game_state.wave_number);
+}
+
+fn merchant_departure_system(mut commands: Commands, time: Res<Time>, mut merchant_query: Query<(Entity, &mut Merchant)>) {
+    for (entity, mut merchant) in merchant_query.iter_mut() {
+        if merchant.departure.tick(time.delta()).finished() { commands.entity(entity).despawn_recursive(); }
+    }
+}
+
+fn merchant_contact_system(
+    mut commands: Commands,
+    merchant_query: Query<(Entity, &Merchant, &Transform)>,
+    survivor_query: Query<&Transform, With<Survivor>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let Ok(survivor_transform) = survivor_query.get_single() else { return; };
+    for (entity, merchant, transform) in merchant_query.iter() {
+        if survivor_transform.translation.truncate().distance(transform.translation.truncate()) > (SURVIVOR_SIZE.x + MERCHANT_SIZE.x) / 2.0 { continue; }
+        commands.insert_resource(MerchantShop { offers: merchant.stock.clone() });
+        commands.entity(entity).despawn_recursive(); // Moves on once the survivor is done browsing
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+        next_app_state.set(AppState::Merchant);
+        return;
+    }
+}
+
+fn ware_label(ware: &MerchantWare, item_library: &ItemLibrary, skill_library: &SkillLibrary) -> String {
+    match ware {
+        MerchantWare::Relic(item_id) => format!("Relic: {}", item_library.get_item_definition(*item_id).map_or("Unknown", |def| def.name.as_str())),
+        MerchantWare::Skill(skill_id) => format!("Skill: {}", skill_library.get_skill_definition(*skill_id).map_or("Unknown", |def| def.name.as_str())),
+        MerchantWare::Healing => format!("Healing draught ({}% Endurance)", (HEALING_FRACTION * 100.0) as u32),
+        MerchantWare::WeaponUpgrade(card) => format!("Weapon: {}", card.name),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn merchant_shop_input_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    offer_interaction_query: Query<(&Interaction, &MerchantOfferButton), Changed<Interaction>>,
+    leave_interaction_query: Query<&Interaction, (Changed<Interaction>, With<LeaveShopButton>)>,
+    shop: Option<ResMut<MerchantShop>>,
+    mut survivor_query: Query<(&mut Survivor, &mut Health)>,
+    mut upgrade_chosen_writer: EventWriter<UpgradeChosenEvent>,
+    mut item_collected_writer: EventWriter<ItemCollectedEvent>,
+    mut healed_writer: EventWriter<SurvivorHealedEvent>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let leave = keyboard_input.just_pressed(KeyCode::Escape) || leave_interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed);
+    let Some(mut shop) = shop else { next_app_state.set(AppState::InGame); return; };
+    if leave { next_app_state.set(AppState::InGame); return; }
+    const OFFER_KEYS: [KeyCode; 6] = [KeyCode::Key1, KeyCode::Key2, KeyCode::Key3, KeyCode::Key4, KeyCode::Key5, KeyCode::Key6];
+    let chosen = OFFER_KEYS.iter().position(|key| keyboard_input.just_pressed(*key))
+        .or_else(|| offer_interaction_query.iter().find(|(interaction, _)| **interaction == Interaction::Pressed).map(|(_, button)| button.0));
+    let Some(index) = chosen else { return; };
+    let Ok((mut survivor, mut health)) = survivor_query.get_single_mut() else { return; };
+    let Some(offer) = shop.offers.get(index).filter(|offer| !offer.sold) else { return; };
+    let skill_slot_free = survivor.equipped_skills.len() < MAX_EQUIPPED_SKILLS;
+    if matches!(offer.ware, MerchantWare::Skill(_)) && !skill_slot_free { return; }
+    if !try_spend(&mut survivor.obols, offer.price) { return; }
+    match &offer.ware {
+        MerchantWare::Relic(item_id) => { item_collected_writer.send(ItemCollectedEvent(*item_id)); }
+        MerchantWare::Skill(skill_id) => { survivor.equipped_skills.push(ActiveSkillInstance::new(*skill_id)); }
+        MerchantWare::Healing => { healed_writer.send(SurvivorHealedEvent(survivor.heal(&mut health, (survivor.max_health as f32 * HEALING_FRACTION).round() as i32))); }
+        MerchantWare::WeaponUpgrade(card) => { upgrade_chosen_writer.send(UpgradeChosenEvent(card.clone())); }
+    }
+    shop.offers[index].sold = true;
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::SoulCollect));
+}
+
+// Rebuilt from scratch whenever a purchase changes the shop
+fn rebuild_merchant_ui(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    shop: Option<Res<MerchantShop>>,
+    item_library: Res<ItemLibrary>,
+    skill_library: Res<SkillLibrary>,
+    survivor_query: Query<&Survivor>,
+    ui_query: Query<Entity, With<MerchantUI>>,
+) {
+    let Some(shop) = shop else { return; };
+    if !shop.is_changed() && !ui_query.is_empty() { return; }
+    for entity in ui_query.iter() { commands.entity(entity).despawn_recursive(); }
+    let obols = survivor_query.get_single().map_or(0, |survivor| survivor.obols);
+    let font: Handle<Font> = asset_server.load("fonts/FiraSans-Bold.ttf");
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0), height: Val::Percent(100.0), position_type: PositionType::Absolute,
+                justify_content: JustifyContent::Center, align_items: AlignItems::Center, flex_direction: FlexDirection::Column, row_gap: Val::Px(10.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.08, 0.05, 0.1, 0.9).into(),
+            z_index: ZIndex::Global(10),
+            ..default()
+        },
+        MerchantUI,
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section("The Merchant", TextStyle { font: font.clone(), font_size: 44.0, color: Color::GOLD }));
+        parent.spawn(TextBundle::from_section(format!("Obols: {}", obols), TextStyle { font: font.clone(), font_size: 24.0, color: Color::rgb(0.85, 0.75, 0.4) }));
+        for (index, offer) in shop.offers.iter().enumerate() {
+            let affordable = !offer.sold && obols >= offer.price;
+            let label = if offer.sold { format!("[{}] {} - sold", index + 1, ware_label(&offer.ware, &item_library, &skill_library)) }
+                else { format!("[{}] {} - {} obols", index + 1, ware_label(&offer.ware, &item_library, &skill_library), offer.price) };
+            parent.spawn((
+                ButtonBundle {
+                    style: Style { width: Val::Px(520.0), height: Val::Px(44.0), padding: UiRect::horizontal(Val::Px(10.0)), align_items: AlignItems::Center, border: UiRect::all(Val::Px(2.0)), ..default() },
+                    border_color: BorderColor(if affordable { Color::GOLD } else { Color::DARK_GRAY }),
+                    background_color: Color::rgb(0.2, 0.18, 0.25).into(),
+                    ..default()
+                },
+                MerchantOfferButton(index),
+            )).with_children(|button| {
+                button.spawn(TextBundle::from_section(label, TextStyle { font: font.clone(), font_size: 20.0, color: if affordable { Color::WHITE } else { Color::GRAY } }));
+            });
+        }
+        parent.spawn((
+            ButtonBundle {
+                style: Style { width: Val::Px(200.0), height: Val::Px(44.0), justify_content: JustifyContent::Center, align_items: AlignItems::Center, margin: UiRect::top(Val::Px(10.0)), ..default() },
+                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            },
+            LeaveShopButton,
+        )).with_children(|button| {
+            button.spawn(TextBundle::from_section("Leave [Esc]", TextStyle { font: font.clone(), font_size: 20.0, color: Color::WHITE }));
+        });
+    });
+}
+
+fn despawn_merchant_ui(mut commands: Commands, query: Query<Entity, With<MerchantUI>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+    commands.remove_resource::<MerchantShop>();
+}
+
+#[allow(clippy::type_complexity)]
+fn despawn_merchant_entities(mut commands: Commands, query: Query<Entity, Or<(With<Merchant>, With<Obol>)>>, mut schedule: ResMut<MerchantSchedule>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+    *schedule = MerchantSchedule::default();
+}
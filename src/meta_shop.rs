@@ -0,0 +1,190 @@
+// src/meta_shop.rs
+use bevy::prelude::*;
+use crate::{
+    game::AppState,
+    audio::{PlaySoundEvent, SoundEffect},
+    items::{AutomaticWeaponId, AutomaticWeaponLibrary, ItemId, ItemLibrary},
+    profile::{PlayerProfile, MetaUpgradeKind, WEAPON_UNLOCK_COST, RELIC_UNLOCK_COST},
+};
+
+const SHOP_BUTTON_BG_COLOR: Color = Color::rgba(0.2, 0.2, 0.3, 0.9);
+const SHOP_BUTTON_HOVER_BG_COLOR: Color = Color::rgba(0.3, 0.3, 0.45, 0.95);
+const SHOP_BUTTON_DISABLED_BG_COLOR: Color = Color::rgba(0.12, 0.12, 0.15, 0.9);
+const SHOP_BUTTON_BORDER_COLOR: Color = Color::rgba(0.7, 0.7, 0.8, 0.7);
+const SHOP_TEXT_COLOR: Color = Color::rgb(0.95, 0.95, 0.95);
+const SHOP_SECTION_COLOR: Color = Color::GOLD;
+
+pub struct MetaShopPlugin;
+
+impl Plugin for MetaShopPlugin {
+    fn build(&self, app: &mut App) {
+        app .add_systems(OnEnter(AppState::MetaShop), setup_meta_shop_ui)
+            .add_systems(Update, (
+                meta_shop_button_interaction_system,
+                meta_shop_keyboard_system,
+                rebuild_meta_shop_on_profile_change,
+            ).chain().run_if(in_state(AppState::MetaShop)))
+            .add_systems(OnExit(AppState::MetaShop), despawn_meta_shop_ui);
+    }
+}
+
+#[derive(Component)] struct MetaShopUIRoot;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum MetaShopButton {
+    Upgrade(MetaUpgradeKind),
+    UnlockWeapon(AutomaticWeaponId),
+    UnlockRelic(ItemId),
+    Back,
+}
+
+#[derive(Component)] struct Affordable(bool);
+
+fn setup_meta_shop_ui(
+    mut commands: Commands, asset_server: Res<AssetServer>, profile: Res<PlayerProfile>,
+    weapon_library: Res<AutomaticWeaponLibrary>, item_library: Res<ItemLibrary>,
+) {
+    spawn_meta_shop_ui(&mut commands, &asset_server, &profile, &weapon_library, &item_library);
+}
+
+fn spawn_meta_shop_ui(
+    commands: &mut Commands, asset_server: &Res<AssetServer>, profile: &PlayerProfile,
+    weapon_library: &AutomaticWeaponLibrary, item_library: &ItemLibrary,
+) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let title_style = TextStyle { font: font.clone(), font_size: 50.0, color: Color::WHITE };
+    let section_style = TextStyle { font: font.clone(), font_size: 26.0, color: SHOP_SECTION_COLOR };
+    let button_text_style = TextStyle { font, font_size: 16.0, color: SHOP_TEXT_COLOR };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                padding: UiRect::all(Val::Px(20.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.05, 0.05, 0.1, 0.95).into(),
+            ..default()
+        },
+        MetaShopUIRoot,
+        Name::new("MetaShopUIRoot"),
+    )).with_children(|parent| {
+        parent.spawn(TextBundle::from_section("Forbidden Archive", title_style));
+        parent.spawn(TextBundle::from_section(format!("Lore: {}", profile.lore), section_style.clone()));
+
+        parent.spawn(TextBundle::from_section("Permanent Boons", section_style.clone()));
+        parent.spawn(shop_grid_bundle()).with_children(|grid| {
+            for kind in MetaUpgradeKind::ALL {
+                let level = profile.meta_upgrades.get(kind);
+                let cost = profile.upgrade_cost(kind);
+                let label = match cost {
+                    Some(cost) => format!("{} ({}/{})\n{}\nCost: {}", kind.name(), level, kind.max_level(), kind.description(), cost),
+                    None => format!("{} ({}/{})\n{}\nMastered", kind.name(), level, kind.max_level(), kind.description()),
+                };
+                let affordable = cost.is_some_and(|cost| profile.lore >= cost);
+                spawn_shop_button(grid, MetaShopButton::Upgrade(kind), label, affordable, Val::Px(230.0), &button_text_style);
+            }
+        });
+
+        parent.spawn(TextBundle::from_section(format!("Weapons ({} Lore each)", WEAPON_UNLOCK_COST), section_style.clone()));
+        parent.spawn(shop_grid_bundle()).with_children(|grid| {
+            for weapon_def in weapon_library.weapons.iter().filter(|weapon_def| !profile.is_weapon_unlocked(weapon_def)) {
+                spawn_shop_button(grid, MetaShopButton::UnlockWeapon(weapon_def.id), weapon_def.name.clone(), profile.lore >= WEAPON_UNLOCK_COST, Val::Px(160.0), &button_text_style);
+            }
+        });
+
+        parent.spawn(TextBundle::from_section(format!("Relics ({} Lore each)", RELIC_UNLOCK_COST), section_style));
+        parent.spawn(shop_grid_bundle()).with_children(|grid| {
+            for item_def in item_library.items.iter().filter(|item_def| !profile.is_item_unlocked(item_def)) {
+                spawn_shop_button(grid, MetaShopButton::UnlockRelic(item_def.id), item_def.name.clone(), profile.lore >= RELIC_UNLOCK_COST, Val::Px(160.0), &button_text_style);
+            }
+        });
+
+        spawn_shop_button(parent, MetaShopButton::Back, "Back (Esc)".to_string(), true, Val::Px(200.0), &button_text_style);
+    });
+}
+
+fn shop_grid_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            width: Val::Percent(90.0),
+            max_width: Val::Px(1100.0),
+            row_gap: Val::Px(8.0),
+            column_gap: Val::Px(8.0),
+            ..default()
+        },
+        ..default()
+    }
+}
+
+fn spawn_shop_button(parent: &mut ChildBuilder, button: MetaShopButton, label: String, affordable: bool, width: Val, text_style: &TextStyle) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width,
+                min_height: Val::Px(36.0),
+                padding: UiRect::all(Val::Px(6.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            background_color: if affordable { SHOP_BUTTON_BG_COLOR.into() } else { SHOP_BUTTON_DISABLED_BG_COLOR.into() },
+            border_color: BorderColor(SHOP_BUTTON_BORDER_COLOR),
+            ..default()
+        },
+        button,
+        Affordable(affordable),
+    )).with_children(|button_parent| {
+        button_parent.spawn(TextBundle::from_section(label, text_style.clone()).with_text_alignment(TextAlignment::Center));
+    });
+}
+
+#[allow(clippy::type_complexity)]
+fn meta_shop_button_interaction_system(
+    mut interaction_query: Query<(&Interaction, &MetaShopButton, &Affordable, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut profile: ResMut<PlayerProfile>,
+    weapon_library: Res<AutomaticWeaponLibrary>,
+    item_library: Res<ItemLibrary>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for (interaction, button, affordable, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                let purchased = match *button {
+                    MetaShopButton::Upgrade(kind) => profile.try_purchase_upgrade(kind),
+                    MetaShopButton::UnlockWeapon(weapon_id) => weapon_library.get_weapon_definition(weapon_id).is_some_and(|weapon_def| profile.try_unlock_weapon(weapon_def)),
+                    MetaShopButton::UnlockRelic(item_id) => item_library.get_item_definition(item_id).is_some_and(|item_def| profile.try_unlock_item(item_def)),
+                    MetaShopButton::Back => { next_app_state.set(AppState::MainMenu); true }
+                };
+                if purchased { sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted)); }
+            }
+            Interaction::Hovered if affordable.0 => { *bg_color = SHOP_BUTTON_HOVER_BG_COLOR.into(); }
+            _ => { *bg_color = if affordable.0 { SHOP_BUTTON_BG_COLOR.into() } else { SHOP_BUTTON_DISABLED_BG_COLOR.into() }; }
+        }
+    }
+}
+
+fn meta_shop_keyboard_system(keyboard_input: Res<Input<KeyCode>>, mut next_app_state: ResMut<NextState<AppState>>) {
+    if keyboard_input.just_pressed(KeyCode::Escape) { next_app_state.set(AppState::MainMenu); }
+}
+
+fn rebuild_meta_shop_on_profile_change(
+    mut commands: Commands, asset_server: Res<AssetServer>, profile: Res<PlayerProfile>,
+    weapon_library: Res<AutomaticWeaponLibrary>, item_library: Res<ItemLibrary>,
+    root_query: Query<Entity, With<MetaShopUIRoot>>,
+) {
+    if !profile.is_changed() || profile.is_added() { return; }
+    for entity in root_query.iter() { commands.entity(entity).despawn_recursive(); }
+    spawn_meta_shop_ui(&mut commands, &asset_server, &profile, &weapon_library, &item_library);
+}
+
+fn despawn_meta_shop_ui(mut commands: Commands, query: Query<Entity, With<MetaShopUIRoot>>) { for entity in query.iter() { commands.entity(entity).despawn_recursive(); } }
@@ -0,0 +1,224 @@
+// src/nests.rs
+// Nests are spawner structures: Flesh Nests and Void Rifts appear on the map every so often and keep emitting their
+// brood until destroyed. A nest is an ordinary `Horror` of a stationary type with a `Nest` component on top, so every
+// weapon already hits it and its kill is tallied. While a nest is offscreen an arrow on the screen's edge points at
+// it, and destroying one leaves a reward chest. Circling in one spot lets nests pile up; hunting them down pays.
+use bevy::prelude::*;
+use rand::{Rng, seq::SliceRandom};
+use std::time::Duration;
+use crate::{
+    components::Health,
+    survivor::Survivor,
+    game::{AppState, GameState, session_ending, SCREEN_WIDTH, SCREEN_HEIGHT},
+    camera_systems::MainCamera,
+    audio::{PlaySoundEvent, SoundEffect},
+    horror::{Horror, HorrorType, MaxHorrors, spawn_horror_type, spawn_and_return_horror_entity, handle_horror_death_drops},
+    chests::spawn_reward_chest,
+    props::Prop,
+};
+
+const NEST_FIRST_SECS: f32 = 60.0;
+const NEST_INTERVAL_SECS: f32 = 45.0;
+pub const MAX_ACTIVE_NESTS: usize = 3;
+const NEST_SPAWN_DISTANCE_MIN: f32 = 0.8; // Of the screen width, so a new nest is found rather than seen arriving
+const NEST_SPAWN_DISTANCE_MAX: f32 = 1.3;
+const BROOD_SPAWN_RADIUS: f32 = 60.0;
+const INDICATOR_SIZE: Vec2 = Vec2::new(28.0, 28.0);
+const INDICATOR_MARGIN: f32 = 24.0;
+const INDICATOR_Z_POS: f32 = 5.0;
+
+pub struct NestDefinition {
+    pub horror_type: HorrorType,
+    pub emits: HorrorType,
+    pub emit_interval_secs: f32,
+    pub emit_count: u32,
+    pub max_brood: u32, // Living horrors from this nest at once
+    pub min_wave: u32,
+    pub chest_quality: f32,
+    pub indicator_color: Color,
+}
+
+pub static NEST_DEFINITIONS: [NestDefinition; 2] = [
+    NestDefinition {
+        horror_type: HorrorType::FleshNest, emits: HorrorType::CrawlingTorment, emit_interval_secs: 4.0, emit_count: 3, max_brood: 12,
+        min_wave: 1, chest_quality: 1.0, indicator_color: Color::rgb(0.9, 0.3, 0.3),
+    },
+    NestDefinition {
+        horror_type: HorrorType::VoidRift, emits: HorrorType::VoidBlinker, emit_interval_secs: 6.0, emit_count: 1, max_brood: 5,
+        min_wave: 4, chest_quality: 2.0, indicator_color: Color::rgb(0.6, 0.3, 1.0),
+    },
+];
+
+pub fn get_nest_definition(horror_type: HorrorType) -> Option<&'static NestDefinition> {
+    NEST_DEFINITIONS.iter().find(|def| def.horror_type == horror_type)
+}
+
+// When the next nest is due; resets with each run
+#[derive(Resource)]
+pub struct NestSchedule { timer: Timer }
+
+impl Default for NestSchedule { fn default() -> Self { Self { timer: Timer::from_seconds(NEST_FIRST_SECS, TimerMode::Once) } } }
+
+impl NestSchedule {
+    // True when a nest should appear now. A nest that's due while the map is full is skipped, not queued.
+    pub fn tick(&mut self, delta: Duration, active_nests: usize) -> bool {
+        if !self.timer.tick(delta).finished() { return false; }
+        self.timer = Timer::from_seconds(NEST_INTERVAL_SECS, TimerMode::Once);
+        active_nests < MAX_ACTIVE_NESTS
+    }
+}
+
+#[derive(Component)]
+pub struct Nest {
+    emit_timer: Timer,
+    brood: Vec<Entity>,
+    rewarded: bool,
+}
+
+// Points at its nest from the screen's edge; despawns itself once the nest is gone
+#[derive(Component)] struct NestIndicator(Entity);
+
+// Where on the screen's edge an arrow should sit to point at `target`, or None if it's already on screen
+pub fn edge_indicator_position(view_centre: Vec2, half_extents: Vec2, target: Vec2, margin: f32) -> Option<Vec2> {
+    let offset = target - view_centre;
+    if offset.x.abs() <= half_extents.x && offset.y.abs() <= half_extents.y { return None; }
+    let inner = half_extents - Vec2::splat(margin);
+    let scale = (inner.x / offset.x.abs()).min(inner.y / offset.y.abs());
+    Some(view_centre + offset * scale)
+}
+
+pub struct NestsPlugin;
+
+impl Plugin for NestsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NestSchedule>()
+            .add_systems(OnEnter(AppState::MainMenu), reset_nest_schedule)
+            .add_systems(Update, (
+                nest_schedule_system,
+                nest_emission_system,
+                nest_destroyed_system.before(handle_horror_death_drops), // Must see the nest before its entity is despawned
+                nest_indicator_system,
+            ).chain().run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), despawn_nest_indicators.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_nest_indicators.run_if(session_ending));
+    }
+}
+
+fn reset_nest_schedule(mut nest_schedule: ResMut<NestSchedule>) { *nest_schedule = NestSchedule::default(); }
+
+pub fn spawn_nest(commands: &mut Commands, asset_server: &Res<AssetServer>, horror_type: HorrorType, position: Vec3, wave_multiplier: f32) -> Option<Entity> {
+    let def = get_nest_definition(horror_type)?;
+    let nest_entity = spawn_horror_type(commands, asset_server, horror_type, position, wave_multiplier, &[])?;
+    commands.entity(nest_entity).insert(Nest {
+        emit_timer: Timer::from_seconds(def.emit_interval_secs, TimerMode::Repeating),
+        brood: Vec::new(),
+        rewarded: false,
+    });
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("sprites/offscreen_arrow_placeholder.png"),
+            sprite: Sprite { custom_size: Some(INDICATOR_SIZE), color: def.indicator_color, ..default() },
+            visibility: Visibility::Hidden,
+            transform: Transform::from_translation(position.truncate().extend(INDICATOR_Z_POS)),
+            ..default()
+        },
+        NestIndicator(nest_entity),
+        Name::new("NestIndicator"),
+    ));
+    Some(nest_entity)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn nest_schedule_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    mut nest_schedule: ResMut<NestSchedule>,
+    nest_query: Query<(), With<Nest>>,
+    player_query: Query<&Transform, With<Survivor>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    if !nest_schedule.tick(time.delta(), nest_query.iter().count()) { return; }
+    let Ok(player_transform) = player_query.get_single() else { return; };
+    let mut rng = rand::thread_rng();
+    let eligible: Vec<&NestDefinition> = NEST_DEFINITIONS.iter().filter(|def| game_state.wave_number >= def.min_wave).collect();
+    let Some(def) = eligible.choose(&mut rng) else { return; };
+    let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(SCREEN_WIDTH * NEST_SPAWN_DISTANCE_MIN..SCREEN_WIDTH * NEST_SPAWN_DISTANCE_MAX);
+    let wave_multiplier = 1.0 + (game_state.wave_number as f32 - 1.0) * 0.1;
+    if spawn_nest(&mut commands, &asset_server, def.horror_type, (player_transform.translation.truncate() + offset).extend(0.5), wave_multiplier).is_some() {
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation));
+        info!("{} appeared", def.horror_type.display_name());
+    }
+}
+
+fn nest_emission_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    asset_server: Res<AssetServer>,
+    game_state: Res<GameState>,
+    max_horrors: Res<MaxHorrors>,
+    mut nest_query: Query<(&mut Nest, &Horror, &Health, &Transform)>,
+    horror_query: Query<(), (With<Horror>, Without<Prop>)>,
+) {
+    let mut horror_count = horror_query.iter().count();
+    let wave_multiplier = 1.0 + (game_state.wave_number as f32 - 1.0) * 0.1;
+    let mut rng = rand::thread_rng();
+    for (mut nest, horror, health, transform) in nest_query.iter_mut() {
+        if health.0 <= 0 { continue; }
+        let Some(def) = get_nest_definition(horror.horror_type) else { continue; };
+        nest.brood.retain(|&minion| commands.get_entity(minion).is_some());
+        if !nest.emit_timer.tick(time.delta()).just_finished() { continue; }
+        for _ in 0..def.emit_count {
+            if nest.brood.len() >= def.max_brood as usize || horror_count >= max_horrors.0 as usize { break; }
+            let offset = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * BROOD_SPAWN_RADIUS;
+            nest.brood.push(spawn_and_return_horror_entity(&mut commands, &asset_server, def.emits, (transform.translation.truncate() + offset).extend(0.5), wave_multiplier));
+            horror_count += 1;
+        }
+    }
+}
+
+fn nest_destroyed_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut nest_query: Query<(&mut Nest, &Horror, &Health, &Transform)>,
+) {
+    for (mut nest, horror, health, transform) in nest_query.iter_mut() {
+        if health.0 > 0 || nest.rewarded { continue; }
+        nest.rewarded = true;
+        let quality = get_nest_definition(horror.horror_type).map_or(1.0, |def| def.chest_quality);
+        spawn_reward_chest(&mut commands, &asset_server, transform.translation.truncate(), quality);
+        info!("{} destroyed", horror.horror_type.display_name());
+    }
+}
+
+fn nest_indicator_system(
+    mut commands: Commands,
+    camera_query: Query<&Transform, (With<MainCamera>, Without<NestIndicator>)>,
+    nest_query: Query<&Transform, (With<Nest>, Without<NestIndicator>)>,
+    mut indicator_query: Query<(Entity, &NestIndicator, &mut Transform, &mut Visibility)>,
+) {
+    let Ok(camera_transform) = camera_query.get_single() else { return; };
+    let view_centre = camera_transform.translation.truncate();
+    let half_extents = Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT) / 2.0;
+    for (indicator_entity, indicator, mut transform, mut visibility) in indicator_query.iter_mut() {
+        let Ok(nest_transform) = nest_query.get(indicator.0) else {
+            commands.entity(indicator_entity).despawn_recursive();
+            continue;
+        };
+        let nest_pos = nest_transform.translation.truncate();
+        match edge_indicator_position(view_centre, half_extents, nest_pos, INDICATOR_MARGIN) {
+            Some(edge_pos) => {
+                let direction = nest_pos - edge_pos;
+                transform.translation = edge_pos.extend(INDICATOR_Z_POS);
+                transform.rotation = Quat::from_rotation_z(direction.y.atan2(direction.x));
+                *visibility = Visibility::Visible;
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
+
+fn despawn_nest_indicators(mut commands: Commands, query: Query<Entity, With<NestIndicator>>) {
+    for entity in query.iter() { commands.entity(entity).despawn_recursive(); }
+}
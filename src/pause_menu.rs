@@ -0,0 +1,271 @@
+// src/pause_menu.rs
+use bevy::prelude::*;
+use bevy::app::AppExit;
+use crate::{
+    game::AppState,
+    audio::{PlaySoundEvent, SoundEffect},
+    settings::{GameSettings, VOLUME_STEP, SCREEN_SHAKE_STEP},
+};
+
+const PAUSE_BUTTON_BG_COLOR: Color = Color::rgba(0.2, 0.2, 0.3, 0.9);
+const PAUSE_BUTTON_HOVER_BG_COLOR: Color = Color::rgba(0.3, 0.3, 0.45, 0.95);
+const PAUSE_BUTTON_PRESSED_BG_COLOR: Color = Color::rgba(0.1, 0.1, 0.2, 0.95);
+const PAUSE_BUTTON_BORDER_COLOR: Color = Color::rgba(0.7, 0.7, 0.8, 0.7);
+const PAUSE_TEXT_COLOR: Color = Color::rgb(0.95, 0.95, 0.95);
+
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app .init_resource::<PauseMenuScreen>()
+            .add_systems(OnEnter(AppState::Paused), (reset_pause_menu_screen, setup_pause_menu_ui).chain())
+            .add_systems(Update, (
+                pause_menu_keyboard_system,
+                pause_menu_button_interaction_system,
+                settings_button_interaction_system,
+                rebuild_pause_menu_on_screen_change,
+                update_setting_value_texts,
+            ).chain().run_if(in_state(AppState::Paused)))
+            .add_systems(OnExit(AppState::Paused), despawn_pause_menu_ui);
+    }
+}
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseMenuScreen {
+    #[default]
+    Main,
+    Settings,
+}
+
+#[derive(Component)] struct PauseMenuUIRoot;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum PauseMenuButton { Resume, Settings, AbandonRun, Quit }
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingsButton {
+    MasterVolumeDown, MasterVolumeUp,
+    MusicVolumeDown, MusicVolumeUp,
+    SfxVolumeDown, SfxVolumeUp,
+    ToggleDamageNumbers,
+    ScreenShakeDown, ScreenShakeUp,
+    CycleWindowMode,
+    Back,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingValueText { MasterVolume, MusicVolume, SfxVolume, DamageNumbers, ScreenShake, WindowMode }
+
+fn setting_value_label(settings: &GameSettings, kind: SettingValueText) -> String {
+    match kind {
+        SettingValueText::MasterVolume => format!("{:.0}%", settings.master_volume * 100.0),
+        SettingValueText::MusicVolume => format!("{:.0}%", settings.music_volume * 100.0),
+        SettingValueText::SfxVolume => format!("{:.0}%", settings.sfx_volume * 100.0),
+        SettingValueText::DamageNumbers => if settings.show_damage_numbers { "On".to_string() } else { "Off".to_string() },
+        SettingValueText::ScreenShake => format!("{:.0}%", settings.screen_shake_intensity * 100.0),
+        SettingValueText::WindowMode => settings.window_mode.label().to_string(),
+    }
+}
+
+fn apply_settings_button(settings: &mut GameSettings, button: SettingsButton) {
+    match button {
+        SettingsButton::MasterVolumeDown => GameSettings::adjust_volume(&mut settings.master_volume, -VOLUME_STEP),
+        SettingsButton::MasterVolumeUp => GameSettings::adjust_volume(&mut settings.master_volume, VOLUME_STEP),
+        SettingsButton::MusicVolumeDown => GameSettings::adjust_volume(&mut settings.music_volume, -VOLUME_STEP),
+        SettingsButton::MusicVolumeUp => GameSettings::adjust_volume(&mut settings.music_volume, VOLUME_STEP),
+        SettingsButton::SfxVolumeDown => GameSettings::adjust_volume(&mut settings.sfx_volume, -VOLUME_STEP),
+        SettingsButton::SfxVolumeUp => GameSettings::adjust_volume(&mut settings.sfx_volume, VOLUME_STEP),
+        SettingsButton::ToggleDamageNumbers => settings.show_damage_numbers = !settings.show_damage_numbers,
+        SettingsButton::ScreenShakeDown => settings.adjust_screen_shake(-SCREEN_SHAKE_STEP),
+        SettingsButton::ScreenShakeUp => settings.adjust_screen_shake(SCREEN_SHAKE_STEP),
+        SettingsButton::CycleWindowMode => settings.window_mode = settings.window_mode.next(),
+        SettingsButton::Back => {}
+    }
+}
+
+fn reset_pause_menu_screen(mut screen: ResMut<PauseMenuScreen>) { screen.set_if_neq(PauseMenuScreen::Main); }
+
+fn setup_pause_menu_ui(mut commands: Commands, asset_server: Res<AssetServer>, screen: Res<PauseMenuScreen>, settings: Res<GameSettings>) {
+    spawn_pause_menu_ui(&mut commands, &asset_server, *screen, &settings);
+}
+
+fn spawn_pause_menu_ui(commands: &mut Commands, asset_server: &Res<AssetServer>, screen: PauseMenuScreen, settings: &GameSettings) {
+    let font = asset_server.load("fonts/FiraSans-Bold.ttf");
+    let title_style = TextStyle { font: font.clone(), font_size: 60.0, color: Color::WHITE };
+    let button_text_style = TextStyle { font: font.clone(), font_size: 24.0, color: PAUSE_TEXT_COLOR };
+    let label_text_style = TextStyle { font, font_size: 22.0, color: PAUSE_TEXT_COLOR };
+
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(15.0),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.75).into(),
+            z_index: ZIndex::Global(40),
+            ..default()
+        },
+        PauseMenuUIRoot,
+        Name::new("PauseMenuUIRoot"),
+    )).with_children(|parent| {
+        match screen {
+            PauseMenuScreen::Main => {
+                parent.spawn(TextBundle::from_section("Paused", title_style).with_style(Style { margin: UiRect::bottom(Val::Px(20.0)), ..default() }));
+                for (button, label) in [
+                    (PauseMenuButton::Resume, "Resume"),
+                    (PauseMenuButton::Settings, "Settings"),
+                    (PauseMenuButton::AbandonRun, "Abandon Run"),
+                    (PauseMenuButton::Quit, "Save & Quit"),
+                ] {
+                    parent.spawn((pause_button_bundle(Val::Px(260.0)), button, Name::new(format!("PauseButton_{}", label))))
+                        .with_children(|button_parent| { button_parent.spawn(TextBundle::from_section(label, button_text_style.clone())); });
+                }
+            }
+            PauseMenuScreen::Settings => {
+                parent.spawn(TextBundle::from_section("Settings", title_style).with_style(Style { margin: UiRect::bottom(Val::Px(20.0)), ..default() }));
+                let stepper_rows = [
+                    ("Master Volume", SettingsButton::MasterVolumeDown, SettingValueText::MasterVolume, SettingsButton::MasterVolumeUp),
+                    ("Music Volume", SettingsButton::MusicVolumeDown, SettingValueText::MusicVolume, SettingsButton::MusicVolumeUp),
+                    ("SFX Volume", SettingsButton::SfxVolumeDown, SettingValueText::SfxVolume, SettingsButton::SfxVolumeUp),
+                    ("Screen Shake", SettingsButton::ScreenShakeDown, SettingValueText::ScreenShake, SettingsButton::ScreenShakeUp),
+                ];
+                for (label, down_button, value_kind, up_button) in stepper_rows {
+                    parent.spawn(settings_row_bundle()).with_children(|row| {
+                        row.spawn(TextBundle::from_section(label, label_text_style.clone()).with_style(Style { width: Val::Px(200.0), ..default() }));
+                        row.spawn((pause_button_bundle(Val::Px(50.0)), down_button)).with_children(|b| { b.spawn(TextBundle::from_section("-", button_text_style.clone())); });
+                        row.spawn((
+                            TextBundle::from_section(setting_value_label(settings, value_kind), label_text_style.clone())
+                                .with_style(Style { width: Val::Px(120.0), justify_content: JustifyContent::Center, ..default() })
+                                .with_text_alignment(TextAlignment::Center),
+                            value_kind,
+                        ));
+                        row.spawn((pause_button_bundle(Val::Px(50.0)), up_button)).with_children(|b| { b.spawn(TextBundle::from_section("+", button_text_style.clone())); });
+                    });
+                }
+                let toggle_rows = [
+                    ("Damage Numbers", SettingsButton::ToggleDamageNumbers, SettingValueText::DamageNumbers),
+                    ("Window Mode", SettingsButton::CycleWindowMode, SettingValueText::WindowMode),
+                ];
+                for (label, button, value_kind) in toggle_rows {
+                    parent.spawn(settings_row_bundle()).with_children(|row| {
+                        row.spawn(TextBundle::from_section(label, label_text_style.clone()).with_style(Style { width: Val::Px(200.0), ..default() }));
+                        row.spawn((pause_button_bundle(Val::Px(240.0)), button)).with_children(|b| {
+                            b.spawn((TextBundle::from_section(setting_value_label(settings, value_kind), button_text_style.clone()), value_kind));
+                        });
+                    });
+                }
+                parent.spawn((pause_button_bundle(Val::Px(260.0)), SettingsButton::Back, Name::new("SettingsBackButton")))
+                    .with_children(|b| { b.spawn(TextBundle::from_section("Back", button_text_style.clone())); });
+            }
+        }
+    });
+}
+
+fn pause_button_bundle(width: Val) -> ButtonBundle {
+    ButtonBundle {
+        style: Style {
+            width,
+            height: Val::Px(50.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        background_color: PAUSE_BUTTON_BG_COLOR.into(),
+        border_color: BorderColor(PAUSE_BUTTON_BORDER_COLOR),
+        ..default()
+    }
+}
+
+fn settings_row_bundle() -> NodeBundle {
+    NodeBundle {
+        style: Style { flex_direction: FlexDirection::Row, align_items: AlignItems::Center, column_gap: Val::Px(10.0), ..default() },
+        ..default()
+    }
+}
+
+fn pause_menu_keyboard_system(
+    keyboard_input: Res<Input<KeyCode>>,
+    mut screen: ResMut<PauseMenuScreen>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) {
+        match *screen {
+            PauseMenuScreen::Settings => *screen = PauseMenuScreen::Main,
+            PauseMenuScreen::Main => next_app_state.set(AppState::InGame),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn pause_menu_button_interaction_system(
+    mut interaction_query: Query<(&Interaction, &PauseMenuButton, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut screen: ResMut<PauseMenuScreen>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut app_exit_events: EventWriter<AppExit>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for (interaction, button, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PAUSE_BUTTON_PRESSED_BG_COLOR.into();
+                sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+                match button {
+                    PauseMenuButton::Resume => next_app_state.set(AppState::InGame),
+                    PauseMenuButton::Settings => *screen = PauseMenuScreen::Settings,
+                    PauseMenuButton::AbandonRun => next_app_state.set(AppState::MainMenu),
+                    PauseMenuButton::Quit => app_exit_events.send(AppExit),
+                }
+            }
+            Interaction::Hovered => { *bg_color = PAUSE_BUTTON_HOVER_BG_COLOR.into(); }
+            Interaction::None => { *bg_color = PAUSE_BUTTON_BG_COLOR.into(); }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn settings_button_interaction_system(
+    mut interaction_query: Query<(&Interaction, &SettingsButton, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut screen: ResMut<PauseMenuScreen>,
+    mut settings: ResMut<GameSettings>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    for (interaction, button, mut bg_color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *bg_color = PAUSE_BUTTON_PRESSED_BG_COLOR.into();
+                sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+                if *button == SettingsButton::Back { *screen = PauseMenuScreen::Main; } else { apply_settings_button(&mut settings, *button); }
+            }
+            Interaction::Hovered => { *bg_color = PAUSE_BUTTON_HOVER_BG_COLOR.into(); }
+            Interaction::None => { *bg_color = PAUSE_BUTTON_BG_COLOR.into(); }
+        }
+    }
+}
+
+fn rebuild_pause_menu_on_screen_change(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    screen: Res<PauseMenuScreen>,
+    settings: Res<GameSettings>,
+    root_query: Query<Entity, With<PauseMenuUIRoot>>,
+) {
+    if !screen.is_changed() || screen.is_added() { return; }
+    for entity in root_query.iter() { commands.entity(entity).despawn_recursive(); }
+    spawn_pause_menu_ui(&mut commands, &asset_server, *screen, &settings);
+}
+
+fn update_setting_value_texts(settings: Res<GameSettings>, mut text_query: Query<(&mut Text, &SettingValueText)>) {
+    if !settings.is_changed() { return; }
+    for (mut text, kind) in text_query.iter_mut() {
+        if let Some(section) = text.sections.get_mut(0) { section.value = setting_value_label(&settings, *kind); }
+    }
+}
+
+fn despawn_pause_menu_ui(mut commands: Commands, query: Query<Entity, With<PauseMenuUIRoot>>) { for entity in query.iter() { commands.entity(entity).despawn_recursive(); } }
@@ -0,0 +1,36 @@
+// src/persistence.rs
+// Shared helpers for the small RON files the game keeps next to the executable
+// (settings, profile, ...). Failures are logged rather than surfaced, since
+// losing a write should never crash a run.
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+pub fn to_ron_string<T: Serialize>(value: &T) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+}
+
+pub fn from_ron_str<T: DeserializeOwned>(contents: &str) -> Result<T, ron::error::SpannedError> {
+    ron::from_str(contents)
+}
+
+pub fn load_ron_or_default<T: DeserializeOwned + Default>(path: &str) -> T {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => from_ron_str(&contents).unwrap_or_else(|err| {
+            warn!("Could not parse {}: {}. Using defaults.", path, err);
+            T::default()
+        }),
+        Err(_) => T::default(),
+    }
+}
+
+pub fn save_ron<T: Serialize>(path: &str, value: &T) {
+    let contents = match to_ron_string(value) {
+        Ok(contents) => contents,
+        Err(err) => { error!("Failed to serialize {}: {}", path, err); return; }
+    };
+    // Write to a sibling file first so a crash mid-write can't truncate the existing save
+    let temp_path = format!("{}.tmp", path);
+    if let Err(err) = std::fs::write(&temp_path, contents).and_then(|_| std::fs::rename(&temp_path, path)) {
+        error!("Failed to write {}: {}", path, err);
+    }
+}
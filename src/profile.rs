@@ -0,0 +1,215 @@
+// src/profile.rs
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::{
+    persistence,
+    items::{AutomaticWeaponDefinition, AutomaticWeaponId, ItemDefinition, ItemId, UnlockCondition},
+    achievements::AchievementRecord,
+    survivor::Survivor,
+};
+
+pub const PROFILE_FILE_PATH: &str = "profile.ron";
+pub const PROFILE_VERSION: u32 = 1;
+
+pub const WEAPON_UNLOCK_COST: u32 = 150;
+pub const RELIC_UNLOCK_COST: u32 = 100;
+const SCORE_PER_LORE: u32 = 10;
+const ECHOES_PER_LORE: u32 = 25;
+
+const MAX_HEALTH_PER_LEVEL: i32 = 10;
+const SPEED_BONUS_PER_LEVEL: f32 = 0.03;
+const XP_GAIN_BONUS_PER_LEVEL: f32 = 0.05;
+const REROLL_CHARGES_PER_LEVEL: u32 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Reflect)]
+pub enum MetaUpgradeKind {
+    MaxHealth,
+    Speed,
+    XpGain,
+    RerollCharges,
+}
+
+impl MetaUpgradeKind {
+    pub const ALL: [MetaUpgradeKind; 4] = [MetaUpgradeKind::MaxHealth, MetaUpgradeKind::Speed, MetaUpgradeKind::XpGain, MetaUpgradeKind::RerollCharges];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            MetaUpgradeKind::MaxHealth => "Hardened Flesh",
+            MetaUpgradeKind::Speed => "Fleet Footing",
+            MetaUpgradeKind::XpGain => "Keen Insight",
+            MetaUpgradeKind::RerollCharges => "Second Omen",
+        }
+    }
+
+    pub fn description(self) -> String {
+        match self {
+            MetaUpgradeKind::MaxHealth => format!("+{} starting Max Endurance", MAX_HEALTH_PER_LEVEL),
+            MetaUpgradeKind::Speed => format!("+{:.0}% movement speed", SPEED_BONUS_PER_LEVEL * 100.0),
+            MetaUpgradeKind::XpGain => format!("+{:.0}% Echoes gained", XP_GAIN_BONUS_PER_LEVEL * 100.0),
+            MetaUpgradeKind::RerollCharges => format!("+{} level-up reroll per run", REROLL_CHARGES_PER_LEVEL),
+        }
+    }
+
+    pub fn max_level(self) -> u32 {
+        match self {
+            MetaUpgradeKind::MaxHealth | MetaUpgradeKind::Speed | MetaUpgradeKind::XpGain => 5,
+            MetaUpgradeKind::RerollCharges => 3,
+        }
+    }
+
+    pub fn base_cost(self) -> u32 {
+        match self {
+            MetaUpgradeKind::MaxHealth | MetaUpgradeKind::Speed => 40,
+            MetaUpgradeKind::XpGain => 60,
+            MetaUpgradeKind::RerollCharges => 100,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Reflect)]
+#[serde(default)]
+pub struct MetaUpgradeLevels {
+    pub max_health: u32,
+    pub speed: u32,
+    pub xp_gain: u32,
+    pub reroll_charges: u32,
+}
+
+impl MetaUpgradeLevels {
+    pub fn get(&self, kind: MetaUpgradeKind) -> u32 {
+        match kind {
+            MetaUpgradeKind::MaxHealth => self.max_health,
+            MetaUpgradeKind::Speed => self.speed,
+            MetaUpgradeKind::XpGain => self.xp_gain,
+            MetaUpgradeKind::RerollCharges => self.reroll_charges,
+        }
+    }
+
+    fn get_mut(&mut self, kind: MetaUpgradeKind) -> &mut u32 {
+        match kind {
+            MetaUpgradeKind::MaxHealth => &mut self.max_health,
+            MetaUpgradeKind::Speed => &mut self.speed,
+            MetaUpgradeKind::XpGain => &mut self.xp_gain,
+            MetaUpgradeKind::RerollCharges => &mut self.reroll_charges,
+        }
+    }
+}
+
+// Everything that survives between runs. Fields missing from an older file fall back to
+// their defaults; a missing `version` reads as 0 so `migrate` can tell pre-versioned files apart.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PlayerProfile {
+    #[serde(default)]
+    pub version: u32,
+    pub lore: u32, // Meta currency, banked at the end of every run
+    pub lifetime_lore_earned: u32,
+    pub runs_completed: u32,
+    pub last_run_lore_earned: u32,
+    pub meta_upgrades: MetaUpgradeLevels,
+    pub unlocked_weapon_ids: Vec<AutomaticWeaponId>,
+    pub unlocked_item_ids: Vec<ItemId>,
+    pub achievements: AchievementRecord,
+}
+
+impl Default for PlayerProfile {
+    fn default() -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            lore: 0,
+            lifetime_lore_earned: 0,
+            runs_completed: 0,
+            last_run_lore_earned: 0,
+            meta_upgrades: MetaUpgradeLevels::default(),
+            unlocked_weapon_ids: Vec::new(),
+            unlocked_item_ids: Vec::new(),
+            achievements: AchievementRecord::default(),
+        }
+    }
+}
+
+impl PlayerProfile {
+    pub fn migrate(&mut self) {
+        if self.version > PROFILE_VERSION {
+            warn!("Profile version {} is newer than supported version {}; unknown data will be dropped on save.", self.version, PROFILE_VERSION);
+        }
+        // Version 0 (pre-versioned) needs no field changes, serde defaults already filled the gaps.
+        self.version = PROFILE_VERSION;
+    }
+
+    pub fn lore_for_run(score: u32, echoes_collected: u32) -> u32 { score / SCORE_PER_LORE + echoes_collected / ECHOES_PER_LORE }
+
+    pub fn bank_run(&mut self, score: u32, echoes_collected: u32) -> u32 {
+        let earned = PlayerProfile::lore_for_run(score, echoes_collected);
+        self.lore += earned;
+        self.lifetime_lore_earned += earned;
+        self.runs_completed += 1;
+        self.last_run_lore_earned = earned;
+        earned
+    }
+
+    pub fn upgrade_cost(&self, kind: MetaUpgradeKind) -> Option<u32> {
+        let level = self.meta_upgrades.get(kind);
+        if level >= kind.max_level() { None } else { Some(kind.base_cost() * (level + 1)) }
+    }
+
+    pub fn try_purchase_upgrade(&mut self, kind: MetaUpgradeKind) -> bool {
+        let Some(cost) = self.upgrade_cost(kind) else { return false; };
+        if self.lore < cost { return false; }
+        self.lore -= cost;
+        *self.meta_upgrades.get_mut(kind) += 1;
+        true
+    }
+
+    // Starters are always available; everything else once bought or granted
+    pub fn is_weapon_unlocked(&self, weapon_def: &AutomaticWeaponDefinition) -> bool {
+        weapon_def.unlock == UnlockCondition::Starter || self.unlocked_weapon_ids.contains(&weapon_def.id)
+    }
+    pub fn is_item_unlocked(&self, item_def: &ItemDefinition) -> bool {
+        item_def.unlock == UnlockCondition::Starter || self.unlocked_item_ids.contains(&item_def.id)
+    }
+
+    pub fn try_unlock_weapon(&mut self, weapon_def: &AutomaticWeaponDefinition) -> bool {
+        if self.is_weapon_unlocked(weapon_def) || self.lore < WEAPON_UNLOCK_COST { return false; }
+        self.lore -= WEAPON_UNLOCK_COST;
+        self.grant_weapon(weapon_def.id);
+        true
+    }
+
+    pub fn try_unlock_item(&mut self, item_def: &ItemDefinition) -> bool {
+        if self.is_item_unlocked(item_def) || self.lore < RELIC_UNLOCK_COST { return false; }
+        self.lore -= RELIC_UNLOCK_COST;
+        self.grant_item(item_def.id);
+        true
+    }
+
+    // Free unlocks, e.g. achievement rewards
+    pub fn grant_weapon(&mut self, weapon_id: AutomaticWeaponId) { if !self.unlocked_weapon_ids.contains(&weapon_id) { self.unlocked_weapon_ids.push(weapon_id); } }
+    pub fn grant_item(&mut self, item_id: ItemId) { if !self.unlocked_item_ids.contains(&item_id) { self.unlocked_item_ids.push(item_id); } }
+
+    pub fn apply_permanent_upgrades(&self, survivor: &mut Survivor) {
+        let levels = &self.meta_upgrades;
+        survivor.max_health += MAX_HEALTH_PER_LEVEL * levels.max_health as i32;
+        survivor.speed *= 1.0 + SPEED_BONUS_PER_LEVEL * levels.speed as f32;
+        survivor.xp_gain_multiplier += XP_GAIN_BONUS_PER_LEVEL * levels.xp_gain as f32;
+        survivor.reroll_charges += REROLL_CHARGES_PER_LEVEL * levels.reroll_charges;
+    }
+}
+
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        let mut profile = persistence::load_ron_or_default::<PlayerProfile>(PROFILE_FILE_PATH);
+        profile.migrate();
+        app .register_type::<PlayerProfile>()
+            .insert_resource(profile)
+            .add_systems(Update, save_profile_on_change_system.run_if(resource_changed::<PlayerProfile>()));
+    }
+}
+
+fn save_profile_on_change_system(profile: Res<PlayerProfile>) {
+    if profile.is_added() { return; }
+    persistence::save_ron(PROFILE_FILE_PATH, &*profile);
+}
@@ -0,0 +1,221 @@
+// src/props.rs
+// Breakable props: urns, idols and ritual candles scattered among the ruins. A prop is an ordinary `Horror` of a
+// stationary type with a `Prop` marker on top, so every weapon, skill and relic that hurts horrors already breaks
+// it; the marker keeps props out of kill tallies, contact damage and the horror count. Props are placed from the run
+// seed chunk by chunk as the ruins stream in, and one that was broken stays broken when its chunk comes back. What a
+// prop leaves behind is rolled from its definition's drop table.
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use rand::{Rng, SeedableRng, rngs::StdRng, seq::SliceRandom};
+use crate::{
+    components::Health,
+    survivor::Survivor,
+    game::{AppState, RunSeed, session_ending},
+    audio::{PlaySoundEvent, SoundEffect},
+    profile::PlayerProfile,
+    horror::{Horror, HorrorType, spawn_horror_type},
+    arena::{ArenaLayout, ObstacleSpec, ARENA_CHUNK_SIZE, arena_streaming_system, chunk_seed},
+    items::{ItemLibrary, spawn_item_drop},
+    echoing_soul::{spawn_echoing_soul, ECHOING_SOUL_VALUE},
+    consumables::{ConsumableKind, get_consumable_definition, spawn_consumable},
+    run_stats::record_damage_dealt_system,
+};
+
+const PROP_SEED_SALT: u64 = 0x5052_4F50_5F53_4545; // Keeps prop placement independent of the ruins' own rolls
+const PROP_CHUNK_MARGIN: f32 = 60.0;
+const PROP_CLEARANCE: f32 = 40.0; // Props never touch the ruins
+const PROPS_PER_CHUNK_MIN: u32 = 1;
+const PROPS_PER_CHUNK_MAX: u32 = 4;
+const MAX_PLACEMENT_ATTEMPTS: u32 = 8;
+const PROP_Z_POS: f32 = 0.3;
+const LOOT_SCATTER_RADIUS: f32 = 30.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PropLoot {
+    EchoingSouls { count: u32, value: u32 },
+    Relic, // One of the relics the profile has unlocked
+    Consumable(ConsumableKind),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct PropDrop { pub chance: f64, pub loot: PropLoot }
+
+pub struct PropDefinition {
+    pub horror_type: HorrorType,
+    pub weight: u32, // How often this prop is placed relative to the others
+    pub drops: &'static [PropDrop], // Each entry is rolled on its own
+}
+
+pub static PROP_DEFINITIONS: [PropDefinition; 3] = [
+    PropDefinition {
+        horror_type: HorrorType::RitualCandle, weight: 5,
+        drops: &[
+            PropDrop { chance: 0.6, loot: PropLoot::EchoingSouls { count: 1, value: ECHOING_SOUL_VALUE } },
+            PropDrop { chance: 0.02, loot: PropLoot::Consumable(ConsumableKind::PurgeSigil) },
+        ],
+    },
+    PropDefinition {
+        horror_type: HorrorType::Urn, weight: 4,
+        drops: &[
+            PropDrop { chance: 0.9, loot: PropLoot::EchoingSouls { count: 3, value: ECHOING_SOUL_VALUE } },
+            PropDrop { chance: 0.03, loot: PropLoot::Relic },
+            PropDrop { chance: 0.06, loot: PropLoot::Consumable(ConsumableKind::HealingIchor) },
+        ],
+    },
+    PropDefinition {
+        horror_type: HorrorType::Idol, weight: 1,
+        drops: &[
+            PropDrop { chance: 1.0, loot: PropLoot::EchoingSouls { count: 2, value: ECHOING_SOUL_VALUE * 3 } },
+            PropDrop { chance: 0.15, loot: PropLoot::Relic },
+            PropDrop { chance: 0.1, loot: PropLoot::Consumable(ConsumableKind::SoulMagnet) },
+            PropDrop { chance: 0.1, loot: PropLoot::Consumable(ConsumableKind::FrenzyTotem) },
+        ],
+    },
+];
+
+pub fn get_prop_definition(horror_type: HorrorType) -> Option<&'static PropDefinition> {
+    PROP_DEFINITIONS.iter().find(|def| def.horror_type == horror_type)
+}
+
+// Rolls a definition's drop table. Soul drops scale with `echoes_multiplier`, relic drops with `relic_multiplier`, and
+// consumables with whichever of the two their own definition names.
+pub fn roll_prop_loot(def: &PropDefinition, echoes_multiplier: f32, relic_multiplier: f32, rng: &mut impl Rng) -> Vec<PropLoot> {
+    def.drops.iter()
+        .filter(|drop| {
+            let chance = match drop.loot {
+                PropLoot::EchoingSouls { .. } => drop.chance * echoes_multiplier as f64,
+                PropLoot::Relic => drop.chance * relic_multiplier as f64,
+                PropLoot::Consumable(kind) => get_consumable_definition(kind).scaled_chance(drop.chance, echoes_multiplier, relic_multiplier),
+            };
+            rng.gen_bool(chance.clamp(0.0, 1.0))
+        })
+        .map(|drop| drop.loot)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PropPlacement { pub index: u32, pub horror_type: HorrorType, pub position: Vec2 }
+
+// The props of one chunk, a pure function of the seed, the chunk's coordinates and the ruins already in it
+pub fn generate_props(seed: u64, chunk: IVec2, obstacles: &[ObstacleSpec]) -> Vec<PropPlacement> {
+    let mut rng = StdRng::seed_from_u64(chunk_seed(seed ^ PROP_SEED_SALT, chunk));
+    let origin = chunk.as_vec2() * ARENA_CHUNK_SIZE;
+    let mut placements: Vec<PropPlacement> = Vec::new();
+    for index in 0..rng.gen_range(PROPS_PER_CHUNK_MIN..=PROPS_PER_CHUNK_MAX) {
+        let def = PROP_DEFINITIONS.choose_weighted(&mut rng, |def| def.weight).expect("prop definitions have weights");
+        for _ in 0..MAX_PLACEMENT_ATTEMPTS {
+            let position = origin + Vec2::new(rng.gen_range(PROP_CHUNK_MARGIN..ARENA_CHUNK_SIZE - PROP_CHUNK_MARGIN), rng.gen_range(PROP_CHUNK_MARGIN..ARENA_CHUNK_SIZE - PROP_CHUNK_MARGIN));
+            if obstacles.iter().any(|obstacle| obstacle.penetration(position, PROP_CLEARANCE).is_some()) { continue; }
+            placements.push(PropPlacement { index, horror_type: def.horror_type, position });
+            break;
+        }
+    }
+    placements
+}
+
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Prop { pub chunk: IVec2, pub index: u32 }
+
+// Which chunks have their props out, and which props this run has already broken
+#[derive(Resource, Default)]
+pub struct PropField {
+    seed: u64,
+    spawned: HashSet<IVec2>,
+    broken: HashSet<(IVec2, u32)>,
+}
+
+impl PropField {
+    pub fn new(seed: u64, broken: impl IntoIterator<Item = (IVec2, u32)>) -> Self {
+        Self { seed, spawned: HashSet::default(), broken: broken.into_iter().collect() }
+    }
+
+    pub fn seed(&self) -> u64 { self.seed }
+
+    pub fn is_broken(&self, chunk: IVec2, index: u32) -> bool { self.broken.contains(&(chunk, index)) }
+
+    pub fn break_prop(&mut self, chunk: IVec2, index: u32) { self.broken.insert((chunk, index)); }
+
+    pub fn broken(&self) -> impl Iterator<Item = (IVec2, u32)> + '_ { self.broken.iter().copied() }
+}
+
+pub struct PropsPlugin;
+
+impl Plugin for PropsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PropField>()
+            .add_systems(Update, prop_streaming_system.after(arena_streaming_system).run_if(in_state(AppState::InGame)))
+            // Run stats need to see which hits landed on a prop before the broken ones are gone
+            .add_systems(PostUpdate, prop_break_system.after(record_damage_dealt_system).run_if(in_state(AppState::InGame)))
+            .add_systems(OnExit(AppState::InGame), reset_prop_field.run_if(session_ending)) // The props themselves go with the horrors
+            .add_systems(OnExit(AppState::Paused), reset_prop_field.run_if(session_ending));
+    }
+}
+
+fn reset_prop_field(mut prop_field: ResMut<PropField>) { *prop_field = PropField::default(); }
+
+fn prop_streaming_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    run_seed: Res<RunSeed>,
+    layout: Res<ArenaLayout>,
+    mut prop_field: ResMut<PropField>,
+    prop_query: Query<(Entity, &Prop)>,
+) {
+    if prop_field.seed() != run_seed.0 {
+        for (entity, _) in prop_query.iter() { commands.entity(entity).despawn_recursive(); }
+        *prop_field = PropField::new(run_seed.0, []);
+    } else if !layout.is_changed() {
+        return;
+    }
+    prop_field.spawned.retain(|chunk| layout.is_loaded(*chunk));
+    for (entity, prop) in prop_query.iter() {
+        if !layout.is_loaded(prop.chunk) { commands.entity(entity).despawn_recursive(); }
+    }
+    let new_chunks: Vec<IVec2> = layout.loaded_chunks().filter(|chunk| !prop_field.spawned.contains(chunk)).collect();
+    for chunk in new_chunks {
+        let obstacles = layout.chunk_obstacles(chunk).unwrap_or_default();
+        for placement in generate_props(run_seed.0, chunk, obstacles) {
+            if prop_field.is_broken(chunk, placement.index) { continue; }
+            if let Some(prop_entity) = spawn_horror_type(&mut commands, &asset_server, placement.horror_type, placement.position.extend(PROP_Z_POS), 1.0, &[]) {
+                commands.entity(prop_entity).insert(Prop { chunk, index: placement.index });
+            }
+        }
+        prop_field.spawned.insert(chunk);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn prop_break_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    item_library: Res<ItemLibrary>,
+    profile: Res<PlayerProfile>,
+    mut prop_field: ResMut<PropField>,
+    prop_query: Query<(Entity, &Prop, &Horror, &Health, &Transform)>,
+    survivor_query: Query<&Survivor>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    let (echoes_multiplier, relic_multiplier) = survivor_query.get_single()
+        .map_or((1.0, 1.0), |survivor| (survivor.echoes_drop_rate_multiplier, survivor.relic_drop_rate_multiplier));
+    let mut rng = rand::thread_rng();
+    for (entity, prop, horror, health, transform) in prop_query.iter() {
+        if health.0 > 0 { continue; }
+        prop_field.break_prop(prop.chunk, prop.index);
+        let position = transform.translation.truncate();
+        for loot in get_prop_definition(horror.horror_type).map_or_else(Vec::new, |def| roll_prop_loot(def, echoes_multiplier, relic_multiplier, &mut rng)) {
+            match loot {
+                PropLoot::EchoingSouls { count, value } => for _ in 0..count {
+                    let scatter = Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU)) * rng.gen_range(0.0..LOOT_SCATTER_RADIUS);
+                    spawn_echoing_soul(&mut commands, &asset_server, (position + scatter).extend(0.5), value);
+                },
+                PropLoot::Relic => {
+                    let unlocked: Vec<_> = item_library.items.iter().filter(|item_def| profile.is_item_unlocked(item_def)).collect();
+                    if let Some(item_def) = unlocked.choose(&mut rng) { spawn_item_drop(&mut commands, &asset_server, position, item_def); }
+                }
+                PropLoot::Consumable(kind) => { spawn_consumable(&mut commands, &asset_server, kind, position); }
+            }
+        }
+        sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit));
+        commands.entity(entity).despawn_recursive();
+    }
+}
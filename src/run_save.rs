@@ -0,0 +1,303 @@
+// src/run_save.rs
+// Suspend-and-resume for a run in progress. Quitting the game mid-run writes a snapshot of the
+// survivor, the run timers and everything alive on the field; the main menu offers to resume it.
+// The save is consumed on resume and thrown away as soon as a fresh run starts.
+use bevy::prelude::*;
+use bevy::app::AppExit;
+use serde::{Deserialize, Serialize};
+use crate::{
+    persistence,
+    game::{AppState, GameState, GameMode, RunSeed, SelectedCharacter, SelectedGameMode},
+    components::{Health, Velocity},
+    survivor::{Survivor, SanityStrain, survivor_sprite_bundle},
+    weapons::{CircleOfWarding, SwarmOfNightmares},
+    horror::{Horror, HorrorType, HorrorSpawnTimer, MaxHorrors, spawn_horror_type},
+    horror_behavior::{BehaviorLayerSnapshot, HorrorBehavior},
+    echoing_soul::{EchoingSoul, spawn_echoing_soul},
+    characters::CharacterId,
+    run_stats::RunStats,
+    boss::{Boss, BossId, BossTimeline, spawn_boss},
+    nests::{get_nest_definition, spawn_nest},
+    props::{Prop, PropField},
+    elites::{EliteAffix, EliteAffixes, EliteWard, roll_elite_affixes},
+    items::{ItemDrop, ItemId, ItemLibrary, spawn_item_drop},
+    chests::{RewardChest, spawn_reward_chest},
+    merchant::{MerchantSchedule, Obol, spawn_obols},
+    audio::{PlaySoundEvent, SoundEffect},
+};
+
+pub const RUN_SAVE_FILE_PATH: &str = "run_save.ron";
+pub const RUN_SAVE_VERSION: u32 = 1;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SurvivorSnapshot {
+    pub stats: Survivor, // Carries the equipped weapon, skills with their cooldowns and collected item ids
+    pub health: i32,
+    pub position: Vec2,
+    pub sanity_strain: SanityStrain,
+    pub circle_of_warding: CircleOfWarding,
+    pub swarm_of_nightmares: SwarmOfNightmares,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HorrorSnapshot {
+    pub horror_type: HorrorType, pub position: Vec2, pub health: i32, pub is_elite: bool,
+    #[serde(default)] pub elite_affixes: Vec<EliteAffix>,
+    #[serde(default)] pub boss: Option<BossId>,
+    #[serde(default)] pub ward: Option<i32>, // What's left of a Shielded elite's ward
+    #[serde(default)] pub behavior: Vec<BehaviorLayerSnapshot>,
+}
+
+impl HorrorSnapshot {
+    // Run against the freshly spawned horror, whose components start from full health
+    pub fn restore_state(&self, horror: &mut EntityWorldMut) {
+        horror.insert(Health(self.health));
+        if let (Some(mut ward), Some(amount)) = (horror.get_mut::<EliteWard>(), self.ward) { ward.amount = amount; }
+        if let Some(mut behavior) = horror.get_mut::<HorrorBehavior>() { behavior.restore(&self.behavior); }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EchoingSoulSnapshot { pub position: Vec2, pub value: u32 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ItemDropSnapshot { pub position: Vec2, pub item_id: ItemId }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ObolSnapshot { pub position: Vec2, pub value: u32 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RewardChestSnapshot { pub position: Vec2, pub quality: f32 }
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct RunSnapshot {
+    pub version: u32,
+    pub selected_character: CharacterId,
+    #[serde(default)] pub game_mode: GameMode,
+    #[serde(default)] pub seed: RunSeed,
+    pub resume_in_level_up: bool, // Quit while choosing an upgrade; the choice is offered again on resume
+    pub score: u32,
+    pub wave_number: u32,
+    pub echoes_collected: u32,
+    pub game_timer: Timer,
+    pub difficulty_timer: Timer,
+    pub horror_spawn_timer: Timer,
+    pub max_horrors: u32,
+    pub survivor: SurvivorSnapshot,
+    pub horrors: Vec<HorrorSnapshot>,
+    pub echoing_souls: Vec<EchoingSoulSnapshot>,
+    pub item_drops: Vec<ItemDropSnapshot>,
+    #[serde(default)] pub run_stats: RunStats, // Results-screen tallies so far, so a resumed run reports the whole run
+    #[serde(default)] pub broken_props: Vec<(IVec2, u32)>, // Props are rebuilt from the seed; these stay broken
+    #[serde(default)] pub reward_chests: Vec<RewardChestSnapshot>, // Chests still waiting to be walked into
+    #[serde(default)] pub obols: Vec<ObolSnapshot>,
+    #[serde(default)] pub merchant_last_visit_wave: u32, // So the merchant doesn't come twice for the same wave
+}
+
+impl RunSnapshot {
+    // Mid-run saves are short-lived, so a snapshot from another version is dropped instead of migrated.
+    pub fn from_ron_str(contents: &str) -> Option<Self> {
+        match persistence::from_ron_str::<RunSnapshot>(contents) {
+            Ok(snapshot) if snapshot.version == RUN_SAVE_VERSION => Some(snapshot),
+            Ok(snapshot) => { warn!("Discarding suspended run from save version {} (expected {}).", snapshot.version, RUN_SAVE_VERSION); None }
+            Err(err) => { warn!("Could not parse {}: {}. Discarding suspended run.", RUN_SAVE_FILE_PATH, err); None }
+        }
+    }
+
+    // Same scaling horror_spawn_system applies for the current wave
+    pub fn wave_multiplier(&self) -> f32 { 1.0 + (self.wave_number as f32 - 1.0) * 0.1 }
+
+    pub fn restore_resources(&self, game_state: &mut GameState, horror_spawn_timer: &mut HorrorSpawnTimer, max_horrors: &mut MaxHorrors) {
+        game_state.score = self.score;
+        game_state.wave_number = self.wave_number;
+        game_state.horror_count = self.horrors.len() as u32;
+        game_state.echoes_collected = self.echoes_collected;
+        game_state.game_timer = self.game_timer.clone();
+        game_state.difficulty_timer = self.difficulty_timer.clone();
+        horror_spawn_timer.timer = self.horror_spawn_timer.clone();
+        max_horrors.0 = self.max_horrors;
+    }
+}
+
+// The run waiting on the main menu, if the last session was quit mid-run.
+#[derive(Resource, Default)]
+pub struct SuspendedRun(pub Option<RunSnapshot>);
+
+#[derive(Component)] pub struct ResumeRunButton;
+
+pub struct RunSavePlugin;
+
+impl Plugin for RunSavePlugin {
+    fn build(&self, app: &mut App) {
+        app .insert_resource(SuspendedRun(load_suspended_run()))
+            .add_systems(Update, resume_run_button_system.run_if(in_state(AppState::MainMenu)))
+            .add_systems(OnEnter(AppState::InGame), discard_suspended_run)
+            .add_systems(Last, save_run_on_exit_system.run_if(on_event::<AppExit>()));
+    }
+}
+
+fn load_suspended_run() -> Option<RunSnapshot> {
+    let contents = std::fs::read_to_string(RUN_SAVE_FILE_PATH).ok()?;
+    RunSnapshot::from_ron_str(&contents)
+}
+
+fn remove_run_save_file() {
+    if let Err(err) = std::fs::remove_file(RUN_SAVE_FILE_PATH) {
+        if err.kind() != std::io::ErrorKind::NotFound { error!("Failed to remove {}: {}", RUN_SAVE_FILE_PATH, err); }
+    }
+}
+
+// Starting a fresh run abandons whatever was suspended; after a resume the resource is already empty.
+fn discard_suspended_run(mut suspended_run: ResMut<SuspendedRun>) {
+    if suspended_run.0.take().is_some() { remove_run_save_file(); }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn save_run_on_exit_system(
+    current_app_state: Res<State<AppState>>,
+    game_state: Res<GameState>,
+    horror_spawn_timer: Res<HorrorSpawnTimer>,
+    max_horrors: Res<MaxHorrors>,
+    selected_character: Res<SelectedCharacter>,
+    selected_game_mode: Res<SelectedGameMode>,
+    run_seed: Res<RunSeed>,
+    survivor_query: Query<(&Survivor, &Health, &Transform, &SanityStrain, &CircleOfWarding, &SwarmOfNightmares)>,
+    horror_query: Query<(&Horror, &Health, &Transform, Option<&EliteAffixes>, Option<&Boss>, Option<&EliteWard>, Option<&HorrorBehavior>), Without<Prop>>,
+    soul_query: Query<(&EchoingSoul, &Transform)>,
+    item_drop_query: Query<(&ItemDrop, &Transform)>,
+    chest_query: Query<(&RewardChest, &Transform)>,
+    obol_query: Query<(&Obol, &Transform)>,
+    run_stats: Res<RunStats>,
+    prop_field: Res<PropField>,
+    merchant_schedule: Res<MerchantSchedule>,
+) {
+    let resume_in_level_up = match current_app_state.get() {
+        AppState::InGame | AppState::Paused | AppState::ChestReward | AppState::Merchant => false, // An unclaimed chest or an open shop is forfeited
+        AppState::LevelUp => true,
+        _ => return,
+    };
+    let Ok((survivor, health, transform, sanity_strain, circle_of_warding, swarm_of_nightmares)) = survivor_query.get_single() else { return; };
+    if health.0 <= 0 { return; }
+
+    let snapshot = RunSnapshot {
+        version: RUN_SAVE_VERSION,
+        selected_character: selected_character.0,
+        game_mode: selected_game_mode.0,
+        seed: *run_seed,
+        resume_in_level_up,
+        score: game_state.score,
+        wave_number: game_state.wave_number,
+        echoes_collected: game_state.echoes_collected,
+        game_timer: game_state.game_timer.clone(),
+        difficulty_timer: game_state.difficulty_timer.clone(),
+        horror_spawn_timer: horror_spawn_timer.timer.clone(),
+        max_horrors: max_horrors.0,
+        survivor: SurvivorSnapshot {
+            stats: survivor.clone(),
+            health: health.0,
+            position: transform.translation.truncate(),
+            sanity_strain: sanity_strain.clone(),
+            circle_of_warding: circle_of_warding.clone(),
+            swarm_of_nightmares: swarm_of_nightmares.clone(),
+        },
+        horrors: horror_query.iter()
+            .filter(|(_, health, _, _, _, _, _)| health.0 > 0)
+            .map(|(horror, health, transform, elite_affixes, boss, ward, behavior)| HorrorSnapshot {
+                horror_type: horror.horror_type, position: transform.translation.truncate(), health: health.0, is_elite: horror.is_elite,
+                elite_affixes: elite_affixes.map(|affixes| affixes.affixes.clone()).unwrap_or_default(), boss: boss.map(|boss| boss.id),
+                ward: ward.map(|ward| ward.amount), behavior: behavior.map(HorrorBehavior::snapshot).unwrap_or_default(),
+            })
+            .collect(),
+        echoing_souls: soul_query.iter().map(|(soul, transform)| EchoingSoulSnapshot { position: transform.translation.truncate(), value: soul.value }).collect(),
+        item_drops: item_drop_query.iter().map(|(item_drop, transform)| ItemDropSnapshot { position: transform.translation.truncate(), item_id: item_drop.item_id }).collect(),
+        run_stats: run_stats.clone(),
+        broken_props: prop_field.broken().collect(),
+        reward_chests: chest_query.iter().map(|(chest, transform)| RewardChestSnapshot { position: transform.translation.truncate(), quality: chest.quality }).collect(),
+        obols: obol_query.iter().map(|(obol, transform)| ObolSnapshot { position: transform.translation.truncate(), value: obol.value }).collect(),
+        merchant_last_visit_wave: merchant_schedule.last_visit_wave,
+    };
+    persistence::save_ron(RUN_SAVE_FILE_PATH, &snapshot);
+    info!("Suspended run saved: wave {}, {} horrors on the field.", snapshot.wave_number, snapshot.horrors.len());
+}
+
+#[allow(clippy::too_many_arguments)]
+fn resume_run_button_system(
+    mut commands: Commands,
+    interaction_query: Query<&Interaction, (Changed<Interaction>, With<ResumeRunButton>)>,
+    asset_server: Res<AssetServer>,
+    item_library: Res<ItemLibrary>,
+    mut suspended_run: ResMut<SuspendedRun>,
+    mut game_state: ResMut<GameState>,
+    mut horror_spawn_timer: ResMut<HorrorSpawnTimer>,
+    mut max_horrors: ResMut<MaxHorrors>,
+    mut selected_character: ResMut<SelectedCharacter>,
+    mut selected_game_mode: ResMut<SelectedGameMode>,
+    mut run_seed: ResMut<RunSeed>,
+    mut run_stats: ResMut<RunStats>,
+    mut boss_timeline: ResMut<BossTimeline>,
+    mut prop_field: ResMut<PropField>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut sound_event_writer: EventWriter<PlaySoundEvent>,
+) {
+    if !interaction_query.iter().any(|interaction| *interaction == Interaction::Pressed) { return; }
+    let Some(snapshot) = suspended_run.0.take() else { return; };
+    remove_run_save_file(); // A resumed run can only be continued once
+
+    sound_event_writer.send(PlaySoundEvent(SoundEffect::OmenAccepted));
+    selected_character.0 = snapshot.selected_character;
+    selected_game_mode.0 = snapshot.game_mode;
+    *run_seed = snapshot.seed;
+    snapshot.restore_resources(&mut game_state, &mut horror_spawn_timer, &mut max_horrors);
+    *run_stats = snapshot.run_stats.clone();
+    *boss_timeline = BossTimeline::resumed_at(snapshot.game_timer.elapsed_secs());
+    *prop_field = PropField::new(snapshot.seed.0, snapshot.broken_props.iter().copied());
+    commands.insert_resource(MerchantSchedule { last_visit_wave: snapshot.merchant_last_visit_wave });
+
+    // Spawning the survivor here makes spawn_survivor skip itself on entering InGame
+    let survivor = &snapshot.survivor;
+    let survivor_name = survivor.stats.equipped_weapon_definition.as_ref()
+        .map_or_else(|| "Survivor (Unknown Class)".to_string(), |weapon_def| format!("Survivor ({})", weapon_def.name));
+    commands.spawn((
+        survivor_sprite_bundle(&asset_server, survivor.position),
+        survivor.stats.clone(),
+        Health(survivor.health),
+        Velocity(Vec2::ZERO),
+        survivor.sanity_strain.clone(),
+        survivor.circle_of_warding.clone(),
+        survivor.swarm_of_nightmares.clone(),
+        Name::new(survivor_name),
+    ));
+
+    let wave_multiplier = snapshot.wave_multiplier();
+    for horror in snapshot.horrors.iter() {
+        let spawned = match horror.boss {
+            Some(boss_id) => Some(spawn_boss(&mut commands, &asset_server, boss_id, horror.position.extend(0.5), wave_multiplier)),
+            None if get_nest_definition(horror.horror_type).is_some() => spawn_nest(&mut commands, &asset_server, horror.horror_type, horror.position.extend(0.5), wave_multiplier),
+            None => {
+                // Saves from before affixes existed only know the horror was elite
+                let elite_affixes = if horror.is_elite && horror.elite_affixes.is_empty() { roll_elite_affixes(&mut rand::thread_rng(), horror.horror_type, snapshot.wave_number) } else { horror.elite_affixes.clone() };
+                spawn_horror_type(&mut commands, &asset_server, horror.horror_type, horror.position.extend(0.5), wave_multiplier, &elite_affixes)
+            }
+        };
+        if let Some(horror_entity) = spawned {
+            let restored = horror.clone();
+            commands.entity(horror_entity).add(move |mut horror: EntityWorldMut| restored.restore_state(&mut horror));
+        }
+    }
+    for soul in snapshot.echoing_souls.iter() {
+        spawn_echoing_soul(&mut commands, &asset_server, soul.position.extend(0.5), soul.value);
+    }
+    for item_drop in snapshot.item_drops.iter() {
+        if let Some(item_def) = item_library.get_item_definition(item_drop.item_id) {
+            spawn_item_drop(&mut commands, &asset_server, item_drop.position, item_def);
+        }
+    }
+    for chest in snapshot.reward_chests.iter() {
+        spawn_reward_chest(&mut commands, &asset_server, chest.position, chest.quality);
+    }
+    for obol in snapshot.obols.iter() {
+        spawn_obols(&mut commands, &asset_server, obol.position, obol.value);
+    }
+
+    next_app_state.set(if snapshot.resume_in_level_up { AppState::LevelUp } else { AppState::InGame });
+}
@@ -0,0 +1,277 @@
+// src/run_stats.rs
+// Per-run bookkeeping for the results screen: kills, damage in and out, healing and a per-source damage
+// table. Damage systems report what lands through `DamageDealtEvent` and heal sites report what they
+// restore through `SurvivorHealedEvent`; the survivor's own health is watched frame to frame for damage taken.
+// Hits on breakable props are dropped here, the same way props never count as kills.
+// Finished runs are appended to a small local history file.
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use crate::{
+    persistence,
+    game::{AppState, GameState, SelectedCharacter, session_ending},
+    components::Health,
+    survivor::Survivor,
+    horror::{HorrorKilledEvent, HorrorType},
+    items::{AutomaticWeaponId, AutomaticWeaponLibrary},
+    skills::{SkillId, SkillLibrary},
+    characters::CharacterId,
+    props::Prop,
+};
+
+pub const RUN_HISTORY_FILE_PATH: &str = "run_history.ron";
+pub const MAX_RUN_HISTORY_ENTRIES: usize = 50;
+pub const DPS_SAMPLE_SECS: f32 = 15.0;
+const DPS_CHART_HEIGHT: f32 = 60.0;
+const DPS_CHART_WIDTH: f32 = 520.0;
+
+// Where a hit on a horror came from, for the results screen's damage table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DamageSource {
+    InherentWeapon,
+    Skill(SkillId),
+    Explosion,       // ExplosionEffect from on-kill relics
+    RetaliationNova, // RetaliationNovaEffect from on-hit relics
+    CircleOfWarding,
+    SwarmOfNightmares,
+    DeathBurst, // Fuse blasts left by dying horrors
+}
+
+impl DamageSource {
+    pub fn label(self, weapon_id: Option<AutomaticWeaponId>, weapon_library: &AutomaticWeaponLibrary, skill_library: &SkillLibrary) -> String {
+        match self {
+            DamageSource::InherentWeapon => weapon_id.and_then(|id| weapon_library.get_weapon_definition(id)).map_or_else(|| "Inherent Weapon".to_string(), |def| def.name.clone()),
+            DamageSource::Skill(skill_id) => skill_library.get_skill_definition(skill_id).map_or_else(|| format!("Skill #{}", skill_id.0), |def| def.name.clone()),
+            DamageSource::Explosion => "Relic: Explosions".to_string(),
+            DamageSource::RetaliationNova => "Relic: Retaliation Nova".to_string(),
+            DamageSource::CircleOfWarding => "Circle of Warding".to_string(),
+            DamageSource::SwarmOfNightmares => "Swarm of Nightmares".to_string(),
+            DamageSource::DeathBurst => "Death Bursts".to_string(),
+        }
+    }
+}
+
+#[derive(Event, Debug, Clone, Copy)]
+pub struct DamageDealtEvent { pub source: DamageSource, pub target: Entity, pub amount: i32 }
+
+impl DamageDealtEvent {
+    // Overkill doesn't count: only what the target still had left to lose
+    pub fn new(source: DamageSource, target: Entity, damage: i32, health_before: i32) -> Self { Self { source, target, amount: damage.min(health_before.max(0)) } }
+}
+
+// What a heal actually restored under the max-endurance cap
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SurvivorHealedEvent(pub i32);
+
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunStats {
+    pub weapon_id: Option<AutomaticWeaponId>,
+    pub highest_level: u32,
+    pub kills: HashMap<HorrorType, u32>,
+    pub elites_killed: u32,
+    pub damage_taken: u32,
+    pub healing_done: u32,
+    pub damage_by_source: HashMap<DamageSource, u64>,
+    pub damage_per_sample: Vec<u64>, // Damage dealt in each DPS_SAMPLE_SECS slice of game time
+    #[serde(skip)] last_survivor_health: Option<i32>,
+}
+
+impl RunStats {
+    pub fn record_damage(&mut self, source: DamageSource, amount: i32, elapsed_secs: f32) {
+        if amount <= 0 { return; }
+        *self.damage_by_source.entry(source).or_insert(0) += amount as u64;
+        let sample_index = (elapsed_secs / DPS_SAMPLE_SECS) as usize;
+        if self.damage_per_sample.len() <= sample_index { self.damage_per_sample.resize(sample_index + 1, 0); }
+        self.damage_per_sample[sample_index] += amount as u64;
+    }
+
+    pub fn record_kill(&mut self, horror_type: HorrorType, is_elite: bool) {
+        *self.kills.entry(horror_type).or_insert(0) += 1;
+        if is_elite { self.elites_killed += 1; }
+    }
+
+    // Drops count as damage taken, whichever system caused them; rises are left to `record_healing`,
+    // since a bigger max endurance lifts health without healing anything
+    pub fn record_survivor_health(&mut self, health: i32) {
+        if let Some(last) = self.last_survivor_health {
+            if health < last { self.damage_taken += (last - health) as u32; }
+        }
+        self.last_survivor_health = Some(health);
+    }
+
+    pub fn record_healing(&mut self, amount: i32) { if amount > 0 { self.healing_done += amount as u32; } }
+
+    pub fn total_kills(&self) -> u32 { self.kills.values().sum() }
+    pub fn total_damage_dealt(&self) -> u64 { self.damage_by_source.values().sum() }
+
+    // Largest share first
+    pub fn damage_table(&self) -> Vec<(DamageSource, u64)> {
+        let mut table: Vec<_> = self.damage_by_source.iter().map(|(source, damage)| (*source, *damage)).collect();
+        table.sort_by_key(|(_, damage)| std::cmp::Reverse(*damage));
+        table
+    }
+
+    // Average DPS over each sample slice; the last slice only covers the time actually survived.
+    pub fn dps_samples(&self, survived_secs: f32) -> Vec<f32> {
+        let sample_count = self.damage_per_sample.len();
+        self.damage_per_sample.iter().enumerate().map(|(index, damage)| {
+            let slice_secs = if index + 1 == sample_count { (survived_secs - index as f32 * DPS_SAMPLE_SECS).clamp(1.0, DPS_SAMPLE_SECS) } else { DPS_SAMPLE_SECS };
+            *damage as f32 / slice_secs
+        }).collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub finished_at_unix_secs: u64,
+    pub character: CharacterId,
+    pub ended_by_death: bool, // False when the run was abandoned from the pause menu
+    pub score: u32,
+    pub survived_secs: u32,
+    pub wave_reached: u32,
+    pub echoes_collected: u32,
+    pub stats: RunStats,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunHistory { pub runs: Vec<RunSummary> }
+
+impl RunHistory {
+    // Oldest runs are dropped once the history is full
+    pub fn push(&mut self, summary: RunSummary) {
+        self.runs.push(summary);
+        if self.runs.len() > MAX_RUN_HISTORY_ENTRIES { let excess = self.runs.len() - MAX_RUN_HISTORY_ENTRIES; self.runs.drain(..excess); }
+    }
+}
+
+pub struct RunStatsPlugin;
+
+impl Plugin for RunStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app .init_resource::<RunStats>()
+            .add_event::<DamageDealtEvent>()
+            .add_event::<SurvivorHealedEvent>()
+            .add_systems(Update, (record_healing_system, record_horror_kills_system))
+            .add_systems(PostUpdate, record_damage_dealt_system) // After every hit this frame, while broken props are still around to be told apart
+            .add_systems(Update, track_survivor_progress_system.run_if(in_state(AppState::InGame)))
+            .add_systems(OnEnter(AppState::MainMenu), reset_run_stats)
+            .add_systems(OnEnter(AppState::GameOver), record_run_history)
+            .add_systems(OnExit(AppState::Paused), record_run_history.run_if(session_ending));
+    }
+}
+
+pub fn record_damage_dealt_system(mut events: EventReader<DamageDealtEvent>, mut run_stats: ResMut<RunStats>, game_state: Res<GameState>, prop_query: Query<(), With<Prop>>) {
+    let elapsed_secs = game_state.game_timer.elapsed_secs();
+    for event in events.read().filter(|event| !prop_query.contains(event.target)) { run_stats.record_damage(event.source, event.amount, elapsed_secs); }
+}
+
+fn record_healing_system(mut events: EventReader<SurvivorHealedEvent>, mut run_stats: ResMut<RunStats>) {
+    for event in events.read() { run_stats.record_healing(event.0); }
+}
+
+fn record_horror_kills_system(mut events: EventReader<HorrorKilledEvent>, mut run_stats: ResMut<RunStats>) {
+    for event in events.read() { run_stats.record_kill(event.horror_type, event.is_elite); }
+}
+
+fn track_survivor_progress_system(mut run_stats: ResMut<RunStats>, survivor_query: Query<(&Survivor, &Health)>) {
+    let Ok((survivor, health)) = survivor_query.get_single() else { return; };
+    run_stats.weapon_id = Some(survivor.inherent_weapon_id);
+    run_stats.highest_level = run_stats.highest_level.max(survivor.level);
+    run_stats.record_survivor_health(health.0);
+}
+
+// Kept until the main menu so the game-over screen can still read it; a resumed run restores its own.
+fn reset_run_stats(mut run_stats: ResMut<RunStats>) { *run_stats = RunStats::default(); }
+
+fn record_run_history(run_stats: Res<RunStats>, game_state: Res<GameState>, selected_character: Res<SelectedCharacter>, current_app_state: Res<State<AppState>>) {
+    let summary = RunSummary {
+        finished_at_unix_secs: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs()),
+        character: selected_character.0,
+        ended_by_death: *current_app_state.get() == AppState::GameOver,
+        score: game_state.score,
+        survived_secs: game_state.game_timer.elapsed_secs() as u32,
+        wave_reached: game_state.wave_number,
+        echoes_collected: game_state.echoes_collected,
+        stats: run_stats.clone(),
+    };
+    let mut history: RunHistory = persistence::load_ron_or_default(RUN_HISTORY_FILE_PATH);
+    history.push(summary);
+    persistence::save_ron(RUN_HISTORY_FILE_PATH, &history);
+}
+
+pub fn format_duration(secs: u32) -> String { format!("{:02}:{:02}", secs / 60, secs % 60) }
+
+// The results panel under the game-over header: overview, kills, damage table and a DPS chart.
+pub fn spawn_run_stats_panel(
+    parent: &mut ChildBuilder, font: Handle<Font>, run_stats: &RunStats, game_state: &GameState,
+    weapon_library: &AutomaticWeaponLibrary, skill_library: &SkillLibrary,
+) {
+    let heading_style = TextStyle { font: font.clone(), font_size: 22.0, color: Color::GOLD };
+    let line_style = TextStyle { font, font_size: 16.0, color: Color::rgb(0.85, 0.85, 0.9) };
+    let survived_secs = game_state.game_timer.elapsed_secs();
+
+    let column = |parent: &mut ChildBuilder, build: &dyn Fn(&mut ChildBuilder)| {
+        parent.spawn(NodeBundle {
+            style: Style { flex_direction: FlexDirection::Column, row_gap: Val::Px(4.0), padding: UiRect::all(Val::Px(10.0)), min_width: Val::Px(220.0), ..default() },
+            background_color: Color::rgba(0.08, 0.08, 0.12, 0.85).into(),
+            ..default()
+        }).with_children(|column_parent| build(column_parent));
+    };
+
+    parent.spawn(NodeBundle { style: Style { flex_direction: FlexDirection::Row, column_gap: Val::Px(16.0), align_items: AlignItems::FlexStart, ..default() }, ..default() })
+        .with_children(|row| {
+            column(row, &|col| {
+                col.spawn(TextBundle::from_section("The Run", heading_style.clone()));
+                let lines = [
+                    format!("Time Survived: {}", format_duration(survived_secs as u32)),
+                    format!("Wave Reached: {}", game_state.wave_number),
+                    format!("Level: {}", run_stats.highest_level),
+                    format!("Echoes Collected: {}", game_state.echoes_collected),
+                    format!("Damage Taken: {}", run_stats.damage_taken),
+                    format!("Healing Done: {}", run_stats.healing_done),
+                ];
+                for line in lines { col.spawn(TextBundle::from_section(line, line_style.clone())); }
+            });
+
+            column(row, &|col| {
+                col.spawn(TextBundle::from_section(format!("Kills: {} ({} elite)", run_stats.total_kills(), run_stats.elites_killed), heading_style.clone()));
+                let mut kills: Vec<_> = run_stats.kills.iter().collect();
+                kills.sort_by(|a, b| b.1.cmp(a.1));
+                for (horror_type, count) in kills { col.spawn(TextBundle::from_section(format!("{}: {}", horror_type.display_name(), count), line_style.clone())); }
+            });
+
+            column(row, &|col| {
+                let total_damage = run_stats.total_damage_dealt().max(1);
+                col.spawn(TextBundle::from_section(format!("Damage Dealt: {}", run_stats.total_damage_dealt()), heading_style.clone()));
+                for (source, damage) in run_stats.damage_table() {
+                    let label = source.label(run_stats.weapon_id, weapon_library, skill_library);
+                    let share = damage as f32 / total_damage as f32 * 100.0;
+                    let dps = damage as f32 / survived_secs.max(1.0);
+                    col.spawn(TextBundle::from_section(format!("{}: {} ({:.0}%, {:.1} DPS)", label, damage, share, dps), line_style.clone()));
+                }
+            });
+        });
+
+    // DPS over time as a bar per sample slice, scaled to the best slice
+    let samples = run_stats.dps_samples(survived_secs);
+    if samples.is_empty() { return; }
+    let peak_dps = samples.iter().copied().fold(0.0_f32, f32::max).max(1.0);
+    parent.spawn(TextBundle::from_section(format!("DPS over time (peak {:.1}, every {}s)", peak_dps, DPS_SAMPLE_SECS as u32), line_style.clone()));
+    let bar_width = (DPS_CHART_WIDTH / samples.len() as f32).clamp(2.0, 24.0);
+    parent.spawn(NodeBundle {
+        style: Style { height: Val::Px(DPS_CHART_HEIGHT), flex_direction: FlexDirection::Row, align_items: AlignItems::FlexEnd, column_gap: Val::Px(1.0), ..default() },
+        background_color: Color::rgba(0.0, 0.0, 0.0, 0.5).into(),
+        ..default()
+    }).with_children(|chart| {
+        for dps in samples {
+            chart.spawn(NodeBundle {
+                style: Style { width: Val::Px(bar_width), height: Val::Px((dps / peak_dps * DPS_CHART_HEIGHT).max(1.0)), ..default() },
+                background_color: Color::rgb(0.8, 0.3, 0.3).into(),
+                ..default()
+            });
+        }
+    });
+}
@@ -0,0 +1,113 @@
+// src/settings.rs
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowMode};
+use serde::{Deserialize, Serialize};
+use crate::persistence;
+
+pub const SETTINGS_FILE_PATH: &str = "settings.ron";
+pub const VOLUME_STEP: f32 = 0.1;
+pub const SCREEN_SHAKE_STEP: f32 = 0.25;
+pub const MAX_SCREEN_SHAKE_INTENSITY: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, Reflect)]
+pub enum WindowModeSetting {
+    #[default]
+    Windowed,
+    BorderlessFullscreen,
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub fn next(self) -> Self {
+        match self {
+            WindowModeSetting::Windowed => WindowModeSetting::BorderlessFullscreen,
+            WindowModeSetting::BorderlessFullscreen => WindowModeSetting::Fullscreen,
+            WindowModeSetting::Fullscreen => WindowModeSetting::Windowed,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            WindowModeSetting::Windowed => "Windowed",
+            WindowModeSetting::BorderlessFullscreen => "Borderless",
+            WindowModeSetting::Fullscreen => "Fullscreen",
+        }
+    }
+
+    pub fn to_window_mode(self) -> WindowMode {
+        match self {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::BorderlessFullscreen => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen,
+        }
+    }
+}
+
+// Player-facing options. Missing fields in an older settings file fall back to their defaults.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct GameSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub show_damage_numbers: bool,
+    pub screen_shake_intensity: f32,
+    pub window_mode: WindowModeSetting,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            master_volume: 1.0,
+            music_volume: 1.0,
+            sfx_volume: 1.0,
+            show_damage_numbers: true,
+            screen_shake_intensity: 1.0,
+            window_mode: WindowModeSetting::Windowed,
+        }
+    }
+}
+
+impl GameSettings {
+    pub fn effective_music_volume(&self) -> f32 { self.master_volume * self.music_volume }
+    pub fn effective_sfx_volume(&self) -> f32 { self.master_volume * self.sfx_volume }
+
+    pub fn adjust_volume(value: &mut f32, delta: f32) {
+        // Round to the step so repeated presses don't drift (0.1 + 0.2 != 0.3)
+        *value = ((*value + delta) / VOLUME_STEP).round() * VOLUME_STEP;
+        *value = value.clamp(0.0, 1.0);
+    }
+
+    pub fn adjust_screen_shake(&mut self, delta: f32) {
+        self.screen_shake_intensity = (self.screen_shake_intensity + delta).clamp(0.0, MAX_SCREEN_SHAKE_INTENSITY);
+    }
+
+    pub fn to_ron_string(&self) -> Result<String, ron::Error> { persistence::to_ron_string(self) }
+    pub fn from_ron_str(contents: &str) -> Result<Self, ron::error::SpannedError> { persistence::from_ron_str(contents) }
+}
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app .register_type::<GameSettings>()
+            .insert_resource(persistence::load_ron_or_default::<GameSettings>(SETTINGS_FILE_PATH))
+            .add_systems(Update, (
+                apply_window_mode_system,
+                save_settings_on_change_system,
+            ).run_if(resource_changed::<GameSettings>()));
+    }
+}
+
+fn apply_window_mode_system(settings: Res<GameSettings>, mut window_query: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = window_query.get_single_mut() {
+        let desired_mode = settings.window_mode.to_window_mode();
+        if window.mode != desired_mode { window.mode = desired_mode; }
+    }
+}
+
+fn save_settings_on_change_system(settings: Res<GameSettings>) {
+    if settings.is_added() { return; } // Freshly loaded, nothing new to write
+    persistence::save_ron(SETTINGS_FILE_PATH, &*settings);
+}
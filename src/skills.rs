@@ -8,6 +8,8 @@ use crate::{
     horror::Horror,
     visual_effects::spawn_damage_text,
     audio::{PlaySoundEvent, SoundEffect},
+    elites::HorrorDamage,
+    run_stats::DamageSource,
 };
 use serde::{Serialize, Deserialize};
 
@@ -74,7 +76,7 @@ pub struct SkillDefinition {
     pub effect: SkillEffectType,
 }
 
-#[derive(Component, Debug, Clone, Reflect)]
+#[derive(Component, Debug, Clone, Reflect, Serialize, Deserialize)]
 pub struct ActiveSkillInstance {
     pub definition_id: SkillId,
     pub current_cooldown: Duration,
@@ -115,7 +117,7 @@ pub struct SkillProjectile {
 #[derive(Component, Debug)] pub struct SurvivorBuffEffect { pub speed_multiplier_bonus: f32, pub fire_rate_multiplier_bonus: f32, pub duration_timer: Timer, }
 
 #[derive(Component, Debug, Reflect, Default)] #[reflect(Component)]
-pub struct FreezingNovaEffect { pub damage: i32, pub radius_sq: f32, pub lifetime_timer: Timer, pub slow_multiplier: f32, pub slow_duration_secs: f32, pub already_hit_entities: Vec<Entity>, }
+pub struct FreezingNovaEffect { pub skill_id: SkillId, pub damage: i32, pub radius_sq: f32, pub lifetime_timer: Timer, pub slow_multiplier: f32, pub slow_duration_secs: f32, pub already_hit_entities: Vec<Entity>, }
 
 #[derive(Component, Debug, Reflect, Default)]
 #[reflect(Component)]
@@ -302,7 +304,7 @@ fn survivor_skill_input_system(
                     }
                     SkillEffectType::SurvivorBuff { speed_multiplier_bonus, fire_rate_multiplier_bonus, duration_secs } => { commands.entity(player_entity).insert(SurvivorBuffEffect { speed_multiplier_bonus: *speed_multiplier_bonus, fire_rate_multiplier_bonus: *fire_rate_multiplier_bonus, duration_timer: Timer::from_seconds(*duration_secs, TimerMode::Once), }); effect_was_triggered = true; }
                     SkillEffectType::SummonSentry { sentry_tick_interval_secs, sentry_duration_secs, sentry_color, .. } => { let sentry_spawn_position = player_transform.translation.truncate().extend(0.15); commands.spawn(( SpriteBundle { texture: asset_server.load("sprites/psychic_sentry_placeholder.png"), sprite: Sprite { custom_size: Some(Vec2::splat(effective_sentry_radius * 0.5)), color: *sentry_color, ..default() }, transform: Transform::from_translation(sentry_spawn_position), ..default() }, ActiveSkillAoEEffect { skill_id: skill_def.id, actual_damage_per_tick: sentry_damage_val, actual_radius_sq: effective_sentry_radius.powi(2), tick_timer: Timer::from_seconds(*sentry_tick_interval_secs, TimerMode::Repeating), lifetime_timer: Timer::from_seconds(*sentry_duration_secs, TimerMode::Once), already_hit_this_tick: Vec::new(), }, Name::new("PsychicSentry"), )); effect_was_triggered = true; }
-                    SkillEffectType::FreezingNova { nova_duration_secs, slow_multiplier, slow_duration_secs, color, .. } => { let nova_spawn_position = player_transform.translation; commands.spawn(( SpriteBundle { texture: asset_server.load("sprites/frost_nova_placeholder.png"), sprite: Sprite { custom_size: Some(Vec2::splat(0.1)), color: *color, ..default() }, transform: Transform::from_translation(nova_spawn_position.truncate().extend(0.25)), ..default() }, FreezingNovaEffect { damage: nova_damage_val, radius_sq: effective_nova_radius.powi(2), lifetime_timer: Timer::from_seconds(*nova_duration_secs, TimerMode::Once), slow_multiplier: *slow_multiplier, slow_duration_secs: *slow_duration_secs, already_hit_entities: Vec::new(), }, Name::new("GlacialNovaEffect"), )); effect_was_triggered = true; sound_event_writer.send(PlaySoundEvent(SoundEffect::RitualCast)); }
+                    SkillEffectType::FreezingNova { nova_duration_secs, slow_multiplier, slow_duration_secs, color, .. } => { let nova_spawn_position = player_transform.translation; commands.spawn(( SpriteBundle { texture: asset_server.load("sprites/frost_nova_placeholder.png"), sprite: Sprite { custom_size: Some(Vec2::splat(0.1)), color: *color, ..default() }, transform: Transform::from_translation(nova_spawn_position.truncate().extend(0.25)), ..default() }, FreezingNovaEffect { skill_id: skill_def.id, damage: nova_damage_val, radius_sq: effective_nova_radius.powi(2), lifetime_timer: Timer::from_seconds(*nova_duration_secs, TimerMode::Once), slow_multiplier: *slow_multiplier, slow_duration_secs: *slow_duration_secs, already_hit_entities: Vec::new(), }, Name::new("GlacialNovaEffect"), )); effect_was_triggered = true; sound_event_writer.send(PlaySoundEvent(SoundEffect::RitualCast)); }
                     SkillEffectType::TemporaryShield { duration_secs, .. } => {
                         commands.entity(player_entity).insert(ActiveShield {
                             amount: shield_amount,
@@ -385,6 +387,7 @@ fn skill_projectile_collision_system(
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
     skill_library: Res<SkillLibrary>,
     _player_query: Query<&Survivor>, 
+    mut horror_damage: HorrorDamage,
 ) {
     for (proj_entity, proj_g_transform, proj_damage, mut skill_projectile_data, proj_sprite) in skill_projectile_query.iter_mut() {
         if skill_projectile_data.already_hit_by_this_projectile.len() > (skill_projectile_data.piercing_left + skill_projectile_data.bounces_left + 5) as usize { commands.entity(proj_entity).despawn_recursive(); continue; }
@@ -396,7 +399,7 @@ fn skill_projectile_collision_system(
             let horror_radius = horror_data.size.x / 2.0;
             if proj_pos.distance(horror_pos) < proj_radius + horror_radius {
                 sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit));
-                horror_health.0 -= proj_damage.0;
+                horror_damage.apply(DamageSource::Skill(skill_projectile_data.skill_id), horror_entity, &mut horror_health, proj_damage.0);
                 spawn_damage_text(&mut commands, &asset_server, horror_g_transform.translation(), proj_damage.0, &time);
                 skill_projectile_data.already_hit_by_this_projectile.push(horror_entity);
                 if skill_projectile_data.piercing_left > 0 { skill_projectile_data.piercing_left -= 1; }
@@ -431,7 +434,7 @@ fn skill_projectile_collision_system(
     }
 }
 
-fn active_skill_aoe_system(mut commands: Commands, time: Res<Time>, mut aoe_query: Query<(Entity, &mut ActiveSkillAoEEffect, &GlobalTransform, Option<&mut Sprite>)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>,) { for (aoe_entity, mut aoe_effect, aoe_g_transform, opt_sprite) in aoe_query.iter_mut() { aoe_effect.lifetime_timer.tick(time.delta()); if let Some(mut sprite) = opt_sprite { let lifetime_remaining_fraction = 1.0 - aoe_effect.lifetime_timer.percent(); let initial_alpha = sprite.color.a(); sprite.color.set_a((initial_alpha * lifetime_remaining_fraction).clamp(0.0, initial_alpha)); } if aoe_effect.lifetime_timer.finished() { commands.entity(aoe_entity).despawn_recursive(); continue; } aoe_effect.tick_timer.tick(time.delta()); if aoe_effect.tick_timer.just_finished() { aoe_effect.already_hit_this_tick.clear(); let aoe_pos = aoe_g_transform.translation().truncate(); for (horror_entity, horror_g_transform, mut horror_health) in horror_query.iter_mut() { if aoe_effect.already_hit_this_tick.contains(&horror_entity) { continue; } let horror_pos = horror_g_transform.translation().truncate(); if horror_pos.distance_squared(aoe_pos) < aoe_effect.actual_radius_sq { sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); horror_health.0 -= aoe_effect.actual_damage_per_tick; spawn_damage_text(&mut commands, &asset_server, horror_g_transform.translation(), aoe_effect.actual_damage_per_tick, &time); aoe_effect.already_hit_this_tick.push(horror_entity); } } } } }
+fn active_skill_aoe_system(mut commands: Commands, time: Res<Time>, mut aoe_query: Query<(Entity, &mut ActiveSkillAoEEffect, &GlobalTransform, Option<&mut Sprite>)>, mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, asset_server: Res<AssetServer>, mut sound_event_writer: EventWriter<PlaySoundEvent>, mut horror_damage: HorrorDamage,) { for (aoe_entity, mut aoe_effect, aoe_g_transform, opt_sprite) in aoe_query.iter_mut() { aoe_effect.lifetime_timer.tick(time.delta()); if let Some(mut sprite) = opt_sprite { let lifetime_remaining_fraction = 1.0 - aoe_effect.lifetime_timer.percent(); let initial_alpha = sprite.color.a(); sprite.color.set_a((initial_alpha * lifetime_remaining_fraction).clamp(0.0, initial_alpha)); } if aoe_effect.lifetime_timer.finished() { commands.entity(aoe_entity).despawn_recursive(); continue; } aoe_effect.tick_timer.tick(time.delta()); if aoe_effect.tick_timer.just_finished() { aoe_effect.already_hit_this_tick.clear(); let aoe_pos = aoe_g_transform.translation().truncate(); for (horror_entity, horror_g_transform, mut horror_health) in horror_query.iter_mut() { if aoe_effect.already_hit_this_tick.contains(&horror_entity) { continue; } let horror_pos = horror_g_transform.translation().truncate(); if horror_pos.distance_squared(aoe_pos) < aoe_effect.actual_radius_sq { sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); horror_damage.apply(DamageSource::Skill(aoe_effect.skill_id), horror_entity, &mut horror_health, aoe_effect.actual_damage_per_tick); spawn_damage_text(&mut commands, &asset_server, horror_g_transform.translation(), aoe_effect.actual_damage_per_tick, &time); aoe_effect.already_hit_this_tick.push(horror_entity); } } } } }
 
 fn freezing_nova_effect_damage_system( 
     mut commands: Commands, 
@@ -440,6 +443,7 @@ fn freezing_nova_effect_damage_system(
     mut horror_query: Query<(Entity, &GlobalTransform, &mut Health, &mut Velocity, &Horror)>, 
     asset_server: Res<AssetServer>, 
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut horror_damage: HorrorDamage,
 ) { 
     for (nova_entity, mut nova, nova_g_transform, mut sprite, mut vis_transform) in nova_query.iter_mut() { 
         nova.lifetime_timer.tick(time.delta()); 
@@ -454,7 +458,7 @@ fn freezing_nova_effect_damage_system(
                 if nova.already_hit_entities.contains(&horror_entity) { continue; } 
                 let horror_pos = horror_g_transform.translation().truncate(); 
                 if horror_pos.distance_squared(nova_pos) < nova.radius_sq { 
-                    horror_health.0 -= nova.damage; 
+                    horror_damage.apply(DamageSource::Skill(nova.skill_id), horror_entity, &mut horror_health, nova.damage);
                     spawn_damage_text(&mut commands, &asset_server, horror_g_transform.translation(), nova.damage, &time); // Corrected typo: horror_gtransform to horror_g_transform
                     sound_event_writer.send(PlaySoundEvent(SoundEffect::RitualCast)); 
                     commands.entity(horror_entity).insert(crate::horror::Frozen { timer: Timer::from_seconds(nova.slow_duration_secs, TimerMode::Once), speed_multiplier: nova.slow_multiplier, }); 
@@ -467,6 +471,7 @@ fn freezing_nova_effect_damage_system(
     } 
 }
 
+#[allow(clippy::too_many_arguments)]
 fn active_channeled_beam_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -475,6 +480,7 @@ fn active_channeled_beam_system(
     asset_server: Res<AssetServer>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
     player_query: Query<&Transform, (With<Survivor>, Without<ActiveChanneledBeamComponent>)>, 
+    mut horror_damage: HorrorDamage,
 ) {
     let Ok(player_transform) = player_query.get_single() else { return; };
 
@@ -514,7 +520,7 @@ fn active_channeled_beam_system(
                     let perpendicular_dist = (vec_to_horror - projection_len * beam.direction).length();
                     if perpendicular_dist < (beam.width / 2.0 + horror_radius) {
                         sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit)); 
-                        horror_health.0 -= beam.actual_damage_per_tick;
+                        horror_damage.apply(DamageSource::Skill(beam.skill_id), horror_entity, &mut horror_health, beam.actual_damage_per_tick);
                         spawn_damage_text(&mut commands, &asset_server, horror_g_transform.translation(), beam.actual_damage_per_tick, &time);
                         beam.already_hit_this_tick.push(horror_entity);
                     }
@@ -2,16 +2,25 @@
 use bevy::{prelude::*, window::PrimaryWindow};
 use std::time::Duration;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     components::{Velocity, Health as ComponentHealth, PlayerSparkAuraComponent}, // Added PlayerSparkAuraComponent
-    game::{AppState, ItemCollectedEvent, SelectedCharacter},
+    game::{AppState, ItemCollectedEvent, SelectedCharacter, session_ending},
+    camera_systems::{ScreenShake, SURVIVOR_HIT_TRAUMA},
     automatic_projectiles::{spawn_automatic_projectile},
     items::AutomaticWeaponDefinition, 
     horror::Horror,
+    elites::{EliteAffix, EliteAffixes, HorrorDamage},
+    knockback::{KnockbackEvent, KnockbackOnHit},
     weapons::{CircleOfWarding, SwarmOfNightmares},
     audio::{PlaySoundEvent, SoundEffect},
-    skills::{ActiveSkillInstance, SkillLibrary, SkillId, SurvivorBuffEffect, ActiveShield},
+    profile::PlayerProfile,
+    characters::{CharacterLibrary, CharacterPassive},
+    skills::{ActiveSkillInstance, SkillLibrary, SurvivorBuffEffect, ActiveShield},
+    consumables::FrenzyBuff,
+    run_stats::SurvivorHealedEvent,
+    upgrades::{UpgradeId, STARTING_BANISH_CHARGES, STARTING_SKIP_CHARGES},
     items::{ItemId, ItemDrop, ItemLibrary, ItemEffect, RetaliationNovaEffect, AutomaticWeaponId, AutomaticWeaponLibrary, AttackTypeData}, 
 };
 
@@ -20,14 +29,16 @@ const XP_FOR_LEVEL: [u32; 10] = [100, 150, 250, 400, 600, 850, 1100, 1400, 1800,
 pub const BASE_PICKUP_RADIUS: f32 = 100.0;
 const PROJECTILE_SPREAD_ANGLE_DEGREES: f32 = 15.0;
 pub const INITIAL_SURVIVOR_MAX_HEALTH: i32 = 100;
+pub const BASE_CRIT_DAMAGE_MULTIPLIER: f32 = 1.5; // Crit damage cards add on top of this
+const ARMOR_SCALE: f32 = 100.0; // Armor equal to this halves incoming damage
 pub const BASE_SURVIVOR_SPEED: f32 = 250.0;
 const ITEM_COLLECTION_RADIUS: f32 = SURVIVOR_SIZE.x / 2.0 + crate::items::ITEM_DROP_SIZE.x / 2.0;
-const MIND_STRAIN_DEBUFF_DURATION: f32 = 5.0;
+pub const MIND_STRAIN_DEBUFF_DURATION: f32 = 5.0;
 const MIND_STRAIN_SPEED_REDUCTION_PER_STACK: f32 = 0.05;
-const MAX_MIND_STRAIN_STACKS: u32 = 4;
+pub const MAX_MIND_STRAIN_STACKS: u32 = 4;
 
 
-#[derive(Component)] pub struct SanityStrain { pub base_fire_rate_secs: f32, pub fire_timer: Timer, }
+#[derive(Component, Clone, Serialize, Deserialize)] pub struct SanityStrain { pub base_fire_rate_secs: f32, pub fire_timer: Timer, }
 
 #[derive(Component, Debug)]
 pub struct MindStrainDebuff {
@@ -35,8 +46,12 @@ pub struct MindStrainDebuff {
     pub timer: Timer,
 }
 
+// A horror's body or projectile landed on the survivor, whether or not a shield soaked the damage
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SurvivorHitEvent;
+
 pub struct SurvivorPlugin;
-#[derive(Component)]
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct Survivor {
     pub speed: f32, pub experience: u32, pub current_level_xp: u32, pub level: u32,
     pub aim_direction: Vec2, pub invincibility_timer: Timer,
@@ -96,6 +111,10 @@ pub struct Survivor {
     pub echoes_drop_rate_multiplier: f32,
     pub relic_drop_rate_multiplier: f32,
     pub free_skill_use_chance: f32,
+    #[serde(default)] pub luck: f32, // Percent; improves the reward chests the survivor opens
+    #[serde(default)] pub obols: u32, // Run currency, see merchant.rs
+    #[serde(default)] pub banished_upgrades: Vec<UpgradeId>, // Never offered again this run
+    #[serde(default)] pub picked_upgrades: Vec<UpgradeId>, // Every card taken this run, one entry per pick
 
     pub aura_size_per_kill_bonus_percent: f32,
     pub orbiter_speed_per_kill_bonus_percent: f32,
@@ -104,18 +123,32 @@ pub struct Survivor {
     pub orbiter_explosion_damage: u32,
     pub aura_debuff_enemies_damage_increase_percent: f32,
     pub equipped_weapon_definition: Option<AutomaticWeaponDefinition>,
+    pub reroll_charges: u32, // Spent on the level-up screen to redraw the offered cards
+    #[serde(default)] pub banish_charges: u32, // Free banishes, used before any obols are
+    #[serde(default)] pub skip_charges: u32, // Level-ups that can be passed over without taking a card
+    #[serde(default)] pub pending_level_ups: u32, // Levels reached whose card screens haven't been shown yet
+    pub character_passive: CharacterPassive,
 }
 
 impl Survivor {
     pub fn experience_to_next_level(&self) -> u32 { if self.level == 0 { return 0; } if (self.level as usize -1) < XP_FOR_LEVEL.len() { XP_FOR_LEVEL[self.level as usize - 1] } else { XP_FOR_LEVEL.last().unwrap_or(&2500) + (self.level - XP_FOR_LEVEL.len() as u32) * 500 } }
-    pub fn add_experience( &mut self, amount: u32, next_state_value: &mut NextState<AppState>, sound_event_writer: &mut EventWriter<PlaySoundEvent>,) { let actual_xp_gained = (amount as f32 * self.xp_gain_multiplier).round() as u32; self.current_level_xp += actual_xp_gained; self.experience += actual_xp_gained; while self.current_level_xp >= self.experience_to_next_level() && self.level > 0 { let needed = self.experience_to_next_level(); self.current_level_xp -= needed; self.level += 1; sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation)); next_state_value.set(AppState::LevelUp); if next_state_value.0 == Some(AppState::LevelUp) { break; } } }
+    pub fn add_experience( &mut self, amount: u32, next_state_value: &mut NextState<AppState>, sound_event_writer: &mut EventWriter<PlaySoundEvent>,) { if self.gain_experience(amount) > 0 { sound_event_writer.send(PlaySoundEvent(SoundEffect::Revelation)); next_state_value.set(AppState::LevelUp); } }
+    // Takes every level the experience pays for and queues a card screen for each; returns how many were gained
+    pub fn gain_experience(&mut self, amount: u32) -> u32 { let actual_xp_gained = (amount as f32 * self.xp_gain_multiplier).round() as u32; self.current_level_xp += actual_xp_gained; self.experience += actual_xp_gained; let mut levels_gained = 0; while self.current_level_xp >= self.experience_to_next_level() && self.level > 0 { let needed = self.experience_to_next_level(); self.current_level_xp -= needed; self.level += 1; let passive = self.character_passive; passive.apply_on_level_reached(self); levels_gained += 1; } self.pending_level_ups += levels_gained; levels_gained }
+    // Called once a level's card screen is done with; true while more queued levels are waiting
+    pub fn finish_level_up(&mut self) -> bool { self.pending_level_ups = self.pending_level_ups.saturating_sub(1); self.pending_level_ups > 0 }
     pub fn get_effective_pickup_radius(&self) -> f32 { BASE_PICKUP_RADIUS * self.pickup_radius_multiplier }
+    // Diminishing returns, and every hit that lands still stings for at least 1
+    // Capped at max endurance; returns what was actually restored, for SurvivorHealedEvent
+    pub fn heal(&self, health: &mut ComponentHealth, amount: i32) -> i32 { let before = health.0; health.0 = (health.0 + amount).min(self.max_health); (health.0 - before).max(0) }
+    pub fn armored_damage(&self, damage: i32) -> i32 { if damage <= 0 { return damage; } ((damage as f32 * ARMOR_SCALE / (ARMOR_SCALE + self.armor as f32)).round() as i32).max(1) }
+    pub fn roll_auto_attack_crit(&self, damage: i32, rng: &mut impl Rng) -> i32 { if self.auto_attack_crit_chance > 0.0 && rng.gen::<f32>() < self.auto_attack_crit_chance { (damage as f32 * (BASE_CRIT_DAMAGE_MULTIPLIER + self.auto_attack_crit_damage_multiplier)).round() as i32 } else { damage } }
 
     pub fn new_with_skills_items_and_weapon(
         initial_skills: Vec<ActiveSkillInstance>,
         initial_items: Vec<ItemId>,
         inherent_weapon_id: AutomaticWeaponId,
-        _weapon_library: &Res<AutomaticWeaponLibrary>,
+        _weapon_library: &AutomaticWeaponLibrary,
     ) -> Self {
         Self {
             speed: BASE_SURVIVOR_SPEED,
@@ -177,6 +210,10 @@ impl Survivor {
             echoes_drop_rate_multiplier: 1.0,
             relic_drop_rate_multiplier: 1.0,
             free_skill_use_chance: 0.0,
+            luck: 0.0,
+            obols: 0,
+            banished_upgrades: Vec::new(),
+            picked_upgrades: Vec::new(),
 
             aura_size_per_kill_bonus_percent: 0.0,
             orbiter_speed_per_kill_bonus_percent: 0.0,
@@ -185,6 +222,11 @@ impl Survivor {
             orbiter_explosion_damage: 0,
             aura_debuff_enemies_damage_increase_percent: 0.0,
             equipped_weapon_definition: None,
+            reroll_charges: 0,
+            banish_charges: STARTING_BANISH_CHARGES,
+            skip_charges: STARTING_SKIP_CHARGES,
+            pending_level_ups: 0,
+            character_passive: CharacterPassive::None,
         }
     }
 }
@@ -211,12 +253,12 @@ pub fn initialize_player_weapon_system(
     }
 }
 
-fn should_despawn_survivor(next_state: Res<NextState<AppState>>) -> bool { match next_state.0 { Some(AppState::GameOver) | Some(AppState::MainMenu) => true, _ => false, } }
 fn no_survivor_exists(survivor_query: Query<(), With<Survivor>>) -> bool { survivor_query.is_empty() }
 
 impl Plugin for SurvivorPlugin {
     fn build(&self, app: &mut App) {
         app .register_type::<PlayerSparkAuraComponent>() // Register the component
+            .add_event::<SurvivorHitEvent>()
             .add_systems(OnEnter(AppState::InGame), spawn_survivor.run_if(no_survivor_exists))
             .add_systems(Update, (
                 survivor_movement,
@@ -231,7 +273,8 @@ impl Plugin for SurvivorPlugin {
                 mind_strain_debuff_update_system,
                 manage_chain_lightning_aura_system, // Add the new system
             ).chain().run_if(in_state(AppState::InGame)))
-            .add_systems(OnExit(AppState::InGame), despawn_survivor.run_if(should_despawn_survivor));
+            .add_systems(OnExit(AppState::InGame), despawn_survivor.run_if(session_ending))
+            .add_systems(OnExit(AppState::Paused), despawn_survivor.run_if(session_ending));
     }
 }
 
@@ -298,20 +341,23 @@ fn manage_chain_lightning_aura_system(
 }
 
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_survivor(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     skill_library: Res<SkillLibrary>,
     weapon_library: Res<AutomaticWeaponLibrary>,
+    character_library: Res<CharacterLibrary>,
     selected_character: Res<SelectedCharacter>,
+    profile: Res<PlayerProfile>,
+    mut item_collected_writer: EventWriter<ItemCollectedEvent>,
 ) {
-    let mut initial_skills = Vec::new();
-    if let Some(_skill_def_bolt) = skill_library.get_skill_definition(SkillId(1)) {
-        let bolt_instance = ActiveSkillInstance::new(SkillId(1)); 
-        initial_skills.push(bolt_instance);
-    }
+    let Some(character) = character_library.get_character_definition(selected_character.0).or_else(|| character_library.characters.first()) else {
+        warn!("No character definitions loaded; cannot spawn a survivor.");
+        return;
+    };
 
-    let chosen_inherent_weapon_id = selected_character.0;
+    let chosen_inherent_weapon_id = character.starting_weapon_id;
     let mut initial_fire_rate = 0.5;
     let mut survivor_name = "Survivor (Unknown Class)".to_string();
     let mut fire_timer_mode = TimerMode::Repeating;
@@ -343,24 +389,19 @@ fn spawn_survivor(
             AttackTypeData::BlinkStrikeProjectile(params) => initial_fire_rate = params.base_fire_rate_secs,
             AttackTypeData::LobbedBouncingMagma(params) => initial_fire_rate = params.base_fire_rate_secs,
         }
-        survivor_name = format!("Survivor ({})", weapon_def.name);
+        survivor_name = format!("{} ({})", character.name, weapon_def.name);
     }
 
+    let mut survivor = character.create_survivor(&weapon_library);
+    survivor.equipped_skills.retain(|skill| skill_library.get_skill_definition(skill.definition_id).is_some());
+    profile.apply_permanent_upgrades(&mut survivor);
+    let starting_health = survivor.max_health;
+
 
     let survivor_entity_id = commands.spawn((
-        SpriteBundle {
-            texture: asset_server.load("sprites/survivor_placeholder.png"),
-            sprite: Sprite { custom_size: Some(SURVIVOR_SIZE), ..default() },
-            transform: Transform::from_xyz(0.0, 0.0, 1.0),
-            ..default()
-        },
-        Survivor::new_with_skills_items_and_weapon(
-            initial_skills,
-            Vec::new(),
-            chosen_inherent_weapon_id, 
-            &weapon_library
-        ),
-        ComponentHealth(INITIAL_SURVIVOR_MAX_HEALTH),
+        survivor_sprite_bundle(&asset_server, Vec2::ZERO),
+        survivor,
+        ComponentHealth(starting_health),
         Velocity(Vec2::ZERO),
         SanityStrain { 
             base_fire_rate_secs: initial_fire_rate,
@@ -371,17 +412,28 @@ fn spawn_survivor(
         Name::new(survivor_name), 
     )).id(); 
 
+    for relic_id in character.starting_relics.iter() { item_collected_writer.send(ItemCollectedEvent(*relic_id)); }
+
     info!("SM_DEBUG: Survivor spawned. Entity ID: {:?}, Chosen Weapon ID: {:?}", survivor_entity_id, chosen_inherent_weapon_id);
 }
+
+pub fn survivor_sprite_bundle(asset_server: &AssetServer, position: Vec2) -> SpriteBundle {
+    SpriteBundle {
+        texture: asset_server.load("sprites/survivor_placeholder.png"),
+        sprite: Sprite { custom_size: Some(SURVIVOR_SIZE), ..default() },
+        transform: Transform::from_translation(position.extend(1.0)),
+        ..default()
+    }
+}
 fn despawn_survivor(mut commands: Commands, survivor_query: Query<Entity, With<Survivor>>) { if let Ok(survivor_entity) = survivor_query.get_single() { commands.entity(survivor_entity).despawn_recursive(); } }
-fn survivor_health_regeneration_system(time: Res<Time>, mut query: Query<(&Survivor, &mut ComponentHealth)>,) { for (survivor_stats, mut current_health) in query.iter_mut() { if survivor_stats.health_regen_rate > 0.0 && current_health.0 > 0 && current_health.0 < survivor_stats.max_health { let regen_amount = survivor_stats.health_regen_rate * time.delta_seconds(); current_health.0 = (current_health.0 as f32 + regen_amount).round() as i32; current_health.0 = current_health.0.min(survivor_stats.max_health); } } }
+fn survivor_health_regeneration_system(time: Res<Time>, mut query: Query<(&Survivor, &mut ComponentHealth)>, mut healed_writer: EventWriter<SurvivorHealedEvent>,) { for (survivor_stats, mut current_health) in query.iter_mut() { if survivor_stats.health_regen_rate > 0.0 && current_health.0 > 0 && current_health.0 < survivor_stats.max_health { let regen_amount = survivor_stats.health_regen_rate * time.delta_seconds(); healed_writer.send(SurvivorHealedEvent(survivor_stats.heal(&mut current_health, regen_amount.round() as i32))); } } }
 
-fn survivor_movement(
+pub fn survivor_movement(
     keyboard_input: Res<Input<KeyCode>>,
-    mut query: Query<(&Survivor, &mut Transform, &mut Velocity, Option<&SurvivorBuffEffect>, Option<&MindStrainDebuff>)>,
+    mut query: Query<(&Survivor, &mut Transform, &mut Velocity, Option<&SurvivorBuffEffect>, Option<&MindStrainDebuff>, Option<&crate::horror::Frozen>)>,
     time: Res<Time>,
 ) {
-    for (survivor, mut transform, mut velocity, buff_effect_opt, mind_strain_opt) in query.iter_mut() {
+    for (survivor, mut transform, mut velocity, buff_effect_opt, mind_strain_opt, frozen_opt) in query.iter_mut() {
         let mut direction = Vec2::ZERO;
         if keyboard_input.pressed(KeyCode::A) { direction.x -= 1.0; }
         if keyboard_input.pressed(KeyCode::D) { direction.x += 1.0; }
@@ -396,6 +448,7 @@ fn survivor_movement(
             current_speed *= 1.0 - (debuff.stacks as f32 * MIND_STRAIN_SPEED_REDUCTION_PER_STACK);
             current_speed = current_speed.max(BASE_SURVIVOR_SPEED * 0.1);
         }
+        if let Some(frozen) = frozen_opt { current_speed *= frozen.speed_multiplier; } // Frost Nova elites
 
         velocity.0 = if direction != Vec2::ZERO { direction.normalize() * current_speed } else { Vec2::ZERO };
         transform.translation.x += velocity.0.x * time.delta_seconds();
@@ -423,7 +476,7 @@ fn survivor_casting_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
-    mut player_query: Query<(Entity, &Transform, &mut Survivor, Option<&mut SanityStrain>, Option<&SurvivorBuffEffect>)>, // Survivor is now &mut
+    mut player_query: Query<(Entity, &Transform, &mut Survivor, Option<&mut SanityStrain>, Option<&SurvivorBuffEffect>, Option<&FrenzyBuff>)>, // Survivor is now &mut
     mut channeling_status_query: Query<&mut crate::weapon_systems::IsChannelingComponent>, 
     charging_comp_query: Query<&crate::weapon_systems::ChargingWeaponComponent>,
     reticule_query: Query<(&GlobalTransform, &Parent), With<crate::weapon_systems::LobbedWeaponTargetReticuleComponent>>,
@@ -432,6 +485,8 @@ fn survivor_casting_system(
     weapon_library: Res<AutomaticWeaponLibrary>,
     mouse_button_input: Res<Input<MouseButton>>,
     mut log_timer: Local<Timer>,
+    mut horror_damage: HorrorDamage,
+    mut knockback_writer: EventWriter<KnockbackEvent>,
 ) {
     // Initialize the timer if it's the first run
     if log_timer.duration().as_secs_f32() == 0.0 {
@@ -449,7 +504,7 @@ fn survivor_casting_system(
         unsafe { PREV_PLAYER_COUNT = current_player_count };
     }
 
-    for (survivor_entity, survivor_transform, mut survivor_stats, opt_mut_sanity_strain, buff_effect_opt) in player_query.iter_mut() { // survivor_stats is now &mut
+    for (survivor_entity, survivor_transform, mut survivor_stats, opt_mut_sanity_strain, buff_effect_opt, frenzy_opt) in player_query.iter_mut() { // survivor_stats is now &mut
         static mut PREV_WEAPON_ID: Option<AutomaticWeaponId> = None;
         static mut PREV_SURVIVOR_ENTITY: Option<Entity> = None;
 
@@ -760,12 +815,13 @@ fn survivor_casting_system(
                         let color_to_use = level_params.projectile_color;
 
 
-                        crate::automatic_projectiles::spawn_automatic_projectile(
+                        let projectile = crate::automatic_projectiles::spawn_automatic_projectile(
                             &mut commands, &asset_server, survivor_entity, survivor_transform.translation, survivor_stats.aim_direction,
                             projectile_damage, projectile_speed, projectile_piercing, weapon_def.id,
                             sprite_path, level_params.projectile_size, color_to_use, shot_params.projectile_lifetime_secs,
                             None, None, None, None, None, None, None 
                         );
+                        if level_params.knockback > 0.0 { commands.entity(projectile).insert(KnockbackOnHit(level_params.knockback)); }
                     }
                     
                     commands.entity(survivor_entity).remove::<crate::weapon_systems::ChargingWeaponComponent>();
@@ -784,6 +840,9 @@ fn survivor_casting_system(
         if let Some(buff) = buff_effect_opt {
             effective_fire_rate_secs /= 1.0 + buff.fire_rate_multiplier_bonus;
         }
+        if let Some(frenzy) = frenzy_opt {
+            effective_fire_rate_secs /= 1.0 + frenzy.fire_rate_multiplier_bonus;
+        }
 
         let new_duration = Duration::from_secs_f32(effective_fire_rate_secs.max(0.05));
         if sanity_strain.fire_timer.duration() != new_duration {
@@ -1004,6 +1063,8 @@ fn survivor_casting_system(
                             &mut horror_query, // Pass as mutable reference
                             &time,
                             &mut sound_event_writer,
+                            &mut horror_damage,
+                            &mut knockback_writer,
                         );
                     }
                     AttackTypeData::ChainZap(_params) => {
@@ -1013,7 +1074,22 @@ fn survivor_casting_system(
                         if let Some(sound_path) = &params.fire_sound_effect {
                             sound_event_writer.send(PlaySoundEvent(SoundEffect::Path(sound_path.clone())));
                         }
-                        // TODO: Actual PointBlankNova logic (e.g., call a system or function)
+                        crate::weapon_systems::spawn_explosion_effect(
+                            &mut commands, &asset_server, survivor_transform.translation, params.damage, params.radius,
+                            params.nova_color, String::from("sprites/explosion_placeholder.png"), params.visual_duration_secs,
+                        );
+                        let survivor_pos = survivor_transform.translation.truncate();
+                        for (horror_entity, horror_transform, mut horror_health, _) in horror_query.iter_mut() {
+                            let offset = horror_transform.translation.truncate() - survivor_pos;
+                            if offset.length_squared() > params.radius * params.radius { continue; }
+                            horror_damage.apply(crate::run_stats::DamageSource::InherentWeapon, horror_entity, &mut horror_health, params.damage);
+                            crate::visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_transform.translation, params.damage, &time);
+                            commands.entity(horror_entity).insert(crate::horror::Frozen {
+                                timer: Timer::from_seconds(params.slow_duration_secs, TimerMode::Once),
+                                speed_multiplier: params.slow_effect_multiplier,
+                            });
+                            knockback_writer.send(KnockbackEvent { target: horror_entity, direction: offset, force: params.knockback });
+                        }
                     }
                     AttackTypeData::GroundTargetedAoE(params) => {
                         let mut final_target_pos = survivor_transform.translation + survivor_stats.aim_direction.extend(0.0) * params.targeting_range;
@@ -1062,19 +1138,23 @@ fn survivor_casting_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn survivor_horror_collision_system(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut survivor_query: Query<(Entity, &Transform, &mut ComponentHealth, &mut Survivor, Option<&mut ActiveShield>, Option<&mut MindStrainDebuff>)>,
-    horror_query: Query<(Entity, &Transform, &Horror)>,
+    horror_query: Query<(Entity, &Transform, &Horror, Option<&EliteAffixes>), Without<crate::props::Prop>>, // Props are harmless to bump into
+    mut horror_health_query: Query<&mut ComponentHealth, (With<Horror>, Without<Survivor>)>,
     item_library: Res<ItemLibrary>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut survivor_hit_writer: EventWriter<SurvivorHitEvent>,
+    mut screen_shake: ResMut<ScreenShake>,
 ) {
     if let Ok((survivor_entity, survivor_transform, mut survivor_health, mut survivor_component,
                mut opt_active_shield, mut opt_mind_strain)) = survivor_query.get_single_mut() {
         if !survivor_component.invincibility_timer.finished() { return; }
 
-        for (horror_entity, horror_transform, horror_stats) in horror_query.iter() {
+        for (horror_entity, horror_transform, horror_stats, elite_affixes) in horror_query.iter() {
             let distance = survivor_transform.translation.truncate().distance(horror_transform.translation.truncate());
             let survivor_radius = SURVIVOR_SIZE.x / 2.0;
             let horror_radius = horror_stats.size.x / 2.0;
@@ -1082,6 +1162,8 @@ fn survivor_horror_collision_system(
             if distance < survivor_radius + horror_radius {
                 if survivor_component.invincibility_timer.finished() {
                     sound_event_writer.send(PlaySoundEvent(SoundEffect::SurvivorHit));
+                    survivor_hit_writer.send(SurvivorHitEvent);
+                    screen_shake.add_trauma(SURVIVOR_HIT_TRAUMA);
 
                     if horror_stats.horror_type == crate::horror::HorrorType::MindLeech {
                         if let Some(debuff) = opt_mind_strain.as_mut() {
@@ -1095,7 +1177,7 @@ fn survivor_horror_collision_system(
                         }
                         commands.entity(horror_entity).despawn_recursive();
                     } else {
-                        let mut damage_to_take = horror_stats.damage_on_collision;
+                        let mut damage_to_take = survivor_component.armored_damage(horror_stats.damage_on_collision);
                         if let Some(ref mut shield) = opt_active_shield {
                             if shield.amount > 0 {
                                 let damage_absorbed = damage_to_take.min(shield.amount);
@@ -1109,6 +1191,11 @@ fn survivor_horror_collision_system(
                         if damage_to_take > 0 {
                             survivor_health.0 -= damage_to_take;
                         }
+                        if let Some(affixes) = elite_affixes.filter(|affixes| affixes.has(EliteAffix::Vampiric)) {
+                            if let Ok(mut horror_health) = horror_health_query.get_mut(horror_entity) {
+                                horror_health.0 = (horror_health.0 + affixes.vampiric_heal()).min(affixes.max_health);
+                            }
+                        }
                     }
 
                     survivor_component.invincibility_timer.reset();
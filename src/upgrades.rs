@@ -1,12 +1,14 @@
 // src/upgrades.rs
 use bevy::prelude::*;
-use rand::seq::SliceRandom;
+use rand::{seq::SliceRandom, Rng};
+use serde::{Deserialize, Serialize};
 use crate::{
     skills::SkillId,
+    survivor::Survivor,
     automatic_weapons, // Added this line
 };
 
-use crate::items::AutomaticWeaponId;
+use crate::items::{AutomaticWeaponId, ItemId};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
 #[reflect(Default)]
@@ -116,12 +118,13 @@ pub enum UpgradeType {
     IncreaseNightmareRotationSpeed(f32),
     
     // Active Skill Upgrades
-    IncreaseSkillDamage { slot_index: usize, amount: i32 }, 
-    ReduceSkillCooldown { slot_index: usize, percent_reduction: f32 }, 
-    IncreaseSkillAoERadius { slot_index: usize, percent_increase: f32 },
+    IncreaseSkillDamage { skill_id: SkillId, amount: i32 }, 
+    ReduceSkillCooldown { skill_id: SkillId, percent_reduction: f32 }, 
+    IncreaseSkillAoERadius { skill_id: SkillId, percent_increase: f32 },
     
     // Utility/Granting
     GrantRandomRelic, 
+    RestoreEndurance(i32), // Flat heal, capped at max endurance
     GrantSkill(SkillId),
 
     // --- Auto-Attack Focused (New Batch) ---
@@ -169,6 +172,7 @@ pub enum UpgradeType {
     IncreaseEchoesDropRate(f32), // Percent more echoes orbs
     IncreaseRelicDropRate(f32), // Percent higher chance for relics
     ChanceForFreeSkillUse(f32), // Percent chance
+    IncreaseLuck(f32), // Percent better reward chests
 
     // --- Weapon-Specific (Aura/Orbiter - Circle of Warding / Swarm of Nightmares) (New Batch) ---
     AuraIncreaseSizePerKill(f32), // Percent size increase stack
@@ -186,6 +190,54 @@ pub enum UpgradeType {
     ModifyOrbitingPet { weapon_id: AutomaticWeaponId, field: OrbitingPetField, change_value: f32, is_percentage: bool },
 }
 
+impl UpgradeType {
+    // The weapon a card tunes, for cards that only mean something with that weapon equipped
+    pub fn weapon_id(&self) -> Option<AutomaticWeaponId> {
+        match self {
+            UpgradeType::ModifyStandardProjectile { weapon_id, .. } |
+            UpgradeType::ModifyReturningProjectile { weapon_id, .. } |
+            UpgradeType::ModifyChanneledBeam { weapon_id, .. } |
+            UpgradeType::ModifyConeAttack { weapon_id, .. } |
+            UpgradeType::ModifyLobbedAoEPool { weapon_id, .. } |
+            UpgradeType::ModifyOrbitingPet { weapon_id, .. } => Some(*weapon_id),
+            _ => None,
+        }
+    }
+
+    // Cards stop being offered once taken this many times
+    pub fn max_stacks(&self) -> u32 {
+        match self {
+            UpgradeType::GrantSkill(_) => 1,
+            UpgradeType::RestoreEndurance(_) => u32::MAX, // The filler card never runs out
+            UpgradeType::InscribeCircleOfWarding | UpgradeType::ManifestSwarmOfNightmares | UpgradeType::GrantRandomRelic => 3,
+            _ => DEFAULT_MAX_STACKS,
+        }
+    }
+
+    // False for cards whose stat no system reads yet; they stay in the pool but are never offered
+    pub fn has_effect(&self) -> bool {
+        !matches!(self,
+            UpgradeType::AutoAttackAddFireDamage(_) | UpgradeType::AutoAttackAddColdDamage(_) | UpgradeType::AutoAttackAddLightningDamage(_) |
+            UpgradeType::AutoAttackAddPoisonDamage(_) |
+            UpgradeType::AutoAttackExecuteLowHealth(_) | UpgradeType::AutoAttackLifeSteal(_) | UpgradeType::AutoAttackChainChance(_) |
+            UpgradeType::AutoAttackForkChance(_) | UpgradeType::AutoAttackChillChance(_) | UpgradeType::AutoAttackStunChance(_) |
+            UpgradeType::AutoAttackBurnChance(_) | UpgradeType::AutoAttackReduceHealingChance(_) | UpgradeType::AutoAttackAreaDamageOnHitChance(_) |
+            UpgradeType::AutoAttackIncreaseDuration(_) | UpgradeType::AutoAttackHomingStrength(_) | UpgradeType::AutoAttackRicochetChance(_) |
+            UpgradeType::AutoAttackCullStrikeChance(_) |
+            UpgradeType::IncreaseEvasionChance(_) | UpgradeType::IncreaseBlockChance(_) |
+            UpgradeType::IncreaseDamageReduction(_) | UpgradeType::IncreaseTenacity(_) | UpgradeType::IncreaseStatusEffectResistance(_) |
+            UpgradeType::IncreaseHealingEffectiveness(_) | UpgradeType::OnHitGainTemporaryArmor(_) | UpgradeType::OnHitGainTemporarySpeed(_) |
+            UpgradeType::AfterBeingHitSpawnRetaliationNova(_) |
+            UpgradeType::IncreaseDashCharges(_) | UpgradeType::ReduceDashCooldown(_) | UpgradeType::IncreaseDashRange(_) |
+            UpgradeType::DashGrantsInvulnerability(_) | UpgradeType::IncreaseMovementOutOfCombat(_) | UpgradeType::ReduceSlowEffectiveness(_) |
+            UpgradeType::GainShieldOnKill(_) | UpgradeType::ChanceForFreeSkillUse(_) |
+            UpgradeType::AuraIncreaseSizePerKill(_) | UpgradeType::OrbiterIncreaseSpeedPerKill(_) | UpgradeType::AuraPullEnemiesChance(_) |
+            UpgradeType::OrbiterExplodeOnKillChance(_) | UpgradeType::AuraDebuffEnemies(_) |
+            UpgradeType::ModifyOrbitingPet { .. }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)]
 #[reflect(Default)]
 pub enum OrbitingPetField {
@@ -216,8 +268,43 @@ pub struct UpgradeCard {
     pub description: String,
     pub upgrade_type: UpgradeType,
     pub rarity: UpgradeRarity,
+    pub prerequisites: Vec<UpgradePrerequisite>, // All must hold before the card is offered
+    pub follow_ups: Vec<UpgradeId>, // Cards that stay locked until this one has been picked
 }
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default)] // Added Reflect and Default
+
+impl UpgradeCard {
+    // A card with no prerequisites and nothing chained after it; chain the `with_*` calls for the rest
+    pub fn new(id: UpgradeId, name: impl Into<String>, description: impl Into<String>, upgrade_type: UpgradeType, rarity: UpgradeRarity) -> Self {
+        Self { id, name: name.into(), description: description.into(), upgrade_type, rarity, prerequisites: Vec::new(), follow_ups: Vec::new() }
+    }
+    pub fn with_prerequisites(mut self, prerequisites: Vec<UpgradePrerequisite>) -> Self { self.prerequisites = prerequisites; self }
+    pub fn with_follow_ups(mut self, follow_ups: Vec<UpgradeId>) -> Self { self.follow_ups = follow_ups; self }
+}
+
+// Something the survivor must already have for a card to be worth offering
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub enum UpgradePrerequisite {
+    OwnsCard(UpgradeId),
+    OwnsRelic(ItemId),
+    WeaponEquipped(AutomaticWeaponId),
+    KnowsSkill(SkillId),
+    SkillInSlot { slot_index: usize, skill_id: SkillId }, // For cards tied to a kit's layout rather than to the skill itself
+    MinLevel(u32),
+}
+
+impl UpgradePrerequisite {
+    pub fn is_met(&self, context: &UpgradeContext) -> bool {
+        match self {
+            UpgradePrerequisite::OwnsCard(id) => context.picked.contains(id),
+            UpgradePrerequisite::OwnsRelic(item_id) => context.relics.contains(item_id),
+            UpgradePrerequisite::WeaponEquipped(weapon_id) => context.equipped_weapon == Some(*weapon_id),
+            UpgradePrerequisite::KnowsSkill(skill_id) => context.skills.contains(skill_id),
+            UpgradePrerequisite::SkillInSlot { slot_index, skill_id } => context.skills.get(*slot_index) == Some(skill_id),
+            UpgradePrerequisite::MinLevel(level) => context.level >= *level,
+        }
+    }
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Default, Serialize, Deserialize)] // Added Reflect and Default
 pub struct UpgradeId(pub u32);
 
 #[derive(Resource, Default, Reflect)] // Added Reflect
@@ -230,1233 +317,1346 @@ impl UpgradePool {
     pub fn initialize(&mut self) {
         self.available_upgrades = vec![
             // Survivor Stats
-            UpgradeCard {id: UpgradeId(0), name: "Borrowed Swiftness".to_string(), description: "Your limbs move with uncanny swiftness borrowed from beyond. +10% speed.".to_string(), upgrade_type: UpgradeType::SurvivorSpeed(10), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(1), name: "Flesh-Bound Pact".to_string(), description: "A pact seals your flesh against oblivion. +20 Max Endurance.".to_string(), upgrade_type: UpgradeType::MaxEndurance(20), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(5), name: "Otherworldly Agility".to_string(), description: "You glide like a creature not of this realm. +15% speed.".to_string(), upgrade_type: UpgradeType::SurvivorSpeed(15), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(6), name: "Resilient Corpus".to_string(), description: "Your form knits itself against harsher realities. +30 Max Endurance.".to_string(), upgrade_type: UpgradeType::MaxEndurance(30), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(300), name: "Unnatural Vigor".to_string(), description: "Reality warps to mend your wounds. Regenerate 0.5 Endurance/sec.".to_string(), upgrade_type: UpgradeType::EnduranceRegeneration(0.5), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(301), name: "Bound by Ichor".to_string(), description: "Strange energies sustain your form. Regenerate 1.0 Endurance/sec.".to_string(), upgrade_type: UpgradeType::EnduranceRegeneration(1.0), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(0), "Borrowed Swiftness", "Your limbs move with uncanny swiftness borrowed from beyond. +10% speed.", UpgradeType::SurvivorSpeed(10), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(1), "Flesh-Bound Pact", "A pact seals your flesh against oblivion. +20 Max Endurance.", UpgradeType::MaxEndurance(20), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(5), "Otherworldly Agility", "You glide like a creature not of this realm. +15% speed.", UpgradeType::SurvivorSpeed(15), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(6), "Resilient Corpus", "Your form knits itself against harsher realities. +30 Max Endurance.", UpgradeType::MaxEndurance(30), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(300), "Unnatural Vigor", "Reality warps to mend your wounds. Regenerate 0.5 Endurance/sec.", UpgradeType::EnduranceRegeneration(0.5), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(301), "Bound by Ichor", "Strange energies sustain your form. Regenerate 1.0 Endurance/sec.", UpgradeType::EnduranceRegeneration(1.0), UpgradeRarity::Regular),
 
             // Automatic Weapon (Main Attack)
-            UpgradeCard {id: UpgradeId(2), name: "Maddening Focus".to_string(), description: "Your automatic attacks strike with greater force. +5 Damage.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponDamage(5), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(3), name: "Rapid Sanity Strain".to_string(), description: "Your mind strains faster, casting automatic attacks more quickly. +15% fire rate.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponFireRate(15), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(4), name: "Swift Projectiles".to_string(), description: "Your automatic projectiles travel faster. +20% velocity.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponProjectileSpeed(20), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(7), name: "Piercing Thoughts".to_string(), description: "Your automatic attacks carry deeper malevolence. +8 Damage.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponDamage(8), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(8), name: "Hyper Reflex".to_string(), description: "Your mind strains with startling alacrity, casting automatic attacks faster. +20% fire rate.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponFireRate(20), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(9), name: "Unraveling Force".to_string(), description: "Your automatic projectiles tear through more horrors. Pierce +1 horror.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponPiercing(1), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(12), name: "Persistent Dread".to_string(), description: "Your automatic projectiles linger longer in reality. Pierce +2 horrors.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponPiercing(2), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(200), name: "Fractured Consciousness".to_string(), description: "Your mind splinters, projecting an additional automatic projectile. +1 Projectile.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponProjectiles(1), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(201), name: "Projectile Barrage".to_string(), description: "Your consciousness erupts, projecting two additional automatic projectiles. +2 Projectiles.".to_string(), upgrade_type: UpgradeType::IncreaseAutoWeaponProjectiles(2), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(2), "Maddening Focus", "Your automatic attacks strike with greater force. +5 Damage.", UpgradeType::IncreaseAutoWeaponDamage(5), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(3), "Rapid Sanity Strain", "Your mind strains faster, casting automatic attacks more quickly. +15% fire rate.", UpgradeType::IncreaseAutoWeaponFireRate(15), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(4), "Swift Projectiles", "Your automatic projectiles travel faster. +20% velocity.", UpgradeType::IncreaseAutoWeaponProjectileSpeed(20), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(7), "Piercing Thoughts", "Your automatic attacks carry deeper malevolence. +8 Damage.", UpgradeType::IncreaseAutoWeaponDamage(8), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(8), "Hyper Reflex", "Your mind strains with startling alacrity, casting automatic attacks faster. +20% fire rate.", UpgradeType::IncreaseAutoWeaponFireRate(20), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(9), "Unraveling Force", "Your automatic projectiles tear through more horrors. Pierce +1 horror.", UpgradeType::IncreaseAutoWeaponPiercing(1), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(12), "Persistent Dread", "Your automatic projectiles linger longer in reality. Pierce +2 horrors.", UpgradeType::IncreaseAutoWeaponPiercing(2), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(200), "Fractured Consciousness", "Your mind splinters, projecting an additional automatic projectile. +1 Projectile.", UpgradeType::IncreaseAutoWeaponProjectiles(1), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(201), "Projectile Barrage", "Your consciousness erupts, projecting two additional automatic projectiles. +2 Projectiles.", UpgradeType::IncreaseAutoWeaponProjectiles(2), UpgradeRarity::Regular),
 
             // Echoes (XP) & Pickups
-            UpgradeCard {id: UpgradeId(10), name: "Glimpse Beyond The Veil".to_string(), description: "Glimpses of the abyss accelerate your horrific understanding. +20% Echoes gain.".to_string(), upgrade_type: UpgradeType::EchoesGainMultiplier(20), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(11), name: "Soul Grasp".to_string(), description: "The echoes of fallen horrors are drawn to you. +25% Echoing Soul attraction radius.".to_string(), upgrade_type: UpgradeType::SoulAttractionRadius(25), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(13), name: "Abyssal Understanding".to_string(), description: "You perceive deeper truths, hastening your evolution. +30% Echoes gain.".to_string(), upgrade_type: UpgradeType::EchoesGainMultiplier(30), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(10), "Glimpse Beyond The Veil", "Glimpses of the abyss accelerate your horrific understanding. +20% Echoes gain.", UpgradeType::EchoesGainMultiplier(20), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(11), "Soul Grasp", "The echoes of fallen horrors are drawn to you. +25% Echoing Soul attraction radius.", UpgradeType::SoulAttractionRadius(25), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(13), "Abyssal Understanding", "You perceive deeper truths, hastening your evolution. +30% Echoes gain.", UpgradeType::EchoesGainMultiplier(30), UpgradeRarity::Regular),
             
             // Circle of Warding (Aura Weapon)
-            UpgradeCard {id: UpgradeId(100), name: "Inscribe Circle of Warding".to_string(), description: "Manifest an aura of protective, damaging glyphs.".to_string(), upgrade_type: UpgradeType::InscribeCircleOfWarding, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(101), name: "Echoing Wards".to_string(), description: "Your protective circle extends further. +20% circle radius.".to_string(), upgrade_type: UpgradeType::IncreaseCircleRadius(20), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(102), name: "Maddening Wards".to_string(), description: "Your circle inflicts greater mental anguish. +2 circle damage.".to_string(), upgrade_type: UpgradeType::IncreaseCircleDamage(2), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(103), name: "Frenzied Wards".to_string(), description: "Your circle pulses with greater frequency. Circle damages 15% faster.".to_string(), upgrade_type: UpgradeType::DecreaseCircleTickRate(15), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(100), "Inscribe Circle of Warding", "Manifest an aura of protective, damaging glyphs.", UpgradeType::InscribeCircleOfWarding, UpgradeRarity::Regular).with_follow_ups(vec![UpgradeId(101), UpgradeId(102), UpgradeId(103)]),
+            UpgradeCard::new(UpgradeId(101), "Echoing Wards", "Your protective circle extends further. +20% circle radius.", UpgradeType::IncreaseCircleRadius(20), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(102), "Maddening Wards", "Your circle inflicts greater mental anguish. +2 circle damage.", UpgradeType::IncreaseCircleDamage(2), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(103), "Frenzied Wards", "Your circle pulses with greater frequency. Circle damages 15% faster.", UpgradeType::DecreaseCircleTickRate(15), UpgradeRarity::Regular),
 
             // Swarm of Nightmares (Orbiter Weapon)
-            UpgradeCard {id: UpgradeId(400), name: "Manifest Swarm of Nightmares".to_string(), description: "Conjure 2 nightmare larva that orbit and attack foes.".to_string(), upgrade_type: UpgradeType::ManifestSwarmOfNightmares, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(401), name: "Grow the Nightmare Swarm".to_string(), description: "Add another Nightmare Larva to your psychic defenses. +1 nightmare.".to_string(), upgrade_type: UpgradeType::IncreaseNightmareCount(1), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(402), name: "Venomous Nightmares".to_string(), description: "Your Nightmare Larva inflict deeper wounds. +3 nightmare damage.".to_string(), upgrade_type: UpgradeType::IncreaseNightmareDamage(3), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(403), name: "Extended Nightmare Patrol".to_string(), description: "Your Nightmare Larva patrol a wider area. +15 orbit radius.".to_string(), upgrade_type: UpgradeType::IncreaseNightmareRadius(15.0), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(404), name: "Swifter Nightmares".to_string(), description: "Your Nightmare Larva move with increased speed. +0.5 rad/s orbit speed.".to_string(), upgrade_type: UpgradeType::IncreaseNightmareRotationSpeed(0.5), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(400), "Manifest Swarm of Nightmares", "Conjure 2 nightmare larva that orbit and attack foes.", UpgradeType::ManifestSwarmOfNightmares, UpgradeRarity::Regular).with_follow_ups(vec![UpgradeId(401), UpgradeId(402), UpgradeId(403), UpgradeId(404)]),
+            UpgradeCard::new(UpgradeId(401), "Grow the Nightmare Swarm", "Add another Nightmare Larva to your psychic defenses. +1 nightmare.", UpgradeType::IncreaseNightmareCount(1), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(402), "Venomous Nightmares", "Your Nightmare Larva inflict deeper wounds. +3 nightmare damage.", UpgradeType::IncreaseNightmareDamage(3), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(403), "Extended Nightmare Patrol", "Your Nightmare Larva patrol a wider area. +15 orbit radius.", UpgradeType::IncreaseNightmareRadius(15.0), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(404), "Swifter Nightmares", "Your Nightmare Larva move with increased speed. +0.5 rad/s orbit speed.", UpgradeType::IncreaseNightmareRotationSpeed(0.5), UpgradeRarity::Regular),
             
             // Skill Specific Upgrades
-            UpgradeCard {id: UpgradeId(500), name: "Empower Eldritch Bolt".to_string(), description: "Increase Eldritch Bolt damage by 10.".to_string(), upgrade_type: UpgradeType::IncreaseSkillDamage { slot_index: 0, amount: 10 }, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(501), name: "Intensify Mind Shatter".to_string(), description: "Mind Shatter fragments each deal +3 damage.".to_string(), upgrade_type: UpgradeType::IncreaseSkillDamage { slot_index: 1, amount: 3 }, rarity: UpgradeRarity::Regular,}, 
-            UpgradeCard {id: UpgradeId(502), name: "Sharpen Void Lance".to_string(), description: "Increase Void Lance damage by 20.".to_string(), upgrade_type: UpgradeType::IncreaseSkillDamage { slot_index: 2, amount: 20 }, rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(500), "Empower Eldritch Bolt", "Increase Eldritch Bolt damage by 10.", UpgradeType::IncreaseSkillDamage { skill_id: SkillId(1), amount: 10 }, UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(501), "Intensify Mind Shatter", "Mind Shatter fragments each deal +3 damage.", UpgradeType::IncreaseSkillDamage { skill_id: SkillId(2), amount: 3 }, UpgradeRarity::Regular), 
+            UpgradeCard::new(UpgradeId(502), "Sharpen Void Lance", "Increase Void Lance damage by 20.", UpgradeType::IncreaseSkillDamage { skill_id: SkillId(3), amount: 20 }, UpgradeRarity::Regular),
             
             // General/Utility
-            UpgradeCard {id: UpgradeId(600), name: "Mysterious Relic".to_string(), description: "The abyss grants you a random relic.".to_string(), upgrade_type: UpgradeType::GrantRandomRelic, rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(600), "Mysterious Relic", "The abyss grants you a random relic.", UpgradeType::GrantRandomRelic, UpgradeRarity::Regular),
 
             // Grant Skills
-            UpgradeCard {id: UpgradeId(700), name: "Learn: Mind Shatter".to_string(), description: "Unlock the Mind Shatter psychic burst skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(2)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(701), name: "Learn: Void Lance".to_string(), description: "Unlock the Void Lance piercing projectile skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(3)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(702), name: "Learn: Fleeting Agility".to_string(), description: "Unlock the Fleeting Agility self-buff skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(4)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(703), name: "Learn: Glacial Nova".to_string(), description: "Unlock the Glacial Nova chilling skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(5)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(704), name: "Learn: Psychic Sentry".to_string(), description: "Unlock the Psychic Sentry summon skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(6)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(705), name: "Learn: Ethereal Ward".to_string(), description: "Unlock the Ethereal Ward defensive skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(7)), rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(706), name: "Learn: Gaze of the Abyss".to_string(), description: "Unlock the Gaze of the Abyss channeled beam skill.".to_string(), upgrade_type: UpgradeType::GrantSkill(SkillId(8)), rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(700), "Learn: Mind Shatter", "Unlock the Mind Shatter psychic burst skill.", UpgradeType::GrantSkill(SkillId(2)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(701), "Learn: Void Lance", "Unlock the Void Lance piercing projectile skill.", UpgradeType::GrantSkill(SkillId(3)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(702), "Learn: Fleeting Agility", "Unlock the Fleeting Agility self-buff skill.", UpgradeType::GrantSkill(SkillId(4)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(703), "Learn: Glacial Nova", "Unlock the Glacial Nova chilling skill.", UpgradeType::GrantSkill(SkillId(5)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(704), "Learn: Psychic Sentry", "Unlock the Psychic Sentry summon skill.", UpgradeType::GrantSkill(SkillId(6)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(705), "Learn: Ethereal Ward", "Unlock the Ethereal Ward defensive skill.", UpgradeType::GrantSkill(SkillId(7)), UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(706), "Learn: Gaze of the Abyss", "Unlock the Gaze of the Abyss channeled beam skill.", UpgradeType::GrantSkill(SkillId(8)), UpgradeRarity::Regular),
 
 
             // Skill Meta Upgrades
-            UpgradeCard {id: UpgradeId(800), name: "Echoing Bolt".to_string(), description: "Eldritch Bolt recharges 15% faster.".to_string(), upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 0, percent_reduction: 0.15 }, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(801), name: "Focused Mind Shatter".to_string(), description: "Mind Shatter recharges 15% faster.".to_string(), upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 1, percent_reduction: 0.15 }, rarity: UpgradeRarity::Regular,}, 
-            UpgradeCard {id: UpgradeId(802), name: "Accelerated Void".to_string(), description: "Void Lance recharges 10% faster.".to_string(), upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 2, percent_reduction: 0.10 }, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(803), name: "Heightened Reflexes".to_string(), description: "Fleeting Agility recharges 10% faster.".to_string(), upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 3, percent_reduction: 0.10 }, rarity: UpgradeRarity::Regular,},
-            UpgradeCard {id: UpgradeId(804), name: "Cryo-Resonance".to_string(), description: "Glacial Nova recharges 10% faster.".to_string(), upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 4, percent_reduction: 0.10 }, rarity: UpgradeRarity::Regular,}, 
-            UpgradeCard {id: UpgradeId(805), name: "Expanded Chill".to_string(), description: "Glacial Nova's area of effect expands by 15%.".to_string(), upgrade_type: UpgradeType::IncreaseSkillAoERadius { slot_index: 4, percent_increase: 0.15 }, rarity: UpgradeRarity::Regular,},
+            UpgradeCard::new(UpgradeId(800), "Echoing Bolt", "Eldritch Bolt recharges 15% faster.", UpgradeType::ReduceSkillCooldown { skill_id: SkillId(1), percent_reduction: 0.15 }, UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(801), "Focused Mind Shatter", "Mind Shatter recharges 15% faster.", UpgradeType::ReduceSkillCooldown { skill_id: SkillId(2), percent_reduction: 0.15 }, UpgradeRarity::Regular), 
+            UpgradeCard::new(UpgradeId(802), "Accelerated Void", "Void Lance recharges 10% faster.", UpgradeType::ReduceSkillCooldown { skill_id: SkillId(3), percent_reduction: 0.10 }, UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(803), "Heightened Reflexes", "Fleeting Agility recharges 10% faster.", UpgradeType::ReduceSkillCooldown { skill_id: SkillId(4), percent_reduction: 0.10 }, UpgradeRarity::Regular),
+            UpgradeCard::new(UpgradeId(804), "Cryo-Resonance", "Glacial Nova recharges 10% faster.", UpgradeType::ReduceSkillCooldown { skill_id: SkillId(5), percent_reduction: 0.10 }, UpgradeRarity::Regular), 
+            UpgradeCard::new(UpgradeId(805), "Expanded Chill", "Glacial Nova's area of effect expands by 15%.", UpgradeType::IncreaseSkillAoERadius { skill_id: SkillId(5), percent_increase: 0.15 }, UpgradeRarity::Regular),
 
             // --- New Auto-Attack Upgrades with Rarity ---
 
             // AutoAttackDamagePercent (Base: 10.0)
-            UpgradeCard {
-                id: UpgradeId(900),
-                name: "Faint Malevolence".to_string(),
-                description: "Auto-attacks whisper of greater pain. +10 base damage bonus.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackDamagePercent(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(901),
-                name: "Resonant Malice".to_string(),
-                description: "Auto-attacks echo with amplified force. +20 base damage bonus.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackDamagePercent(10.0), // Base value remains 10.0
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(902),
-                name: "Screaming Hatred".to_string(),
-                description: "Auto-attacks become conduits of pure agony. +30 base damage bonus.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackDamagePercent(10.0), // Base value remains 10.0
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(900),
+                "Faint Malevolence",
+                "Auto-attacks whisper of greater pain. +10 base damage bonus.",
+                UpgradeType::AutoAttackDamagePercent(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(901),
+                "Resonant Malice",
+                "Auto-attacks echo with amplified force. +20 base damage bonus.",
+                UpgradeType::AutoAttackDamagePercent(10.0), // Base value remains 10.0
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(902),
+                "Screaming Hatred",
+                "Auto-attacks become conduits of pure agony. +30 base damage bonus.",
+                UpgradeType::AutoAttackDamagePercent(10.0), // Base value remains 10.0
+                UpgradeRarity::Legendary,
+            ),
 
             // AutoAttackSpeedPercent (Base: 10.0) -> Projectile Speed
-            UpgradeCard {
-                id: UpgradeId(903),
-                name: "Erratic Impulses".to_string(),
-                description: "Auto-attack projectiles gain a slight surge in speed. +10% projectile speed.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackSpeedPercent(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(904),
-                name: "Accelerated Trajectory".to_string(),
-                description: "Auto-attack projectiles are noticeably swifter. +20% projectile speed.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackSpeedPercent(10.0), // Base value
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(905),
-                name: "Warp-Speed Projectiles".to_string(),
-                description: "Auto-attack projectiles tear through reality. +30% projectile speed.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackSpeedPercent(10.0), // Base value
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(903),
+                "Erratic Impulses",
+                "Auto-attack projectiles gain a slight surge in speed. +10% projectile speed.",
+                UpgradeType::AutoAttackSpeedPercent(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(904),
+                "Accelerated Trajectory",
+                "Auto-attack projectiles are noticeably swifter. +20% projectile speed.",
+                UpgradeType::AutoAttackSpeedPercent(10.0), // Base value
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(905),
+                "Warp-Speed Projectiles",
+                "Auto-attack projectiles tear through reality. +30% projectile speed.",
+                UpgradeType::AutoAttackSpeedPercent(10.0), // Base value
+                UpgradeRarity::Legendary,
+            ),
 
             // AutoAttackFireRatePercent (Base: 10.0)
-            UpgradeCard {
-                id: UpgradeId(906),
-                name: "Hastened Thoughts".to_string(),
-                description: "The rhythm of your auto-attacks quickens slightly. +10% fire rate.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackFireRatePercent(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(907),
-                name: "Frenzied Volley".to_string(),
-                description: "Your auto-attacks unleash in a rapid succession. +20% fire rate.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackFireRatePercent(10.0), // Base value
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(908),
-                name: "Ceaseless Barrage".to_string(),
-                description: "Auto-attacks become a relentless storm. +30% fire rate.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackFireRatePercent(10.0), // Base value
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(906),
+                "Hastened Thoughts",
+                "The rhythm of your auto-attacks quickens slightly. +10% fire rate.",
+                UpgradeType::AutoAttackFireRatePercent(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(907),
+                "Frenzied Volley",
+                "Your auto-attacks unleash in a rapid succession. +20% fire rate.",
+                UpgradeType::AutoAttackFireRatePercent(10.0), // Base value
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(908),
+                "Ceaseless Barrage",
+                "Auto-attacks become a relentless storm. +30% fire rate.",
+                UpgradeType::AutoAttackFireRatePercent(10.0), // Base value
+                UpgradeRarity::Legendary,
+            ),
 
             // AutoAttackAddProjectiles (Base: 1)
-            UpgradeCard {
-                id: UpgradeId(909),
-                name: "Echoing Shot".to_string(),
-                description: "A faint echo follows your auto-attack. +1 projectile.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddProjectiles(1),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(910),
-                name: "Splintering Manifestation".to_string(),
-                description: "Your auto-attack splits into multiple vectors. +2 projectiles.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddProjectiles(1), // Base value
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(911),
-                name: "Myriad Assault".to_string(),
-                description: "Your auto-attack shatters into a cascade of force. +3 projectiles.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddProjectiles(1), // Base value
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(909),
+                "Echoing Shot",
+                "A faint echo follows your auto-attack. +1 projectile.",
+                UpgradeType::AutoAttackAddProjectiles(1),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(910),
+                "Splintering Manifestation",
+                "Your auto-attack splits into multiple vectors. +2 projectiles.",
+                UpgradeType::AutoAttackAddProjectiles(1), // Base value
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(911),
+                "Myriad Assault",
+                "Your auto-attack shatters into a cascade of force. +3 projectiles.",
+                UpgradeType::AutoAttackAddProjectiles(1), // Base value
+                UpgradeRarity::Legendary,
+            ),
 
             // AutoAttackAddPiercing (Base: 1)
-            UpgradeCard {
-                id: UpgradeId(912),
-                name: "Penetrating Whisper".to_string(),
-                description: "Your auto-attacks pierce an additional foe.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPiercing(1),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(913),
-                name: "Unseen Impalement".to_string(),
-                description: "Your auto-attacks pass through multiple horrors. +2 piercing.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPiercing(1), // Base value
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(914),
-                name: "Reality Rending Force".to_string(),
-                description: "Your auto-attacks ignore the forms of many foes. +3 piercing.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPiercing(1), // Base value
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(912),
+                "Penetrating Whisper",
+                "Your auto-attacks pierce an additional foe.",
+                UpgradeType::AutoAttackAddPiercing(1),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(913),
+                "Unseen Impalement",
+                "Your auto-attacks pass through multiple horrors. +2 piercing.",
+                UpgradeType::AutoAttackAddPiercing(1), // Base value
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(914),
+                "Reality Rending Force",
+                "Your auto-attacks ignore the forms of many foes. +3 piercing.",
+                UpgradeType::AutoAttackAddPiercing(1), // Base value
+                UpgradeRarity::Legendary,
+            ),
 
             // --- Batch 1: New Auto-Attack Focused Upgrades ---
 
             // 1. AutoAttackAddFireDamage (Base: 5)
-            UpgradeCard {
-                id: UpgradeId(915),
-                name: "Singeing Strike".to_string(),
-                description: "Auto-attacks inflict an additional 5 fire damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddFireDamage(5),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(916),
-                name: "Burning Lash".to_string(),
-                description: "Auto-attacks inflict an additional 10 fire damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddFireDamage(5),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(917),
-                name: "Inferno Brand".to_string(),
-                description: "Auto-attacks inflict an additional 15 fire damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddFireDamage(5),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(915),
+                "Singeing Strike",
+                "Auto-attacks inflict an additional 5 fire damage.",
+                UpgradeType::AutoAttackAddFireDamage(5),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(916),
+                "Burning Lash",
+                "Auto-attacks inflict an additional 10 fire damage.",
+                UpgradeType::AutoAttackAddFireDamage(5),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(917),
+                "Inferno Brand",
+                "Auto-attacks inflict an additional 15 fire damage.",
+                UpgradeType::AutoAttackAddFireDamage(5),
+                UpgradeRarity::Legendary,
+            ),
 
             // 2. AutoAttackAddColdDamage (Base: 5)
-            UpgradeCard {
-                id: UpgradeId(918),
-                name: "Chilling Touch".to_string(),
-                description: "Auto-attacks inflict an additional 5 cold damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddColdDamage(5),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(919),
-                name: "Freezing Blow".to_string(),
-                description: "Auto-attacks inflict an additional 10 cold damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddColdDamage(5),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(920),
-                name: "Glacial Spike".to_string(), // Note: name collision with a weapon, might need rename
-                description: "Auto-attacks inflict an additional 15 cold damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddColdDamage(5),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(918),
+                "Chilling Touch",
+                "Auto-attacks inflict an additional 5 cold damage.",
+                UpgradeType::AutoAttackAddColdDamage(5),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(919),
+                "Freezing Blow",
+                "Auto-attacks inflict an additional 10 cold damage.",
+                UpgradeType::AutoAttackAddColdDamage(5),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(920),
+                "Glacial Spike", // Note: name collision with a weapon, might need rename
+                "Auto-attacks inflict an additional 15 cold damage.",
+                UpgradeType::AutoAttackAddColdDamage(5),
+                UpgradeRarity::Legendary,
+            ),
 
             // 3. AutoAttackAddLightningDamage (Base: 5)
-            UpgradeCard {
-                id: UpgradeId(921),
-                name: "Sparking Hit".to_string(),
-                description: "Auto-attacks inflict an additional 5 lightning damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddLightningDamage(5),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(922),
-                name: "Shocking Jolt".to_string(),
-                description: "Auto-attacks inflict an additional 10 lightning damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddLightningDamage(5),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(923),
-                name: "Thunderclap Strike".to_string(),
-                description: "Auto-attacks inflict an additional 15 lightning damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddLightningDamage(5),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(921),
+                "Sparking Hit",
+                "Auto-attacks inflict an additional 5 lightning damage.",
+                UpgradeType::AutoAttackAddLightningDamage(5),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(922),
+                "Shocking Jolt",
+                "Auto-attacks inflict an additional 10 lightning damage.",
+                UpgradeType::AutoAttackAddLightningDamage(5),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(923),
+                "Thunderclap Strike",
+                "Auto-attacks inflict an additional 15 lightning damage.",
+                UpgradeType::AutoAttackAddLightningDamage(5),
+                UpgradeRarity::Legendary,
+            ),
 
             // 4. AutoAttackAddPoisonDamage (Base: 3 DPS)
-            UpgradeCard {
-                id: UpgradeId(924),
-                name: "Venomous Barb".to_string(),
-                description: "Auto-attacks apply poison, dealing 3 damage per second.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPoisonDamage(3),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(925),
-                name: "Toxic Sting".to_string(),
-                description: "Auto-attacks apply potent poison, dealing 6 damage per second.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPoisonDamage(3),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(926),
-                name: "Withering Contagion".to_string(),
-                description: "Auto-attacks apply virulent poison, dealing 9 damage per second.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAddPoisonDamage(3),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(924),
+                "Venomous Barb",
+                "Auto-attacks apply poison, dealing 3 damage per second.",
+                UpgradeType::AutoAttackAddPoisonDamage(3),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(925),
+                "Toxic Sting",
+                "Auto-attacks apply potent poison, dealing 6 damage per second.",
+                UpgradeType::AutoAttackAddPoisonDamage(3),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(926),
+                "Withering Contagion",
+                "Auto-attacks apply virulent poison, dealing 9 damage per second.",
+                UpgradeType::AutoAttackAddPoisonDamage(3),
+                UpgradeRarity::Legendary,
+            ),
 
             // 5. AutoAttackCritChance (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(927),
-                name: "Precise Incision".to_string(),
-                description: "Increases auto-attack critical strike chance by 5%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritChance(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(928),
-                name: "Keen Edge".to_string(),
-                description: "Increases auto-attack critical strike chance by 10%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritChance(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(929),
-                name: "Fatal Flaw".to_string(),
-                description: "Increases auto-attack critical strike chance by 15%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritChance(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(927),
+                "Precise Incision",
+                "Increases auto-attack critical strike chance by 5%.",
+                UpgradeType::AutoAttackCritChance(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(928),
+                "Keen Edge",
+                "Increases auto-attack critical strike chance by 10%.",
+                UpgradeType::AutoAttackCritChance(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(929),
+                "Fatal Flaw",
+                "Increases auto-attack critical strike chance by 15%.",
+                UpgradeType::AutoAttackCritChance(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 6. AutoAttackCritDamage (Base: 20.0%)
-            UpgradeCard {
-                id: UpgradeId(930),
-                name: "Forceful Blow".to_string(),
-                description: "Increases auto-attack critical strike damage by 20%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritDamage(20.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(931),
-                name: "Devastating Impact".to_string(),
-                description: "Increases auto-attack critical strike damage by 40%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritDamage(20.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(932),
-                name: "Obliterating Force".to_string(),
-                description: "Increases auto-attack critical strike damage by 60%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCritDamage(20.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(930),
+                "Forceful Blow",
+                "Increases auto-attack critical strike damage by 20%.",
+                UpgradeType::AutoAttackCritDamage(20.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(931),
+                "Devastating Impact",
+                "Increases auto-attack critical strike damage by 40%.",
+                UpgradeType::AutoAttackCritDamage(20.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(932),
+                "Obliterating Force",
+                "Increases auto-attack critical strike damage by 60%.",
+                UpgradeType::AutoAttackCritDamage(20.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 7. AutoAttackExecuteLowHealth (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(933),
-                name: "Finisher's Touch".to_string(),
-                description: "Auto-attacks have a chance to instantly kill enemies below 10% health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackExecuteLowHealth(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(934),
-                name: "Executioner's Precision".to_string(),
-                description: "Auto-attacks have a higher chance to instantly kill enemies below 20% health.".to_string(), // Description implies higher chance and threshold
-                upgrade_type: UpgradeType::AutoAttackExecuteLowHealth(10.0), // Base threshold
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(935),
-                name: "Reaper's Scythe".to_string(),
-                description: "Auto-attacks reliably instantly kill enemies below 30% health.".to_string(), // Description implies even higher chance and threshold
-                upgrade_type: UpgradeType::AutoAttackExecuteLowHealth(10.0), // Base threshold
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(933),
+                "Finisher's Touch",
+                "Auto-attacks have a chance to instantly kill enemies below 10% health.",
+                UpgradeType::AutoAttackExecuteLowHealth(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(934),
+                "Executioner's Precision",
+                "Auto-attacks have a higher chance to instantly kill enemies below 20% health.", // Description implies higher chance and threshold
+                UpgradeType::AutoAttackExecuteLowHealth(10.0), // Base threshold
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(935),
+                "Reaper's Scythe",
+                "Auto-attacks reliably instantly kill enemies below 30% health.", // Description implies even higher chance and threshold
+                UpgradeType::AutoAttackExecuteLowHealth(10.0), // Base threshold
+                UpgradeRarity::Legendary,
+            ),
 
             // 8. AutoAttackLifeSteal (Base: 2.0%)
-            UpgradeCard {
-                id: UpgradeId(936),
-                name: "Siphoning Strike".to_string(),
-                description: "Auto-attacks steal 2% of damage dealt as health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackLifeSteal(2.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(937),
-                name: "Vampiric Touch".to_string(),
-                description: "Auto-attacks steal 4% of damage dealt as health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackLifeSteal(2.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(938),
-                name: "Essence Drain".to_string(),
-                description: "Auto-attacks steal 6% of damage dealt as health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackLifeSteal(2.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(936),
+                "Siphoning Strike",
+                "Auto-attacks steal 2% of damage dealt as health.",
+                UpgradeType::AutoAttackLifeSteal(2.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(937),
+                "Vampiric Touch",
+                "Auto-attacks steal 4% of damage dealt as health.",
+                UpgradeType::AutoAttackLifeSteal(2.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(938),
+                "Essence Drain",
+                "Auto-attacks steal 6% of damage dealt as health.",
+                UpgradeType::AutoAttackLifeSteal(2.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 9. AutoAttackChainChance (Base: 15.0%)
-            UpgradeCard {
-                id: UpgradeId(939),
-                name: "Ricocheting Shard".to_string(),
-                description: "Auto-attacks have a 15% chance to chain to one additional enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChainChance(15.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(940),
-                name: "Chaining Bolts".to_string(),
-                description: "Auto-attacks have a 30% chance to chain to one additional enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChainChance(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(941),
-                name: "Arc Lightning".to_string(),
-                description: "Auto-attacks have a 45% chance to chain to one additional enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChainChance(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(939),
+                "Ricocheting Shard",
+                "Auto-attacks have a 15% chance to chain to one additional enemy.",
+                UpgradeType::AutoAttackChainChance(15.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(940),
+                "Chaining Bolts",
+                "Auto-attacks have a 30% chance to chain to one additional enemy.",
+                UpgradeType::AutoAttackChainChance(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(941),
+                "Arc Lightning",
+                "Auto-attacks have a 45% chance to chain to one additional enemy.",
+                UpgradeType::AutoAttackChainChance(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 10. AutoAttackForkChance (Base: 15.0%)
-            UpgradeCard {
-                id: UpgradeId(942),
-                name: "Splitting Missile".to_string(),
-                description: "Auto-attacks have a 15% chance to fork into two projectiles.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackForkChance(15.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(943),
-                name: "Diverging Force".to_string(),
-                description: "Auto-attacks have a 30% chance to fork into two projectiles.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackForkChance(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(944),
-                name: "Fracturing Barrage".to_string(),
-                description: "Auto-attacks have a 45% chance to fork into two projectiles.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackForkChance(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(942),
+                "Splitting Missile",
+                "Auto-attacks have a 15% chance to fork into two projectiles.",
+                UpgradeType::AutoAttackForkChance(15.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(943),
+                "Diverging Force",
+                "Auto-attacks have a 30% chance to fork into two projectiles.",
+                UpgradeType::AutoAttackForkChance(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(944),
+                "Fracturing Barrage",
+                "Auto-attacks have a 45% chance to fork into two projectiles.",
+                UpgradeType::AutoAttackForkChance(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 11. AutoAttackChillChance (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(945),
-                name: "Numbing Grasp".to_string(),
-                description: "Auto-attacks have a 10% chance to chill enemies, slowing them.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChillChance(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(946),
-                name: "Frostbite".to_string(),
-                description: "Auto-attacks have a 20% chance to chill enemies, slowing them.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChillChance(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(947),
-                name: "Absolute Zero".to_string(),
-                description: "Auto-attacks have a 30% chance to chill enemies, slowing them.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackChillChance(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(945),
+                "Numbing Grasp",
+                "Auto-attacks have a 10% chance to chill enemies, slowing them.",
+                UpgradeType::AutoAttackChillChance(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(946),
+                "Frostbite",
+                "Auto-attacks have a 20% chance to chill enemies, slowing them.",
+                UpgradeType::AutoAttackChillChance(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(947),
+                "Absolute Zero",
+                "Auto-attacks have a 30% chance to chill enemies, slowing them.",
+                UpgradeType::AutoAttackChillChance(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 12. AutoAttackStunChance (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(948),
-                name: "Concussive Force".to_string(),
-                description: "Auto-attacks have a 5% chance to briefly stun enemies.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackStunChance(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(949),
-                name: "Impact Trauma".to_string(),
-                description: "Auto-attacks have a 10% chance to briefly stun enemies.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackStunChance(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(950),
-                name: "Paralyzing Blow".to_string(),
-                description: "Auto-attacks have a 15% chance to briefly stun enemies.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackStunChance(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(948),
+                "Concussive Force",
+                "Auto-attacks have a 5% chance to briefly stun enemies.",
+                UpgradeType::AutoAttackStunChance(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(949),
+                "Impact Trauma",
+                "Auto-attacks have a 10% chance to briefly stun enemies.",
+                UpgradeType::AutoAttackStunChance(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(950),
+                "Paralyzing Blow",
+                "Auto-attacks have a 15% chance to briefly stun enemies.",
+                UpgradeType::AutoAttackStunChance(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 13. AutoAttackBurnChance (Base: 10.0%) - Distinct from flat fire, implies DoT
-            UpgradeCard {
-                id: UpgradeId(951),
-                name: "Igniting Spark".to_string(),
-                description: "Auto-attacks have a 10% chance to ignite enemies, dealing fire damage over time.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackBurnChance(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(952),
-                name: "Wildfire Spread".to_string(),
-                description: "Auto-attacks have a 20% chance to ignite enemies, dealing fire damage over time.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackBurnChance(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(953),
-                name: "Immolation".to_string(),
-                description: "Auto-attacks have a 30% chance to ignite enemies, dealing fire damage over time.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackBurnChance(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(951),
+                "Igniting Spark",
+                "Auto-attacks have a 10% chance to ignite enemies, dealing fire damage over time.",
+                UpgradeType::AutoAttackBurnChance(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(952),
+                "Wildfire Spread",
+                "Auto-attacks have a 20% chance to ignite enemies, dealing fire damage over time.",
+                UpgradeType::AutoAttackBurnChance(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(953),
+                "Immolation",
+                "Auto-attacks have a 30% chance to ignite enemies, dealing fire damage over time.",
+                UpgradeType::AutoAttackBurnChance(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 14. AutoAttackReduceHealingChance (Base: 25.0%)
-            UpgradeCard {
-                id: UpgradeId(954),
-                name: "Mortal Wound".to_string(),
-                description: "Auto-attacks have a 25% chance to reduce enemy healing effectiveness.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackReduceHealingChance(25.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(955),
-                name: "Grievous Affliction".to_string(),
-                description: "Auto-attacks have a 50% chance to reduce enemy healing effectiveness.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackReduceHealingChance(25.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(956),
-                name: "Curse of Frailty".to_string(),
-                description: "Auto-attacks have a 75% chance to reduce enemy healing effectiveness.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackReduceHealingChance(25.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(954),
+                "Mortal Wound",
+                "Auto-attacks have a 25% chance to reduce enemy healing effectiveness.",
+                UpgradeType::AutoAttackReduceHealingChance(25.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(955),
+                "Grievous Affliction",
+                "Auto-attacks have a 50% chance to reduce enemy healing effectiveness.",
+                UpgradeType::AutoAttackReduceHealingChance(25.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(956),
+                "Curse of Frailty",
+                "Auto-attacks have a 75% chance to reduce enemy healing effectiveness.",
+                UpgradeType::AutoAttackReduceHealingChance(25.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 15. AutoAttackAreaDamageOnHitChance (Base: 10.0% chance, 8 flat damage for AoE)
-            UpgradeCard {
-                id: UpgradeId(957),
-                name: "Shockwave Impact".to_string(),
-                description: "Auto-attacks have a 10% chance to create a small shockwave, dealing 8 area damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAreaDamageOnHitChance(8.0), // Storing AoE damage as base_val
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(958),
-                name: "Explosive Burst".to_string(),
-                description: "Auto-attacks have a 20% chance to create a small shockwave, dealing 16 area damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAreaDamageOnHitChance(8.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(959),
-                name: "Cataclysmic Eruption".to_string(),
-                description: "Auto-attacks have a 30% chance to create a small shockwave, dealing 24 area damage.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackAreaDamageOnHitChance(8.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(957),
+                "Shockwave Impact",
+                "Auto-attacks have a 10% chance to create a small shockwave, dealing 8 area damage.",
+                UpgradeType::AutoAttackAreaDamageOnHitChance(8.0), // Storing AoE damage as base_val
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(958),
+                "Explosive Burst",
+                "Auto-attacks have a 20% chance to create a small shockwave, dealing 16 area damage.",
+                UpgradeType::AutoAttackAreaDamageOnHitChance(8.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(959),
+                "Cataclysmic Eruption",
+                "Auto-attacks have a 30% chance to create a small shockwave, dealing 24 area damage.",
+                UpgradeType::AutoAttackAreaDamageOnHitChance(8.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 16. AutoAttackIncreaseDuration (Base: 15.0%)
-            UpgradeCard {
-                id: UpgradeId(960),
-                name: "Lingering Presence".to_string(),
-                description: "Increases auto-attack projectile lifetime by 15%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackIncreaseDuration(15.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(961),
-                name: "Extended Reach".to_string(),
-                description: "Increases auto-attack projectile lifetime by 30%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackIncreaseDuration(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(962),
-                name: "Eternal Pursuit".to_string(),
-                description: "Increases auto-attack projectile lifetime by 45%.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackIncreaseDuration(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(960),
+                "Lingering Presence",
+                "Increases auto-attack projectile lifetime by 15%.",
+                UpgradeType::AutoAttackIncreaseDuration(15.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(961),
+                "Extended Reach",
+                "Increases auto-attack projectile lifetime by 30%.",
+                UpgradeType::AutoAttackIncreaseDuration(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(962),
+                "Eternal Pursuit",
+                "Increases auto-attack projectile lifetime by 45%.",
+                UpgradeType::AutoAttackIncreaseDuration(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 17. AutoAttackHomingStrength (Base: 0.5)
-            UpgradeCard {
-                id: UpgradeId(963),
-                name: "Seeking Whispers".to_string(),
-                description: "Auto-attack projectiles gain a slight homing effect (strength 0.5).".to_string(),
-                upgrade_type: UpgradeType::AutoAttackHomingStrength(0.5),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(964),
-                name: "Guided Path".to_string(),
-                description: "Auto-attack projectiles gain a noticeable homing effect (strength 1.0).".to_string(),
-                upgrade_type: UpgradeType::AutoAttackHomingStrength(0.5),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(965),
-                name: "Inevitable Impact".to_string(),
-                description: "Auto-attack projectiles strongly home in on targets (strength 1.5).".to_string(),
-                upgrade_type: UpgradeType::AutoAttackHomingStrength(0.5),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(963),
+                "Seeking Whispers",
+                "Auto-attack projectiles gain a slight homing effect (strength 0.5).",
+                UpgradeType::AutoAttackHomingStrength(0.5),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(964),
+                "Guided Path",
+                "Auto-attack projectiles gain a noticeable homing effect (strength 1.0).",
+                UpgradeType::AutoAttackHomingStrength(0.5),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(965),
+                "Inevitable Impact",
+                "Auto-attack projectiles strongly home in on targets (strength 1.5).",
+                UpgradeType::AutoAttackHomingStrength(0.5),
+                UpgradeRarity::Legendary,
+            ),
 
             // 18. AutoAttackRicochetChance (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(966),
-                name: "Bouncing Shard".to_string(),
-                description: "Auto-attacks have a 10% chance to ricochet to a nearby enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackRicochetChance(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(967),
-                name: "Rebounding Force".to_string(),
-                description: "Auto-attacks have a 20% chance to ricochet to a nearby enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackRicochetChance(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(968),
-                name: "Unstoppable Deflection".to_string(),
-                description: "Auto-attacks have a 30% chance to ricochet to a nearby enemy.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackRicochetChance(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(966),
+                "Bouncing Shard",
+                "Auto-attacks have a 10% chance to ricochet to a nearby enemy.",
+                UpgradeType::AutoAttackRicochetChance(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(967),
+                "Rebounding Force",
+                "Auto-attacks have a 20% chance to ricochet to a nearby enemy.",
+                UpgradeType::AutoAttackRicochetChance(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(968),
+                "Unstoppable Deflection",
+                "Auto-attacks have a 30% chance to ricochet to a nearby enemy.",
+                UpgradeType::AutoAttackRicochetChance(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 19. AutoAttackShieldPenetration (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(969),
-                name: "Armor Piercer".to_string(),
-                description: "Auto-attacks ignore 10% of enemy shields/armor.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackShieldPenetration(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(970),
-                name: "Shield Breaker".to_string(),
-                description: "Auto-attacks ignore 20% of enemy shields/armor.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackShieldPenetration(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(971),
-                name: "Defense Shatterer".to_string(),
-                description: "Auto-attacks ignore 30% of enemy shields/armor.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackShieldPenetration(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(969),
+                "Armor Piercer",
+                "Auto-attacks ignore 10% of enemy shields/armor.",
+                UpgradeType::AutoAttackShieldPenetration(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(970),
+                "Shield Breaker",
+                "Auto-attacks ignore 20% of enemy shields/armor.",
+                UpgradeType::AutoAttackShieldPenetration(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(971),
+                "Defense Shatterer",
+                "Auto-attacks ignore 30% of enemy shields/armor.",
+                UpgradeType::AutoAttackShieldPenetration(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 20. AutoAttackCullStrikeChance (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(972),
-                name: "Mercy Stroke".to_string(),
-                description: "Auto-attacks have a 5% chance to deal massive damage to non-elite enemies below 15% health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCullStrikeChance(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(973),
-                name: "Decimator".to_string(),
-                description: "Auto-attacks have a 10% chance to deal massive damage to non-elite enemies below 15% health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCullStrikeChance(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(974),
-                name: "Annihilator".to_string(),
-                description: "Auto-attacks have a 15% chance to deal massive damage to non-elite enemies below 15% health.".to_string(),
-                upgrade_type: UpgradeType::AutoAttackCullStrikeChance(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(972),
+                "Mercy Stroke",
+                "Auto-attacks have a 5% chance to deal massive damage to non-elite enemies below 15% health.",
+                UpgradeType::AutoAttackCullStrikeChance(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(973),
+                "Decimator",
+                "Auto-attacks have a 10% chance to deal massive damage to non-elite enemies below 15% health.",
+                UpgradeType::AutoAttackCullStrikeChance(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(974),
+                "Annihilator",
+                "Auto-attacks have a 15% chance to deal massive damage to non-elite enemies below 15% health.",
+                UpgradeType::AutoAttackCullStrikeChance(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // --- Batch 2: Survivor Defensive Stats ---
 
             // 21. IncreaseArmor (Base: 10)
-            UpgradeCard {
-                id: UpgradeId(975),
-                name: "Reinforced Hide".to_string(),
-                description: "Your form becomes tougher, granting +10 armor.".to_string(),
-                upgrade_type: UpgradeType::IncreaseArmor(10),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(976),
-                name: "Ironclad Form".to_string(),
-                description: "Your resilience is greatly enhanced, granting +20 armor.".to_string(),
-                upgrade_type: UpgradeType::IncreaseArmor(10),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(977),
-                name: "Adamantine Body".to_string(),
-                description: "You become nearly impervious to harm, granting +30 armor.".to_string(),
-                upgrade_type: UpgradeType::IncreaseArmor(10),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(975),
+                "Reinforced Hide",
+                "Your form becomes tougher, granting +10 armor.",
+                UpgradeType::IncreaseArmor(10),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(976),
+                "Ironclad Form",
+                "Your resilience is greatly enhanced, granting +20 armor.",
+                UpgradeType::IncreaseArmor(10),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(977),
+                "Adamantine Body",
+                "You become nearly impervious to harm, granting +30 armor.",
+                UpgradeType::IncreaseArmor(10),
+                UpgradeRarity::Legendary,
+            ),
 
             // 22. IncreaseEvasionChance (Base: 3.0%)
-            UpgradeCard {
-                id: UpgradeId(978),
-                name: "Slight Shift".to_string(),
-                description: "You become slightly harder to hit. +3% evasion chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEvasionChance(3.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(979),
-                name: "Blurred Form".to_string(),
-                description: "Attacks sometimes pass through you. +6% evasion chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEvasionChance(3.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(980),
-                name: "Phase Walker".to_string(),
-                description: "You are exceptionally elusive. +9% evasion chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEvasionChance(3.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(978),
+                "Slight Shift",
+                "You become slightly harder to hit. +3% evasion chance.",
+                UpgradeType::IncreaseEvasionChance(3.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(979),
+                "Blurred Form",
+                "Attacks sometimes pass through you. +6% evasion chance.",
+                UpgradeType::IncreaseEvasionChance(3.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(980),
+                "Phase Walker",
+                "You are exceptionally elusive. +9% evasion chance.",
+                UpgradeType::IncreaseEvasionChance(3.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 23. IncreaseBlockChance (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(981),
-                name: "Guarded Stance".to_string(),
-                description: "You are more adept at blocking incoming attacks. +5% block chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseBlockChance(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(982),
-                name: "Reactive Barrier".to_string(),
-                description: "You instinctively deflect attacks. +10% block chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseBlockChance(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(983),
-                name: "Impenetrable Defense".to_string(),
-                description: "Few attacks can find their mark. +15% block chance.".to_string(),
-                upgrade_type: UpgradeType::IncreaseBlockChance(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(981),
+                "Guarded Stance",
+                "You are more adept at blocking incoming attacks. +5% block chance.",
+                UpgradeType::IncreaseBlockChance(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(982),
+                "Reactive Barrier",
+                "You instinctively deflect attacks. +10% block chance.",
+                UpgradeType::IncreaseBlockChance(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(983),
+                "Impenetrable Defense",
+                "Few attacks can find their mark. +15% block chance.",
+                UpgradeType::IncreaseBlockChance(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 24. IncreaseDamageReduction (Base: 2.0%)
-            UpgradeCard {
-                id: UpgradeId(984),
-                name: "Thick Skin".to_string(),
-                description: "Reduces all incoming damage by 2%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDamageReduction(2.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(985),
-                name: "Resilient Soul".to_string(),
-                description: "Reduces all incoming damage by 4%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDamageReduction(2.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(986),
-                name: "Aegis of Survival".to_string(),
-                description: "Reduces all incoming damage by 6%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDamageReduction(2.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(984),
+                "Thick Skin",
+                "Reduces all incoming damage by 2%.",
+                UpgradeType::IncreaseDamageReduction(2.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(985),
+                "Resilient Soul",
+                "Reduces all incoming damage by 4%.",
+                UpgradeType::IncreaseDamageReduction(2.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(986),
+                "Aegis of Survival",
+                "Reduces all incoming damage by 6%.",
+                UpgradeType::IncreaseDamageReduction(2.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 25. IncreaseTenacity (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(987),
-                name: "Steadfast Will".to_string(),
-                description: "Reduces the duration of crowd control effects on you by 10%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseTenacity(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(988),
-                name: "Unbreakable Mind".to_string(),
-                description: "Reduces the duration of crowd control effects on you by 20%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseTenacity(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(989),
-                name: "Indomitable Spirit".to_string(),
-                description: "Reduces the duration of crowd control effects on you by 30%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseTenacity(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(987),
+                "Steadfast Will",
+                "Reduces the duration of crowd control effects on you by 10%.",
+                UpgradeType::IncreaseTenacity(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(988),
+                "Unbreakable Mind",
+                "Reduces the duration of crowd control effects on you by 20%.",
+                UpgradeType::IncreaseTenacity(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(989),
+                "Indomitable Spirit",
+                "Reduces the duration of crowd control effects on you by 30%.",
+                UpgradeType::IncreaseTenacity(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 26. IncreaseStatusEffectResistance (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(990),
-                name: "Purified Blood".to_string(),
-                description: "Increases resistance to harmful status effects by 10%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseStatusEffectResistance(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(991),
-                name: "Warding Sigils".to_string(),
-                description: "Increases resistance to harmful status effects by 20%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseStatusEffectResistance(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(992),
-                name: "Aura of Purity".to_string(),
-                description: "Increases resistance to harmful status effects by 30%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseStatusEffectResistance(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(990),
+                "Purified Blood",
+                "Increases resistance to harmful status effects by 10%.",
+                UpgradeType::IncreaseStatusEffectResistance(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(991),
+                "Warding Sigils",
+                "Increases resistance to harmful status effects by 20%.",
+                UpgradeType::IncreaseStatusEffectResistance(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(992),
+                "Aura of Purity",
+                "Increases resistance to harmful status effects by 30%.",
+                UpgradeType::IncreaseStatusEffectResistance(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 27. IncreaseHealingEffectiveness (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(993),
-                name: "Vitality Boost".to_string(),
-                description: "Increases effectiveness of all healing received by 10%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseHealingEffectiveness(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(994),
-                name: "Rejuvenating Flow".to_string(),
-                description: "Increases effectiveness of all healing received by 20%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseHealingEffectiveness(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(995),
-                name: "Blessed Constitution".to_string(),
-                description: "Increases effectiveness of all healing received by 30%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseHealingEffectiveness(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(993),
+                "Vitality Boost",
+                "Increases effectiveness of all healing received by 10%.",
+                UpgradeType::IncreaseHealingEffectiveness(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(994),
+                "Rejuvenating Flow",
+                "Increases effectiveness of all healing received by 20%.",
+                UpgradeType::IncreaseHealingEffectiveness(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(995),
+                "Blessed Constitution",
+                "Increases effectiveness of all healing received by 30%.",
+                UpgradeType::IncreaseHealingEffectiveness(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 28. OnHitGainTemporaryArmor (Base: 15)
-            UpgradeCard {
-                id: UpgradeId(996),
-                name: "Reactive Plating".to_string(),
-                description: "Gain +15 armor for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporaryArmor(15),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(997),
-                name: "Adaptive Carapace".to_string(),
-                description: "Gain +30 armor for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporaryArmor(15),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(998),
-                name: "Instant Fortress".to_string(),
-                description: "Gain +45 armor for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporaryArmor(15),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(996),
+                "Reactive Plating",
+                "Gain +15 armor for a short duration when hit.",
+                UpgradeType::OnHitGainTemporaryArmor(15),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(997),
+                "Adaptive Carapace",
+                "Gain +30 armor for a short duration when hit.",
+                UpgradeType::OnHitGainTemporaryArmor(15),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(998),
+                "Instant Fortress",
+                "Gain +45 armor for a short duration when hit.",
+                UpgradeType::OnHitGainTemporaryArmor(15),
+                UpgradeRarity::Legendary,
+            ),
 
             // 29. OnHitGainTemporarySpeed (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(999),
-                name: "Adrenaline Rush".to_string(),
-                description: "Gain +10% movement speed for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporarySpeed(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1000),
-                name: "Escape Reflex".to_string(),
-                description: "Gain +20% movement speed for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporarySpeed(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1001),
-                name: "Phantom Step".to_string(),
-                description: "Gain +30% movement speed for a short duration when hit.".to_string(),
-                upgrade_type: UpgradeType::OnHitGainTemporarySpeed(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(999),
+                "Adrenaline Rush",
+                "Gain +10% movement speed for a short duration when hit.",
+                UpgradeType::OnHitGainTemporarySpeed(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1000),
+                "Escape Reflex",
+                "Gain +20% movement speed for a short duration when hit.",
+                UpgradeType::OnHitGainTemporarySpeed(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1001),
+                "Phantom Step",
+                "Gain +30% movement speed for a short duration when hit.",
+                UpgradeType::OnHitGainTemporarySpeed(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 30. AfterBeingHitSpawnRetaliationNova (Base: 20 damage)
-            UpgradeCard {
-                id: UpgradeId(1002),
-                name: "Painful Retort".to_string(),
-                description: "After being hit, release a nova dealing 20 damage to nearby enemies.".to_string(),
-                upgrade_type: UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1003),
-                name: "Vengeful Burst".to_string(),
-                description: "After being hit, release a powerful nova dealing 40 damage to nearby enemies.".to_string(),
-                upgrade_type: UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1004),
-                name: "Wrathful Detonation".to_string(),
-                description: "After being hit, release a devastating nova dealing 60 damage to nearby enemies.".to_string(),
-                upgrade_type: UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1002),
+                "Painful Retort",
+                "After being hit, release a nova dealing 20 damage to nearby enemies.",
+                UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1003),
+                "Vengeful Burst",
+                "After being hit, release a powerful nova dealing 40 damage to nearby enemies.",
+                UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1004),
+                "Wrathful Detonation",
+                "After being hit, release a devastating nova dealing 60 damage to nearby enemies.",
+                UpgradeType::AfterBeingHitSpawnRetaliationNova(20),
+                UpgradeRarity::Legendary,
+            ),
             
             // --- Batch 3: Survivor Utility/Mobility ---
 
             // 31. IncreaseDashCharges (Base: 1)
-            UpgradeCard {
-                id: UpgradeId(1005),
-                name: "Evasive Maneuver".to_string(),
-                description: "Gain an additional dash charge.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashCharges(1),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1006),
-                name: "Repeated Shift".to_string(),
-                description: "Gain 2 additional dash charges.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashCharges(1),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1007),
-                name: "Blink Master".to_string(),
-                description: "Gain 3 additional dash charges.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashCharges(1),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1005),
+                "Evasive Maneuver",
+                "Gain an additional dash charge.",
+                UpgradeType::IncreaseDashCharges(1),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1006),
+                "Repeated Shift",
+                "Gain 2 additional dash charges.",
+                UpgradeType::IncreaseDashCharges(1),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1007),
+                "Blink Master",
+                "Gain 3 additional dash charges.",
+                UpgradeType::IncreaseDashCharges(1),
+                UpgradeRarity::Legendary,
+            ),
 
             // 32. ReduceDashCooldown (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(1008),
-                name: "Quick Step".to_string(),
-                description: "Reduces dash cooldown by 10%.".to_string(),
-                upgrade_type: UpgradeType::ReduceDashCooldown(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1009),
-                name: "Rapid Evasion".to_string(),
-                description: "Reduces dash cooldown by 20%.".to_string(),
-                upgrade_type: UpgradeType::ReduceDashCooldown(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1010),
-                name: "Continuous Motion".to_string(),
-                description: "Reduces dash cooldown by 30%.".to_string(),
-                upgrade_type: UpgradeType::ReduceDashCooldown(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1008),
+                "Quick Step",
+                "Reduces dash cooldown by 10%.",
+                UpgradeType::ReduceDashCooldown(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1009),
+                "Rapid Evasion",
+                "Reduces dash cooldown by 20%.",
+                UpgradeType::ReduceDashCooldown(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1010),
+                "Continuous Motion",
+                "Reduces dash cooldown by 30%.",
+                UpgradeType::ReduceDashCooldown(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 33. IncreaseDashRange (Base: 15.0%)
-            UpgradeCard {
-                id: UpgradeId(1011),
-                name: "Extended Lunge".to_string(),
-                description: "Increases dash range by 15%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashRange(15.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1012),
-                name: "Far Reach".to_string(),
-                description: "Increases dash range by 30%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashRange(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1013),
-                name: "Dimension Strider".to_string(),
-                description: "Increases dash range by 45%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseDashRange(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1011),
+                "Extended Lunge",
+                "Increases dash range by 15%.",
+                UpgradeType::IncreaseDashRange(15.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1012),
+                "Far Reach",
+                "Increases dash range by 30%.",
+                UpgradeType::IncreaseDashRange(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1013),
+                "Dimension Strider",
+                "Increases dash range by 45%.",
+                UpgradeType::IncreaseDashRange(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 34. DashGrantsInvulnerability (Base: 0.1s)
-            UpgradeCard {
-                id: UpgradeId(1014),
-                name: "Fleeting Invincibility".to_string(),
-                description: "Dashing grants 0.1 seconds of invulnerability.".to_string(),
-                upgrade_type: UpgradeType::DashGrantsInvulnerability(0.1),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1015),
-                name: "Phasing Dash".to_string(),
-                description: "Dashing grants 0.2 seconds of invulnerability.".to_string(),
-                upgrade_type: UpgradeType::DashGrantsInvulnerability(0.1),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1016),
-                name: "Ghostly Evasion".to_string(),
-                description: "Dashing grants 0.3 seconds of invulnerability.".to_string(),
-                upgrade_type: UpgradeType::DashGrantsInvulnerability(0.1),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1014),
+                "Fleeting Invincibility",
+                "Dashing grants 0.1 seconds of invulnerability.",
+                UpgradeType::DashGrantsInvulnerability(0.1),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1015),
+                "Phasing Dash",
+                "Dashing grants 0.2 seconds of invulnerability.",
+                UpgradeType::DashGrantsInvulnerability(0.1),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1016),
+                "Ghostly Evasion",
+                "Dashing grants 0.3 seconds of invulnerability.",
+                UpgradeType::DashGrantsInvulnerability(0.1),
+                UpgradeRarity::Legendary,
+            ),
 
             // 35. IncreaseMovementOutOfCombat (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(1017),
-                name: "Swift Explorer".to_string(),
-                description: "Increases movement speed by 10% when out of combat.".to_string(),
-                upgrade_type: UpgradeType::IncreaseMovementOutOfCombat(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1018),
-                name: "Pathfinder's Pace".to_string(),
-                description: "Increases movement speed by 20% when out of combat.".to_string(),
-                upgrade_type: UpgradeType::IncreaseMovementOutOfCombat(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1019),
-                name: "Wind Runner".to_string(),
-                description: "Increases movement speed by 30% when out of combat.".to_string(),
-                upgrade_type: UpgradeType::IncreaseMovementOutOfCombat(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1017),
+                "Swift Explorer",
+                "Increases movement speed by 10% when out of combat.",
+                UpgradeType::IncreaseMovementOutOfCombat(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1018),
+                "Pathfinder's Pace",
+                "Increases movement speed by 20% when out of combat.",
+                UpgradeType::IncreaseMovementOutOfCombat(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1019),
+                "Wind Runner",
+                "Increases movement speed by 30% when out of combat.",
+                UpgradeType::IncreaseMovementOutOfCombat(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 36. ReduceSlowEffectiveness (Base: 15.0%)
-            UpgradeCard {
-                id: UpgradeId(1020),
-                name: "Surefooted".to_string(),
-                description: "Reduces effectiveness of slows on you by 15%.".to_string(),
-                upgrade_type: UpgradeType::ReduceSlowEffectiveness(15.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1021),
-                name: "Unwavering Stride".to_string(),
-                description: "Reduces effectiveness of slows on you by 30%.".to_string(),
-                upgrade_type: UpgradeType::ReduceSlowEffectiveness(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1022),
-                name: "Freedom of Movement".to_string(),
-                description: "Reduces effectiveness of slows on you by 45%.".to_string(),
-                upgrade_type: UpgradeType::ReduceSlowEffectiveness(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1020),
+                "Surefooted",
+                "Reduces effectiveness of slows on you by 15%.",
+                UpgradeType::ReduceSlowEffectiveness(15.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1021),
+                "Unwavering Stride",
+                "Reduces effectiveness of slows on you by 30%.",
+                UpgradeType::ReduceSlowEffectiveness(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1022),
+                "Freedom of Movement",
+                "Reduces effectiveness of slows on you by 45%.",
+                UpgradeType::ReduceSlowEffectiveness(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 37. GainShieldOnKill (Base: 5)
-            UpgradeCard {
-                id: UpgradeId(1023),
-                name: "Ephemeral Ward".to_string(),
-                description: "Gain a 5 point shield for a short duration on killing an enemy.".to_string(),
-                upgrade_type: UpgradeType::GainShieldOnKill(5),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1024),
-                name: "Soul Barrier".to_string(),
-                description: "Gain a 10 point shield for a short duration on killing an enemy.".to_string(),
-                upgrade_type: UpgradeType::GainShieldOnKill(5),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1025),
-                name: "Spirit Fortress".to_string(),
-                description: "Gain a 15 point shield for a short duration on killing an enemy.".to_string(),
-                upgrade_type: UpgradeType::GainShieldOnKill(5),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1023),
+                "Ephemeral Ward",
+                "Gain a 5 point shield for a short duration on killing an enemy.",
+                UpgradeType::GainShieldOnKill(5),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1024),
+                "Soul Barrier",
+                "Gain a 10 point shield for a short duration on killing an enemy.",
+                UpgradeType::GainShieldOnKill(5),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1025),
+                "Spirit Fortress",
+                "Gain a 15 point shield for a short duration on killing an enemy.",
+                UpgradeType::GainShieldOnKill(5),
+                UpgradeRarity::Legendary,
+            ),
 
             // 38. IncreaseEchoesDropRate (Base: 10.0%)
-            UpgradeCard {
-                id: UpgradeId(1026),
-                name: "Echo Collector".to_string(),
-                description: "Increases the drop rate of Echoes by 10%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEchoesDropRate(10.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1027),
-                name: "Abyssal Magnet".to_string(),
-                description: "Increases the drop rate of Echoes by 20%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEchoesDropRate(10.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1028),
-                name: "Soul Harvester".to_string(),
-                description: "Increases the drop rate of Echoes by 30%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseEchoesDropRate(10.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1026),
+                "Echo Collector",
+                "Increases the drop rate of Echoes by 10%.",
+                UpgradeType::IncreaseEchoesDropRate(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1027),
+                "Abyssal Magnet",
+                "Increases the drop rate of Echoes by 20%.",
+                UpgradeType::IncreaseEchoesDropRate(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1028),
+                "Soul Harvester",
+                "Increases the drop rate of Echoes by 30%.",
+                UpgradeType::IncreaseEchoesDropRate(10.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 39. IncreaseRelicDropRate (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(1029),
-                name: "Fortune Seeker".to_string(),
-                description: "Slightly increases the chance of finding Relics by 5%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseRelicDropRate(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1030),
-                name: "Relic Hunter".to_string(),
-                description: "Moderately increases the chance of finding Relics by 10%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseRelicDropRate(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1031),
-                name: "Treasure Master".to_string(),
-                description: "Significantly increases the chance of finding Relics by 15%.".to_string(),
-                upgrade_type: UpgradeType::IncreaseRelicDropRate(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1029),
+                "Fortune Seeker",
+                "Slightly increases the chance of finding Relics by 5%.",
+                UpgradeType::IncreaseRelicDropRate(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1030),
+                "Relic Hunter",
+                "Moderately increases the chance of finding Relics by 10%.",
+                UpgradeType::IncreaseRelicDropRate(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1031),
+                "Treasure Master",
+                "Significantly increases the chance of finding Relics by 15%.",
+                UpgradeType::IncreaseRelicDropRate(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 40. ChanceForFreeSkillUse (Base: 3.0%)
-            UpgradeCard {
-                id: UpgradeId(1032),
-                name: "Moment of Clarity".to_string(),
-                description: "Skills have a 3% chance to not consume resources or cooldown.".to_string(),
-                upgrade_type: UpgradeType::ChanceForFreeSkillUse(3.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1033),
-                name: "Inspired Casting".to_string(),
-                description: "Skills have a 6% chance to not consume resources or cooldown.".to_string(),
-                upgrade_type: UpgradeType::ChanceForFreeSkillUse(3.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1034),
-                name: "Transcendent Power".to_string(),
-                description: "Skills have a 9% chance to not consume resources or cooldown.".to_string(),
-                upgrade_type: UpgradeType::ChanceForFreeSkillUse(3.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1032),
+                "Moment of Clarity",
+                "Skills have a 3% chance to not consume resources or cooldown.",
+                UpgradeType::ChanceForFreeSkillUse(3.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1033),
+                "Inspired Casting",
+                "Skills have a 6% chance to not consume resources or cooldown.",
+                UpgradeType::ChanceForFreeSkillUse(3.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1034),
+                "Transcendent Power",
+                "Skills have a 9% chance to not consume resources or cooldown.",
+                UpgradeType::ChanceForFreeSkillUse(3.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // --- Batch 4: Weapon-Specific (Aura/Orbiter) ---
 
             // 41. AuraIncreaseSizePerKill (Base: 1.0%)
-            UpgradeCard {
-                id: UpgradeId(1035),
-                name: "Consuming Aura".to_string(),
-                description: "Aura grows by 1% per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::AuraIncreaseSizePerKill(1.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1036),
-                name: "Devouring Expanse".to_string(),
-                description: "Aura grows by 2% per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::AuraIncreaseSizePerKill(1.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1037),
-                name: "All-Encompassing Void".to_string(),
-                description: "Aura grows by 3% per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::AuraIncreaseSizePerKill(1.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1035),
+                "Consuming Aura",
+                "Aura grows by 1% per kill for a short duration (stacks).",
+                UpgradeType::AuraIncreaseSizePerKill(1.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1036),
+                "Devouring Expanse",
+                "Aura grows by 2% per kill for a short duration (stacks).",
+                UpgradeType::AuraIncreaseSizePerKill(1.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1037),
+                "All-Encompassing Void",
+                "Aura grows by 3% per kill for a short duration (stacks).",
+                UpgradeType::AuraIncreaseSizePerKill(1.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 42. OrbiterIncreaseSpeedPerKill (Base: 1.0%)
-            UpgradeCard {
-                id: UpgradeId(1038),
-                name: "Frenzied Orbiters".to_string(),
-                description: "Orbiters gain 1% speed per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1039),
-                name: "Accelerated Swarm".to_string(),
-                description: "Orbiters gain 2% speed per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1040),
-                name: "Blinding Whirlwind".to_string(),
-                description: "Orbiters gain 3% speed per kill for a short duration (stacks).".to_string(),
-                upgrade_type: UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1038),
+                "Frenzied Orbiters",
+                "Orbiters gain 1% speed per kill for a short duration (stacks).",
+                UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1039),
+                "Accelerated Swarm",
+                "Orbiters gain 2% speed per kill for a short duration (stacks).",
+                UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1040),
+                "Blinding Whirlwind",
+                "Orbiters gain 3% speed per kill for a short duration (stacks).",
+                UpgradeType::OrbiterIncreaseSpeedPerKill(1.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 43. AuraPullEnemiesChance (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(1041),
-                name: "Weakening Grasp".to_string(),
-                description: "Aura has a 5% chance each tick to pull weak enemies closer.".to_string(),
-                upgrade_type: UpgradeType::AuraPullEnemiesChance(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1042),
-                name: "Binding Field".to_string(),
-                description: "Aura has a 10% chance each tick to pull weak enemies closer.".to_string(),
-                upgrade_type: UpgradeType::AuraPullEnemiesChance(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1043),
-                name: "Singularity Effect".to_string(),
-                description: "Aura has a 15% chance each tick to pull weak enemies closer.".to_string(),
-                upgrade_type: UpgradeType::AuraPullEnemiesChance(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1041),
+                "Weakening Grasp",
+                "Aura has a 5% chance each tick to pull weak enemies closer.",
+                UpgradeType::AuraPullEnemiesChance(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1042),
+                "Binding Field",
+                "Aura has a 10% chance each tick to pull weak enemies closer.",
+                UpgradeType::AuraPullEnemiesChance(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1043),
+                "Singularity Effect",
+                "Aura has a 15% chance each tick to pull weak enemies closer.",
+                UpgradeType::AuraPullEnemiesChance(5.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 44. OrbiterExplodeOnKillChance (Base: 10.0% chance, 15 damage)
-            UpgradeCard {
-                id: UpgradeId(1044),
-                name: "Unstable Orbiters".to_string(),
-                description: "Orbiters have a 10% chance to explode on killing an enemy, dealing 15 damage.".to_string(),
-                upgrade_type: UpgradeType::OrbiterExplodeOnKillChance(15.0), // Storing explosion damage
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1045),
-                name: "Volatile Swarm".to_string(),
-                description: "Orbiters have a 20% chance to explode on killing an enemy, dealing 30 damage.".to_string(),
-                upgrade_type: UpgradeType::OrbiterExplodeOnKillChance(15.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1046),
-                name: "Chain Reaction Nightmares".to_string(),
-                description: "Orbiters have a 30% chance to explode on killing an enemy, dealing 45 damage.".to_string(),
-                upgrade_type: UpgradeType::OrbiterExplodeOnKillChance(15.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1044),
+                "Unstable Orbiters",
+                "Orbiters have a 10% chance to explode on killing an enemy, dealing 15 damage.",
+                UpgradeType::OrbiterExplodeOnKillChance(15.0), // Storing explosion damage
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1045),
+                "Volatile Swarm",
+                "Orbiters have a 20% chance to explode on killing an enemy, dealing 30 damage.",
+                UpgradeType::OrbiterExplodeOnKillChance(15.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1046),
+                "Chain Reaction Nightmares",
+                "Orbiters have a 30% chance to explode on killing an enemy, dealing 45 damage.",
+                UpgradeType::OrbiterExplodeOnKillChance(15.0),
+                UpgradeRarity::Legendary,
+            ),
 
             // 45. AuraDebuffEnemies (Base: 5.0%)
-            UpgradeCard {
-                id: UpgradeId(1047),
-                name: "Weakening Presence".to_string(),
-                description: "Enemies within your aura take 5% increased damage.".to_string(),
-                upgrade_type: UpgradeType::AuraDebuffEnemies(5.0),
-                rarity: UpgradeRarity::Regular,
-            },
-            UpgradeCard {
-                id: UpgradeId(1048),
-                name: "Curse of Vulnerability".to_string(),
-                description: "Enemies within your aura take 10% increased damage.".to_string(),
-                upgrade_type: UpgradeType::AuraDebuffEnemies(5.0),
-                rarity: UpgradeRarity::Rare,
-            },
-            UpgradeCard {
-                id: UpgradeId(1049),
-                name: "Mark of the Abyss".to_string(),
-                description: "Enemies within your aura take 15% increased damage.".to_string(),
-                upgrade_type: UpgradeType::AuraDebuffEnemies(5.0),
-                rarity: UpgradeRarity::Legendary,
-            },
+            UpgradeCard::new(
+                UpgradeId(1047),
+                "Weakening Presence",
+                "Enemies within your aura take 5% increased damage.",
+                UpgradeType::AuraDebuffEnemies(5.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1048),
+                "Curse of Vulnerability",
+                "Enemies within your aura take 10% increased damage.",
+                UpgradeType::AuraDebuffEnemies(5.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1049),
+                "Mark of the Abyss",
+                "Enemies within your aura take 15% increased damage.",
+                UpgradeType::AuraDebuffEnemies(5.0),
+                UpgradeRarity::Legendary,
+            ),
+
+            // IncreaseLuck (Base: 10.0%)
+            UpgradeCard::new(
+                UpgradeId(1050),
+                "Gambler's Omen",
+                "Reward chests open a little richer. +10% Luck.",
+                UpgradeType::IncreaseLuck(10.0),
+                UpgradeRarity::Regular,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1051),
+                "Favour of the Void",
+                "Reward chests open richer. +20% Luck.",
+                UpgradeType::IncreaseLuck(10.0),
+                UpgradeRarity::Rare,
+            ),
+            UpgradeCard::new(
+                UpgradeId(1052),
+                "Star-Touched",
+                "Reward chests overflow with wonders. +30% Luck.",
+                UpgradeType::IncreaseLuck(10.0),
+                UpgradeRarity::Legendary,
+            ).with_prerequisites(vec![UpgradePrerequisite::MinLevel(10)]),
 
         ];
         let specific_weapon_upgrades = automatic_weapons::get_all_specific_weapon_upgrades();
         self.available_upgrades.extend(specific_weapon_upgrades);
     }
-    pub fn get_random_upgrades(&self, count: usize) -> Vec<UpgradeCard> { let mut rng = rand::thread_rng(); self.available_upgrades.choose_multiple(&mut rng, count).cloned().collect() }
+    pub fn get_random_upgrades(&self, count: usize) -> Vec<UpgradeCard> { draw_upgrades(&self.available_upgrades, count, 0.0, &mut rand::thread_rng()) }
+    pub fn eligible_upgrades(&self, context: &UpgradeContext) -> Vec<UpgradeCard> { self.available_upgrades.iter().filter(|card| is_upgrade_eligible(card, context) && self.is_unlocked(card, context)).cloned().collect() }
+    pub fn get_card(&self, id: UpgradeId) -> Option<&UpgradeCard> { self.available_upgrades.iter().find(|card| card.id == id) }
+    // The cards whose follow-ups include this one
+    pub fn unlocked_by(&self, id: UpgradeId) -> impl Iterator<Item = &UpgradeCard> { self.available_upgrades.iter().filter(move |card| card.follow_ups.contains(&id)) }
+    // How a card sits in its chain, for the level-up screen: what led to it and what picking it opens up
+    pub fn chain_summary(&self, card: &UpgradeCard) -> Option<String> {
+        let follows: Vec<&str> = self.unlocked_by(card.id).map(|parent| parent.name.as_str())
+            .chain(card.prerequisites.iter().filter_map(|prerequisite| match prerequisite { UpgradePrerequisite::OwnsCard(id) => self.get_card(*id).map(|owned| owned.name.as_str()), _ => None }))
+            .collect();
+        let unlocks: Vec<&str> = card.follow_ups.iter().filter_map(|id| self.get_card(*id)).map(|follow_up| follow_up.name.as_str()).collect();
+        let mut lines = Vec::new();
+        if !follows.is_empty() { lines.push(format!("Follows: {}", follows.join(", "))); }
+        if !unlocks.is_empty() { lines.push(format!("Unlocks: {}", unlocks.join(", "))); }
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+    // A follow-up card is locked until any one of the cards leading to it has been picked
+    pub fn is_unlocked(&self, card: &UpgradeCard, context: &UpgradeContext) -> bool {
+        let mut parents = self.unlocked_by(card.id).peekable();
+        parents.peek().is_none() || parents.any(|parent| context.picked.contains(&parent.id))
+    }
+    // The hand dealt on a level-up: eligible cards only, rarer ones more likely the luckier the survivor
+    // Never an empty hand: with nothing left to offer, a plain heal keeps the level-up screen passable
+    pub fn offer_upgrades<R: Rng + ?Sized>(&self, count: usize, context: &UpgradeContext, rng: &mut R) -> Vec<UpgradeCard> {
+        let eligible = self.eligible_upgrades(context);
+        if eligible.is_empty() { return vec![filler_upgrade()]; }
+        draw_upgrades(&eligible, count, context.luck, rng)
+    }
+}
+
+pub const DEFAULT_MAX_STACKS: u32 = 5;
+pub const MAX_EQUIPPED_SKILLS: usize = 5; // As in apply_chosen_upgrade's GrantSkill
+pub const STARTING_BANISH_CHARGES: u32 = 1;
+pub const STARTING_SKIP_CHARGES: u32 = 1;
+pub const FILLER_UPGRADE_ID: UpgradeId = UpgradeId(9000);
+const FILLER_HEAL_AMOUNT: i32 = 30;
+
+pub fn filler_upgrade() -> UpgradeCard {
+    UpgradeCard::new(FILLER_UPGRADE_ID, "Moment of Respite", format!("Nothing more stirs in the abyss. Restore {} Endurance.", FILLER_HEAL_AMOUNT), UpgradeType::RestoreEndurance(FILLER_HEAL_AMOUNT), UpgradeRarity::Regular)
+}
+
+// What the survivor has going for them when a hand is dealt
+#[derive(Debug, Clone, Default)]
+pub struct UpgradeContext {
+    pub equipped_weapon: Option<AutomaticWeaponId>,
+    pub skills: Vec<SkillId>,
+    pub picked: Vec<UpgradeId>,
+    pub banished: Vec<UpgradeId>,
+    pub relics: Vec<ItemId>,
+    pub level: u32,
+    pub luck: f32,
+}
+
+impl UpgradeContext {
+    pub fn for_survivor(survivor: &Survivor) -> Self {
+        Self {
+            equipped_weapon: survivor.equipped_weapon_definition.as_ref().map(|def| def.id),
+            skills: survivor.equipped_skills.iter().map(|skill| skill.definition_id).collect(),
+            picked: survivor.picked_upgrades.clone(),
+            banished: survivor.banished_upgrades.clone(),
+            relics: survivor.collected_item_ids.clone(),
+            level: survivor.level,
+            luck: survivor.luck,
+        }
+    }
+    pub fn times_picked(&self, id: UpgradeId) -> u32 { self.picked.iter().filter(|picked| **picked == id).count() as u32 }
+}
+
+pub fn is_upgrade_eligible(card: &UpgradeCard, context: &UpgradeContext) -> bool {
+    if !card.upgrade_type.has_effect() || context.banished.contains(&card.id) || context.times_picked(card.id) >= card.upgrade_type.max_stacks() { return false; }
+    if !card.prerequisites.iter().all(|prerequisite| prerequisite.is_met(context)) { return false; }
+    if card.upgrade_type.weapon_id().is_some_and(|weapon_id| context.equipped_weapon != Some(weapon_id)) { return false; }
+    match &card.upgrade_type {
+        UpgradeType::GrantSkill(skill_id) => !context.skills.contains(skill_id) && context.skills.len() < MAX_EQUIPPED_SKILLS,
+        UpgradeType::IncreaseSkillDamage { skill_id, .. } | UpgradeType::ReduceSkillCooldown { skill_id, .. } | UpgradeType::IncreaseSkillAoERadius { skill_id, .. } => context.skills.contains(skill_id),
+        _ => true,
+    }
+}
+
+// Luck only ever helps, and helps legendaries the most
+pub fn rarity_weight(rarity: UpgradeRarity, luck: f32) -> f32 {
+    let luck_factor = 1.0 + luck.max(0.0) / 100.0;
+    match rarity {
+        UpgradeRarity::Regular => 60.0,
+        UpgradeRarity::Rare => 30.0 * luck_factor,
+        UpgradeRarity::Legendary => 10.0 * luck_factor * luck_factor,
+    }
+}
+
+pub fn draw_upgrades<R: Rng + ?Sized>(cards: &[UpgradeCard], count: usize, luck: f32, rng: &mut R) -> Vec<UpgradeCard> {
+    cards.choose_multiple_weighted(rng, count.min(cards.len()), |card| rarity_weight(card.rarity, luck)).map(|chosen| chosen.cloned().collect()).unwrap_or_default()
 }
 
 #[derive(Component, Debug, Clone, Reflect, Default)] // Added Default
@@ -1472,6 +1672,7 @@ impl Plugin for UpgradePlugin {
             .register_type::<UpgradeRarity>()
             .register_type::<UpgradeType>()
             .register_type::<UpgradeCard>()
+            .register_type::<UpgradePrerequisite>()
             .register_type::<UpgradeId>()
             .register_type::<UpgradePool>()
             .register_type::<OfferedUpgrades>()
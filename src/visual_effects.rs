@@ -2,6 +2,7 @@
 use bevy::prelude::*;
 use rand::Rng; // Added import for gen_range
 use crate::components::{Lifetime, Velocity, ExpandingWaveVisual}; // Added ExpandingWaveVisual
+use crate::settings::GameSettings;
 
 const DAMAGE_TEXT_LIFETIME: f32 = 0.75;
 const DAMAGE_TEXT_VELOCITY_Y: f32 = 50.0;
@@ -15,6 +16,7 @@ impl Plugin for VisualEffectsPlugin {
             damage_text_movement_system,
             damage_text_fade_despawn_system, // Combined fade and despawn
             expanding_wave_visual_system, // Added new system
+            damage_text_visibility_system,
         ));
     }
 }
@@ -84,6 +86,12 @@ fn damage_text_fade_despawn_system(
     }
 }
 
+// Damage numbers are still spawned when disabled so toggling the setting applies to ones already on screen.
+fn damage_text_visibility_system(settings: Res<GameSettings>, mut query: Query<&mut Visibility, With<DamageText>>) {
+    let desired_visibility = if settings.show_damage_numbers { Visibility::Inherited } else { Visibility::Hidden };
+    for mut visibility in query.iter_mut() { visibility.set_if_neq(desired_visibility); }
+}
+
 pub fn expanding_wave_visual_system(
     mut query: Query<(
         &mut Transform,
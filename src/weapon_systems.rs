@@ -18,6 +18,10 @@ use crate::horror::Horror;
 use crate::game::AppState;
 use crate::visual_effects;
 use crate::audio::{PlaySoundEvent, SoundEffect}; // Re-added for orb pulse sound
+use crate::run_stats::DamageSource;
+use crate::elites::HorrorDamage;
+use crate::knockback::KnockbackEvent;
+use crate::arena::{ArenaLayout, ObstacleKind};
 
 // --- Chain Lightning Log State Resource ---
 #[derive(Resource, Default)]
@@ -719,6 +723,7 @@ pub fn fire_trail_segment_system(
     asset_server: Res<AssetServer>,
     mut segment_query: Query<(Entity, &mut FireTrailSegmentComponent, &GlobalTransform, &mut Sprite)>,
     mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (segment_entity, mut segment_comp, segment_gtransform, mut segment_sprite) in segment_query.iter_mut() {
         // Segment Lifetime & Fade Out
@@ -748,7 +753,7 @@ pub fn fire_trail_segment_system(
 
                 if distance_sq < combined_radius_sq {
                     if !segment_comp.already_hit_this_tick.contains(&horror_entity) {
-                        horror_health.0 = horror_health.0.saturating_sub(segment_comp.damage_per_tick);
+                        horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, segment_comp.damage_per_tick);
 
                         // Spawn damage text visual effect using the horror's GlobalTransform for position
                         visual_effects::spawn_damage_text(
@@ -876,7 +881,7 @@ pub fn homing_projectile_system(mut _commands: Commands) {
 }
 
 // Helper function to spawn pool and despawn projectile
-fn spawn_pool_and_despawn_projectile(
+pub fn spawn_pool_and_despawn_projectile(
     commands: &mut Commands,
     asset_server: &Res<AssetServer>,
     projectile_entity: Entity,
@@ -945,6 +950,7 @@ pub fn ichor_pool_system(
     asset_server: Res<AssetServer>, // For damage text
     mut pool_query: Query<(Entity, &mut IchorPoolComponent, &GlobalTransform)>,
     mut horror_query: Query<(Entity, &Transform, &mut crate::components::Health), With<crate::horror::Horror>>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (pool_entity, mut pool_comp, pool_gtransform) in pool_query.iter_mut() {
         // Tick duration timer and despawn if finished
@@ -968,7 +974,7 @@ pub fn ichor_pool_system(
                 
                 let horror_pos = horror_transform.translation.truncate(); // Horror's world position
                 if horror_pos.distance_squared(pool_center_pos) < pool_radius_sq {
-                    horror_health.0 -= pool_comp.damage_per_tick;
+                    horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, pool_comp.damage_per_tick);
                     // Spawn damage text visual effect
                     crate::visual_effects::spawn_damage_text(
                         &mut commands,
@@ -1136,16 +1142,19 @@ impl Plugin for WeaponSystemsPlugin {
 }
 
 // --- Chain Lightning Attack System ---
+#[allow(clippy::too_many_arguments)]
 pub fn chain_lightning_attack_system(
     mut commands: Commands, 
     asset_server: Res<AssetServer>, 
     time: Res<Time>, 
+    layout: Res<ArenaLayout>, // The zap can't arc through stone
     player_query: Query<(&Transform, &Survivor)>, 
     sanity_strain_query: Query<&SurvivorSanityStrain, With<Survivor>>, 
     weapon_library: Res<AutomaticWeaponLibrary>, // Added
     mut horror_query: Query<(Entity, &Transform, &mut crate::components::Health), With<crate::horror::Horror>>, 
     mut log_state: ResMut<ChainLightningLogState>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
+    mut horror_damage: HorrorDamage,
 ) {
     let Ok((player_transform, survivor_stats)) = player_query.get_single() else { return; }; 
     let Ok(sanity_strain) = sanity_strain_query.get_single() else { return; }; 
@@ -1188,7 +1197,7 @@ pub fn chain_lightning_attack_system(
     let mut initial_target_search_results: Vec<(Entity, f32, Transform)> = Vec::new();
     for (horror_entity, horror_transform, _health) in horror_query.iter() {
         let distance_sq = player_position.distance_squared(horror_transform.translation.truncate());
-        if distance_sq < actual_params.initial_target_range.powi(2) { // Use actual_params
+        if distance_sq < actual_params.initial_target_range.powi(2) && layout.line_of_sight(player_position, horror_transform.translation.truncate(), ObstacleKind::blocks_projectiles) { // Use actual_params
             initial_target_search_results.push((horror_entity, distance_sq, *horror_transform));
         }
     }
@@ -1217,7 +1226,7 @@ pub fn chain_lightning_attack_system(
                 for (possible_next_entity, possible_next_transform, _health) in horror_query.iter() {
                     if hit_targets.contains(&possible_next_entity) { continue; }
                     let distance_sq_for_chaining = current_search_origin_for_chaining.distance_squared(possible_next_transform.translation.truncate());
-                    if distance_sq_for_chaining < actual_params.chain_search_radius.powi(2) { // Use actual_params
+                    if distance_sq_for_chaining < actual_params.chain_search_radius.powi(2) && layout.line_of_sight(current_search_origin_for_chaining, possible_next_transform.translation.truncate(), ObstacleKind::blocks_projectiles) { // Use actual_params
                         next_target_options_for_chaining.push((possible_next_entity, distance_sq_for_chaining, *possible_next_transform));
                     }
                 }
@@ -1262,7 +1271,7 @@ pub fn chain_lightning_attack_system(
             let player_actual_pos = player_transform.translation;
             if let Ok((_, target_actual_transform_ref, mut health)) = horror_query.get_mut(initial_target_entity) {
                 let initial_target_actual_pos = target_actual_transform_ref.translation;
-                health.0 = health.0.saturating_sub(actual_params.base_damage_per_zap); // Use actual_params
+                horror_damage.apply(DamageSource::InherentWeapon, initial_target_entity, &mut health, actual_params.base_damage_per_zap);
                 crate::visual_effects::spawn_damage_text(&mut commands, &asset_server, initial_target_actual_pos, actual_params.base_damage_per_zap, &time); // Use actual_params
                 info!("  - Initial Target: {:?}, Damage: {}, Dist: {:.0}", initial_target_entity, actual_params.base_damage_per_zap, initial_target_dist_sq.sqrt()); // Use actual_params
                 commands.spawn((
@@ -1284,7 +1293,7 @@ pub fn chain_lightning_attack_system(
                     for (possible_next_entity, possible_next_transform, _health) in horror_query.iter() {
                         if actual_hit_targets_in_chain_for_log.contains(&possible_next_entity) { continue; }
                         let distance_sq = current_search_origin.distance_squared(possible_next_transform.translation.truncate());
-                        if distance_sq < actual_params.chain_search_radius.powi(2) { // Use actual_params
+                        if distance_sq < actual_params.chain_search_radius.powi(2) && layout.line_of_sight(current_search_origin, possible_next_transform.translation.truncate(), ObstacleKind::blocks_projectiles) { // Use actual_params
                             next_target_options.push((possible_next_entity, distance_sq, *possible_next_transform));
                         }
                     }
@@ -1336,7 +1345,7 @@ pub fn chain_lightning_attack_system(
         let player_actual_pos = player_transform.translation;
         if let Ok((_, target_actual_transform_ref, mut health)) = horror_query.get_mut(initial_target_entity) {
             let initial_target_actual_pos = target_actual_transform_ref.translation;
-            health.0 = health.0.saturating_sub(actual_params.base_damage_per_zap); // Use actual_params
+            horror_damage.apply(DamageSource::InherentWeapon, initial_target_entity, &mut health, actual_params.base_damage_per_zap);
             crate::visual_effects::spawn_damage_text(&mut commands, &asset_server, initial_target_actual_pos, actual_params.base_damage_per_zap, &time); // Use actual_params
             commands.spawn((
                 SpatialBundle::default(), // Added SpatialBundle
@@ -1410,7 +1419,8 @@ pub fn explode_on_lifetime_end_system(
     asset_server: Res<AssetServer>,
     // Query for entities that have a lifetime, can explode, and optionally have damage for color reference
     mut query: Query<(Entity, &mut Lifetime, &GlobalTransform, &ExplodesOnFinalImpact, Option<&Damage>)>, 
-    mut horror_query: Query<(&GlobalTransform, &mut Health), With<Horror>>, // For applying damage
+    mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, // For applying damage
+    mut horror_damage: HorrorDamage,
 ) {
     for (entity, mut lifetime, g_transform, explodes_comp, _opt_damage_comp) in query.iter_mut() { // Changed opt_damage_comp to _opt_damage_comp
         // Important: We tick the timer here. If generic_lifetime_system also ticks it, it might double tick or cause issues.
@@ -1433,9 +1443,9 @@ pub fn explode_on_lifetime_end_system(
 
             // Apply damage to horrors in radius
             let explosion_center = g_transform.translation();
-            for (horror_gtransform, mut horror_health) in horror_query.iter_mut() {
+            for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() {
                 if horror_gtransform.translation().distance_squared(explosion_center) < explodes_comp.explosion_radius.powi(2) {
-                    horror_health.0 = horror_health.0.saturating_sub(explodes_comp.explosion_damage);
+                    horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, explodes_comp.explosion_damage);
                     visual_effects::spawn_damage_text(
                         &mut commands,
                         &asset_server,
@@ -1832,6 +1842,7 @@ pub fn orbiting_pet_behavior_system(
     horror_query: Query<(Entity, &GlobalTransform), With<Horror>>,
     mut horror_health_query: Query<&mut Health, With<Horror>>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>, // Added to play sounds
+    mut horror_damage: HorrorDamage,
 ) {
     for (orb_entity, mut orb_transform, mut orb_comp) in pet_query.iter_mut() {
         orb_comp.duration_timer.tick(time.delta());
@@ -1905,7 +1916,7 @@ pub fn orbiting_pet_behavior_system(
                     for (horror_entity, horror_gtransform) in horror_query.iter() {
                         if horror_gtransform.translation().distance_squared(orb_position) < orb_comp.params_snapshot.pulse_radius.powi(2) {
                             if let Ok(mut health) = horror_health_query.get_mut(horror_entity) {
-                                health.0 -= orb_comp.params_snapshot.pulse_damage;
+                                horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut health, orb_comp.params_snapshot.pulse_damage);
                                 visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), orb_comp.params_snapshot.pulse_damage, &time);
                             }
                         }
@@ -2047,6 +2058,7 @@ pub fn lobbed_bouncing_projectile_system(
         Option<&ExplodesOnFinalImpact>, // To check for explosion data
     )>,
     mut horror_query: Query<(Entity, &GlobalTransform, &mut Health), With<Horror>>, // For applying damage
+    mut horror_damage: HorrorDamage,
 ) {
     for (
         entity,
@@ -2089,9 +2101,9 @@ pub fn lobbed_bouncing_projectile_system(
                         0.5,                                   // Duration
                     );
                     // Apply damage to horrors in radius
-                    for (_horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() {
+                    for (horror_entity, horror_gtransform, mut horror_health) in horror_query.iter_mut() {
                         if horror_gtransform.translation().distance_squared(g_transform.translation()) < explodes_comp.explosion_radius.powi(2) {
-                            horror_health.0 = horror_health.0.saturating_sub(explodes_comp.explosion_damage);
+                            horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, explodes_comp.explosion_damage);
                             visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), explodes_comp.explosion_damage, &time);
                         }
                     }
@@ -2138,6 +2150,7 @@ pub fn magma_pool_system(
     mut pool_query: Query<(Entity, &mut MagmaPoolComponent, &GlobalTransform)>,
     mut horror_query: Query<(Entity, &Transform, &mut Health), With<Horror>>,
     asset_server: Res<AssetServer>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (pool_entity, mut pool_comp, pool_gtransform) in pool_query.iter_mut() {
         pool_comp.duration_timer.tick(time.delta());
@@ -2158,7 +2171,7 @@ pub fn magma_pool_system(
                 }
                 let horror_pos = horror_transform.translation.truncate();
                 if horror_pos.distance_squared(pool_center_pos) < pool_radius_sq {
-                    horror_health.0 -= pool_comp.damage_per_tick;
+                    horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, pool_comp.damage_per_tick);
                     visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_transform.translation, pool_comp.damage_per_tick, &time);
                     pool_comp.already_hit_this_tick.push(horror_entity);
                 }
@@ -2242,6 +2255,7 @@ pub fn player_dashing_system(
     asset_server: Res<AssetServer>,
     mut player_query: Query<(Entity, &mut Transform, &mut Survivor, &mut PlayerDashingComponent), (With<Survivor>, Without<Horror>)>,
     mut horror_query: Query<(Entity, &GlobalTransform, &mut Health, &Horror)>,
+    mut horror_damage: HorrorDamage,
 ) {
     if let Ok((player_entity, mut player_transform, mut player_stats, mut dashing_comp)) = player_query.get_single_mut() {
         dashing_comp.dash_timer.tick(time.delta());
@@ -2268,7 +2282,7 @@ pub fn player_dashing_system(
 
 
             if x_collision && y_collision {
-                horror_health.0 -= dashing_comp.params.damage_per_hit;
+                horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, dashing_comp.params.damage_per_hit);
                 visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), dashing_comp.params.damage_per_hit, &time);
                 dashing_comp.already_hit_horrors.push(horror_entity);
             }
@@ -2408,6 +2422,7 @@ pub fn pending_ground_aoe_system(
     asset_server: Res<AssetServer>,
     mut pending_aoe_query: Query<(Entity, &mut PendingGroundAoEComponent)>,
     mut horror_query: Query<(Entity, &GlobalTransform, &mut Health, &mut Velocity), With<Horror>>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (pending_entity, mut pending_comp) in pending_aoe_query.iter_mut() {
         pending_comp.eruption_timer.tick(time.delta());
@@ -2437,7 +2452,7 @@ pub fn pending_ground_aoe_system(
             for (horror_entity, horror_gtransform, mut horror_health, mut horror_velocity) in horror_query.iter_mut() {
                 if horror_gtransform.translation().distance_squared(pending_comp.position_of_impact) < pending_comp.params.eruption_radius.powi(2) {
                     let damage_to_apply = pending_comp.params.damage;
-                    horror_health.0 -= damage_to_apply;
+                    horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, damage_to_apply);
                     visual_effects::spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), damage_to_apply, &time);
 
                     if pending_comp.params.knock_up_strength > 0.0 {
@@ -2484,6 +2499,7 @@ pub fn channeled_beam_damage_system(
     mut enemy_query: Query<(Entity, &Transform, &mut Health), With<Horror>>,
     asset_server: Res<AssetServer>, 
     mut sound_event_writer: EventWriter<crate::audio::PlaySoundEvent>, 
+    mut horror_damage: HorrorDamage,
 ) {
     for (mut beam_comp, beam_gtransform) in beam_query.iter_mut() {
         beam_comp.tick_timer.tick(time.delta());
@@ -2495,7 +2511,7 @@ pub fn channeled_beam_damage_system(
         let beam_rotation_quat = beam_gtransform.compute_transform().rotation;
         let beam_direction = (beam_rotation_quat * Vec3::X).truncate();
 
-        for (_enemy_entity, enemy_transform, mut enemy_health) in enemy_query.iter_mut() {
+        for (enemy_entity, enemy_transform, mut enemy_health) in enemy_query.iter_mut() {
             let enemy_pos = enemy_transform.translation.truncate();
             let to_enemy = enemy_pos - beam_start_pos;
             let distance_along_beam = to_enemy.dot(beam_direction);
@@ -2504,7 +2520,7 @@ pub fn channeled_beam_damage_system(
                 let perpendicular_distance = (to_enemy - distance_along_beam * beam_direction).length();
                 let enemy_radius = 16.0;
                 if perpendicular_distance < (beam_comp.width / 2.0) + enemy_radius {
-                    enemy_health.0 -= beam_comp.damage_per_tick;
+                    horror_damage.apply(DamageSource::InherentWeapon, enemy_entity, &mut enemy_health, beam_comp.damage_per_tick);
                     visual_effects::spawn_damage_text( &mut commands, &asset_server, enemy_transform.translation, beam_comp.damage_per_tick, &time);
                     sound_event_writer.send(crate::audio::PlaySoundEvent(crate::audio::SoundEffect::HorrorHit));
                 }
@@ -2513,6 +2529,7 @@ pub fn channeled_beam_damage_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn returning_projectile_system(
     mut commands: Commands,
     game_time: Res<Time>, 
@@ -2521,6 +2538,7 @@ pub fn returning_projectile_system(
     projectile_damage_query: Query<&Damage, With<ReturningProjectileComponent>>,
     mut horror_query: Query<(Entity, &Transform, &mut Health, &Horror), (With<Horror>, Without<ReturningProjectileComponent>)>,
     mut sound_event_writer: EventWriter<crate::audio::PlaySoundEvent>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (entity, mut projectile_comp, mut velocity, mut transform) in query.iter_mut() {
         // Apply movement based on velocity
@@ -2545,7 +2563,7 @@ pub fn returning_projectile_system(
                 let horror_radius = horror_stats.size.x / 2.0; // Assuming Horror struct has 'size: Vec2'
 
                 if distance < projectile_radius + horror_radius {
-                    horror_health.0 = horror_health.0.saturating_sub(projectile_damage.0);
+                    horror_damage.apply(DamageSource::InherentWeapon, horror_entity, &mut horror_health, projectile_damage.0);
                     crate::visual_effects::spawn_damage_text(
                         &mut commands,
                         &asset_server,
@@ -2686,6 +2704,8 @@ pub fn execute_cone_attack(
     enemy_query: &mut Query<(Entity, &Transform, &mut Health, &Horror), With<Horror>>,
     time: &Res<Time>,
     sound_event_writer: &mut EventWriter<PlaySoundEvent>,
+    horror_damage: &mut HorrorDamage,
+    knockback_writer: &mut EventWriter<KnockbackEvent>,
 ) {
     if let Some(sound_path) = &params.fire_sound_effect {
         sound_event_writer.send(PlaySoundEvent(SoundEffect::Path(sound_path.clone())));
@@ -2730,7 +2750,7 @@ pub fn execute_cone_attack(
         ));
     }
 
-    for (_enemy_entity, enemy_transform, mut enemy_health, _horror_tag) in enemy_query.iter_mut() {
+    for (enemy_entity, enemy_transform, mut enemy_health, _horror_tag) in enemy_query.iter_mut() {
         let enemy_pos = enemy_transform.translation.truncate();
         let vector_to_enemy = enemy_pos - player_pos;
 
@@ -2744,8 +2764,9 @@ pub fn execute_cone_attack(
             let half_cone_angle_rad = params.cone_angle_degrees.to_radians() / 2.0;
 
             if angle_to_enemy_rad.abs() <= half_cone_angle_rad {
-                enemy_health.0 -= params.base_damage;
+                horror_damage.apply(DamageSource::InherentWeapon, enemy_entity, &mut enemy_health, params.base_damage);
                 visual_effects::spawn_damage_text(commands, asset_server, enemy_transform.translation, params.base_damage, &time);
+                knockback_writer.send(KnockbackEvent { target: enemy_entity, direction: vector_to_enemy, force: params.knockback });
             }
         }
     }
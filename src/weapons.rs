@@ -1,5 +1,6 @@
 // mescgit/bulletheavengame/bulletheavengame-a4c13a6183f1601049189db29b13bcfdace86153/src/weapons.rs
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::{
     survivor::Survivor, // Changed
     horror::Horror,   // Changed
@@ -7,16 +8,18 @@ use crate::{
     game::AppState, // GameState import removed as it was unused
     audio::{PlaySoundEvent, SoundEffect},
     visual_effects::{spawn_damage_text}, // Removed ImpactEffectRequest, spawn_impact_effect
+    elites::HorrorDamage,
+    run_stats::DamageSource,
 };
 
 // --- Circle of Warding Aura Weapon ---
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct CircleOfWarding {
     pub damage_tick_timer: Timer,
     pub current_radius: f32,
     pub base_damage_per_tick: i32,
     pub is_active: bool,
-    pub visual_entity: Option<Entity>,
+    #[serde(skip)] pub visual_entity: Option<Entity>, // Respawned by the visual system after a restore
 }
 
 impl Default for CircleOfWarding {
@@ -40,7 +43,7 @@ const NIGHTMARE_LARVA_SPRITE_SIZE: Vec2 = Vec2::new(32.0, 32.0);
 const NIGHTMARE_LARVA_DEBUG_COLOR: Color = Color::rgb(0.4, 0.8, 0.3);
 const NIGHTMARE_LARVA_LOCAL_Z: f32 = 0.3;
 
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct SwarmOfNightmares {
     pub is_active: bool,
     pub num_larvae: u32,
@@ -93,7 +96,8 @@ fn circle_of_warding_aura_system(
     _commands: Commands,
     time: Res<Time>,
     mut player_query: Query<(&Transform, &mut CircleOfWarding), With<Survivor>>,
-    mut horror_query: Query<(&Transform, &mut Health, &Horror), With<Horror>>,
+    mut horror_query: Query<(Entity, &Transform, &mut Health, &Horror), With<Horror>>,
+    mut horror_damage: HorrorDamage,
 ) {
     for (player_transform, mut aura_weapon) in player_query.iter_mut() {
         if !aura_weapon.is_active { continue; }
@@ -101,10 +105,10 @@ fn circle_of_warding_aura_system(
         if aura_weapon.damage_tick_timer.just_finished() {
             let player_position = player_transform.translation.truncate();
             let aura_radius_sq = aura_weapon.current_radius.powi(2);
-            for (horror_transform, mut horror_health, _horror_data) in horror_query.iter_mut() {
+            for (horror_entity, horror_transform, mut horror_health, _horror_data) in horror_query.iter_mut() {
                 let horror_position = horror_transform.translation.truncate();
                 if player_position.distance_squared(horror_position) < aura_radius_sq {
-                    horror_health.0 -= aura_weapon.base_damage_per_tick;
+                    horror_damage.apply(DamageSource::CircleOfWarding, horror_entity, &mut horror_health, aura_weapon.base_damage_per_tick);
                 }
             }
         }
@@ -208,6 +212,7 @@ fn nightmare_larva_movement_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn nightmare_larva_collision_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -216,6 +221,7 @@ fn nightmare_larva_collision_system(
     asset_server: Res<AssetServer>,
     mut sound_event_writer: EventWriter<PlaySoundEvent>,
     player_weapon_query: Query<&SwarmOfNightmares, With<Survivor>>,
+    mut horror_damage: HorrorDamage,
 ) {
     let Ok(weapon_stats) = player_weapon_query.get_single() else { return; };
     if !weapon_stats.is_active { return; }
@@ -233,7 +239,7 @@ fn nightmare_larva_collision_system(
             let horror_radius = horror_data.size.x / 2.0; // Use horror_data
             if larva_pos.distance(horror_pos) < larva_radius + horror_radius {
                 sound_event_writer.send(PlaySoundEvent(SoundEffect::HorrorHit));
-                horror_health.0 -= larva_damage.0;
+                horror_damage.apply(DamageSource::SwarmOfNightmares, horror_entity, &mut horror_health, larva_damage.0);
                 spawn_damage_text(&mut commands, &asset_server, horror_gtransform.translation(), larva_damage.0, &time);
                 larva_data.enemies_on_cooldown.push((horror_entity, Timer::from_seconds(weapon_stats.hit_cooldown_duration, TimerMode::Once)));
             }
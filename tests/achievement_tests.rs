@@ -0,0 +1,75 @@
+use eldritch_hero::achievements::{AchievementCondition, AchievementId, AchievementRecord, AchievementSession, track_survivor_hits_system};
+use eldritch_hero::survivor::SurvivorHitEvent;
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use eldritch_hero::horror::HorrorType;
+use eldritch_hero::items::AutomaticWeaponId;
+use eldritch_hero::profile::PlayerProfile;
+use eldritch_hero::persistence::{from_ron_str, to_ron_string};
+
+#[test]
+fn test_kill_progress_accumulates_across_runs() {
+    let condition = AchievementCondition::KillHorrors { horror_type: HorrorType::MindLeech, count: 250 };
+    let mut record = AchievementRecord::default();
+    let mut session = AchievementSession::default();
+    for _ in 0..120 { session.record_kill(HorrorType::MindLeech); }
+    session.record_kill(HorrorType::SkitteringShadowling);
+
+    assert_eq!(record.progress(condition, &session), (120, 250), "the run in progress counts before it is merged");
+    record.merge_session(&session);
+    record.merge_session(&session);
+    assert_eq!(record.progress(condition, &AchievementSession::default()), (240, 250));
+    record.merge_session(&session);
+    assert_eq!(record.progress(condition, &AchievementSession::default()), (250, 250), "progress is clamped to the target");
+}
+
+#[test]
+fn test_survive_and_level_progress_keep_the_best_run() {
+    let void_vigil = AchievementCondition::Survive { weapon_id: Some(AutomaticWeaponId(2)), seconds: 900 };
+    let any_weapon = AchievementCondition::Survive { weapon_id: None, seconds: 600 };
+    let mut record = AchievementRecord::default();
+
+    let mut long_run = AchievementSession::default();
+    long_run.weapon_id = Some(AutomaticWeaponId(0));
+    long_run.survived_secs = 500;
+    long_run.highest_level = 22;
+    let mut short_run = AchievementSession::default();
+    short_run.weapon_id = Some(AutomaticWeaponId(2));
+    short_run.survived_secs = 300;
+    short_run.highest_level = 9;
+    record.merge_session(&long_run);
+    record.merge_session(&short_run);
+
+    let idle = AchievementSession::default();
+    assert_eq!(record.progress(void_vigil, &idle), (300, 900), "only runs with the required weapon count");
+    assert_eq!(record.progress(any_weapon, &idle), (500, 600));
+    assert_eq!(record.progress(AchievementCondition::ReachLevel(40), &idle), (22, 40));
+}
+
+#[test]
+fn test_only_reported_hits_count_against_the_survivor() {
+    let mut world = World::new();
+    world.init_resource::<AchievementSession>();
+    world.init_resource::<Events<SurvivorHitEvent>>();
+    world.run_system_once(track_survivor_hits_system);
+    assert_eq!(world.resource::<AchievementSession>().survivor_hits, 0, "no hits reported, none counted");
+
+    world.send_event(SurvivorHitEvent);
+    world.send_event(SurvivorHitEvent);
+    world.run_system_once(track_survivor_hits_system);
+    assert_eq!(world.resource::<AchievementSession>().survivor_hits, 2, "a shielded hit is still a hit");
+}
+
+#[test]
+fn test_profile_round_trips_achievements_and_tolerates_old_files() {
+    let mut profile = PlayerProfile::default();
+    profile.achievements.unlocked.push(AchievementId::Ascendant);
+    profile.achievements.horror_kills.insert(HorrorType::FrenziedBehemoth, 12);
+
+    let restored: PlayerProfile = from_ron_str(&to_ron_string(&profile).unwrap()).unwrap();
+    assert!(restored.achievements.is_unlocked(AchievementId::Ascendant));
+    assert_eq!(restored.achievements.horror_kills.get(&HorrorType::FrenziedBehemoth), Some(&12));
+
+    let old_profile: PlayerProfile = from_ron_str("(lore: 5)").unwrap();
+    assert_eq!(old_profile.achievements, AchievementRecord::default());
+}
@@ -0,0 +1,80 @@
+use eldritch_hero::arena::{generate_chunk, chunk_of, slide_direction, ArenaLayout, ObstacleKind, ObstacleShape, ObstacleSpec, ARENA_CHUNK_SIZE};
+use bevy::prelude::{IVec2, Vec2};
+
+#[test]
+fn test_same_seed_same_ruins() {
+    for chunk in [IVec2::new(0, 0), IVec2::new(3, -2), IVec2::new(-7, 11)] {
+        assert_eq!(generate_chunk(42, chunk), generate_chunk(42, chunk));
+    }
+    let chunks: Vec<IVec2> = (-2..=2).flat_map(|x| (-2..=2).map(move |y| IVec2::new(x, y))).collect();
+    let layout_a: Vec<_> = chunks.iter().map(|chunk| generate_chunk(42, *chunk)).collect();
+    let layout_b: Vec<_> = chunks.iter().map(|chunk| generate_chunk(43, *chunk)).collect();
+    assert_ne!(layout_a, layout_b, "another seed builds other ruins");
+
+    // Every obstacle sits wholly inside its chunk, and the survivor's starting spot is left clear
+    for (chunk, obstacles) in chunks.iter().zip(&layout_a) {
+        for obstacle in obstacles {
+            assert_eq!(chunk_of(obstacle.centre), *chunk);
+            assert!(obstacle.centre.length() - obstacle.shape.bounding_radius() > 300.0);
+            let reach = obstacle.shape.bounding_radius();
+            assert_eq!(chunk_of(obstacle.centre + Vec2::splat(reach)), *chunk);
+            assert_eq!(chunk_of(obstacle.centre - Vec2::splat(reach)), *chunk);
+        }
+    }
+}
+
+#[test]
+fn test_circles_are_pushed_out_of_obstacles() {
+    let pillar = ObstacleSpec { kind: ObstacleKind::Pillar, centre: Vec2::ZERO, shape: ObstacleShape::Circle { radius: 30.0 } };
+    let push = pillar.penetration(Vec2::new(35.0, 0.0), 10.0).unwrap();
+    assert!((push - Vec2::new(5.0, 0.0)).length() < 1e-4);
+    assert_eq!(pillar.penetration(Vec2::new(41.0, 0.0), 10.0), None);
+
+    let wall = ObstacleSpec { kind: ObstacleKind::Wall, centre: Vec2::ZERO, shape: ObstacleShape::Rect { half_extents: Vec2::new(100.0, 15.0) } };
+    let push = wall.penetration(Vec2::new(0.0, -20.0), 10.0).unwrap();
+    assert!((push - Vec2::new(0.0, -5.0)).length() < 1e-4);
+    // A centre inside the wall leaves through the nearest face
+    let push = wall.penetration(Vec2::new(95.0, 2.0), 10.0).unwrap();
+    assert!((push - Vec2::new(15.0, 0.0)).length() < 1e-4);
+
+    // Sliding along the wall keeps heading the way the horror wanted to go
+    assert!(slide_direction(Vec2::Y, Vec2::new(0.3, -1.0).normalize()).x > 0.0);
+    assert!(slide_direction(Vec2::Y, Vec2::new(-0.3, -1.0).normalize()).x < 0.0);
+}
+
+#[test]
+fn test_layout_resolves_only_what_blocks() {
+    let mut layout = ArenaLayout::new(7);
+    let chunk = (0..50).map(|x| IVec2::new(x, 1)).find(|chunk| generate_chunk(7, *chunk).iter().any(|o| o.kind == ObstacleKind::Pit))
+        .expect("some chunk has a pit");
+    let pit = *layout.load_chunk(chunk).unwrap().iter().find(|o| o.kind == ObstacleKind::Pit).unwrap();
+    assert!(layout.load_chunk(chunk).is_none(), "already loaded");
+
+    let (walker, normal) = layout.resolve(pit.centre + Vec2::X, 12.0, |_| true);
+    assert!(normal.is_some());
+    assert!(!layout.is_blocked(walker, 12.0, |_| true), "pushed clear of the pit");
+    let (flyer, normal) = layout.resolve(pit.centre, 12.0, ObstacleKind::blocks_flyers);
+    assert_eq!((flyer, normal), (pit.centre, None), "flyers and projectiles pass over pits");
+
+    assert!(layout.unload_chunk(chunk));
+    assert!(!layout.is_blocked(pit.centre, 12.0, |_| true), "unloaded ruins no longer collide");
+    assert_eq!(chunk_of(Vec2::new(-1.0, ARENA_CHUNK_SIZE)), IVec2::new(-1, 1));
+}
+
+#[test]
+fn test_sight_lines_stop_at_stone_and_drops_land_on_open_ground() {
+    let mut layout = ArenaLayout::new(7);
+    let chunk = (0..50).map(|x| IVec2::new(x, 1)).find(|chunk| generate_chunk(7, *chunk).iter().any(|o| o.kind == ObstacleKind::Wall))
+        .expect("some chunk has a wall");
+    let wall = *layout.load_chunk(chunk).unwrap().iter().find(|o| o.kind == ObstacleKind::Wall).unwrap();
+    let ObstacleShape::Rect { half_extents } = wall.shape else { panic!("walls are rectangles") };
+    let across = if half_extents.x < half_extents.y { Vec2::X } else { Vec2::Y }; // Through the wall's thin side
+    let (near, far) = (wall.centre - across * 60.0, wall.centre + across * 60.0);
+    assert!(!layout.line_of_sight(near, far, ObstacleKind::blocks_projectiles), "no zap through a wall");
+    assert!(layout.line_of_sight(near, near - across * 100.0, ObstacleKind::blocks_projectiles));
+
+    let chest = layout.open_ground(wall.centre, 20.0);
+    assert!(!layout.is_blocked(chest, 19.0, |_| true), "a chest dropped in the wall is moved out of it (to just touching)");
+    let clear = near - across * 100.0;
+    assert_eq!(layout.open_ground(clear, 20.0), clear, "open ground is left alone");
+}
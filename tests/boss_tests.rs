@@ -0,0 +1,46 @@
+use eldritch_hero::boss::{get_boss_definition, BossId, BossTimeline, BOSS_DEFINITIONS, BOSS_TIMELINE};
+use eldritch_hero::run_save::HorrorSnapshot;
+use eldritch_hero::horror::HorrorType;
+use eldritch_hero::persistence::{from_ron_str, to_ron_string};
+use bevy::math::Vec2;
+
+#[test]
+fn test_phase_follows_health_thresholds() {
+    let colossus = get_boss_definition(BossId::GorgedColossus);
+    assert_eq!(colossus.phase_for_health(1000, 1000), 0);
+    assert_eq!(colossus.phase_for_health(601, 1000), 0);
+    assert_eq!(colossus.phase_for_health(600, 1000), 1, "a threshold is entered once health falls to it");
+    assert_eq!(colossus.phase_for_health(100, 1000), 2);
+    assert_eq!(colossus.phase_for_health(-20, 1000), 2);
+
+    for def in BOSS_DEFINITIONS.iter() {
+        assert_eq!(def.phases[0].health_threshold, 1.0, "{} must start in its first phase", def.name);
+        assert!(def.phases.windows(2).all(|pair| pair[0].health_threshold > pair[1].health_threshold), "{} phases must be listed from full health down", def.name);
+    }
+}
+
+#[test]
+fn test_timeline_spawns_each_mark_once() {
+    let mut timeline = BossTimeline::default();
+    assert_eq!(timeline.next_due(BOSS_TIMELINE[0].at_secs - 1.0), None);
+    assert_eq!(timeline.next_due(BOSS_TIMELINE[0].at_secs), Some(BOSS_TIMELINE[0].boss));
+    assert_eq!(timeline.next_due(BOSS_TIMELINE[0].at_secs + 1.0), None);
+
+    // A long frame past two marks spawns both, one call at a time
+    let late = BOSS_TIMELINE[2].at_secs;
+    assert_eq!(timeline.next_due(late), Some(BOSS_TIMELINE[1].boss));
+    assert_eq!(timeline.next_due(late), Some(BOSS_TIMELINE[2].boss));
+    assert_eq!(timeline.next_due(late), None);
+
+    assert_eq!(BossTimeline::resumed_at(BOSS_TIMELINE[1].at_secs + 30.0).next_mark, 2, "marks already reached are not replayed on resume");
+}
+
+#[test]
+fn test_boss_survives_snapshot_round_trip() {
+    let snapshot = HorrorSnapshot { horror_type: HorrorType::VoidBlinker, position: Vec2::new(5.0, -3.0), health: 900, is_elite: false, elite_affixes: Vec::new(), boss: Some(BossId::HeraldOfHollowStars), ward: None, behavior: Vec::new() };
+    let restored: HorrorSnapshot = from_ron_str(&to_ron_string(&snapshot).unwrap()).unwrap();
+    assert_eq!(restored, snapshot);
+
+    let plain: HorrorSnapshot = from_ron_str("(horror_type: MindLeech, position: (0.0, 0.0), health: 4, is_elite: false)").unwrap();
+    assert_eq!(plain.boss, None);
+}
@@ -0,0 +1,66 @@
+use eldritch_hero::bullet_patterns::{intercept_direction, volley, BulletPattern, HomingProjectile, PatternTarget};
+use bevy::prelude::{Timer, TimerMode, Vec2};
+use std::time::Duration;
+
+#[test]
+fn test_lead_shot_meets_a_moving_survivor() {
+    let origin = Vec2::ZERO;
+    let still = PatternTarget { position: Vec2::new(300.0, 0.0), velocity: Vec2::ZERO };
+    assert!((intercept_direction(origin, still, 250.0) - Vec2::X).length() < 1e-5, "a still target is shot at directly");
+
+    let moving = PatternTarget { position: Vec2::new(300.0, 0.0), velocity: Vec2::new(0.0, 150.0) };
+    let direction = intercept_direction(origin, moving, 250.0);
+    assert!(direction.y > 0.0, "leads ahead of the survivor");
+    // Find when the shot is closest to the survivor; it should actually hit them
+    let closest = (0..400).map(|step| step as f32 * 0.01)
+        .map(|t| (direction * 250.0 * t).distance(moving.position + moving.velocity * t))
+        .fold(f32::MAX, f32::min);
+    assert!(closest < 3.0, "missed by {closest}");
+
+    let uncatchable = PatternTarget { position: Vec2::new(300.0, 0.0), velocity: Vec2::new(500.0, 0.0) };
+    assert!((intercept_direction(origin, uncatchable, 250.0) - Vec2::X).length() < 1e-5, "falls back to aiming straight at it");
+}
+
+#[test]
+fn test_ring_and_wall_shapes() {
+    let mut rng = rand::thread_rng();
+    let target = PatternTarget { position: Vec2::new(0.0, 400.0), velocity: Vec2::ZERO };
+    let ring = volley(BulletPattern::Ring { projectiles: 8 }, Vec2::ZERO, target, 200.0, 0.0, &mut rng);
+    assert_eq!(ring.len(), 8);
+    let sum: Vec2 = ring.iter().map(|shot| shot.direction).sum();
+    assert!(sum.length() < 1e-4, "evenly spaced all round");
+
+    for _ in 0..20 {
+        let wall = volley(BulletPattern::Wall { projectiles: 10, spacing: 30.0, gap: 3 }, Vec2::ZERO, target, 200.0, 0.0, &mut rng);
+        assert_eq!(wall.len(), 7, "three shots are left out");
+        assert!(wall.iter().all(|shot| shot.direction == Vec2::Y && shot.origin.y.abs() < 1e-4), "a line across the aim");
+        let mut slots: Vec<i32> = wall.iter().map(|shot| ((shot.origin.x / 30.0) + 4.5).round() as i32).collect();
+        slots.sort();
+        let missing: Vec<i32> = (0..10).filter(|slot| !slots.contains(slot)).collect();
+        assert!(missing.windows(2).all(|pair| pair[1] == pair[0] + 1), "the gap is in one piece: {missing:?}");
+    }
+    assert!(volley(BulletPattern::AimedSpread { projectiles: 3, spread_degrees: 30.0 }, target.position, target, 200.0, 0.0, &mut rng).is_empty(), "nothing to aim at");
+}
+
+#[test]
+fn test_homing_orb_waits_then_turns_at_a_limited_rate() {
+    let mut orb = HomingProjectile {
+        delay: Timer::from_seconds(0.5, TimerMode::Once),
+        homing: Timer::from_seconds(1.0, TimerMode::Once),
+        turn_rate: std::f32::consts::FRAC_PI_2,
+        speed: 200.0,
+    };
+    let drifting = Vec2::new(20.0, 0.0);
+    let behind = Vec2::new(-500.0, 0.0);
+    let step = Duration::from_millis(100);
+    for _ in 0..5 { assert_eq!(orb.steer(step, drifting, Vec2::ZERO, behind), drifting, "hangs in the air first"); }
+
+    let velocity = orb.steer(step, drifting, Vec2::ZERO, behind);
+    assert!((velocity.length() - 200.0).abs() < 1e-3);
+    assert!(velocity.angle_between(Vec2::X).abs() <= std::f32::consts::FRAC_PI_2 * 0.1 + 1e-4, "turns no faster than its turn rate");
+
+    let mut velocity = velocity;
+    for _ in 0..20 { velocity = orb.steer(step, velocity, Vec2::ZERO, behind); }
+    let settled = velocity;
+    assert_eq!(orb.steer(step, settled, Vec2::ZERO, Vec2::new(0.0, 500.0)), settled, "stops homing once its time is up");
+}
@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use eldritch_hero::characters::{authored_characters, populate_character_library, CharacterId, CharacterLibrary, CharacterPassive};
+use eldritch_hero::items::{populate_item_library, AutomaticWeaponDefinition, AutomaticWeaponId, AutomaticWeaponLibrary, ItemLibrary};
+use eldritch_hero::survivor::{INITIAL_SURVIVOR_MAX_HEALTH, BASE_CRIT_DAMAGE_MULTIPLIER};
+use eldritch_hero::profile::PlayerProfile;
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_character_survivor_uses_kit_and_overrides() {
+    let weapon_library = AutomaticWeaponLibrary::default();
+    let characters = authored_characters();
+    let void_seer = characters.iter().find(|character| character.id == CharacterId(2)).unwrap();
+
+    let survivor = void_seer.create_survivor(&weapon_library);
+    assert_eq!(survivor.inherent_weapon_id, void_seer.starting_weapon_id);
+    assert_eq!(survivor.max_health, void_seer.stat_overrides.max_health.unwrap());
+    assert_ne!(survivor.max_health, INITIAL_SURVIVOR_MAX_HEALTH);
+    assert_eq!(survivor.equipped_skills.iter().map(|skill| skill.definition_id).collect::<Vec<_>>(), void_seer.starting_skills);
+    assert_eq!(survivor.character_passive, void_seer.passive);
+    assert!(survivor.collected_item_ids.is_empty(), "starting relics are granted through ItemCollectedEvent on spawn");
+}
+
+#[test]
+fn test_extra_projectile_passive_triggers_every_n_levels() {
+    let zealot = authored_characters().into_iter().find(|character| character.passive == CharacterPassive::ExtraProjectileEveryLevels(10)).unwrap();
+    let mut survivor = zealot.create_survivor(&AutomaticWeaponLibrary::default());
+    let starting_bonus = survivor.auto_weapon_additional_projectiles_bonus;
+
+    for level in 2..=21 {
+        survivor.level = level;
+        survivor.character_passive.apply_on_level_reached(&mut survivor);
+    }
+    assert_eq!(survivor.auto_weapon_additional_projectiles_bonus, starting_bonus + 2);
+}
+
+#[test]
+fn test_library_adds_drifters_for_weapons_without_characters() {
+    let mut world = World::new();
+    world.init_resource::<CharacterLibrary>();
+    world.insert_resource(AutomaticWeaponLibrary {
+        weapons: vec![
+            AutomaticWeaponDefinition { id: AutomaticWeaponId(0), name: "Primordial Ichor Blast".to_string(), ..default() },
+            AutomaticWeaponDefinition { id: AutomaticWeaponId(42), name: "Test Weapon".to_string(), ..default() },
+        ],
+    });
+    world.run_system_once(populate_character_library);
+
+    let library = world.resource::<CharacterLibrary>();
+    assert_eq!(library.characters.len(), authored_characters().len() + 1);
+    let drifter = library.get_for_weapon(AutomaticWeaponId(42)).expect("uncovered weapon should get a drifter");
+    assert_eq!(drifter.passive, CharacterPassive::None);
+    assert!(library.get_character_definition(drifter.id).is_some());
+}
+
+#[test]
+fn test_zealot_armor_and_void_seer_crit_change_the_numbers() {
+    let characters = authored_characters();
+    let weapon_library = AutomaticWeaponLibrary::default();
+    let zealot = characters.iter().find(|character| character.id == CharacterId(1)).unwrap().create_survivor(&weapon_library);
+    let alchemist = characters.iter().find(|character| character.id == CharacterId(0)).unwrap().create_survivor(&weapon_library);
+    assert!(zealot.armored_damage(20) < alchemist.armored_damage(20), "armor softens every hit");
+    assert_eq!(zealot.armored_damage(1), 1, "a landed hit is never free");
+
+    let mut void_seer = characters.iter().find(|character| character.id == CharacterId(2)).unwrap().create_survivor(&weapon_library);
+    let mut rng = StdRng::seed_from_u64(29);
+    assert!((0..200).all(|_| alchemist.roll_auto_attack_crit(10, &mut rng) == 10), "no crit chance, no crits");
+    void_seer.auto_attack_crit_chance = 1.0;
+    assert_eq!(void_seer.roll_auto_attack_crit(10, &mut rng), (10.0 * BASE_CRIT_DAMAGE_MULTIPLIER).round() as i32);
+}
+
+#[test]
+fn test_starting_relics_are_available_without_buying_them() {
+    let profile = PlayerProfile::default();
+    let mut world = World::new();
+    world.init_resource::<ItemLibrary>();
+    world.run_system_once(populate_item_library);
+    let item_library = world.resource::<ItemLibrary>();
+    for character in authored_characters() {
+        let starts_unlocked = |relic| item_library.get_item_definition(relic).is_some_and(|item_def| profile.is_item_unlocked(item_def));
+        assert!(character.starting_relics.iter().all(|relic| starts_unlocked(*relic)), "{} starts with a relic that has to be bought", character.name);
+    }
+}
@@ -0,0 +1,71 @@
+use eldritch_hero::chests::{effective_chest_quality, elite_chest_quality, roll_chest_rewards, roll_reward_count, ChestReward, OpenedChest, BOSS_CHEST_QUALITY, MAX_CHEST_REWARDS, REVEAL_INTERVAL_SECS};
+use eldritch_hero::items::ItemId;
+use eldritch_hero::upgrades::{UpgradeCard, UpgradeContext, UpgradeId, UpgradeRarity, UpgradeType};
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::Duration;
+
+fn card(id: u32, rarity: UpgradeRarity) -> UpgradeCard {
+    UpgradeCard::new(UpgradeId(id), format!("Card {}", id), "", UpgradeType::SurvivorSpeed(1), rarity)
+}
+
+#[test]
+fn test_better_chests_hold_more_rewards() {
+    let mut rng = StdRng::seed_from_u64(3);
+    let average = |quality: f32, rng: &mut StdRng| (0..2000).map(|_| roll_reward_count(quality, rng)).sum::<usize>() as f32 / 2000.0;
+    for quality in [0.0, 1.0, 2.5, BOSS_CHEST_QUALITY, 20.0] {
+        assert!((0..200).all(|_| [1, 3, MAX_CHEST_REWARDS].contains(&roll_reward_count(quality, &mut rng))));
+    }
+    assert!((0..200).all(|_| roll_reward_count(0.0, &mut rng) == 1), "a worthless chest holds one thing");
+    let (plain, three_affixes, boss) = (average(elite_chest_quality(0), &mut rng), average(elite_chest_quality(3), &mut rng), average(BOSS_CHEST_QUALITY, &mut rng));
+    assert!(plain < three_affixes && three_affixes < boss, "{} < {} < {}", plain, three_affixes, boss);
+    assert!(effective_chest_quality(2.0, 50.0) == 3.0 && effective_chest_quality(2.0, -20.0) == 2.0, "luck only ever helps");
+}
+
+#[test]
+fn test_rewards_never_repeat_a_relic_and_fall_back_to_echoes() {
+    let mut rng = StdRng::seed_from_u64(4);
+    let weapon_cards = [card(1, UpgradeRarity::Regular), card(2, UpgradeRarity::Rare), card(3, UpgradeRarity::Legendary)];
+    let mut legendary = [0usize; 2];
+    for (slot, quality) in [1.0, 10.0].into_iter().enumerate() {
+        for _ in 0..500 {
+            let rewards = roll_chest_rewards(quality, &weapon_cards, &UpgradeContext::default(), &[ItemId(7)], &mut rng);
+            assert!(rewards.iter().filter(|reward| **reward == ChestReward::Relic(ItemId(7))).count() <= 1);
+            legendary[slot] += rewards.iter().filter(|reward| matches!(reward, ChestReward::WeaponLevelUp(card) if card.rarity == UpgradeRarity::Legendary)).count();
+        }
+    }
+    assert!(legendary[1] > legendary[0] * 2, "better chests favour rarer cards ({:?})", legendary);
+
+    // Nothing to give but echoes
+    let rewards = roll_chest_rewards(BOSS_CHEST_QUALITY, &[], &UpgradeContext::default(), &[], &mut rng);
+    assert!(rewards.iter().all(|reward| matches!(reward, ChestReward::Echoes { souls, .. } if *souls > 0)));
+}
+
+#[test]
+fn test_weapon_cards_come_out_once_and_only_while_they_still_stack() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let spent = card(1, UpgradeRarity::Regular);
+    let weapon_cards = [spent.clone(), card(2, UpgradeRarity::Regular), card(3, UpgradeRarity::Rare)];
+    let context = UpgradeContext { picked: vec![spent.id; spent.upgrade_type.max_stacks() as usize], ..Default::default() };
+    for _ in 0..500 {
+        let cards: Vec<UpgradeId> = roll_chest_rewards(20.0, &weapon_cards, &context, &[], &mut rng).into_iter()
+            .filter_map(|reward| match reward { ChestReward::WeaponLevelUp(card) => Some(card.id), _ => None })
+            .collect();
+        assert!(!cards.contains(&spent.id), "a maxed card isn't handed out again");
+        assert!(cards.len() <= 2 && (cards.len() < 2 || cards[0] != cards[1]), "no card twice in one chest: {:?}", cards);
+    }
+}
+
+#[test]
+fn test_reveal_advances_on_a_timer_and_can_be_skipped() {
+    let rewards = vec![ChestReward::Echoes { souls: 1, value: 1 }; 3];
+    let mut chest = OpenedChest::new(rewards.clone());
+    assert_eq!(chest.tick(Duration::from_secs_f32(REVEAL_INTERVAL_SECS * 0.5)), 0);
+    assert_eq!(chest.tick(Duration::from_secs_f32(REVEAL_INTERVAL_SECS * 0.6)), 1);
+    assert_eq!(chest.tick(Duration::from_secs_f32(REVEAL_INTERVAL_SECS * 10.0)), 2, "never past the last reward");
+    assert!(chest.all_revealed());
+
+    let mut skipped = OpenedChest::new(rewards);
+    skipped.reveal_all();
+    assert_eq!(skipped.revealed(), 3);
+    assert_eq!(skipped.tick(Duration::from_secs(1)), 0);
+}
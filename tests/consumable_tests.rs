@@ -0,0 +1,45 @@
+use eldritch_hero::consumables::{get_consumable_definition, roll_consumable_drop, ConsumableKind, DropRateStat, CONSUMABLE_DEFINITIONS};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_every_consumable_is_defined_and_elites_drop_more() {
+    for kind in [ConsumableKind::HealingIchor, ConsumableKind::SoulMagnet, ConsumableKind::PurgeSigil, ConsumableKind::FrenzyTotem] {
+        let def = get_consumable_definition(kind);
+        assert_eq!(def.kind, kind);
+        assert!(def.elite_drop_chance > def.drop_chance, "{} should favour elites", def.name);
+        assert!(def.lifetime_secs > 0.0);
+    }
+    assert_eq!(get_consumable_definition(ConsumableKind::SoulMagnet).drop_rate_stat, DropRateStat::Echoes);
+
+    let mut rng = StdRng::seed_from_u64(1);
+    let drops = |is_elite: bool, rng: &mut StdRng| (0..5000).filter(|_| roll_consumable_drop(is_elite, 1.0, 1.0, rng).is_some()).count();
+    assert!(drops(true, &mut rng) > drops(false, &mut rng) * 5);
+}
+
+#[test]
+fn test_drop_rates_follow_the_survivors_multipliers() {
+    let mut rng = StdRng::seed_from_u64(2);
+    assert!((0..5000).all(|_| roll_consumable_drop(true, 0.0, 0.0, &mut rng).is_none()), "no drop rate, no drops");
+
+    // With relic drops switched off only the echo-scaled Soul Magnet can still appear
+    let echoes_only: Vec<ConsumableKind> = (0..5000).filter_map(|_| roll_consumable_drop(true, 1.0, 0.0, &mut rng)).collect();
+    assert!(!echoes_only.is_empty());
+    assert!(echoes_only.iter().all(|kind| *kind == ConsumableKind::SoulMagnet));
+
+    let base = (0..5000).filter(|_| roll_consumable_drop(false, 1.0, 1.0, &mut rng).is_some()).count();
+    let boosted = (0..5000).filter(|_| roll_consumable_drop(false, 2.0, 2.0, &mut rng).is_some()).count();
+    assert!(boosted as f32 > base as f32 * 1.5, "doubled rates drop roughly twice as much ({} vs {})", boosted, base);
+}
+
+#[test]
+fn test_scaled_chances_clamp_to_certainty() {
+    for def in CONSUMABLE_DEFINITIONS.iter() {
+        assert_eq!(def.scaled_chance(0.5, 10.0, 10.0), 1.0);
+        assert_eq!(def.scaled_chance(0.5, 0.0, 0.0), 0.0);
+        let (echoes, relics) = (def.scaled_chance(0.1, 2.0, 1.0), def.scaled_chance(0.1, 1.0, 2.0));
+        match def.drop_rate_stat {
+            DropRateStat::Echoes => assert!(echoes > relics),
+            DropRateStat::Relics => assert!(relics > echoes),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use eldritch_hero::crowd::{separation_push, CrowdMember, SpatialGrid, CROWD_CELL_SIZE};
+use eldritch_hero::horror::HorrorType;
+use bevy::prelude::{Entity, Vec2};
+
+fn member(index: u32, position: Vec2, radius: f32, mass: f32) -> CrowdMember {
+    CrowdMember { entity: Entity::from_raw(index), position, velocity: Vec2::ZERO, radius, mass, flock: None }
+}
+
+#[test]
+fn test_grid_neighbors_match_brute_force() {
+    let mut grid = SpatialGrid::new(CROWD_CELL_SIZE);
+    let members: Vec<CrowdMember> = (0..300)
+        .map(|i| member(i, Vec2::new((i * 37 % 1000) as f32 - 500.0, (i * 91 % 800) as f32 - 400.0), 15.0, 1.0))
+        .collect();
+    for m in &members { grid.insert(*m); }
+
+    for centre in [Vec2::ZERO, Vec2::new(-480.0, 390.0), Vec2::new(123.4, -56.7)] {
+        let mut found: Vec<u32> = grid.neighbors(centre, 150.0).map(|m| m.entity.index()).collect();
+        let mut expected: Vec<u32> = members.iter().filter(|m| m.position.distance(centre) <= 150.0).map(|m| m.entity.index()).collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    grid.clear();
+    assert_eq!(grid.members().count(), 0);
+
+    // A crowd that wanders off doesn't leave a trail of empty buckets behind it
+    for step in 0..50 {
+        grid.clear();
+        for m in &members { grid.insert(CrowdMember { position: m.position + Vec2::splat(step as f32 * 500.0), ..*m }); }
+    }
+    grid.clear();
+    grid.clear();
+    assert_eq!(grid.bucket_count(), 0);
+}
+
+#[test]
+fn test_heavier_horrors_push_lighter_ones() {
+    let light = member(0, Vec2::new(-5.0, 0.0), 15.0, 1.0);
+    let heavy = member(1, Vec2::new(5.0, 0.0), 15.0, 4.0);
+    let light_push = separation_push(&light, &heavy);
+    let heavy_push = separation_push(&heavy, &light);
+    assert!(light_push.x < 0.0 && heavy_push.x > 0.0, "they move apart");
+    assert!(light_push.length() > heavy_push.length() * 3.9, "the light one gives way");
+
+    let apart = member(2, Vec2::new(100.0, 0.0), 15.0, 1.0);
+    assert_eq!(separation_push(&light, &apart), Vec2::ZERO);
+
+    // Exactly stacked horrors still split, in opposite directions
+    let a = member(3, Vec2::ZERO, 15.0, 1.0);
+    let b = member(4, Vec2::ZERO, 15.0, 1.0);
+    let (push_a, push_b) = (separation_push(&a, &b), separation_push(&b, &a));
+    assert!(push_a.length() > 0.0);
+    assert!((push_a + push_b).length() < 1e-4);
+}
+
+#[test]
+fn test_swarm_packs_flock_but_others_do_not() {
+    let mut grid = SpatialGrid::new(CROWD_CELL_SIZE);
+    let mut torment = member(0, Vec2::ZERO, 10.0, 1.0);
+    torment.flock = Some(HorrorType::CrawlingTorment);
+    let mut packmate = member(1, Vec2::new(60.0, 0.0), 10.0, 1.0);
+    packmate.flock = Some(HorrorType::CrawlingTorment);
+    packmate.velocity = Vec2::new(0.0, 100.0);
+    let loner = member(2, Vec2::new(0.0, -60.0), 10.0, 1.0);
+    for m in [torment, packmate, loner] { grid.insert(m); }
+
+    let drift = grid.displacement(&torment, 0.1);
+    assert!(drift.x > 0.0, "drawn toward its packmate");
+    assert!(drift.y > 0.0, "turns toward its packmate's heading, not the loner");
+    assert_eq!(grid.displacement(&loner, 0.1), Vec2::ZERO, "nothing overlaps the loner and it doesn't flock");
+}
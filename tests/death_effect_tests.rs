@@ -0,0 +1,43 @@
+use eldritch_hero::death_effects::{death_effects, spawn_death_effect, DeathEffect};
+use eldritch_hero::horror::HorrorType;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+
+#[test]
+fn test_requested_horrors_declare_their_death_effects() {
+    assert!(matches!(death_effects(HorrorType::AmorphousFleshbeast), [DeathEffect::Split { minion: HorrorType::CrawlingTorment, count }] if *count > 0));
+    assert!(matches!(death_effects(HorrorType::FloatingEyeball), [DeathEffect::GazePool { .. }]));
+    assert!(matches!(death_effects(HorrorType::MindLeech), [DeathEffect::SanityPuff { .. }]));
+    assert!(death_effects(HorrorType::FrenziedBehemoth).iter().any(|effect| matches!(effect, DeathEffect::Explode { .. })));
+    // Splitting into itself would never end
+    assert!(death_effects(HorrorType::CrawlingTorment).iter().all(|effect| !matches!(effect, DeathEffect::Split { .. })));
+}
+
+#[test]
+fn test_declared_death_effects_are_sane() {
+    for horror_type in [HorrorType::AmorphousFleshbeast, HorrorType::FrenziedBehemoth, HorrorType::VoidBlinker, HorrorType::FloatingEyeball, HorrorType::MindLeech] {
+        for effect in death_effects(horror_type) {
+            match *effect {
+                DeathEffect::Split { minion, count } => assert!(minion != horror_type && count > 0),
+                DeathEffect::Explode { fuse_secs, radius, damage } => assert!(fuse_secs > 0.0 && radius > 0.0 && damage > 0, "fuses give the survivor time to react"),
+                DeathEffect::GazePool { radius, damage_per_tick, tick_secs, duration_secs } => assert!(radius > 0.0 && damage_per_tick > 0 && tick_secs > 0.0 && duration_secs > tick_secs),
+                DeathEffect::SanityPuff { radius, stacks, duration_secs } => assert!(radius > 0.0 && stacks > 0 && duration_secs > 0.0),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_spawned_death_effects_leave_their_fuse_and_pool() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default())).init_asset::<Image>();
+    let blast = DeathEffect::Explode { fuse_secs: 0.5, radius: 90.0, damage: 30 };
+    let pool = DeathEffect::GazePool { radius: 55.0, damage_per_tick: 3, tick_secs: 0.5, duration_secs: 4.0 };
+    app.world.run_system_once(move |mut commands: Commands, asset_server: Res<AssetServer>| {
+        spawn_death_effect(&mut commands, &asset_server, blast, Vec3::ZERO, 1.0);
+        spawn_death_effect(&mut commands, &asset_server, pool, Vec3::ZERO, 1.0);
+    });
+    let mut names: Vec<String> = app.world.query::<&Name>().iter(&app.world).map(|name| name.as_str().to_string()).collect();
+    names.sort();
+    assert_eq!(names, vec!["DeathFuse", "GazePool"]);
+}
@@ -0,0 +1,44 @@
+use eldritch_hero::echoing_soul::{plan_soul_merges, SOUL_BANK_DISTANCE, SOUL_BUDGET, SOUL_MERGE_CELL_SIZE};
+use bevy::prelude::{Entity, Vec2};
+
+fn soul(index: u32, position: Vec2, value: u32) -> (Entity, Vec2, u32) { (Entity::from_raw(index), position, value) }
+
+#[test]
+fn test_nothing_merges_under_the_budget() {
+    let souls: Vec<_> = (0..SOUL_BUDGET as u32).map(|i| soul(i, Vec2::new(1000.0, 0.0), 25)).collect();
+    assert!(plan_soul_merges(&souls, Vec2::ZERO, 250.0, SOUL_BUDGET) == Default::default());
+}
+
+#[test]
+fn test_distant_souls_merge_by_cell_and_keep_their_value() {
+    let centre = Vec2::splat(SOUL_MERGE_CELL_SIZE * 3.5); // Well inside one cell
+    let souls = vec![
+        soul(0, centre + Vec2::new(-20.0, 0.0), 10),
+        soul(1, centre + Vec2::new(20.0, 0.0), 30),
+        soul(2, centre + Vec2::new(SOUL_MERGE_CELL_SIZE, 0.0), 25), // Alone in the next cell
+        soul(3, Vec2::new(10.0, 10.0), 25), // Within reach of the survivor
+        soul(4, Vec2::new(-12.0, 5.0), 25),
+    ];
+    let plan = plan_soul_merges(&souls, Vec2::ZERO, 250.0, 2);
+    assert_eq!(plan.clusters.len(), 1);
+    let cluster = &plan.clusters[0];
+    let mut members = cluster.members.clone();
+    members.sort();
+    assert_eq!(members, vec![Entity::from_raw(0), Entity::from_raw(1)]);
+    assert_eq!(cluster.value, 40);
+    assert!((cluster.position - (centre + Vec2::new(10.0, 0.0))).length() < 1e-3, "weighted towards the richer soul");
+    assert!(plan.banked.is_empty());
+}
+
+#[test]
+fn test_far_offscreen_souls_go_to_the_bank() {
+    let survivor = Vec2::new(500.0, -300.0);
+    let far = survivor + Vec2::new(SOUL_BANK_DISTANCE + 50.0, 0.0);
+    let souls: Vec<_> = (0..10).map(|i| soul(i, far + Vec2::new(i as f32 * SOUL_MERGE_CELL_SIZE, 0.0), 25))
+        .chain([soul(10, survivor, 25)])
+        .collect();
+    let plan = plan_soul_merges(&souls, survivor, 250.0, 5);
+    assert_eq!(plan.banked.len(), 10);
+    assert_eq!(plan.banked_value, 250);
+    assert!(plan.clusters.is_empty());
+}
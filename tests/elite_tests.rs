@@ -0,0 +1,71 @@
+use eldritch_hero::elites::{affix_count_for_wave, get_affix_definition, roll_elite_affixes, EliteAffix, EliteAffixes, EliteWard, ELITE_AFFIX_REGISTRY};
+use eldritch_hero::elites::HorrorDamage;
+use eldritch_hero::horror::HorrorType;
+use eldritch_hero::components::Health;
+use eldritch_hero::run_stats::{DamageDealtEvent, DamageSource};
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+
+#[test]
+fn test_ward_soaks_damage_until_broken() {
+    let mut ward = EliteWard::new(30);
+    assert_eq!(ward.soak(20, 0.0), 0, "the ward takes the whole hit");
+    assert_eq!(ward.amount, 10);
+    assert_eq!(ward.soak(25, 0.0), 15, "only what's left of the ward is soaked");
+    assert_eq!(ward.amount, 0);
+    assert_eq!(ward.soak(60, 0.0), 60);
+}
+
+#[test]
+fn test_shield_penetration_lets_part_of_a_hit_through() {
+    let mut ward = EliteWard::new(100);
+    assert_eq!(ward.soak(40, 25.0), 10, "25% of a 40 damage auto-attack gets past the ward");
+    assert_eq!(ward.amount, 70);
+
+    assert_eq!(ward.soak(20, 250.0), 20); // Clamped to the whole hit
+    assert_eq!(ward.amount, 70);
+}
+
+#[test]
+fn test_warded_hits_never_reach_health_or_the_damage_tally() {
+    let mut world = World::new();
+    world.init_resource::<Events<DamageDealtEvent>>();
+    let elite = world.spawn((Health(10), EliteWard::new(30))).id();
+    let hit = move |damage: i32| move |mut horror_damage: HorrorDamage, mut health_query: Query<&mut Health>| {
+        let mut health = health_query.get_mut(elite).unwrap();
+        horror_damage.apply(DamageSource::Explosion, elite, &mut health, damage)
+    };
+
+    assert_eq!(world.run_system_once(hit(25)), 0);
+    assert_eq!(world.get::<Health>(elite).unwrap().0, 10, "a hit the ward covers doesn't even briefly look lethal");
+    assert_eq!(world.run_system_once(hit(8)), 3);
+    assert_eq!(world.get::<Health>(elite).unwrap().0, 7);
+    assert_eq!(world.get::<EliteWard>(elite).unwrap().amount, 0);
+
+    let reported: Vec<i32> = world.resource_mut::<Events<DamageDealtEvent>>().drain().map(|event| event.amount).collect();
+    assert_eq!(reported, vec![0, 3], "only what got past the ward is reported");
+}
+
+#[test]
+fn test_affix_rolls_scale_with_wave() {
+    let mut rng = rand::thread_rng();
+    for wave in [1, 8, 20] {
+        for _ in 0..50 {
+            let affixes = roll_elite_affixes(&mut rng, HorrorType::VoidBlinker, wave);
+            assert_eq!(affixes.len(), affix_count_for_wave(wave));
+            assert!(!affixes.contains(&EliteAffix::Teleporting), "Void Blinkers already blink");
+            assert!(affixes.iter().enumerate().all(|(i, affix)| !affixes[i + 1..].contains(affix)), "affixes are distinct");
+        }
+    }
+    assert!(affix_count_for_wave(20) > affix_count_for_wave(1));
+
+    let early = EliteAffixes::new(vec![EliteAffix::Vampiric, EliteAffix::FrostNova], 100, 1.0);
+    let late = EliteAffixes::new(vec![EliteAffix::Vampiric, EliteAffix::FrostNova], 100, 3.0);
+    assert!(late.vampiric_heal() > early.vampiric_heal());
+    assert!(late.frost_nova_damage() > early.frost_nova_damage());
+
+    let expected_bonus = get_affix_definition(EliteAffix::Vampiric).drop_chance_bonus + get_affix_definition(EliteAffix::FrostNova).drop_chance_bonus;
+    assert!((early.drop_chance_bonus() - expected_bonus).abs() < 1e-9);
+    assert!(early.score_multiplier() > 1.0);
+    assert_eq!(ELITE_AFFIX_REGISTRY.len(), 8);
+}
@@ -0,0 +1,93 @@
+use eldritch_hero::flow_field::{traversal_layer, FlowBuild, FlowField, TraversalLayer, FLOW_CELL_SIZE, FLOW_FIELD_DIMENSION};
+use eldritch_hero::arena::{generate_chunk, ArenaLayout, ObstacleKind};
+use eldritch_hero::horror::HorrorType;
+use bevy::prelude::{IVec2, Vec2};
+
+// Follows the field from `start` until it reaches the target cell; returns the positions visited
+fn walk(build: FlowBuild, start: Vec2) -> Vec<Vec2> {
+    let layer = build.finish();
+    let mut position = start;
+    let mut path = vec![position];
+    while let Some(direction) = layer.direction(position) {
+        position += direction * FLOW_CELL_SIZE * direction.abs().max_element().recip();
+        path.push(position);
+        assert!(path.len() < 500, "the field loops");
+    }
+    path
+}
+
+#[test]
+fn test_open_ground_leads_straight_home() {
+    let target = Vec2::new(10.0, 10.0);
+    let mut build = FlowBuild::new(target);
+    assert!(build.advance(usize::MAX, |_| false));
+    let path = walk(build, Vec2::new(10.0 + 10.0 * FLOW_CELL_SIZE, 10.0));
+    assert_eq!(path.len(), 11, "ten steps along the row");
+    assert!(path.last().unwrap().distance(target) < FLOW_CELL_SIZE);
+}
+
+#[test]
+fn test_field_routes_around_walls_and_finishes_over_several_frames() {
+    // A wall two cells east of the target, open only past its far ends
+    let wall = |centre: Vec2| (96.0..144.0).contains(&centre.x) && centre.y.abs() < 10.0 * FLOW_CELL_SIZE;
+    let mut build = FlowBuild::new(Vec2::new(24.0, 24.0));
+    let mut frames = 1;
+    while !build.advance(1000, wall) { frames += 1; }
+    assert!(frames > 1, "a full grid is more than one frame's budget");
+    assert!(frames <= (FLOW_FIELD_DIMENSION * FLOW_FIELD_DIMENSION) as usize / 1000 + 1);
+
+    let path = walk(build, Vec2::new(8.5 * FLOW_CELL_SIZE, 0.5 * FLOW_CELL_SIZE));
+    assert!(path.iter().all(|position| !wall(*position)), "the path never enters the wall");
+    assert!(path.iter().any(|position| position.y.abs() >= 10.0 * FLOW_CELL_SIZE), "it goes round an end");
+}
+
+#[test]
+fn test_flyers_take_their_own_layer_over_pits() {
+    assert_eq!(traversal_layer(HorrorType::FloatingEyeball), TraversalLayer::Flying);
+    assert_eq!(traversal_layer(HorrorType::CrawlingTorment), TraversalLayer::Ground);
+
+    let seed = 11;
+    let chunk = (1..60).map(|x| IVec2::new(x, 0)).find(|chunk| generate_chunk(seed, *chunk).iter().any(|o| o.kind == ObstacleKind::Pit))
+        .expect("some chunk has a pit");
+    let mut layout = ArenaLayout::new(seed);
+    let pit = *layout.load_chunk(chunk).unwrap().iter().find(|o| o.kind == ObstacleKind::Pit).unwrap();
+
+    let mut field = FlowField::default();
+    let target = pit.centre + Vec2::new(0.0, 6.0 * FLOW_CELL_SIZE);
+    field.replan(target, true);
+    for _ in 0..20 { field.advance(2500, &layout); }
+    for layer in TraversalLayer::ALL { assert!(field.layer(layer).is_some()); }
+    assert_eq!(field.sample(TraversalLayer::Ground, pit.centre), None, "walkers can't stand in a pit");
+    assert!(field.sample(TraversalLayer::Flying, pit.centre).is_some(), "flyers cross it");
+    let far = target + Vec2::new(0.0, 20.0 * FLOW_CELL_SIZE);
+    assert!(field.sample(TraversalLayer::Ground, far).unwrap().y < 0.0);
+}
+
+#[test]
+fn test_a_short_step_repairs_only_the_cells_near_the_survivor() {
+    let wall = |centre: Vec2| (96.0..144.0).contains(&centre.x) && centre.y.abs() < 10.0 * FLOW_CELL_SIZE;
+    let mut full = FlowBuild::new(Vec2::new(24.0, 24.0));
+    assert!(full.advance(usize::MAX, wall));
+    let previous = full.finish();
+
+    let target = Vec2::new(24.0 - 2.0 * FLOW_CELL_SIZE, 24.0);
+    let tested = std::cell::Cell::new(0);
+    let mut repair = FlowBuild::repair(target, &previous).expect("two cells is a short step");
+    assert!(repair.advance(usize::MAX, |centre| { tested.set(tested.get() + 1); wall(centre) }));
+    assert!(tested.get() < (FLOW_FIELD_DIMENSION * FLOW_FIELD_DIMENSION) as usize / 4, "only the survivor's surroundings are re-swept");
+
+    let (layer, _) = repair.finish_over(Some(&previous));
+    assert_eq!(layer.target(), IVec2::new(-2, 0));
+    for start in [Vec2::new(8.5, 0.5), Vec2::new(-40.5, 30.5), Vec2::new(30.5, -40.5)] {
+        let mut position = start * FLOW_CELL_SIZE;
+        let mut steps = 0;
+        while let Some(direction) = layer.direction(position) {
+            position += direction * FLOW_CELL_SIZE * direction.abs().max_element().recip();
+            assert!(!wall(position), "the path never enters the wall");
+            steps += 1;
+            assert!(steps < 500, "the merged field loops");
+        }
+        assert!(position.distance(target) < FLOW_CELL_SIZE, "every path ends at the new target");
+    }
+    assert!(FlowBuild::repair(target + Vec2::new(20.0 * FLOW_CELL_SIZE, 0.0), &previous).is_none(), "a long way off needs a full sweep");
+}
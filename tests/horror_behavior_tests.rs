@@ -0,0 +1,62 @@
+use eldritch_hero::horror_behavior::{
+    default_behavior, BehaviorLayer, Condition, StateEffect, Surroundings,
+    BLINK_OVERLAY_BEHAVIOR, FLOATING_EYEBALL_BEHAVIOR, FRENZIED_BEHEMOTH_BEHAVIOR,
+};
+use eldritch_hero::horror::HorrorType;
+use bevy::math::Vec2;
+
+fn at_distance(distance: f32) -> Surroundings {
+    Surroundings { position: Vec2::ZERO, player_position: Vec2::new(distance, 0.0) }
+}
+
+#[test]
+fn test_graph_transitions_stay_in_bounds() {
+    let types = [HorrorType::FloatingEyeball, HorrorType::VoidBlinker, HorrorType::FleshWeaver, HorrorType::FrenziedBehemoth];
+    let graphs = types.iter().filter_map(|horror_type| default_behavior(*horror_type)).chain([&BLINK_OVERLAY_BEHAVIOR]);
+    for graph in graphs {
+        assert!(!graph.states.is_empty(), "{} has no states", graph.name);
+        for state in graph.states {
+            assert!(!state.transitions.is_empty(), "{} would get stuck in {}", graph.name, state.name);
+            for transition in state.transitions {
+                assert!(transition.to < graph.states.len(), "{}: {} leads nowhere", graph.name, state.name);
+                if let Condition::AfterRandom(min, max) = transition.when { assert!(min < max); }
+            }
+        }
+    }
+    assert!(default_behavior(HorrorType::SkitteringShadowling).is_none(), "plain horrors just chase");
+}
+
+#[test]
+fn test_behemoth_charges_only_inside_its_window() {
+    let mut rng = rand::thread_rng();
+    let mut layer = BehaviorLayer::new(&FRENZIED_BEHEMOTH_BEHAVIOR);
+    assert_eq!(layer.advance(0.0, at_distance(50.0), &mut rng), Some(0), "the first advance enters the starting state");
+    assert_eq!(layer.advance(7.0, at_distance(50.0), &mut rng), None, "too close to charge");
+    assert_eq!(layer.advance(0.1, at_distance(250.0), &mut rng), Some(1));
+    assert_eq!(layer.current().name, "Telegraph");
+    assert_eq!(layer.advance(1.0, at_distance(250.0), &mut rng), None);
+    assert_eq!(layer.advance(0.3, at_distance(250.0), &mut rng), Some(2));
+    assert_eq!(layer.advance(1.1, at_distance(250.0), &mut rng), Some(3));
+    assert_eq!(layer.advance(6.1, at_distance(900.0), &mut rng), Some(4));
+    assert_eq!(layer.advance(0.1, at_distance(900.0), &mut rng), None, "out of range after recovering");
+    assert_eq!(layer.advance(0.1, at_distance(300.0), &mut rng), Some(1), "no second cooldown once recovered");
+}
+
+#[test]
+fn test_targeting_and_random_waits() {
+    let mut rng = rand::thread_rng();
+    let mut eyeball = BehaviorLayer::new(&FLOATING_EYEBALL_BEHAVIOR);
+    eyeball.advance(0.0, at_distance(500.0), &mut rng);
+    assert_eq!(eyeball.advance(0.1, at_distance(100.0), &mut rng).map(|state| FLOATING_EYEBALL_BEHAVIOR.states[state].name), Some("Kite"));
+
+    for _ in 0..50 {
+        let mut overlay = BehaviorLayer::new(&BLINK_OVERLAY_BEHAVIOR);
+        overlay.advance(0.0, at_distance(300.0), &mut rng);
+        assert_eq!(overlay.advance(3.9, at_distance(300.0), &mut rng), None, "never blinks before the shortest wait");
+        assert_eq!(overlay.advance(3.2, at_distance(300.0), &mut rng), Some(1), "always blinks by the longest wait");
+
+        overlay.apply_targeting(StateEffect::PickFlankTarget { flank_distance: 75.0, min_range: 100.0, max_range: 250.0 }, at_distance(300.0), &mut rng);
+        let landing = overlay.target.expect("a flank target is picked").distance(Vec2::new(300.0, 0.0));
+        assert!((100.0 - 1e-3..=250.0 + 1e-3).contains(&landing), "landed {landing} from the survivor");
+    }
+}
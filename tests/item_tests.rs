@@ -20,6 +20,7 @@ fn setup_test_item_library() -> ItemLibrary {
             auto_weapon_projectile_speed_multiplier_increase: None,
         }],
         icon_path: "sprites/dummy_icon.png".to_string(),
+        ..default()
     });
     library.items.push(ItemDefinition {
         id: ItemId(1002),
@@ -34,6 +35,7 @@ fn setup_test_item_library() -> ItemLibrary {
             auto_weapon_projectile_speed_multiplier_increase: None,
         }],
         icon_path: "sprites/dummy_icon.png".to_string(),
+        ..default()
     });
     library.items.push(ItemDefinition {
         id: ItemId(1003),
@@ -48,6 +50,7 @@ fn setup_test_item_library() -> ItemLibrary {
             auto_weapon_projectile_speed_multiplier_increase: None,
         }],
         icon_path: "sprites/dummy_icon.png".to_string(),
+        ..default()
     });
     library
 }
@@ -143,6 +146,8 @@ fn test_sunfire_burst_config_with_burn() {
     app.world.insert_resource(State::new(eldritch_hero::game::AppState::InGame));
     app.add_event::<eldritch_hero::game::ItemCollectedEvent>();
     app.add_event::<eldritch_hero::audio::PlaySoundEvent>();
+    app.add_event::<eldritch_hero::run_stats::DamageDealtEvent>();
+    app.add_event::<eldritch_hero::run_stats::SurvivorHealedEvent>();
 
     // Run startup systems (like populate_automatic_weapon_library)
     app.update();
@@ -0,0 +1,54 @@
+use eldritch_hero::knockback::Poise;
+use eldritch_hero::horror_behavior::{BehaviorLayer, Surroundings, FRENZIED_BEHEMOTH_BEHAVIOR};
+use bevy::math::Vec2;
+
+#[test]
+fn test_heavier_horrors_are_shoved_less_and_impulse_decays() {
+    let mut light = Poise::new(1.0, 100.0);
+    let mut heavy = Poise::new(4.0, 400.0);
+    light.take_hit(Vec2::new(3.0, 4.0), 200.0);
+    heavy.take_hit(Vec2::new(3.0, 4.0), 200.0);
+    assert!((light.velocity.length() - 200.0).abs() < 1e-3, "direction is normalised");
+    assert!((heavy.velocity.length() - 50.0).abs() < 1e-3);
+
+    let before = light.velocity.length();
+    light.tick(0.1);
+    assert!(light.velocity.length() < before);
+    for _ in 0..60 { light.tick(0.1); }
+    assert_eq!(light.velocity, Vec2::ZERO, "an impulse dies out");
+}
+
+#[test]
+fn test_poise_breaks_then_recovers() {
+    let mut poise = Poise::new(1.0, 60.0);
+    assert!(!poise.take_hit(Vec2::X, 300.0));
+    assert!(poise.take_hit(Vec2::X, 300.0), "enough poise damage staggers");
+    assert!(poise.is_staggered());
+    assert!(!poise.take_hit(Vec2::X, 5000.0), "no re-stagger while staggered");
+    assert_eq!(poise.current, poise.max, "poise resets after a break");
+
+    poise.tick(1.0);
+    assert!(!poise.is_staggered());
+    poise.take_hit(Vec2::X, 300.0);
+    let damaged = poise.current;
+    poise.tick(1.0);
+    assert_eq!(poise.current, damaged, "no recovery right after a hit");
+    for _ in 0..10 { poise.tick(0.5); }
+    assert_eq!(poise.current, poise.max);
+}
+
+#[test]
+fn test_stagger_interrupts_the_behemoth_telegraph() {
+    let mut rng = rand::thread_rng();
+    let in_range = Surroundings { position: Vec2::ZERO, player_position: Vec2::new(250.0, 0.0) };
+    let mut layer = BehaviorLayer::new(&FRENZIED_BEHEMOTH_BEHAVIOR);
+    layer.advance(0.0, in_range, &mut rng);
+    assert!(!layer.interrupt(), "roaming can't be interrupted");
+    assert_eq!(layer.advance(0.1, in_range, &mut rng), None);
+
+    layer.advance(6.0, in_range, &mut rng);
+    assert_eq!(layer.current().name, "Telegraph");
+    assert!(layer.interrupt());
+    layer.advance(0.01, in_range, &mut rng);
+    assert_eq!(layer.current().name, "Recover", "the charge never happens");
+}
@@ -0,0 +1,51 @@
+use eldritch_hero::leaderboard::{format_date, LeaderboardEntry, LeaderboardKey, Leaderboards, MAX_LEADERBOARD_ENTRIES};
+use eldritch_hero::characters::CharacterId;
+use eldritch_hero::game::GameMode;
+use eldritch_hero::items::{AutomaticWeaponId, ItemId};
+use eldritch_hero::skills::SkillId;
+use eldritch_hero::persistence::{from_ron_str, to_ron_string};
+
+fn entry(score: u32, seed: u64) -> LeaderboardEntry {
+    LeaderboardEntry {
+        score, survived_secs: 600, wave_reached: 20, seed, finished_at_unix_secs: 1_700_000_000,
+        weapon_id: AutomaticWeaponId(2), relic_ids: vec![ItemId(3)], skill_ids: vec![SkillId(1)],
+    }
+}
+
+#[test]
+fn test_submit_ranks_entries_and_keeps_tables_apart() {
+    let void_seer = LeaderboardKey { character: CharacterId(2), mode: GameMode::Standard };
+    let zealot = LeaderboardKey { character: CharacterId(1), mode: GameMode::Standard };
+    let mut leaderboards = Leaderboards::default();
+
+    assert_eq!(leaderboards.submit(void_seer, entry(500, 1)), Some(0));
+    assert_eq!(leaderboards.submit(void_seer, entry(900, 2)), Some(0), "a higher score is the new personal best");
+    assert_eq!(leaderboards.submit(void_seer, entry(500, 3)), Some(2), "ties rank behind the earlier run");
+    assert_eq!(leaderboards.submit(zealot, entry(100, 4)), Some(0), "each character has its own table");
+
+    let seeds: Vec<u64> = leaderboards.table(void_seer).unwrap().entries.iter().map(|entry| entry.seed).collect();
+    assert_eq!(seeds, vec![2, 1, 3]);
+}
+
+#[test]
+fn test_full_table_rejects_low_scores() {
+    let key = LeaderboardKey { character: CharacterId(0), mode: GameMode::Standard };
+    let mut leaderboards = Leaderboards::default();
+    for score in 1..=MAX_LEADERBOARD_ENTRIES as u32 { leaderboards.submit(key, entry(score * 100, score as u64)); }
+
+    assert_eq!(leaderboards.submit(key, entry(50, 99)), None);
+    assert_eq!(leaderboards.submit(key, entry(150, 100)), Some(MAX_LEADERBOARD_ENTRIES - 1));
+    let table = leaderboards.table(key).unwrap();
+    assert_eq!(table.entries.len(), MAX_LEADERBOARD_ENTRIES);
+    assert_eq!(table.entries.last().unwrap().score, 150, "the lowest score fell off");
+
+    let restored: Leaderboards = from_ron_str(&to_ron_string(&leaderboards).unwrap()).unwrap();
+    assert_eq!(restored.tables, leaderboards.tables);
+}
+
+#[test]
+fn test_format_date() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(951_782_400), "2000-02-29");
+    assert_eq!(format_date(1_700_000_000), "2023-11-14");
+}
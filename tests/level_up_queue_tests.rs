@@ -0,0 +1,43 @@
+use eldritch_hero::survivor::Survivor;
+use eldritch_hero::items::{AutomaticWeaponId, AutomaticWeaponLibrary};
+
+fn fresh_survivor() -> Survivor {
+    let mut survivor = Survivor::new_with_skills_items_and_weapon(Vec::new(), Vec::new(), AutomaticWeaponId(0), &AutomaticWeaponLibrary::default());
+    survivor.xp_gain_multiplier = 1.0;
+    survivor
+}
+
+#[test]
+fn test_one_huge_gain_queues_every_level_it_pays_for() {
+    let mut survivor = fresh_survivor();
+    let mut needed = 0;
+    for level in 1..=3 { survivor.level = level; needed += survivor.experience_to_next_level(); }
+    survivor.level = 1;
+
+    assert_eq!(survivor.gain_experience(needed + 1), 3);
+    assert_eq!(survivor.level, 4);
+    assert_eq!(survivor.pending_level_ups, 3);
+    assert_eq!(survivor.current_level_xp, 1, "the remainder carries into the next level");
+}
+
+#[test]
+fn test_small_gains_queue_nothing_until_a_level_is_reached() {
+    let mut survivor = fresh_survivor();
+    let cost = survivor.experience_to_next_level();
+    assert_eq!(survivor.gain_experience(cost - 1), 0);
+    assert_eq!(survivor.pending_level_ups, 0);
+    assert_eq!(survivor.gain_experience(1), 1);
+    assert_eq!((survivor.level, survivor.pending_level_ups), (2, 1));
+}
+
+#[test]
+fn test_each_finished_screen_takes_one_level_off_the_queue() {
+    let mut survivor = fresh_survivor();
+    survivor.pending_level_ups = 3;
+    assert!(survivor.finish_level_up());
+    assert!(survivor.finish_level_up());
+    assert!(!survivor.finish_level_up(), "the last screen returns to the hunt");
+    assert_eq!(survivor.pending_level_ups, 0);
+    assert!(!survivor.finish_level_up(), "never goes below zero");
+    assert_eq!(survivor.pending_level_ups, 0);
+}
@@ -0,0 +1,48 @@
+use eldritch_hero::merchant::{merchant_due, roll_obol_drop, stock_merchant, try_spend, wave_price, MerchantWare, MERCHANT_WAVE_INTERVAL, RELIC_PRICE, STOCK_RELICS, STOCK_WEAPON_UPGRADES};
+use eldritch_hero::items::ItemId;
+use eldritch_hero::skills::SkillId;
+use eldritch_hero::upgrades::{UpgradeCard, UpgradeId, UpgradeRarity, UpgradeType};
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_prices_rise_with_the_wave_and_spending_needs_the_obols() {
+    assert_eq!(wave_price(RELIC_PRICE, 1), RELIC_PRICE);
+    assert!(wave_price(RELIC_PRICE, 5) > wave_price(RELIC_PRICE, 2));
+    assert!((1..30).all(|wave| wave_price(10, wave + 1) >= wave_price(10, wave)));
+
+    let mut obols = 12;
+    assert!(!try_spend(&mut obols, 13));
+    assert_eq!(obols, 12, "a failed purchase costs nothing");
+    assert!(try_spend(&mut obols, 12));
+    assert_eq!(obols, 0);
+}
+
+#[test]
+fn test_elites_always_pay_and_the_merchant_comes_every_few_waves() {
+    let mut rng = StdRng::seed_from_u64(8);
+    assert!(roll_obol_drop(true, 2, &mut rng) > roll_obol_drop(true, 0, &mut rng));
+    let ordinary: u32 = (0..5000).map(|_| roll_obol_drop(false, 0, &mut rng)).sum();
+    assert!(ordinary > 0 && ordinary < 1000, "ordinary horrors drop an obol now and then ({})", ordinary);
+
+    let visits: Vec<u32> = (1..=12).filter(|wave| merchant_due(*wave, 0)).collect();
+    assert_eq!(visits, (1..=12 / MERCHANT_WAVE_INTERVAL).map(|n| n * MERCHANT_WAVE_INTERVAL).collect::<Vec<_>>());
+    assert!(!merchant_due(MERCHANT_WAVE_INTERVAL, MERCHANT_WAVE_INTERVAL), "once per wave");
+}
+
+#[test]
+fn test_stock_covers_every_kind_of_ware() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let cards: Vec<UpgradeCard> = (0..4).map(|i| UpgradeCard::new(UpgradeId(i), format!("Card {}", i), "", UpgradeType::SurvivorSpeed(1), UpgradeRarity::Regular)).collect();
+    let relics = [ItemId(1), ItemId(2), ItemId(3)];
+    let stock = stock_merchant(&relics, &[SkillId(4)], &cards, 4, &mut rng);
+    assert_eq!(stock.len(), STOCK_RELICS + 1 + 1 + STOCK_WEAPON_UPGRADES);
+    assert_eq!(stock.iter().filter(|offer| matches!(offer.ware, MerchantWare::Relic(_))).count(), STOCK_RELICS);
+    assert!(stock.iter().any(|offer| offer.ware == MerchantWare::Skill(SkillId(4))));
+    assert!(stock.iter().any(|offer| offer.ware == MerchantWare::Healing));
+    assert!(stock.iter().all(|offer| !offer.sold && offer.price == wave_price(offer.ware.base_price(), 4)));
+
+    // Nothing to sell but healing
+    let bare = stock_merchant(&[], &[], &[], 1, &mut rng);
+    assert_eq!(bare.len(), 1);
+    assert_eq!(bare[0].ware, MerchantWare::Healing);
+}
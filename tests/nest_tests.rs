@@ -0,0 +1,44 @@
+use eldritch_hero::nests::{edge_indicator_position, get_nest_definition, NestSchedule, MAX_ACTIVE_NESTS, NEST_DEFINITIONS};
+use eldritch_hero::horror::{HorrorStats, HorrorType};
+use bevy::math::Vec2;
+use std::time::Duration;
+
+#[test]
+fn test_nests_are_stationary_and_emit_ordinary_horrors() {
+    for def in NEST_DEFINITIONS.iter() {
+        let stats = HorrorStats::get_for_type(def.horror_type, 1.0);
+        assert_eq!(stats.speed, 0.0, "{} must not wander", def.horror_type.display_name());
+        assert!(stats.health > HorrorStats::get_for_type(def.emits, 1.0).health * 5, "worth the trip to destroy");
+        assert!(get_nest_definition(def.emits).is_none(), "nests don't breed nests");
+        assert!(def.emit_count > 0 && def.emit_count <= def.max_brood && def.chest_quality > 0.0);
+    }
+    assert!(get_nest_definition(HorrorType::FleshNest).is_some() && get_nest_definition(HorrorType::VoidRift).is_some());
+    assert!(get_nest_definition(HorrorType::MindLeech).is_none());
+}
+
+#[test]
+fn test_edge_indicator_sits_on_the_screen_edge_pointing_at_the_nest() {
+    let centre = Vec2::new(100.0, 50.0);
+    let half = Vec2::new(640.0, 360.0);
+    assert_eq!(edge_indicator_position(centre, half, centre + Vec2::new(300.0, -200.0), 20.0), None, "on screen");
+
+    let right = edge_indicator_position(centre, half, centre + Vec2::new(2000.0, 0.0), 20.0).unwrap();
+    assert!((right - (centre + Vec2::new(620.0, 0.0))).length() < 1e-3);
+
+    let target = centre + Vec2::new(-900.0, 1200.0);
+    let corner = edge_indicator_position(centre, half, target, 20.0).unwrap();
+    let offset = corner - centre;
+    assert!((offset.y - 340.0).abs() < 1e-3 && offset.x.abs() <= 620.0, "clamped to the top edge");
+    assert!(offset.normalize().dot((target - centre).normalize()) > 0.9999, "along the line to the nest");
+}
+
+#[test]
+fn test_nest_schedule_waits_then_repeats_and_respects_the_cap() {
+    let mut schedule = NestSchedule::default();
+    assert!(!schedule.tick(Duration::from_secs(30), 0), "no nest right at the start");
+    assert!(schedule.tick(Duration::from_secs(31), 0));
+    assert!(!schedule.tick(Duration::from_secs(1), 0), "the next one waits its interval");
+    assert!(!schedule.tick(Duration::from_secs(60), MAX_ACTIVE_NESTS), "skipped while the map is full");
+    assert!(!schedule.tick(Duration::from_secs(1), 0), "and not queued up");
+    assert!(schedule.tick(Duration::from_secs(60), MAX_ACTIVE_NESTS - 1));
+}
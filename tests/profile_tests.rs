@@ -0,0 +1,63 @@
+use eldritch_hero::profile::{PlayerProfile, MetaUpgradeKind, PROFILE_VERSION, WEAPON_UNLOCK_COST, RELIC_UNLOCK_COST};
+use eldritch_hero::items::{AutomaticWeaponDefinition, AutomaticWeaponId, ItemDefinition, ItemId, UnlockCondition};
+use eldritch_hero::persistence::{from_ron_str, to_ron_string};
+
+#[test]
+fn test_profile_tolerates_missing_fields_and_migrates() {
+    let mut profile: PlayerProfile = from_ron_str("(lore: 42, meta_upgrades: (speed: 2))").expect("partial profile should deserialize");
+    assert_eq!(profile.version, 0);
+    assert_eq!(profile.lore, 42);
+    assert_eq!(profile.meta_upgrades.get(MetaUpgradeKind::Speed), 2);
+    assert_eq!(profile.meta_upgrades.get(MetaUpgradeKind::MaxHealth), 0);
+    assert!(profile.unlocked_weapon_ids.is_empty());
+
+    profile.migrate();
+    assert_eq!(profile.version, PROFILE_VERSION);
+
+    let restored: PlayerProfile = from_ron_str(&to_ron_string(&profile).unwrap()).unwrap();
+    assert_eq!(restored, profile);
+}
+
+#[test]
+fn test_profile_banks_run_and_buys_upgrades() {
+    let mut profile = PlayerProfile::default();
+    let earned = profile.bank_run(1000, 500);
+    assert_eq!(earned, PlayerProfile::lore_for_run(1000, 500));
+    assert_eq!(profile.lore, earned);
+    assert_eq!(profile.runs_completed, 1);
+
+    profile.lore = 1000;
+    let first_cost = profile.upgrade_cost(MetaUpgradeKind::MaxHealth).unwrap();
+    assert!(profile.try_purchase_upgrade(MetaUpgradeKind::MaxHealth));
+    assert_eq!(profile.lore, 1000 - first_cost);
+    assert!(profile.upgrade_cost(MetaUpgradeKind::MaxHealth).unwrap() > first_cost);
+
+    for _ in 0..MetaUpgradeKind::RerollCharges.max_level() { profile.lore += 1000; assert!(profile.try_purchase_upgrade(MetaUpgradeKind::RerollCharges)); }
+    assert_eq!(profile.upgrade_cost(MetaUpgradeKind::RerollCharges), None);
+    assert!(!profile.try_purchase_upgrade(MetaUpgradeKind::RerollCharges));
+}
+
+#[test]
+fn test_profile_unlocks_weapons_and_relics() {
+    let mut profile = PlayerProfile::default();
+    let starter_weapon = AutomaticWeaponDefinition { id: AutomaticWeaponId(0), unlock: UnlockCondition::Starter, ..Default::default() };
+    // A purchasable weapon stays locked whatever its id, and a starter added later is free whatever its id
+    let locked_weapon = AutomaticWeaponDefinition { id: AutomaticWeaponId(1), unlock: UnlockCondition::Purchase, ..Default::default() };
+    let late_starter = AutomaticWeaponDefinition { id: AutomaticWeaponId(99), unlock: UnlockCondition::Starter, ..Default::default() };
+    assert!(profile.is_weapon_unlocked(&starter_weapon));
+    assert!(profile.is_weapon_unlocked(&late_starter));
+    assert!(!profile.is_weapon_unlocked(&locked_weapon));
+
+    assert!(!profile.try_unlock_weapon(&locked_weapon), "cannot unlock without Lore");
+    profile.lore = WEAPON_UNLOCK_COST + RELIC_UNLOCK_COST;
+    assert!(profile.try_unlock_weapon(&locked_weapon));
+    assert!(profile.is_weapon_unlocked(&locked_weapon));
+    assert!(!profile.try_unlock_weapon(&locked_weapon), "already unlocked");
+
+    let locked_relic = ItemDefinition { id: ItemId(2), ..Default::default() };
+    assert_eq!(locked_relic.unlock, UnlockCondition::Purchase, "a definition that doesn't say is bought");
+    assert!(!profile.is_item_unlocked(&locked_relic));
+    assert!(profile.try_unlock_item(&locked_relic));
+    assert!(profile.is_item_unlocked(&locked_relic));
+    assert_eq!(profile.lore, 0);
+}
@@ -0,0 +1,54 @@
+use eldritch_hero::props::{generate_props, get_prop_definition, roll_prop_loot, PropField, PropLoot, PROP_DEFINITIONS};
+use eldritch_hero::arena::{chunk_of, generate_chunk};
+use eldritch_hero::horror::{HorrorStats, HorrorType};
+use bevy::prelude::IVec2;
+use rand::{rngs::StdRng, SeedableRng};
+
+#[test]
+fn test_props_are_seeded_and_clear_of_the_ruins() {
+    let mut total = 0;
+    for chunk in (-3..=3).flat_map(|x| (-3..=3).map(move |y| IVec2::new(x, y))) {
+        let obstacles = generate_chunk(99, chunk);
+        let props = generate_props(99, chunk, &obstacles);
+        assert_eq!(props, generate_props(99, chunk, &obstacles), "same seed, same props");
+        for prop in &props {
+            assert_eq!(chunk_of(prop.position), chunk);
+            assert!(get_prop_definition(prop.horror_type).is_some());
+            assert!(obstacles.iter().all(|obstacle| obstacle.penetration(prop.position, 30.0).is_none()));
+        }
+        total += props.len();
+    }
+    assert!(total > 20, "a 7x7 patch of ruins has props to break");
+}
+
+#[test]
+fn test_drop_tables_respect_drop_rate_multipliers() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let urn = get_prop_definition(HorrorType::Urn).unwrap();
+    let relics = |relic_multiplier: f32, rng: &mut StdRng| (0..2000)
+        .filter(|_| roll_prop_loot(urn, 1.0, relic_multiplier, rng).contains(&PropLoot::Relic))
+        .count();
+    let base = relics(1.0, &mut rng);
+    let boosted = relics(3.0, &mut rng);
+    assert!(boosted > base * 2, "tripling the relic rate roughly triples relic drops ({} vs {})", boosted, base);
+    assert!((0..100).all(|_| roll_prop_loot(urn, 0.0, 0.0, &mut rng).is_empty()), "zero multipliers drop nothing");
+
+    // Props are stationary, harmless and worth no xp of their own
+    for def in PROP_DEFINITIONS.iter() {
+        let stats = HorrorStats::get_for_type(def.horror_type, 1.0);
+        assert_eq!((stats.speed, stats.damage_on_collision, stats.xp_value), (0.0, 0, 0));
+        assert!(!def.drops.is_empty());
+    }
+}
+
+#[test]
+fn test_broken_props_stay_broken() {
+    let mut field = PropField::new(7, [(IVec2::new(1, 1), 2)]);
+    assert!(field.is_broken(IVec2::new(1, 1), 2));
+    assert!(!field.is_broken(IVec2::new(1, 1), 0));
+    field.break_prop(IVec2::new(-4, 0), 0);
+    let mut broken: Vec<_> = field.broken().collect();
+    broken.sort_by_key(|(chunk, index)| (chunk.x, chunk.y, *index));
+    assert_eq!(broken, vec![(IVec2::new(-4, 0), 0), (IVec2::new(1, 1), 2)]);
+    assert_eq!(field.seed(), 7);
+}
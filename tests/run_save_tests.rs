@@ -0,0 +1,116 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use eldritch_hero::run_save::{RunSnapshot, SurvivorSnapshot, HorrorSnapshot, EchoingSoulSnapshot, ItemDropSnapshot, ObolSnapshot, RewardChestSnapshot, RUN_SAVE_VERSION};
+use eldritch_hero::survivor::{Survivor, SanityStrain};
+use eldritch_hero::skills::{ActiveSkillInstance, SkillId};
+use eldritch_hero::items::{AutomaticWeaponId, AutomaticWeaponLibrary, ItemId};
+use eldritch_hero::weapons::{CircleOfWarding, SwarmOfNightmares};
+use eldritch_hero::horror::HorrorType;
+use eldritch_hero::elites::{EliteAffix, EliteWard};
+use eldritch_hero::components::Health;
+use eldritch_hero::horror_behavior::{BehaviorLayerSnapshot, HorrorBehavior, FLOATING_EYEBALL_BEHAVIOR};
+use eldritch_hero::characters::CharacterId;
+use eldritch_hero::persistence::to_ron_string;
+use eldritch_hero::run_stats::RunStats;
+use eldritch_hero::game::{GameMode, RunSeed};
+
+fn sample_snapshot() -> RunSnapshot {
+    let mut survivor = Survivor::new_with_skills_items_and_weapon(vec![ActiveSkillInstance::new(SkillId(1))], vec![ItemId(3)], AutomaticWeaponId(2), &AutomaticWeaponLibrary::default());
+    survivor.level = 7;
+    survivor.equipped_skills[0].current_cooldown = Duration::from_millis(1500);
+
+    let mut game_timer = Timer::from_seconds(3600.0, TimerMode::Once);
+    game_timer.tick(Duration::from_secs(754));
+    RunSnapshot {
+        version: RUN_SAVE_VERSION,
+        selected_character: CharacterId(2),
+        game_mode: GameMode::Standard,
+        seed: RunSeed(0xDEAD_BEEF),
+        resume_in_level_up: false,
+        score: 1234,
+        wave_number: 5,
+        echoes_collected: 900,
+        game_timer,
+        difficulty_timer: Timer::from_seconds(30.0, TimerMode::Repeating),
+        horror_spawn_timer: Timer::from_seconds(1.5, TimerMode::Repeating),
+        max_horrors: 35,
+        survivor: SurvivorSnapshot {
+            stats: survivor,
+            health: 64,
+            position: Vec2::new(120.0, -40.0),
+            sanity_strain: SanityStrain { base_fire_rate_secs: 0.4, fire_timer: Timer::from_seconds(0.4, TimerMode::Repeating) },
+            circle_of_warding: CircleOfWarding { is_active: true, current_radius: 110.0, ..default() },
+            swarm_of_nightmares: SwarmOfNightmares { is_active: true, num_larvae: 3, ..default() },
+        },
+        horrors: vec![HorrorSnapshot { horror_type: HorrorType::FloatingEyeball, position: Vec2::new(300.0, 10.0), health: 12, is_elite: true, elite_affixes: vec![EliteAffix::Shielded], boss: None, ward: Some(18), behavior: vec![BehaviorLayerSnapshot { state: 1, time_in_state: 0.75, aim: Vec2::X, target: Some(Vec2::new(320.0, 40.0)) }] }],
+        echoing_souls: vec![EchoingSoulSnapshot { position: Vec2::new(5.0, 5.0), value: 20 }],
+        item_drops: vec![ItemDropSnapshot { position: Vec2::new(-50.0, 80.0), item_id: ItemId(4) }],
+        run_stats: RunStats::default(),
+        broken_props: vec![(IVec2::new(2, -1), 3)],
+        reward_chests: vec![RewardChestSnapshot { position: Vec2::new(-200.0, 60.0), quality: 2.5 }],
+        obols: vec![ObolSnapshot { position: Vec2::new(15.0, -30.0), value: 7 }],
+        merchant_last_visit_wave: 6,
+    }
+}
+
+#[test]
+fn test_run_snapshot_round_trips_through_ron() {
+    let snapshot = sample_snapshot();
+    let restored = RunSnapshot::from_ron_str(&to_ron_string(&snapshot).unwrap()).expect("snapshot should parse back");
+
+    assert_eq!(restored.score, 1234);
+    assert_eq!(restored.seed, RunSeed(0xDEAD_BEEF));
+    assert_eq!(restored.broken_props, vec![(IVec2::new(2, -1), 3)]);
+    assert_eq!(restored.game_timer.elapsed(), Duration::from_secs(754));
+    assert_eq!(restored.survivor.stats.level, 7);
+    assert_eq!(restored.survivor.stats.inherent_weapon_id, AutomaticWeaponId(2));
+    assert_eq!(restored.survivor.stats.collected_item_ids, vec![ItemId(3)]);
+    assert_eq!(restored.survivor.stats.equipped_skills[0].current_cooldown, Duration::from_millis(1500));
+    assert_eq!(restored.survivor.health, 64);
+    assert!(restored.survivor.circle_of_warding.is_active);
+    assert_eq!(restored.survivor.circle_of_warding.visual_entity, None);
+    assert_eq!(restored.survivor.swarm_of_nightmares.num_larvae, 3);
+    assert_eq!(restored.horrors, snapshot.horrors);
+    assert_eq!(restored.echoing_souls, snapshot.echoing_souls);
+    assert_eq!(restored.item_drops, snapshot.item_drops);
+    assert_eq!(restored.reward_chests, snapshot.reward_chests);
+    assert_eq!(restored.obols, snapshot.obols);
+    assert_eq!(restored.merchant_last_visit_wave, 6, "the merchant doesn't come back for a wave it already visited");
+}
+
+#[test]
+fn test_run_snapshot_from_other_version_is_discarded() {
+    let mut snapshot = sample_snapshot();
+    snapshot.version = RUN_SAVE_VERSION + 1;
+    assert!(RunSnapshot::from_ron_str(&to_ron_string(&snapshot).unwrap()).is_none());
+    assert!(RunSnapshot::from_ron_str("(version: 1, score: oops)").is_none());
+}
+
+#[test]
+fn test_resumed_shielded_elite_keeps_its_wounds() {
+    let mut world = World::new();
+    let elite = world.spawn((Health(100), EliteWard::new(30))).id();
+    let snapshot = &sample_snapshot().horrors[0];
+    snapshot.restore_state(&mut world.entity_mut(elite));
+
+    assert_eq!(world.get::<Health>(elite).unwrap().0, snapshot.health);
+    assert_eq!(Some(world.get::<EliteWard>(elite).unwrap().amount), snapshot.ward, "the ward comes back as worn down as it was");
+}
+
+#[test]
+fn test_elite_ward_and_behavior_round_trip_into_a_fresh_horror() {
+    let snapshot = sample_snapshot();
+    let restored = RunSnapshot::from_ron_str(&to_ron_string(&snapshot).unwrap()).expect("snapshot should parse back");
+    let horror = &restored.horrors[0];
+    assert_eq!(horror, &snapshot.horrors[0]);
+
+    let mut world = World::new();
+    let fresh = world.spawn((Health(100), EliteWard::new(30), HorrorBehavior::new(&[&FLOATING_EYEBALL_BEHAVIOR]))).id();
+    horror.restore_state(&mut world.entity_mut(fresh));
+    assert_eq!(world.get::<EliteWard>(fresh).unwrap().amount, 18);
+    assert_eq!(world.get::<HorrorBehavior>(fresh).unwrap().snapshot(), horror.behavior);
+
+    // Saves from before these fields existed still load, with the fresh horror's own state
+    let old_format: String = to_ron_string(&snapshot).unwrap().lines().filter(|line| !line.trim_start().starts_with("ward:")).collect::<Vec<_>>().join("\n");
+    assert!(RunSnapshot::from_ron_str(&old_format).is_some_and(|old| old.horrors[0].ward.is_none()));
+}
@@ -0,0 +1,81 @@
+use eldritch_hero::run_stats::{DamageDealtEvent, DamageSource, RunHistory, RunStats, RunSummary, DPS_SAMPLE_SECS, MAX_RUN_HISTORY_ENTRIES, record_damage_dealt_system};
+use eldritch_hero::game::GameState;
+use eldritch_hero::props::Prop;
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use eldritch_hero::horror::HorrorType;
+use eldritch_hero::skills::SkillId;
+use eldritch_hero::characters::CharacterId;
+use eldritch_hero::persistence::{from_ron_str, to_ron_string};
+
+#[test]
+fn test_damage_is_tallied_per_source_and_per_sample() {
+    let mut stats = RunStats::default();
+    stats.record_damage(DamageSource::InherentWeapon, 30, 1.0);
+    stats.record_damage(DamageSource::Skill(SkillId(3)), 90, 2.0);
+    stats.record_damage(DamageSource::InherentWeapon, 45, DPS_SAMPLE_SECS + 2.0);
+    stats.record_damage(DamageSource::CircleOfWarding, 0, 3.0); // Zero-damage ticks aren't listed
+
+    assert_eq!(stats.total_damage_dealt(), 165);
+    assert_eq!(stats.damage_table(), vec![(DamageSource::Skill(SkillId(3)), 90), (DamageSource::InherentWeapon, 75)]);
+    assert_eq!(stats.damage_per_sample, vec![120, 45]);
+
+    // The last slice is divided by the five seconds actually survived in it
+    let samples = stats.dps_samples(DPS_SAMPLE_SECS + 5.0);
+    assert_eq!(samples, vec![120.0 / DPS_SAMPLE_SECS, 9.0]);
+
+    assert_eq!(DamageDealtEvent::new(DamageSource::Explosion, Entity::PLACEHOLDER, 50, 12).amount, 12, "overkill is not damage dealt");
+    assert_eq!(DamageDealtEvent::new(DamageSource::Explosion, Entity::PLACEHOLDER, 50, 80).amount, 50);
+    assert_eq!(DamageDealtEvent::new(DamageSource::Explosion, Entity::PLACEHOLDER, 50, -3).amount, 0, "an already dead horror takes nothing");
+}
+
+#[test]
+fn test_survivor_health_drops_are_damage_and_only_reported_heals_are_healing() {
+    let mut stats = RunStats::default();
+    for health in [100, 80, 85, 85, 60, 100, 125] { stats.record_survivor_health(health); }
+    assert_eq!(stats.damage_taken, 45);
+    assert_eq!(stats.healing_done, 0, "a rise alone, say from more max endurance, is not healing");
+    stats.record_healing(5);
+    stats.record_healing(0);
+    stats.record_healing(40);
+    assert_eq!(stats.healing_done, 45);
+
+    stats.record_kill(HorrorType::MindLeech, false);
+    stats.record_kill(HorrorType::MindLeech, true);
+    stats.record_kill(HorrorType::FloatingEyeball, false);
+    assert_eq!(stats.total_kills(), 3);
+    assert_eq!(stats.elites_killed, 1);
+}
+
+#[test]
+fn test_hits_on_props_are_not_damage_dealt() {
+    let mut world = World::new();
+    world.init_resource::<RunStats>();
+    world.init_resource::<GameState>();
+    world.init_resource::<Events<DamageDealtEvent>>();
+    let horror = world.spawn_empty().id();
+    let prop = world.spawn(Prop { chunk: IVec2::ZERO, index: 0 }).id();
+    world.send_event(DamageDealtEvent::new(DamageSource::InherentWeapon, horror, 20, 50));
+    world.send_event(DamageDealtEvent::new(DamageSource::InherentWeapon, prop, 40, 40));
+    world.run_system_once(record_damage_dealt_system);
+    assert_eq!(world.resource::<RunStats>().total_damage_dealt(), 20, "breaking an urn isn't damage dealt");
+}
+
+#[test]
+fn test_run_history_round_trips_and_keeps_latest_runs() {
+    let mut stats = RunStats::default();
+    stats.record_damage(DamageSource::Skill(SkillId(1)), 12, 0.0);
+    stats.record_kill(HorrorType::VoidBlinker, true);
+    let summary = |score| RunSummary {
+        finished_at_unix_secs: 1_700_000_000, character: CharacterId(2), ended_by_death: true,
+        score, survived_secs: 300, wave_reached: 10, echoes_collected: 400, stats: stats.clone(),
+    };
+
+    let mut history = RunHistory::default();
+    for score in 0..(MAX_RUN_HISTORY_ENTRIES as u32 + 5) { history.push(summary(score)); }
+    assert_eq!(history.runs.len(), MAX_RUN_HISTORY_ENTRIES);
+    assert_eq!(history.runs[0].score, 5, "the oldest runs are dropped first");
+
+    let restored: RunHistory = from_ron_str(&to_ron_string(&history).unwrap()).unwrap();
+    assert_eq!(restored.runs, history.runs);
+}
@@ -0,0 +1,52 @@
+use eldritch_hero::echoing_soul::EchoingSoul;
+use eldritch_hero::game::{AppState, SessionCleanupPlugin};
+use bevy::prelude::*;
+
+fn app_in_game() -> App {
+    let mut app = App::new();
+    app.add_plugins(MinimalPlugins).add_state::<AppState>().add_plugins(SessionCleanupPlugin);
+    app.world.resource_mut::<NextState<AppState>>().set(AppState::InGame);
+    app.update();
+    app
+}
+
+fn go_to(app: &mut App, state: AppState) {
+    app.world.resource_mut::<NextState<AppState>>().set(state);
+    app.update();
+}
+
+fn soul_count(app: &mut App) -> usize { app.world.query::<&EchoingSoul>().iter(&app.world).count() }
+
+#[test]
+fn test_souls_survive_a_merchant_visit() {
+    let mut app = app_in_game();
+    app.world.spawn(EchoingSoul { value: 25 });
+    go_to(&mut app, AppState::Merchant);
+    go_to(&mut app, AppState::InGame);
+    assert_eq!(soul_count(&mut app), 1);
+}
+
+#[test]
+fn test_souls_survive_chests_level_ups_and_pauses() {
+    let mut app = app_in_game();
+    app.world.spawn(EchoingSoul { value: 25 });
+    for state in [AppState::ChestReward, AppState::LevelUp, AppState::Paused] {
+        go_to(&mut app, state);
+        go_to(&mut app, AppState::InGame);
+    }
+    assert_eq!(soul_count(&mut app), 1);
+}
+
+#[test]
+fn test_the_field_is_cleared_when_the_run_ends() {
+    let mut app = app_in_game();
+    app.world.spawn(EchoingSoul { value: 25 });
+    go_to(&mut app, AppState::GameOver);
+    assert_eq!(soul_count(&mut app), 0);
+
+    let mut quit = app_in_game();
+    quit.world.spawn(EchoingSoul { value: 25 });
+    go_to(&mut quit, AppState::Paused);
+    go_to(&mut quit, AppState::MainMenu);
+    assert_eq!(soul_count(&mut quit), 0);
+}
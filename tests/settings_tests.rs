@@ -0,0 +1,37 @@
+use eldritch_hero::settings::{GameSettings, WindowModeSetting, MAX_SCREEN_SHAKE_INTENSITY};
+
+#[test]
+fn test_settings_round_trip_through_ron() {
+    let settings = GameSettings {
+        master_volume: 0.8,
+        music_volume: 0.3,
+        sfx_volume: 0.6,
+        show_damage_numbers: false,
+        screen_shake_intensity: 0.5,
+        window_mode: WindowModeSetting::BorderlessFullscreen,
+    };
+    let serialized = settings.to_ron_string().expect("settings should serialize");
+    let restored = GameSettings::from_ron_str(&serialized).expect("settings should deserialize");
+    assert_eq!(restored, settings);
+}
+
+#[test]
+fn test_settings_missing_fields_use_defaults() {
+    let restored = GameSettings::from_ron_str("(music_volume: 0.25)").expect("partial settings should deserialize");
+    assert_eq!(restored.music_volume, 0.25);
+    assert_eq!(restored.master_volume, GameSettings::default().master_volume);
+    assert!(restored.show_damage_numbers);
+    assert_eq!(restored.window_mode, WindowModeSetting::Windowed);
+}
+
+#[test]
+fn test_settings_adjustments_are_clamped() {
+    let mut settings = GameSettings::default();
+    GameSettings::adjust_volume(&mut settings.master_volume, 0.1);
+    assert_eq!(settings.master_volume, 1.0);
+    for _ in 0..12 { GameSettings::adjust_volume(&mut settings.sfx_volume, -0.1); }
+    assert_eq!(settings.sfx_volume, 0.0);
+    settings.adjust_screen_shake(10.0);
+    assert_eq!(settings.screen_shake_intensity, MAX_SCREEN_SHAKE_INTENSITY);
+    assert_eq!(settings.effective_sfx_volume(), 0.0);
+}
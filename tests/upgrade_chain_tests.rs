@@ -0,0 +1,74 @@
+use eldritch_hero::upgrades::{UpgradeCard, UpgradeContext, UpgradeId, UpgradePool, UpgradePrerequisite, UpgradeRarity, UpgradeType};
+use eldritch_hero::items::{AutomaticWeaponId, ItemId};
+use eldritch_hero::skills::SkillId;
+
+fn card(id: u32, prerequisites: Vec<UpgradePrerequisite>, follow_ups: Vec<UpgradeId>) -> UpgradeCard {
+    UpgradeCard::new(UpgradeId(id), format!("Card {}", id), "", UpgradeType::SurvivorSpeed(1), UpgradeRarity::Regular).with_prerequisites(prerequisites).with_follow_ups(follow_ups)
+}
+
+fn offered_ids(pool: &UpgradePool, context: &UpgradeContext) -> Vec<u32> {
+    let mut ids: Vec<u32> = pool.eligible_upgrades(context).iter().map(|card| card.id.0).collect();
+    ids.sort();
+    ids
+}
+
+#[test]
+fn test_prerequisites_must_all_hold() {
+    let context = UpgradeContext { equipped_weapon: Some(AutomaticWeaponId(4)), skills: vec![SkillId(1), SkillId(3)], picked: vec![UpgradeId(90)], relics: vec![ItemId(7)], level: 6, ..Default::default() };
+    let met = [
+        UpgradePrerequisite::OwnsCard(UpgradeId(90)),
+        UpgradePrerequisite::OwnsRelic(ItemId(7)),
+        UpgradePrerequisite::WeaponEquipped(AutomaticWeaponId(4)),
+        UpgradePrerequisite::KnowsSkill(SkillId(3)),
+        UpgradePrerequisite::MinLevel(6),
+        UpgradePrerequisite::SkillInSlot { slot_index: 1, skill_id: SkillId(3) },
+    ];
+    assert!(met.iter().all(|prerequisite| prerequisite.is_met(&context)));
+    let unmet = [
+        UpgradePrerequisite::OwnsCard(UpgradeId(91)),
+        UpgradePrerequisite::OwnsRelic(ItemId(8)),
+        UpgradePrerequisite::WeaponEquipped(AutomaticWeaponId(5)),
+        UpgradePrerequisite::KnowsSkill(SkillId(2)),
+        UpgradePrerequisite::MinLevel(7),
+        UpgradePrerequisite::SkillInSlot { slot_index: 0, skill_id: SkillId(3) },
+    ];
+    assert!(unmet.iter().all(|prerequisite| !prerequisite.is_met(&context)));
+
+    let mut pool = UpgradePool::default();
+    pool.available_upgrades = vec![card(1, met.to_vec(), Vec::new()), card(2, vec![met[0].clone(), unmet[4].clone()], Vec::new())];
+    assert_eq!(offered_ids(&pool, &context), vec![1]);
+}
+
+#[test]
+fn test_follow_ups_unlock_once_any_parent_is_picked() {
+    let mut pool = UpgradePool::default();
+    pool.available_upgrades = vec![
+        card(1, Vec::new(), vec![UpgradeId(3)]),
+        card(2, Vec::new(), vec![UpgradeId(3), UpgradeId(4)]),
+        card(3, Vec::new(), Vec::new()),
+        card(4, Vec::new(), Vec::new()),
+    ];
+    let mut context = UpgradeContext::default();
+    assert_eq!(offered_ids(&pool, &context), vec![1, 2]);
+    context.picked.push(UpgradeId(1));
+    assert_eq!(offered_ids(&pool, &context), vec![1, 2, 3]);
+    context.picked.push(UpgradeId(2));
+    assert_eq!(offered_ids(&pool, &context), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_circle_of_warding_chain_is_described_both_ways() {
+    let mut pool = UpgradePool::default();
+    pool.initialize();
+    let circle = pool.get_card(UpgradeId(100)).expect("Inscribe Circle of Warding").clone();
+    let radius = pool.get_card(UpgradeId(101)).expect("Echoing Wards").clone();
+    assert!(matches!(radius.upgrade_type, UpgradeType::IncreaseCircleRadius(_)));
+
+    let context = UpgradeContext::default();
+    assert!(!pool.is_unlocked(&radius, &context), "no radius before there is a circle");
+    assert!(pool.is_unlocked(&radius, &UpgradeContext { picked: vec![circle.id], ..context }));
+
+    assert!(pool.chain_summary(&circle).is_some_and(|summary| summary.contains("Unlocks:") && summary.contains(&radius.name)));
+    assert_eq!(pool.chain_summary(&radius), Some(format!("Follows: {}", circle.name)));
+    assert_eq!(pool.chain_summary(&card(9999, Vec::new(), Vec::new())), None);
+}
@@ -0,0 +1,84 @@
+use eldritch_hero::upgrades::{draw_upgrades, is_upgrade_eligible, rarity_weight, UpgradeCard, UpgradeContext, UpgradeId, UpgradePool, UpgradeRarity, UpgradeType, StandardProjectileField, DEFAULT_MAX_STACKS, FILLER_UPGRADE_ID};
+use eldritch_hero::items::AutomaticWeaponId;
+use eldritch_hero::skills::SkillId;
+use rand::{rngs::StdRng, SeedableRng};
+
+fn card(id: u32, upgrade_type: UpgradeType, rarity: UpgradeRarity) -> UpgradeCard {
+    UpgradeCard::new(UpgradeId(id), format!("Card {}", id), "", upgrade_type, rarity)
+}
+
+#[test]
+fn test_luck_tilts_the_draw_towards_rarer_cards() {
+    assert!(rarity_weight(UpgradeRarity::Regular, 0.0) > rarity_weight(UpgradeRarity::Rare, 0.0));
+    assert!(rarity_weight(UpgradeRarity::Rare, 0.0) > rarity_weight(UpgradeRarity::Legendary, 0.0));
+    assert_eq!(rarity_weight(UpgradeRarity::Legendary, -50.0), rarity_weight(UpgradeRarity::Legendary, 0.0), "bad luck never hurts");
+
+    let cards: Vec<UpgradeCard> = (0..6).map(|i| card(i, UpgradeType::SurvivorSpeed(1), UpgradeRarity::Regular))
+        .chain((6..8).map(|i| card(i, UpgradeType::SurvivorSpeed(1), UpgradeRarity::Legendary)))
+        .collect();
+    let mut rng = StdRng::seed_from_u64(21);
+    let mut legendary = [0usize; 2];
+    for (slot, luck) in [0.0, 200.0].into_iter().enumerate() {
+        for _ in 0..500 {
+            let hand = draw_upgrades(&cards, 3, luck, &mut rng);
+            assert_eq!(hand.len(), 3);
+            assert!(hand.iter().all(|drawn| hand.iter().filter(|other| other.id == drawn.id).count() == 1), "no card twice in a hand");
+            legendary[slot] += hand.iter().filter(|drawn| drawn.rarity == UpgradeRarity::Legendary).count();
+        }
+    }
+    assert!(legendary[1] > legendary[0] * 2, "luck brings more legendaries ({:?})", legendary);
+}
+
+#[test]
+fn test_only_cards_that_can_matter_are_eligible() {
+    let tune = |weapon: u32| UpgradeType::ModifyStandardProjectile { weapon_id: AutomaticWeaponId(weapon), field: StandardProjectileField::BaseDamage, change_value: 1.0, is_percentage: false };
+    let context = UpgradeContext { equipped_weapon: Some(AutomaticWeaponId(2)), skills: vec![SkillId(1)], ..Default::default() };
+    let eligible = |upgrade_type: UpgradeType| is_upgrade_eligible(&card(1, upgrade_type, UpgradeRarity::Regular), &context);
+
+    assert!(eligible(tune(2)));
+    assert!(!eligible(tune(3)), "tunes a weapon the survivor doesn't carry");
+    assert!(!eligible(UpgradeType::IncreaseEvasionChance(5.0)), "nothing reads evasion yet");
+    assert!(!eligible(UpgradeType::GrantSkill(SkillId(1))), "already known");
+    assert!(eligible(UpgradeType::GrantSkill(SkillId(2))));
+    assert!(eligible(UpgradeType::IncreaseSkillDamage { skill_id: SkillId(1), amount: 1 }));
+    assert!(!eligible(UpgradeType::IncreaseSkillDamage { skill_id: SkillId(2), amount: 1 }), "skill not known");
+
+    let banished = UpgradeContext { banished: vec![UpgradeId(1)], ..context.clone() };
+    assert!(!is_upgrade_eligible(&card(1, UpgradeType::SurvivorSpeed(1), UpgradeRarity::Regular), &banished));
+}
+
+#[test]
+fn test_cards_stop_being_offered_at_max_stacks() {
+    let mut pool = UpgradePool::default();
+    pool.available_upgrades = vec![card(1, UpgradeType::SurvivorSpeed(1), UpgradeRarity::Regular), card(2, UpgradeType::GrantSkill(SkillId(9)), UpgradeRarity::Rare)];
+    let mut context = UpgradeContext { picked: vec![UpgradeId(1); DEFAULT_MAX_STACKS as usize - 1], ..Default::default() };
+    let mut rng = StdRng::seed_from_u64(22);
+    assert_eq!(pool.offer_upgrades(3, &context, &mut rng).len(), 2);
+
+    context.picked.push(UpgradeId(1));
+    let offered = pool.offer_upgrades(3, &context, &mut rng);
+    assert_eq!(offered.iter().map(|offered| offered.id).collect::<Vec<_>>(), vec![UpgradeId(2)]);
+    assert_eq!(UpgradeType::GrantSkill(SkillId(9)).max_stacks(), 1);
+}
+
+#[test]
+fn test_an_exhausted_pool_still_deals_a_card() {
+    let mut pool = UpgradePool::default();
+    pool.available_upgrades = vec![card(1, UpgradeType::GrantSkill(SkillId(9)), UpgradeRarity::Rare)];
+    let context = UpgradeContext { picked: vec![UpgradeId(1)], ..Default::default() };
+    let offered = pool.offer_upgrades(3, &context, &mut StdRng::seed_from_u64(23));
+    assert_eq!(offered.len(), 1, "the level-up screen always has something to pick");
+    assert_eq!(offered[0].id, FILLER_UPGRADE_ID);
+    assert!(matches!(offered[0].upgrade_type, UpgradeType::RestoreEndurance(heal) if heal > 0));
+}
+
+#[test]
+fn test_skill_cards_follow_the_skill_whatever_slot_it_sits_in() {
+    let mut pool = UpgradePool::default();
+    pool.initialize();
+    let void_seer = UpgradeContext { skills: vec![SkillId(3)], ..Default::default() };
+    let offered: Vec<UpgradeId> = pool.eligible_upgrades(&void_seer).iter().map(|card| card.id).collect();
+    assert!(offered.contains(&UpgradeId(502)), "Sharpen Void Lance with Void Lance in the first slot");
+    assert!(offered.contains(&UpgradeId(802)), "Accelerated Void likewise");
+    assert!(!offered.contains(&UpgradeId(500)), "no Eldritch Bolt cards without Eldritch Bolt");
+}
@@ -16,6 +16,7 @@ fn setup_test_app_with_resources() -> App {
         id: AutomaticWeaponId(0), // Dummy weapon
         name: "Test Blaster".to_string(),
         attack_data: AttackTypeData::StandardProjectile(StandardProjectileParams::default()),
+        ..default()
     });
     app
 }
@@ -24,30 +25,30 @@ fn setup_test_app_with_resources() -> App {
 fn setup_test_upgrade_pool() -> UpgradePool {
     let mut pool = UpgradePool::default();
     // Add a subset of varied upgrades for testing
-    pool.available_upgrades.push(UpgradeCard {
-        id: UpgradeId(1001), name: "Test Skill Damage Up".to_string(), 
-        description: "Test +5 Dmg to Skill 0".to_string(), 
-        upgrade_type: UpgradeType::IncreaseSkillDamage { slot_index: 0, amount: 5 },
-        rarity: UpgradeRarity::Regular,
-    });
-    pool.available_upgrades.push(UpgradeCard {
-        id: UpgradeId(1002), name: "Test Skill Cooldown Up".to_string(), 
-        description: "Test -10% Cooldown to Skill 0".to_string(), 
-        upgrade_type: UpgradeType::ReduceSkillCooldown { slot_index: 0, percent_reduction: 0.10 },
-        rarity: UpgradeRarity::Regular,
-    });
-    pool.available_upgrades.push(UpgradeCard {
-        id: UpgradeId(1003), name: "Test Stat Up".to_string(),
-        description: "Test +10 Speed".to_string(),
-        upgrade_type: UpgradeType::SurvivorSpeed(10), // Assuming SurvivorSpeed exists
-        rarity: UpgradeRarity::Regular,
-    });
-     pool.available_upgrades.push(UpgradeCard {
-        id: UpgradeId(1004), name: "Test Skill Grant".to_string(),
-        description: "Grants Test Skill".to_string(),
-        upgrade_type: UpgradeType::GrantSkill(SkillId(99)), // A dummy SkillId for testing
-        rarity: UpgradeRarity::Regular,
-    });
+    pool.available_upgrades.push(UpgradeCard::new(
+        UpgradeId(1001), "Test Skill Damage Up",
+        "Test +5 Dmg to Eldritch Bolt",
+        UpgradeType::IncreaseSkillDamage { skill_id: SkillId(1), amount: 5 },
+        UpgradeRarity::Regular,
+    ));
+    pool.available_upgrades.push(UpgradeCard::new(
+        UpgradeId(1002), "Test Skill Cooldown Up",
+        "Test -10% Cooldown to Eldritch Bolt",
+        UpgradeType::ReduceSkillCooldown { skill_id: SkillId(1), percent_reduction: 0.10 },
+        UpgradeRarity::Regular,
+    ));
+    pool.available_upgrades.push(UpgradeCard::new(
+        UpgradeId(1003), "Test Stat Up",
+        "Test +10 Speed",
+        UpgradeType::SurvivorSpeed(10), // Assuming SurvivorSpeed exists
+        UpgradeRarity::Regular,
+    ));
+     pool.available_upgrades.push(UpgradeCard::new(
+        UpgradeId(1004), "Test Skill Grant",
+        "Grants Test Skill",
+        UpgradeType::GrantSkill(SkillId(99)), // A dummy SkillId for testing
+        UpgradeRarity::Regular,
+    ));
     pool
 }
 
@@ -87,7 +88,7 @@ fn test_apply_skill_damage_upgrade() {
     //     &weapon_library_res // Pass as a reference to Res<T>
     // );
     
-    // let upgrade = UpgradeType::IncreaseSkillDamage { slot_index: 0, amount: 10 };
+    // let upgrade = UpgradeType::IncreaseSkillDamage { skill_id: SkillId(1), amount: 10 };
     
     // // Simulate apply_chosen_upgrade logic for this specific upgrade
     // if let Some(skill_instance) = survivor.equipped_skills.get_mut(0) {
@@ -117,7 +118,7 @@ fn test_apply_skill_cooldown_upgrade() {
     //     &weapon_library_res // Pass as a reference to Res<T>
     // );
     
-    // let upgrade = UpgradeType::ReduceSkillCooldown { slot_index: 0, percent_reduction: 0.20 };
+    // let upgrade = UpgradeType::ReduceSkillCooldown { skill_id: SkillId(1), percent_reduction: 0.20 };
 
     // // Simulate apply_chosen_upgrade logic
     // if let Some(skill_instance) = survivor.equipped_skills.get_mut(0) {